@@ -118,6 +118,8 @@ async fn main() {
             location: "Cornucopia".into(),
             allies: vec![],
             kill_streak: 4,
+            dominant_emotion: Some("furious".into()),
+            goal: None,
             notable_events: vec![
                 "Cato is on fire — 4 kills in a row!".into(),
                 "Killed Peeta (combat)".into(),
@@ -141,6 +143,8 @@ async fn main() {
             location: "Forest".into(),
             allies: vec![],
             kill_streak: 1,
+            dominant_emotion: None,
+            goal: None,
             notable_events: vec!["Found bow in Forest".into()],
             highlights: vec!["Killed Marvel (combat)".into()],
         },
@@ -153,6 +157,8 @@ async fn main() {
             location: "Cornucopia".into(),
             allies: vec![],
             kill_streak: 0,
+            dominant_emotion: None,
+            goal: None,
             notable_events: vec!["Killed by Cato".into()],
             highlights: vec!["Killed by Cato (combat)".into()],
         },
@@ -259,10 +259,117 @@ impl BroadcastPackageBuilder {
                 })
             }
 
+            MessagePayload::WeatherChanged { area, from, to } => {
+                let structured = serde_json::json!({
+                    "type": "weather_changed",
+                    "area": area.name,
+                    "from": from,
+                    "to": to,
+                });
+                Some(EventLine {
+                    kind: EventKind::Hazard,
+                    prose,
+                    structured: Some(structured),
+                })
+            }
+
+            // ---- Gamemaker interventions ----
+            MessagePayload::FireballStrike {
+                area,
+                victims,
+                survivors,
+            } => {
+                let names = |refs: &[shared::messages::TributeRef]| {
+                    refs.iter().map(|t| t.name.clone()).collect::<Vec<_>>()
+                };
+                let structured = serde_json::json!({
+                    "type": "fireball",
+                    "area": area.name,
+                    "victims": names(victims),
+                    "survivors": names(survivors),
+                });
+                Some(EventLine {
+                    kind: EventKind::Hazard,
+                    prose,
+                    structured: Some(structured),
+                })
+            }
+
+            MessagePayload::MuttSwarmAttack {
+                kind: animal,
+                victim,
+                damage,
+                killed,
+                ..
+            } => {
+                let structured = serde_json::json!({
+                    "type": "mutt_attack",
+                    "animal": animal,
+                    "victim": { "id": victim.identifier, "name": victim.name },
+                    "damage": damage,
+                    "killed": killed,
+                });
+                Some(EventLine {
+                    kind: EventKind::Hazard,
+                    prose,
+                    structured: Some(structured),
+                })
+            }
+
+            MessagePayload::MuttSwarmSpawned { .. }
+            | MessagePayload::MuttSwarmDespawned { .. }
+            | MessagePayload::ForceFieldShifted { .. }
+            | MessagePayload::AreaSealEntryDamage { .. } => Some(EventLine {
+                kind: EventKind::Hazard,
+                prose,
+                structured: None,
+            }),
+
+            MessagePayload::ConvergencePointAnnounced { .. }
+            | MessagePayload::ConvergencePointExpired { .. } => Some(EventLine {
+                kind: EventKind::Item,
+                prose,
+                structured: None,
+            }),
+
+            // ---- Contagions ----
+            MessagePayload::ContagionInfected {
+                tribute,
+                source,
+                contagion,
+            } => {
+                let structured = serde_json::json!({
+                    "type": "contagion_infected",
+                    "tribute": { "id": tribute.identifier, "name": tribute.name },
+                    "source": source.as_ref().map(|s| s.name.clone()),
+                    "contagion": contagion,
+                });
+                Some(EventLine {
+                    kind: EventKind::Condition,
+                    prose,
+                    structured: Some(structured),
+                })
+            }
+
+            MessagePayload::ContagionTransformed { tribute, contagion } => {
+                let structured = serde_json::json!({
+                    "type": "contagion_transformed",
+                    "tribute": { "id": tribute.identifier, "name": tribute.name },
+                    "contagion": contagion,
+                });
+                Some(EventLine {
+                    kind: EventKind::Condition,
+                    prose,
+                    structured: Some(structured),
+                })
+            }
+
             // ---- Item events: prose-only ----
             MessagePayload::ItemFound { .. }
             | MessagePayload::ItemUsed { .. }
-            | MessagePayload::ItemDropped { .. } => Some(EventLine {
+            | MessagePayload::ItemDropped { .. }
+            | MessagePayload::CorpseLeft { .. }
+            | MessagePayload::CorpseLooted { .. } => Some(EventLine {
                 kind: EventKind::Item,
                 prose,
                 structured: None,
@@ -680,6 +787,47 @@ mod tests {
         assert_eq!(data["kind"], "fire");
     }
 
+    #[test]
+    fn classifies_weather_change() {
+        let msg = make_msg(MessagePayload::WeatherChanged {
+            area: ar("Forest"),
+            from: "light rain".into(),
+            to: "storm".into(),
+        });
+        let line = BroadcastPackageBuilder::classify_event(&msg).unwrap();
+        assert_eq!(line.kind, EventKind::Hazard);
+        let data = line.structured.unwrap();
+        assert_eq!(data["type"], "weather_changed");
+        assert_eq!(data["to"], "storm");
+    }
+
+    #[test]
+    fn classifies_contagion_transformation() {
+        let msg = make_msg(MessagePayload::ContagionTransformed {
+            tribute: tr("Cato"),
+            contagion: shared::afflictions::Contagion::Lycanthropy,
+        });
+        let line = BroadcastPackageBuilder::classify_event(&msg).unwrap();
+        assert_eq!(line.kind, EventKind::Condition);
+        let data = line.structured.unwrap();
+        assert_eq!(data["type"], "contagion_transformed");
+        assert_eq!(data["contagion"], "lycanthropy");
+    }
+
+    #[test]
+    fn classifies_fireball_strike() {
+        let msg = make_msg(MessagePayload::FireballStrike {
+            area: ar("Sector 2"),
+            victims: vec![tr("Glimmer")],
+            survivors: vec![tr("Cato")],
+        });
+        let line = BroadcastPackageBuilder::classify_event(&msg).unwrap();
+        assert_eq!(line.kind, EventKind::Hazard);
+        let data = line.structured.unwrap();
+        assert_eq!(data["type"], "fireball");
+        assert_eq!(data["victims"][0], "Glimmer");
+    }
+
     #[test]
     fn classifies_game_ended() {
         let msg = make_msg(MessagePayload::GameEnded {
@@ -297,6 +297,21 @@ impl TributeHistories {
                     );
                 }
 
+                MessagePayload::CorpseLooted {
+                    looter,
+                    corpse,
+                    item,
+                    area,
+                } => {
+                    self.push_event(
+                        &looter.identifier,
+                        &format!(
+                            "Took {} off {}'s body in {}",
+                            item.name, corpse.name, area.name
+                        ),
+                    );
+                }
+
                 MessagePayload::SponsorGift {
                     recipient, item, ..
                 } => {
@@ -497,6 +512,46 @@ impl TributeHistories {
                     self.push_event(tribute, &format!("Craving {substance}"));
                 }
 
+                // ------- Contagions -------
+                MessagePayload::ContagionInfected {
+                    tribute,
+                    source,
+                    contagion,
+                } => {
+                    let event = match source {
+                        Some(source) => format!("Caught {contagion} from {}", source.name),
+                        None => format!("Patient zero for {contagion}"),
+                    };
+                    self.push_event(&tribute.identifier, &event);
+                }
+
+                MessagePayload::ContagionTransformed { tribute, contagion } => {
+                    self.push_event(&tribute.identifier, &format!("Transformed by {contagion}"));
+                }
+
+                // ------- Gamemaker interventions -------
+                MessagePayload::FireballStrike { survivors, .. } => {
+                    for survivor in survivors {
+                        self.push_event(&survivor.identifier, "Survived a gamemaker fireball");
+                    }
+                }
+
+                MessagePayload::MuttSwarmAttack {
+                    kind,
+                    victim,
+                    killed: false,
+                    ..
+                } => {
+                    self.push_event(&victim.identifier, &format!("Mauled by {kind} mutts"));
+                }
+
+                MessagePayload::AreaSealEntryDamage { tribute, area, .. } => {
+                    self.push_event(
+                        &tribute.identifier,
+                        &format!("Scorched by the force field in {}", area.name),
+                    );
+                }
+
                 // ------- Cycle / phase: skip (no tribute-specific content) -------
                 MessagePayload::CycleStart { .. }
                 | MessagePayload::CycleEnd { .. }
@@ -524,6 +579,14 @@ impl TributeHistories {
                 | MessagePayload::FixationFaded { .. }
                 | MessagePayload::FixationThwarted { .. }
                 | MessagePayload::AreaEvent { .. }
+                | MessagePayload::WeatherChanged { .. }
+                | MessagePayload::MuttSwarmAttack { .. }
+                | MessagePayload::MuttSwarmSpawned { .. }
+                | MessagePayload::MuttSwarmDespawned { .. }
+                | MessagePayload::ForceFieldShifted { .. }
+                | MessagePayload::ConvergencePointAnnounced { .. }
+                | MessagePayload::ConvergencePointExpired { .. }
+                | MessagePayload::CorpseLeft { .. }
                 | MessagePayload::AreaClosed { .. }
                 | MessagePayload::Generic
                 | MessagePayload::TributeTrapped { .. }
@@ -556,6 +619,23 @@ impl TributeHistories {
         digests
     }
 
+    /// Record a tribute's current dominant emotion. Emotions live on the
+    /// tribute rather than in the message stream, so callers refresh this
+    /// from game state each phase.
+    pub fn set_dominant_emotion(&mut self, identifier: &str, label: impl Into<String>) {
+        if let Some(digest) = self.inner.get_mut(identifier) {
+            digest.dominant_emotion = Some(label.into());
+        }
+    }
+
+    /// Record what a tribute has set out to do, or that it has nothing in
+    /// mind. Like emotions, goals live on the tribute.
+    pub fn set_goal(&mut self, identifier: &str, goal: Option<String>) {
+        if let Some(digest) = self.inner.get_mut(identifier) {
+            digest.goal = goal;
+        }
+    }
+
     /// Return the digest for a specific tribute, if present.
     pub fn get(&self, name: &str) -> Option<&TributeDigest> {
         self.inner.get(name)
@@ -706,6 +786,8 @@ mod tests {
             location: "Cornucopia".into(),
             allies: vec![],
             kill_streak: 0,
+            dominant_emotion: None,
+            goal: None,
             highlights: vec![],
             notable_events: vec![],
         }
@@ -873,4 +955,28 @@ mod tests {
             0
         );
     }
+
+    #[test]
+    fn dominant_emotion_is_refreshed_by_identifier() {
+        let mut histories = TributeHistories::new(vec![make_tribute("Rue", 11)]);
+        let id = test_uuid("Rue").to_string();
+        histories.set_dominant_emotion(&id, "panicked");
+        histories.set_dominant_emotion("nobody", "furious");
+        let digests = histories.digests();
+        assert_eq!(digests.len(), 1);
+        assert_eq!(digests[0].dominant_emotion.as_deref(), Some("panicked"));
+    }
+
+    #[test]
+    fn goal_is_refreshed_and_cleared_by_identifier() {
+        let mut histories = TributeHistories::new(vec![make_tribute("Rue", 11)]);
+        let id = test_uuid("Rue").to_string();
+        histories.set_goal(&id, Some("find food in Sector 2".into()));
+        assert_eq!(
+            histories.digests()[0].goal.as_deref(),
+            Some("find food in Sector 2")
+        );
+        histories.set_goal(&id, None);
+        assert_eq!(histories.digests()[0].goal, None);
+    }
 }
@@ -70,6 +70,8 @@ pub(crate) fn parse_response(text: &str) -> Vec<CommentaryLine> {
 ///
 /// Implementations must be `Send + Sync` so they can be shared across
 /// async API handlers via `Arc<dyn Commentator>`.
+// `async_trait` stamps `#[must_use]` on the boxed future it returns.
+#[allow(clippy::double_must_use)]
 #[async_trait]
 pub trait Commentator: Send + Sync {
     /// Generate a commentary segment for one phase.
@@ -94,6 +94,25 @@ impl OllamaCommentator {
             body.push('\n');
         }
 
+        // ── Alliances ──
+        if !package.header.alliances.is_empty() {
+            body.push_str("=== ALLIANCES ===\n");
+            for alliance in &package.header.alliances {
+                let followers: Vec<&str> = alliance
+                    .members
+                    .iter()
+                    .filter(|m| **m != alliance.leader)
+                    .map(String::as_str)
+                    .collect();
+                body.push_str(&format!(
+                    "• {} leads {}\n",
+                    alliance.leader,
+                    followers.join(", ")
+                ));
+            }
+            body.push('\n');
+        }
+
         // ── Phase events ──
         body.push_str("=== PHASE EVENTS ===\n");
         for event in &package.events {
@@ -120,6 +139,12 @@ impl OllamaCommentator {
                     "{} {} (D{}) — {}, {}, at {}\n",
                     status_icon, t.name, t.district, t.status, t.injury_level, t.location,
                 ));
+                if let Some(emotion) = &t.dominant_emotion {
+                    body.push_str(&format!("  Mood: {emotion}\n"));
+                }
+                if let Some(goal) = &t.goal {
+                    body.push_str(&format!("  Goal: {goal}\n"));
+                }
                 // Highlights (permanent).
                 for h in &t.highlights {
                     body.push_str(&format!("  ★ {h}\n"));
@@ -91,6 +91,9 @@ pub fn spree_label(streak: u32) -> &'static str {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AllianceInfo {
     pub members: Vec<String>,
+    /// Name of the member the others follow.
+    #[serde(default)]
+    pub leader: String,
 }
 
 /// An area with elevated activity (combats, hazards, etc.).
@@ -147,6 +150,14 @@ pub struct TributeDigest {
     /// Current kill streak (consecutive kills without dying or a dry phase).
     #[serde(default)]
     pub kill_streak: u32,
+    /// Label for the tribute's dominant emotion ("calm", "furious", ...),
+    /// refreshed from the live tribute each phase.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dominant_emotion: Option<String>,
+    /// What the tribute has set out to do ("secure water, heading for
+    /// Sector 4"), refreshed from the live tribute each phase.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goal: Option<String>,
     /// Rolling log of notable events for this tribute, newest first.
     /// Capped at 30 entries (prunes oldest).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -81,6 +81,8 @@ fn make_tribute(name: &str, district: u8) -> TributeDigest {
         location: "Cornucopia".into(),
         allies: vec![],
         kill_streak: 0,
+        dominant_emotion: None,
+        goal: None,
         highlights: vec![],
         notable_events: vec![],
     }
@@ -0,0 +1,265 @@
+//! Spectator betting.
+//!
+//! Bets are staked from the player's sponsor wallet at the odds
+//! `Game::price` quotes when the bet is placed; those odds are locked in.
+//! After every day `next_step` runs, `settle_bets` walks the persisted
+//! messages in order and pays out winning bets into the same wallet.
+//!
+//! The `bet` table is server-side only, like the wallet.
+
+use crate::games::orders::auth_user_id;
+use crate::games::snapshots::later_phases;
+use crate::games::sponsorship::{charge_wallet, credit_wallet, refund_charge};
+use crate::{AppError, AppState, AuthDb};
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use game::messages::GameMessage;
+use serde::{Deserialize, Serialize};
+use shared::GameStatus;
+use shared::betting::{Bet, BetStatus, Market, OddsBoard, PlaceBet};
+use shared::messages::Phase;
+use surrealdb::Surreal;
+use surrealdb::engine::any::Any;
+use surrealdb_types::RecordId;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct BetRow {
+    identifier: String,
+    /// JSON-encoded `Market`, like message payloads.
+    market: String,
+    tribute_name: String,
+    stake: u32,
+    payout: f64,
+    status: BetStatus,
+    placed_day: u32,
+}
+
+impl BetRow {
+    fn into_bet(self) -> Option<Bet> {
+        Some(Bet {
+            market: serde_json::from_str(&self.market).ok()?,
+            identifier: self.identifier,
+            tribute_name: self.tribute_name,
+            stake: self.stake,
+            payout: self.payout,
+            status: self.status,
+            placed_day: self.placed_day,
+        })
+    }
+}
+
+/// Current odds on every open market of a game.
+pub async fn game_odds(
+    Path(game_identifier): Path<Uuid>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+) -> Result<Json<OddsBoard>, AppError> {
+    let game = super::get_full_game(&game_identifier.to_string(), &db).await?;
+    Ok(Json(game.odds_board()))
+}
+
+/// The caller's bets on a game, newest first.
+pub async fn my_bets(
+    Path(game_identifier): Path<Uuid>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+) -> Result<Json<Vec<Bet>>, AppError> {
+    let user_id = auth_user_id(&db).await?;
+    let mut response = state
+        .db
+        .query(
+            "SELECT * FROM bet WHERE game_id = $game AND user_id = $user \
+             ORDER BY placed_at DESC",
+        )
+        .bind(("game", game_identifier.to_string()))
+        .bind(("user", user_id))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch bets: {e}")))?;
+    let rows: Vec<serde_json::Value> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to take bets: {e}")))?;
+    let bets = rows
+        .into_iter()
+        .filter_map(|row| serde_json::from_value::<BetRow>(row).ok())
+        .filter_map(BetRow::into_bet)
+        .collect();
+    Ok(Json(bets))
+}
+
+/// Stake play money on a market at its current odds.
+pub async fn place_bet(
+    Path(game_identifier): Path<Uuid>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+    Json(payload): Json<PlaceBet>,
+) -> Result<Json<Bet>, AppError> {
+    if payload.stake == 0 {
+        return Err(AppError::ValidationError(
+            "Stake must be at least 1".to_string(),
+        ));
+    }
+    let game = super::get_full_game(&game_identifier.to_string(), &db).await?;
+    if game.status == GameStatus::Finished {
+        return Err(AppError::InvalidStatus("Game is finished".into()));
+    }
+    let odds = game
+        .price(&payload.market)
+        .ok_or_else(|| AppError::BadRequest("That market is closed".to_string()))?;
+
+    let market = serde_json::to_string(&payload.market)
+        .map_err(|e| AppError::InternalServerError(format!("Failed to encode market: {e}")))?;
+
+    let user_id = auth_user_id(&db).await?;
+    let (wallet, _) = charge_wallet(&state.db, &game, &user_id, payload.stake)
+        .await?
+        .ok_or_else(|| {
+            AppError::BadRequest(format!("Not enough funds for a stake of {}", payload.stake))
+        })?;
+
+    let bet = Bet {
+        identifier: Uuid::new_v4().to_string(),
+        market: payload.market,
+        tribute_name: odds.tribute_name,
+        stake: payload.stake,
+        payout: odds.payout,
+        status: BetStatus::Open,
+        placed_day: game.day.unwrap_or(0),
+    };
+    let recorded = state
+        .db
+        .query(
+            "CREATE $bet CONTENT { identifier: $identifier, game_id: $game, user_id: $user, \
+             wallet: $wallet, market: $market, tribute_name: $tribute_name, stake: $stake, \
+             payout: $payout, status: 'open', placed_day: $day, placed_at: time::now() }",
+        )
+        .bind(("bet", RecordId::new("bet", bet.identifier.as_str())))
+        .bind(("identifier", bet.identifier.clone()))
+        .bind(("game", game.identifier.clone()))
+        .bind(("user", user_id))
+        .bind(("wallet", wallet.clone()))
+        .bind(("market", market))
+        .bind(("tribute_name", bet.tribute_name.clone()))
+        .bind(("stake", bet.stake))
+        .bind(("payout", bet.payout))
+        .bind(("day", bet.placed_day))
+        .await
+        .and_then(|response| response.check());
+    if let Err(e) = recorded {
+        // The stake is already gone; give it back rather than lose it.
+        refund_charge(&state.db, wallet, bet.stake).await?;
+        return Err(AppError::DbError(format!("Failed to record bet: {e}")));
+    }
+
+    Ok(Json(bet))
+}
+
+/// Settle open bets against the messages a day just produced and pay the
+/// winners.
+pub(crate) async fn settle_bets(
+    root: &Surreal<Any>,
+    game_identifier: &str,
+    messages: &[GameMessage],
+) -> Result<(), AppError> {
+    #[derive(Debug, Deserialize, Serialize)]
+    struct OpenBet {
+        id: RecordId,
+        wallet: RecordId,
+        market: String,
+        stake: u32,
+        payout: f64,
+    }
+
+    if messages.is_empty() {
+        return Ok(());
+    }
+    let mut response = root
+        .query("SELECT id, wallet, market, stake, payout FROM bet WHERE game_id = $game AND status = 'open'")
+        .bind(("game", game_identifier.to_string()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch open bets: {e}")))?;
+    let open: Vec<surrealdb_types::SerdeWrapper<OpenBet>> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to take open bets: {e}")))?;
+
+    for bet in open.into_iter().map(|w| w.0) {
+        let Ok(market) = serde_json::from_str::<Market>(&bet.market) else {
+            continue;
+        };
+        let Some((won, message)) = messages
+            .iter()
+            .find_map(|m| game::odds::settle(&market, &m.payload, m.game_day).map(|won| (won, m)))
+        else {
+            continue;
+        };
+        let status = if won { "won" } else { "lost" };
+        root.query(
+            "UPDATE $bet SET status = $status, settled_at = time::now(), \
+             settled_day = $day, settled_phase = $phase",
+        )
+        .bind(("bet", bet.id))
+        .bind(("status", status))
+        .bind(("day", message.game_day))
+        .bind(("phase", message.phase.to_string()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to settle bet: {e}")))?;
+        if won {
+            let winnings = (bet.stake as f64 * bet.payout).floor() as u32;
+            credit_wallet(root, bet.wallet, winnings).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Reopen the bets a rollback un-settles: those settled by a message after
+/// `phase` on `day`. Winnings are taken back, as far as the wallet still
+/// holds them.
+pub(crate) async fn reopen_bets(
+    root: &Surreal<Any>,
+    game_identifier: &str,
+    day: u32,
+    phase: Phase,
+) -> Result<(), AppError> {
+    #[derive(Debug, Deserialize, Serialize)]
+    struct SettledBet {
+        id: RecordId,
+        wallet: RecordId,
+        stake: u32,
+        payout: f64,
+        status: String,
+    }
+
+    let mut response = root
+        .query(
+            "SELECT id, wallet, stake, payout, status FROM bet WHERE game_id = $game \
+             AND (settled_day > $day OR (settled_day = $day AND settled_phase IN $later))",
+        )
+        .bind(("game", game_identifier.to_string()))
+        .bind(("day", day))
+        .bind(("later", later_phases(phase)))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch settled bets: {e}")))?;
+    let settled: Vec<surrealdb_types::SerdeWrapper<SettledBet>> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to take settled bets: {e}")))?;
+
+    for bet in settled.into_iter().map(|w| w.0) {
+        if bet.status == "won" {
+            let winnings = (bet.stake as f64 * bet.payout).floor() as u32;
+            root.query("UPDATE $rid SET balance = math::max([balance - $amount, 0])")
+                .bind(("rid", bet.wallet))
+                .bind(("amount", winnings))
+                .await
+                .and_then(|response| response.check())
+                .map_err(|e| AppError::DbError(format!("Failed to take back winnings: {e}")))?;
+        }
+        root.query(
+            "UPDATE $bet SET status = 'open', settled_at = NONE, settled_day = NONE, \
+             settled_phase = NONE",
+        )
+        .bind(("bet", bet.id))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| AppError::DbError(format!("Failed to reopen bet: {e}")))?;
+    }
+    Ok(())
+}
@@ -13,7 +13,6 @@ use shared::{
     PaginationMetadata,
 };
 use std::collections::HashMap;
-use strum::IntoEnumIterator;
 use surrealdb_types::{RecordId, SerdeWrapper};
 use uuid::Uuid;
 use validator::Validate;
@@ -29,84 +28,8 @@ pub async fn create_game(
         .validate()
         .map_err(|e| AppError::ValidationError(format!("{}", e)))?;
 
-    // Generate server-controlled fields. Game::default() runs WPGen to
-    // produce a three-word "clever" name; use it as the fallback when
-    // the client didn't supply one (e.g. Quickstart).
-    let default_game = Game::default();
-    let game_identifier = Uuid::new_v4().to_string();
-    let game_name = payload.name.unwrap_or(default_game.name);
-
-    // Build the UPSERT body BEFORE constructing the Game struct so we can
-    // use game_name before it moves into `Game { name }`.
-    //
-    // IMPORTANT: Only include fields defined in the `game` table schema
-    // (`schemas/game.surql` is SCHEMAFULL). The full Game struct carries
-    // extra fields (`config`, `combat_tuning`, `sponsors`, `areas`,
-    // `tributes`) that SurrealDB v3 strictly rejects on SCHEMAFULL tables.
-    // See also save_game which uses explicit UPDATE SET for the same reason.
-    let game_rid = RecordId::new("game", game_identifier.as_str());
-    let body = serde_json::json!({
-        "identifier": &game_identifier,
-        "name": &game_name,
-        "status": "NotStarted",
-        "day": null,
-        "private": true,
-    });
-
-    // Construct Game with server-controlled fields
-    let game = Game {
-        identifier: game_identifier.clone(),
-        name: game_name,
-        status: GameStatus::NotStarted,
-        day: None,
-        tributes: vec![],
-        areas: vec![],
-        private: true, // Default to private
-        config: Default::default(),
-        messages: vec![],
-        alliance_events: vec![],
-        ..Default::default()
-    };
-
-    db.query("UPSERT $rid CONTENT $body")
-        .bind(("rid", game_rid.clone()))
-        .bind(("body", body))
-        .await
-        .map_err(|e| AppError::InternalServerError(format!("Failed to create game: {e}")))?;
-
-    let created_game = game;
-
-    // Create tributes concurrently
-    let tribute_futures =
-        (0..24).map(|idx| crate::tributes::create_tribute(None, &game_identifier, &db, idx % 12));
-    let tribute_results = futures::future::join_all(tribute_futures).await;
-
-    if let Some(err) = tribute_results.into_iter().find_map(Result::err) {
-        return Err(AppError::InternalServerError(format!(
-            "Failed to create tributes: {err}"
-        )));
-    }
-
-    // Apply game customization settings
-    let base_item_count = payload.item_quantity.base_item_count();
-
-    // Create areas concurrently with customized item count
-    let area_futures = Area::iter()
-        .map(|area| super::create_area(game_identifier.as_str(), area, base_item_count, &db));
-    let area_results = futures::future::join_all(area_futures).await;
-
-    if let Some(err) = area_results.into_iter().find_map(Result::err) {
-        let detail = match &err {
-            AppError::InternalServerError(s) | AppError::BadRequest(s) | AppError::DbError(s) => {
-                s.clone()
-            }
-            other => other.to_string(),
-        };
-        return Err(AppError::InternalServerError(format!(
-            "Failed to create areas: {}",
-            detail
-        )));
-    }
+    let created_game = super::create_game_from_request(&payload, true, &db).await?;
+    let game_identifier = created_game.identifier.clone();
 
     let location: axum::http::HeaderValue =
         axum::http::HeaderValue::from_str(&format!("/api/games/{}", game_identifier)).map_err(
@@ -119,23 +42,32 @@ pub async fn create_game(
     Ok(response)
 }
 
-/// Quickstart: create a game with 24 tributes, start it, run the first
-/// cycle, and redirect to the game detail page.
+/// Quickstart: create a game with the default roster, start it, run the
+/// first cycle, and redirect to the game detail page.
 pub async fn quickstart(
     Extension(AuthDb(db)): Extension<AuthDb>,
     State(state): State<AppState>,
 ) -> Result<Response, AppError> {
     let default_game = Game::default();
     let game_identifier = Uuid::new_v4().to_string();
-    let game_name = default_game.name;
+    let game_name = &default_game.name;
 
     let game_rid = RecordId::new("game", game_identifier.as_str());
+    let settings = super::persist::EncodedGameSettings::new(&default_game)?;
     let body = serde_json::json!({
         "identifier": &game_identifier,
-        "name": &game_name,
+        "name": game_name,
         "status": "NotStarted",
         "day": null,
         "private": false,
+        "seed": default_game.seed,
+        "config": settings.config,
+        "combat_tuning": settings.combat_tuning,
+        "sponsors": settings.sponsors,
+        "gamemaker": settings.gamemaker,
+        "alliances": settings.alliances,
+        "roster_size": default_game.config.tribute_count,
+        "district_count": default_game.config.district_count,
     });
 
     db.query("UPSERT $rid CONTENT $body")
@@ -144,9 +76,11 @@ pub async fn quickstart(
         .await
         .map_err(|e| AppError::InternalServerError(format!("Failed to create game: {e}")))?;
 
-    // Create 24 tributes
-    let tribute_futures =
-        (0..24).map(|idx| crate::tributes::create_tribute(None, &game_identifier, &db, idx % 12));
+    // Create the roster
+    let config = &default_game.config;
+    let tribute_futures = (0..config.tribute_count).map(|idx| {
+        crate::tributes::create_tribute(None, &game_identifier, &db, config.district_for(idx))
+    });
     let tribute_results = futures::future::join_all(tribute_futures).await;
     if let Some(err) = tribute_results.into_iter().find_map(Result::err) {
         return Err(AppError::InternalServerError(format!(
@@ -154,10 +88,12 @@ pub async fn quickstart(
         )));
     }
 
-    // Create 7 areas
+    // Generate the default arena
     let base_item_count = shared::ItemQuantity::default().base_item_count();
-    let area_futures = Area::iter()
-        .map(|area| super::create_area(game_identifier.as_str(), area, base_item_count, &db));
+    let area_futures = default_game
+        .generate_areas()
+        .into_iter()
+        .map(|details| super::create_area(game_identifier.as_str(), details, base_item_count, &db));
     let area_results = futures::future::join_all(area_futures).await;
     if let Some(err) = area_results.into_iter().find_map(Result::err) {
         return Err(AppError::InternalServerError(format!(
@@ -175,13 +111,7 @@ pub async fn quickstart(
         .map_err(|e| AppError::InternalServerError(format!("Failed to load game: {e}")))?;
     game.status = GameStatus::InProgress;
 
-    super::run_game_cycles(
-        &mut game,
-        &db,
-        &state.broadcaster,
-        state.commentator.clone(),
-    )
-    .await?;
+    super::run_game_cycles(&mut game, &db, &state, super::Granularity::Day).await?;
 
     // HX-Redirect: full page navigation via HTMX (not a 302)
     let mut headers = axum::http::HeaderMap::new();
@@ -224,6 +154,19 @@ pub async fn game_delete(
         super::delete_pieces(pieces, &db).await?
     };
 
+    db.query("DELETE game_snapshot WHERE game_id = $identifier")
+        .bind(("identifier", game_identifier.clone()))
+        .await
+        .map_err(|e| {
+            AppError::InternalServerError(format!("Failed to delete game snapshots: {}", e))
+        })?;
+    db.query("DELETE decision_trace WHERE game_id = $identifier")
+        .bind(("identifier", game_identifier.clone()))
+        .await
+        .map_err(|e| {
+            AppError::InternalServerError(format!("Failed to delete decision traces: {}", e))
+        })?;
+
     // Execute DELETE ignoring the return value. SurrealDB v3 DELETE returns
     // the deleted record which may contain null `day`, tripping the SDK
     // deserializer — we just care that the query succeeds.
@@ -530,21 +473,65 @@ pub async fn game_tributes(
     }
 }
 
-/// Advance the game to the next step (start, run day, or finish).
+/// Advance the game to the next step (start, run day, or finish). With
+/// `?granularity=phase` only the next phase is run, so spectators get the
+/// day's commentary and events one phase at a time.
 pub async fn next_step(
     Path(identifier): Path<Uuid>,
     state: State<AppState>,
     Extension(AuthDb(db)): Extension<AuthDb>,
+    Query(params): Query<AdvanceParams>,
 ) -> Result<Json<Option<Game>>, AppError> {
+    advance(&identifier.to_string(), &state, &db, params.granularity)
+        .await
+        .map(Json)
+}
+
+/// Start the game if needed and play it a day at a time until it finishes
+/// or `max_days` days have been played. Stops early, returning the game as
+/// it stands, when controlled tributes hold the next day up for orders.
+pub async fn run_to_finish(
+    Path(identifier): Path<Uuid>,
+    state: State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+    Query(params): Query<RunParams>,
+) -> Result<Json<Game>, AppError> {
+    params
+        .validate()
+        .map_err(|e| AppError::ValidationError(format!("{}", e)))?;
     let id = identifier.to_string();
-    let id_str = id.as_str();
-    let record_id = RecordId::new("game", id_str);
-    let game_status = super::get_game_status(&db, id_str).await?;
+
+    if super::get_game_status(&db, &id).await? == GameStatus::NotStarted {
+        advance(&id, &state, &db, Granularity::Day).await?;
+    }
+    for played in 0..params.max_days {
+        match advance(&id, &state, &db, Granularity::Day).await {
+            Ok(Some(_)) => {}
+            Ok(None) => break,
+            Err(AppError::Conflict(_)) if played > 0 => break,
+            Err(e) => return Err(e),
+        }
+    }
+
+    let game = super::get_full_game(&id, &db).await?;
+    Ok(Json(game))
+}
+
+/// One step of `next_step`: start a game that hasn't started, finish one
+/// with no tributes left standing, or play the next day or phase.
+async fn advance(
+    id: &str,
+    state: &AppState,
+    db: &Surreal<Any>,
+    granularity: Granularity,
+) -> Result<Option<Game>, AppError> {
+    let record_id = RecordId::new("game", id);
+    let game_status = super::get_game_status(db, id).await?;
 
     match game_status {
         GameStatus::NotStarted => {
-            super::update_game_status(&db, &record_id, GameStatus::InProgress).await?;
-            let mut game = super::get_full_game(&id, &db).await?;
+            super::update_game_status(db, &record_id, GameStatus::InProgress).await?;
+            let mut game = super::get_full_game(id, db).await?;
             game.status = GameStatus::InProgress;
 
             // Broadcast game started
@@ -554,38 +541,53 @@ pub async fn next_step(
                 game.day.unwrap_or(1),
             );
 
-            Ok(Json(Some(game)))
+            Ok(Some(game))
         }
         GameStatus::InProgress => {
-            let dead_tribute_count = super::get_dead_tribute_count(&db, id_str).await?;
+            let dead_tribute_count = super::get_dead_tribute_count(db, id).await?;
+            let mut game = super::get_full_game(id, db).await?;
 
-            if dead_tribute_count >= 24 {
-                super::update_game_status(&db, &record_id, GameStatus::Finished).await?;
+            if dead_tribute_count >= game.config.tribute_count {
+                super::update_game_status(db, &record_id, GameStatus::Finished).await?;
 
                 // Find and broadcast winner
-                let game = super::get_full_game(&id, &db).await?;
                 let winner = game
                     .tributes
                     .iter()
                     .find(|t| t.is_alive())
                     .map(|t| t.name.clone());
-                crate::websocket::broadcast_game_finished(&state.broadcaster, &id, winner);
+                crate::websocket::broadcast_game_finished(&state.broadcaster, id, winner);
 
-                Ok(Json(None))
+                Ok(None)
             } else {
-                let mut game = super::get_full_game(&id, &db).await?;
-                super::run_game_cycles(
-                    &mut game,
-                    &db,
-                    &state.broadcaster,
-                    state.commentator.clone(),
-                )
-                .await?;
-
-                Ok(Json(Some(game)))
+                super::orders::load_orders(&mut game, &state.db).await?;
+                super::orders::hold_for_orders(&game, &state.db).await?;
+                super::sponsorship::load_gifts(&mut game, &state.db).await?;
+                let gifts: Vec<String> = game
+                    .player_gifts
+                    .iter()
+                    .map(|g| g.identifier.clone())
+                    .collect();
+                // Gifts arrive in the first phase the step plays.
+                let delivery = (
+                    game.upcoming_day(),
+                    game.upcoming_phases()
+                        .first()
+                        .copied()
+                        .unwrap_or(shared::messages::Phase::Night),
+                );
+                let messages = super::run_game_cycles(&mut game, db, state, granularity).await?;
+                super::betting::settle_bets(&state.db, id, &messages).await?;
+                // A day played phase by phase keeps its orders until night.
+                if game.resting_phase.is_none() {
+                    super::orders::close_orders(&state.db, id, game.day.unwrap_or(0)).await?;
+                }
+                super::sponsorship::settle_gifts(&game, gifts, delivery, &state.db).await?;
+
+                Ok(Some(game))
             }
         }
-        GameStatus::Finished => Ok(Json(None)),
+        GameStatus::Finished => Ok(None),
     }
 }
 
@@ -747,6 +749,33 @@ pub(crate) async fn timeline_summary(
     }))
 }
 
+/// Replay a game's log and report where its persisted state has drifted
+/// from it; an empty list means the two agree. See `game::replay`.
+pub(crate) async fn game_audit(
+    Path(game_identifier): Path<Uuid>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+) -> Result<Json<Vec<game::replay::Drift>>, AppError> {
+    let game_identifier = game_identifier.to_string();
+    let game = super::get_full_game(&game_identifier, &db).await?;
+
+    let mut response = db
+        .query(
+            r#"SELECT * FROM message
+            WHERE string::starts_with(subject, $identifier)
+            ORDER BY game_day, phase, tick, emit_index;"#,
+        )
+        .bind(("identifier", game_identifier))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch logs: {e}")))?;
+    let rows: Vec<SerdeWrapper<GameLog>> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read logs: {e}")))?;
+    let messages: Vec<GameMessage> = rows.into_iter().map(|w| GameMessage::from(w.0)).collect();
+
+    let view = game::replay::LogView::replay(&messages);
+    Ok(Json(view.check(&game)))
+}
+
 /// Publish a game (set private = false).
 pub(crate) async fn publish_game(
     Path(game_identifier): Path<Uuid>,
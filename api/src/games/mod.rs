@@ -1,7 +1,12 @@
+pub mod betting;
 pub mod handlers;
 pub use handlers::*;
 pub(crate) mod items;
+pub(crate) mod orders;
 pub(crate) mod persist;
+pub mod snapshots;
+pub mod sponsorship;
+pub(crate) mod traces;
 
 use crate::tributes::TRIBUTES_ROUTER;
 use crate::{AppError, AppState};
@@ -9,17 +14,19 @@ use axum::Router;
 use axum::routing::{get, post, put};
 use chrono::{DateTime, Utc};
 use game::areas::{Area, AreaDetails};
+use game::config::GameConfig;
 use game::games::Game;
 use game::items::Item;
 use game::messages::{GameMessage, MessageSource};
 use game::terrain::BaseTerrain;
 use game::tributes::Tribute;
+use game::tributes::combat_tuning::CombatTuning;
 use serde::{Deserialize, Serialize};
 use shared::messages::MessagePayload;
-use shared::{GameArea, GameStatus, PaginationMetadata};
+use shared::{CreateGame, GameArea, GameStatus, PaginationMetadata};
 use std::collections::HashMap;
 use std::str::FromStr;
-use std::sync::{Arc, LazyLock};
+use std::sync::LazyLock;
 use surrealdb::Surreal;
 use surrealdb::engine::any::Any;
 use surrealdb_types::RecordId;
@@ -55,6 +62,36 @@ fn default_offset() -> u32 {
     0
 }
 
+/// How far one `next_step` advances a game.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Granularity {
+    /// The rest of the current day, or all of the next one.
+    #[default]
+    Day,
+    /// Only the next phase.
+    Phase,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdvanceParams {
+    #[serde(default)]
+    granularity: Granularity,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct RunParams {
+    /// Safety cap: a game that can't end (say, an arena nobody dies in)
+    /// stops after this many days instead of tying up the request.
+    #[serde(default = "default_max_days")]
+    #[validate(range(min = 1, max = 100))]
+    max_days: u32,
+}
+
+fn default_max_days() -> u32 {
+    100
+}
+
 pub static GAMES_ROUTER: LazyLock<Router<AppState>> = LazyLock::new(|| {
     Router::new()
         .route("/", get(game_list).post(create_game))
@@ -80,7 +117,18 @@ pub static GAMES_ROUTER: LazyLock<Router<AppState>> = LazyLock::new(|| {
             get(tribute_logs),
         )
         .route("/{game_identifier}/next", put(next_step))
+        .route("/{game_identifier}/run", put(run_to_finish))
+        .route("/{game_identifier}/fork", post(snapshots::fork_game))
+        .route(
+            "/{game_identifier}/rollback",
+            post(snapshots::rollback_game),
+        )
         .route("/{game_identifier}/timeline-summary", get(timeline_summary))
+        .route("/{game_identifier}/audit", get(game_audit))
+        .route(
+            "/{game_identifier}/wallet",
+            get(sponsorship::sponsor_wallet),
+        )
         .route("/{game_identifier}/publish", put(publish_game))
         .route("/{game_identifier}/unpublish", put(unpublish_game))
         .nest("/{game_identifier}/tributes", TRIBUTES_ROUTER.clone())
@@ -94,19 +142,17 @@ pub struct GameAreaEdge {
     area: RecordId,
 }
 
-async fn create_game_area(area: Area, db: &Surreal<Any>) -> Result<GameArea, AppError> {
-    let identifier = Uuid::new_v4().to_string();
-    let area_id: RecordId = RecordId::new("area", identifier.to_string());
-
-    // create the `area` record. Bind via serde_json::Value + a raw
-    // UPDATE...CONTENT query so the SDK's bespoke serializer can't drop
-    // optional fields or collapse externally-tagged enums (see save_game).
-    let game_area = GameArea {
-        identifier: identifier.to_string(),
-        name: area.to_string(),
-        area: area.to_string(),
-    };
-    let body = serde_json::to_value(&game_area)
+async fn create_game_area(details: &AreaDetails, db: &Surreal<Any>) -> Result<GameArea, AppError> {
+    let area_id: RecordId = RecordId::new("area", details.identifier.as_str());
+
+    // create the `area` record, terrain included. Bind via
+    // serde_json::Value + a raw UPDATE...CONTENT query so the SDK's
+    // bespoke serializer can't drop optional fields or collapse
+    // externally-tagged enums (see save_game). Items are related
+    // separately, as in save_game.
+    let mut area_without_items = details.clone();
+    area_without_items.items = vec![];
+    let body = serde_json::to_value(&area_without_items)
         .map_err(|e| AppError::InternalServerError(format!("Failed to encode area: {}", e)))?;
     db.query("UPSERT $rid CONTENT $body")
         .bind(("rid", area_id.clone()))
@@ -114,11 +160,15 @@ async fn create_game_area(area: Area, db: &Surreal<Any>) -> Result<GameArea, App
         .await
         .map_err(|e| AppError::InternalServerError(format!("Failed to create area: {}", e)))?;
 
-    Ok(game_area)
+    Ok(GameArea {
+        identifier: details.identifier.clone(),
+        name: details.name.clone(),
+        area: details.area.unwrap_or_default().to_string(),
+    })
 }
 
 async fn create_game_area_edge(
-    area: Area,
+    details: &AreaDetails,
     game_identifier: Uuid,
     db: &Surreal<Any>,
 ) -> Result<GameAreaEdge, AppError> {
@@ -134,7 +184,7 @@ async fn create_game_area_edge(
         WHERE original_name = '$name'
         AND <-areas<-game.identifier = '$game_id'"#,
         )
-        .bind(("name", area.to_string()))
+        .bind(("name", details.area.unwrap_or_default().to_string()))
         .bind(("game_id", game_identifier_str.clone()))
         .await
         .map_err(|e| AppError::InternalServerError(format!("Failed to find area: {}", e)))?;
@@ -146,7 +196,7 @@ async fn create_game_area_edge(
         Uuid::from_str(&identifier.to_string())
             .map_err(|e| AppError::BadRequest(format!("Invalid area UUID: {}", e)))?
     } else {
-        match create_game_area(area, db).await {
+        match create_game_area(details, db).await {
             Ok(game_area) => Uuid::from_str(game_area.identifier.as_str())
                 .map_err(|e| AppError::BadRequest(format!("Invalid game area UUID: {}", e)))?,
             Err(_) => {
@@ -175,16 +225,117 @@ async fn create_game_area_edge(
     Ok(edge)
 }
 
+/// Create a game row with its tributes and stocked areas from a validated
+/// `CreateGame` request. Shared by the JSON API and the HTML form so both
+/// honor the same options.
+pub async fn create_game_from_request(
+    payload: &CreateGame,
+    private: bool,
+    db: &Surreal<Any>,
+) -> Result<Game, AppError> {
+    // Game::default() runs WPGen to produce a three-word "clever" name;
+    // use it as the fallback when the client didn't supply one.
+    let default_game = Game::default();
+    let game_identifier = Uuid::new_v4().to_string();
+    let game_name = payload.name.clone().unwrap_or(default_game.name);
+
+    let game = Game {
+        identifier: game_identifier.clone(),
+        name: game_name,
+        status: GameStatus::NotStarted,
+        day: None,
+        tributes: vec![],
+        areas: vec![],
+        private,
+        seed: default_game.seed,
+        config: GameConfig::from_create_game(payload),
+        combat_tuning: CombatTuning::for_difficulty(payload.difficulty),
+        messages: vec![],
+        alliance_events: vec![],
+        ..Default::default()
+    };
+
+    // IMPORTANT: Only include fields defined in the `game` table schema
+    // (`schemas/game.surql` is SCHEMAFULL). The full Game struct carries
+    // extra fields (`areas`, `tributes`, transient cycle state) that
+    // SurrealDB v3 strictly rejects on SCHEMAFULL tables, and `config`,
+    // `combat_tuning`, `sponsors`, `gamemaker` and `alliances` are stored
+    // JSON-encoded.
+    // The roster is mirrored into plain columns for the readiness checks.
+    // See also save_game which uses explicit UPDATE SET for the same reason.
+    let game_rid = RecordId::new("game", game_identifier.as_str());
+    let settings = persist::EncodedGameSettings::new(&game)?;
+    let body = serde_json::json!({
+        "identifier": &game.identifier,
+        "name": &game.name,
+        "status": "NotStarted",
+        "day": null,
+        "private": private,
+        "seed": game.seed,
+        "config": settings.config,
+        "combat_tuning": settings.combat_tuning,
+        "sponsors": settings.sponsors,
+        "gamemaker": settings.gamemaker,
+        "alliances": settings.alliances,
+        "roster_size": game.config.tribute_count,
+        "district_count": game.config.district_count,
+    });
+
+    db.query("UPSERT $rid CONTENT $body")
+        .bind(("rid", game_rid.clone()))
+        .bind(("body", body))
+        .await
+        .map_err(|e| AppError::InternalServerError(format!("Failed to create game: {e}")))?;
+
+    // Create tributes concurrently, reaped from the game's seed with
+    // starting health rolled from the config
+    let tribute_futures = game.reap_tributes().into_iter().map(|tribute| {
+        let district = tribute.district;
+        crate::tributes::create_tribute(Some(tribute), &game_identifier, db, district)
+    });
+    let tribute_results = futures::future::join_all(tribute_futures).await;
+
+    if let Some(err) = tribute_results.into_iter().find_map(Result::err) {
+        return Err(AppError::InternalServerError(format!(
+            "Failed to create tributes: {err}"
+        )));
+    }
+
+    // Generate the arena and create its areas concurrently with
+    // customized item count
+    let base_item_count = payload.item_quantity.base_item_count();
+    let area_futures = game
+        .generate_areas()
+        .into_iter()
+        .map(|details| create_area(game_identifier.as_str(), details, base_item_count, db));
+    let area_results = futures::future::join_all(area_futures).await;
+
+    if let Some(err) = area_results.into_iter().find_map(Result::err) {
+        let detail = match &err {
+            AppError::InternalServerError(s) | AppError::BadRequest(s) | AppError::DbError(s) => {
+                s.clone()
+            }
+            other => other.to_string(),
+        };
+        return Err(AppError::InternalServerError(format!(
+            "Failed to create areas: {}",
+            detail
+        )));
+    }
+
+    Ok(game)
+}
+
 /// Create areas for a game, including spawning items within them.
 pub async fn create_area(
     game_identifier: &str,
-    area: Area,
+    details: AreaDetails,
     num_items: u32,
     db: &Surreal<Any>,
 ) -> Result<(), AppError> {
     let game_uuid = Uuid::from_str(game_identifier)
         .map_err(|e| AppError::BadRequest(format!("Invalid game UUID: {}", e)))?;
-    let game_area = create_game_area_edge(area, game_uuid, db)
+    let game_area = create_game_area_edge(&details, game_uuid, db)
         .await
         .map_err(|e| {
             let detail = match &e {
@@ -282,7 +433,10 @@ async fn delete_pieces(
     Ok(())
 }
 
-async fn get_game_status(db: &Surreal<Any>, identifier: &str) -> Result<GameStatus, AppError> {
+pub(crate) async fn get_game_status(
+    db: &Surreal<Any>,
+    identifier: &str,
+) -> Result<GameStatus, AppError> {
     let result = db
         .query("SELECT status FROM game WHERE identifier = $identifier")
         .bind(("identifier", identifier.to_string()))
@@ -345,20 +499,47 @@ async fn get_dead_tribute_count(db: &Surreal<Any>, identifier: &str) -> Result<u
     }
 }
 
+/// Run the next day or phase, persist it and kick off commentary for what
+/// was played. Returns the messages it produced, in emit order.
 async fn run_game_cycles(
     game: &mut Game,
     db: &Surreal<Any>,
-    broadcaster: &crate::websocket::GameBroadcaster,
-    commentator: Option<Arc<dyn announcers::Commentator>>,
-) -> Result<(), AppError> {
-    game.run_full_day()
-        .map_err(|e| AppError::InternalServerError(format!("Failed to run game day: {}", e)))?;
+    state: &AppState,
+    granularity: Granularity,
+) -> Result<Vec<GameMessage>, AppError> {
+    let broadcaster: &crate::websocket::GameBroadcaster = &state.broadcaster;
+    let commentator = state.commentator.clone();
+
+    // Before the first day, also keep the starting state (night 0) so the
+    // first day can be rolled back too.
+    let mut phase_snapshots = Vec::new();
+    if game.day.unwrap_or(0) == 0 {
+        phase_snapshots.push(game.snapshot());
+    }
+    match granularity {
+        Granularity::Day => game.run_full_day_with(|game| phase_snapshots.push(game.snapshot())),
+        Granularity::Phase => game
+            .run_next_phase()
+            .map(|_| phase_snapshots.push(game.snapshot())),
+    }
+    .map_err(|e| AppError::InternalServerError(format!("Failed to run game day: {}", e)))?;
 
     // Clone messages before save_game drains them for commentary.
     let phase_events: Vec<GameMessage> = game.messages.clone();
 
     let _ = persist::save_game(game, db, broadcaster).await?;
 
+    // Snapshots only matter for rolling back or forking later; losing them
+    // shouldn't fail the day that was just played.
+    if let Err(e) = snapshots::save_snapshots(&state.db, &phase_snapshots).await {
+        tracing::warn!(game_id = %game.identifier, error = %e, "Failed to save phase snapshots");
+    }
+    // Same for decision traces: they explain the day, they aren't part of it.
+    let decision_traces = std::mem::take(&mut game.decision_traces);
+    if let Err(e) = traces::save_traces(&state.db, &game.identifier, &decision_traces).await {
+        tracing::warn!(game_id = %game.identifier, error = %e, "Failed to save decision traces");
+    }
+
     // Spawn commentary generation as a non-blocking background task.
     if let (Some(commentator), Some(day)) = (commentator, game.day)
         && !phase_events.is_empty()
@@ -426,6 +607,11 @@ async fn run_game_cycles(
             }
         };
         history_tracker.update(&phase_events);
+        for tribute in &game.tributes {
+            history_tracker
+                .set_dominant_emotion(&tribute.identifier, tribute.dominant_emotion().label());
+            history_tracker.set_goal(&tribute.identifier, tribute.current_goal());
+        }
         let digests = history_tracker.digests();
 
         // Build state snapshot from the game's current state.
@@ -452,7 +638,7 @@ async fn run_game_cycles(
                 TributeHidden { area, .. } => Some(&area.name),
                 AreaEvent { area, .. } => Some(&area.name),
                 AreaClosed { area } => Some(&area.name),
-                ItemFound { area, .. } => Some(&area.name),
+                ItemFound { area, .. } | CorpseLooted { area, .. } => Some(&area.name),
                 _ => None,
             };
             if let Some(name) = area_name {
@@ -476,6 +662,25 @@ async fn run_game_cycles(
             })
             .collect();
 
+        // Alliances as the engine groups them, leader first.
+        let name_of = |id: &uuid::Uuid| {
+            game.tributes
+                .iter()
+                .find(|t| t.id == *id)
+                .map(|t| t.name.clone())
+        };
+        let alliances: Vec<announcers::AllianceInfo> = game
+            .alliances
+            .iter()
+            .map(|alliance| announcers::AllianceInfo {
+                members: std::iter::once(&alliance.leader)
+                    .chain(alliance.members.iter().filter(|m| **m != alliance.leader))
+                    .filter_map(name_of)
+                    .collect(),
+                leader: name_of(&alliance.leader).unwrap_or_default(),
+            })
+            .collect();
+
         // Serialize digests for persistence (clone before moving into spawn).
         let digests_json = serde_json::to_value(&digests).ok();
 
@@ -484,11 +689,12 @@ async fn run_game_cycles(
             phase: phase_label.clone(),
             alive_count,
             kill_leaders,
-            alliances: vec![],
+            alliances,
             hot_zones,
             killing_sprees,
         };
 
+        let phase_events = phase_events.clone();
         tokio::spawn(async move {
             match announcers::generate_commentary(
                 &*commentator,
@@ -578,7 +784,7 @@ async fn run_game_cycles(
         });
     }
 
-    Ok(())
+    Ok(phase_events)
 }
 
 pub async fn get_full_game(identifier: &str, db: &Surreal<Any>) -> Result<Game, AppError> {
@@ -593,10 +799,11 @@ pub async fn get_full_game(identifier: &str, db: &Surreal<Any>) -> Result<Game,
         .take(0)
         .map_err(|e| AppError::InternalServerError(format!("Failed to take game: {}", e)))?;
 
-    let value = raw
+    let mut value = raw
         .into_iter()
         .next()
         .ok_or_else(|| AppError::NotFound(format!("Game {} not found", identifier)))?;
+    persist::decode_game_settings(&mut value)?;
 
     serde_json::from_value(value)
         .map_err(|e| AppError::InternalServerError(format!("Failed to deserialize game: {e}")))
@@ -626,6 +833,25 @@ pub struct GameLog {
     pub payload: String,
 }
 
+impl GameLog {
+    /// The `message` row for `log`, filed under `game_day`.
+    pub(crate) fn new(log: GameMessage, game_day: u32) -> Self {
+        GameLog {
+            id: RecordId::new("message", log.identifier.as_str()),
+            identifier: log.identifier,
+            source: log.source,
+            game_day,
+            subject: log.subject,
+            timestamp: log.timestamp,
+            content: log.content,
+            phase: log.phase,
+            tick: log.tick,
+            emit_index: log.emit_index,
+            payload: serde_json::to_string(&log.payload).unwrap_or_else(|_| "null".to_string()),
+        }
+    }
+}
+
 impl From<GameLog> for GameMessage {
     fn from(row: GameLog) -> Self {
         let payload = serde_json::from_str(&row.payload).unwrap_or_else(|err| {
@@ -672,6 +898,8 @@ fn build_tribute_digest(t: &game::tributes::Tribute) -> announcers::TributeDiges
         location: t.area.to_string(),
         allies: vec![],
         kill_streak: 0,
+        dominant_emotion: Some(t.dominant_emotion().to_string()),
+        goal: t.current_goal(),
         highlights: vec![],
         notable_events: vec![],
     }
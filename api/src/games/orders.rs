@@ -0,0 +1,215 @@
+//! Orders for player-controlled tributes.
+//!
+//! Players submit one order per phase of the coming day; they are kept in
+//! the `tribute_order` table until `next_step` runs that day. While a
+//! controlled tribute still has phases without an order, `next_step` holds
+//! the day back with a `409 Conflict` — for at most
+//! `GameConfig::player_order_timeout_secs`, counted from the first refused
+//! attempt, after which the brains fill in for the missing phases.
+//!
+//! `tribute_order` is server-side only, so the helpers that touch it take
+//! the root connection; callers check who is asking first.
+
+use crate::AppError;
+use game::games::Game;
+use game::tributes::actions::Action;
+use serde::{Deserialize, Serialize};
+use shared::messages::Phase;
+use surrealdb::Surreal;
+use surrealdb::engine::any::Any;
+use surrealdb_types::{RecordId, SerdeWrapper};
+
+#[derive(Debug, Deserialize)]
+struct OrderRow {
+    tribute_id: String,
+    phase: Phase,
+    /// JSON-encoded `Action`, like message payloads.
+    action: String,
+}
+
+/// The signed-in user's record id as `user:<key>`.
+pub(crate) async fn auth_user_id(db: &Surreal<Any>) -> Result<String, AppError> {
+    #[derive(Deserialize, Serialize)]
+    struct AuthId {
+        id: RecordId,
+    }
+    let mut response = db
+        .query("SELECT id FROM $auth")
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to query auth: {e}")))?;
+    response
+        .take::<Option<SerdeWrapper<AuthId>>>(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read auth id: {e}")))?
+        .map(|w| crate::rid_to_string(&w.0.id))
+        .ok_or_else(|| AppError::Unauthorized("No authenticated session".into()))
+}
+
+/// Whether the signed-in user created the game.
+pub(crate) async fn is_game_owner(
+    db: &Surreal<Any>,
+    game_identifier: &str,
+) -> Result<bool, AppError> {
+    let mut response = db
+        .query("RETURN (SELECT VALUE created_by.id == $auth.id FROM game WHERE identifier = $game)[0] ?? false")
+        .bind(("game", game_identifier.to_string()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to check game owner: {e}")))?;
+    let owner: Option<bool> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read game owner: {e}")))?;
+    Ok(owner.unwrap_or(false))
+}
+
+/// Who plays the tribute in the game, if anyone. Games are private to their
+/// creator by default, so this reads through the root connection: an
+/// invited player can't see the game on their own.
+pub(crate) async fn tribute_controller(
+    db: &Surreal<Any>,
+    game_identifier: &str,
+    tribute_identifier: &str,
+) -> Result<Option<String>, AppError> {
+    let mut response = db
+        .query("RETURN (SELECT VALUE in.controller FROM playing_in WHERE in.identifier = $tribute AND out.identifier = $game)[0]")
+        .bind(("game", game_identifier.to_string()))
+        .bind(("tribute", tribute_identifier.to_string()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to check tribute controller: {e}")))?;
+    let controller: Option<String> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read tribute controller: {e}")))?;
+    Ok(controller)
+}
+
+/// Load the stored orders for the coming day onto the game's tributes.
+pub(crate) async fn load_orders(game: &mut Game, db: &Surreal<Any>) -> Result<(), AppError> {
+    let mut response = db
+        .query("SELECT tribute_id, phase, action FROM tribute_order WHERE game_id = $game AND day = $day")
+        .bind(("game", game.identifier.clone()))
+        .bind(("day", game.upcoming_day()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch orders: {e}")))?;
+    let rows: Vec<serde_json::Value> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to take orders: {e}")))?;
+
+    for row in rows {
+        let row = match serde_json::from_value::<OrderRow>(row) {
+            Ok(row) => row,
+            Err(e) => {
+                tracing::warn!(game_id = %game.identifier, error = %e, "Dropping malformed order row");
+                continue;
+            }
+        };
+        let action = match serde_json::from_str::<Action>(&row.action) {
+            Ok(action) => action,
+            Err(e) => {
+                tracing::warn!(
+                    game_id = %game.identifier,
+                    tribute_id = %row.tribute_id,
+                    error = %e,
+                    "Dropping undecodable order"
+                );
+                continue;
+            }
+        };
+        if let Some(tribute) = game
+            .tributes
+            .iter_mut()
+            .find(|t| t.identifier == row.tribute_id)
+        {
+            tribute.orders.insert(row.phase, action);
+        }
+    }
+    Ok(())
+}
+
+/// Store an order for the coming day, replacing any earlier order for the
+/// same phase.
+pub(crate) async fn save_order(
+    db: &Surreal<Any>,
+    game: &Game,
+    tribute_identifier: &str,
+    phase: Phase,
+    action: &Action,
+    submitted_by: &str,
+) -> Result<(), AppError> {
+    let day = game.upcoming_day();
+    let key = format!("{tribute_identifier}-{day}-{phase}");
+    let action = serde_json::to_string(action)
+        .map_err(|e| AppError::InternalServerError(format!("Failed to encode order: {e}")))?;
+    db.query(
+        "UPSERT $rid CONTENT { game_id: $game, tribute_id: $tribute, day: $day, \
+         phase: $phase, action: $action, submitted_by: $submitted_by, \
+         submitted_at: time::now() }",
+    )
+    .bind(("rid", RecordId::new("tribute_order", key)))
+    .bind(("game", game.identifier.clone()))
+    .bind(("tribute", tribute_identifier.to_string()))
+    .bind(("day", day))
+    .bind(("phase", phase.to_string()))
+    .bind(("action", action))
+    .bind(("submitted_by", submitted_by.to_string()))
+    .await
+    .map_err(|e| AppError::DbError(format!("Failed to save order: {e}")))?;
+    Ok(())
+}
+
+/// Refuse to run the day while controlled tributes are missing orders,
+/// unless the order window has been open longer than the game allows.
+pub(crate) async fn hold_for_orders(game: &Game, db: &Surreal<Any>) -> Result<(), AppError> {
+    let waiting: Vec<&str> = game
+        .awaiting_orders()
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect();
+    if waiting.is_empty() {
+        return Ok(());
+    }
+
+    let mut response = db
+        .query("SELECT VALUE time::unix(orders_opened_at) FROM game WHERE identifier = $game AND orders_opened_at != NONE")
+        .bind(("game", game.identifier.clone()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to read order window: {e}")))?;
+    let opened_at: Vec<i64> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read order window: {e}")))?;
+
+    let elapsed = match opened_at.first() {
+        Some(opened_at) => chrono::Utc::now().timestamp() - opened_at,
+        None => {
+            db.query("UPDATE game SET orders_opened_at = time::now() WHERE identifier = $game")
+                .bind(("game", game.identifier.clone()))
+                .await
+                .map_err(|e| AppError::DbError(format!("Failed to open order window: {e}")))?;
+            0
+        }
+    };
+    let timeout = game.config.player_order_timeout_secs as i64;
+    if elapsed >= timeout {
+        return Ok(());
+    }
+    Err(AppError::Conflict(format!(
+        "Waiting {}s more for orders from: {}",
+        timeout - elapsed,
+        waiting.join(", ")
+    )))
+}
+
+/// Forget the orders for the day just played and close its order window.
+pub(crate) async fn close_orders(
+    db: &Surreal<Any>,
+    game_identifier: &str,
+    played_day: u32,
+) -> Result<(), AppError> {
+    db.query("DELETE tribute_order WHERE game_id = $game AND day <= $day")
+        .bind(("game", game_identifier.to_string()))
+        .bind(("day", played_day))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to clear orders: {e}")))?;
+    db.query("UPDATE game SET orders_opened_at = NONE WHERE identifier = $game")
+        .bind(("game", game_identifier.to_string()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to close order window: {e}")))?;
+    Ok(())
+}
@@ -10,6 +10,67 @@ use super::{GameLog, MAX_MESSAGES};
 use crate::AppError;
 use crate::websocket::{GameBroadcaster, broadcast_game_message};
 
+/// `Game` fields that live on the `game` row as JSON-encoded strings (see
+/// the note in `schemas/game.surql`).
+const ENCODED_GAME_FIELDS: [&str; 5] = [
+    "config",
+    "combat_tuning",
+    "sponsors",
+    "gamemaker",
+    "alliances",
+];
+
+/// `Game::config`, `Game::combat_tuning`, `Game::sponsors`,
+/// `Game::gamemaker` and `Game::alliances`, encoded for the `game` row.
+pub(crate) struct EncodedGameSettings {
+    pub config: String,
+    pub combat_tuning: String,
+    pub sponsors: String,
+    pub gamemaker: String,
+    pub alliances: String,
+}
+
+impl EncodedGameSettings {
+    pub fn new(game: &Game) -> Result<Self, AppError> {
+        let encode = |result: Result<String, serde_json::Error>| {
+            result.map_err(|e| {
+                AppError::InternalServerError(format!("Failed to encode game settings: {}", e))
+            })
+        };
+        Ok(Self {
+            config: encode(serde_json::to_string(&game.config))?,
+            combat_tuning: encode(serde_json::to_string(&game.combat_tuning))?,
+            sponsors: encode(serde_json::to_string(&game.sponsors))?,
+            gamemaker: encode(serde_json::to_string(&game.gamemaker))?,
+            alliances: encode(serde_json::to_string(&game.alliances))?,
+        })
+    }
+}
+
+/// Decode the JSON-encoded settings fields of a raw `fn::get_full_game` row
+/// in place. Rows that predate them (NONE) have the key removed so the
+/// `#[serde(default)]`s on `Game` fill in.
+pub(crate) fn decode_game_settings(row: &mut serde_json::Value) -> Result<(), AppError> {
+    let Some(row) = row.as_object_mut() else {
+        return Ok(());
+    };
+    for field in ENCODED_GAME_FIELDS {
+        match row.remove(field) {
+            Some(serde_json::Value::String(encoded)) => {
+                let decoded = serde_json::from_str(&encoded).map_err(|e| {
+                    AppError::InternalServerError(format!("Failed to decode game {field}: {e}"))
+                })?;
+                row.insert(field.to_string(), decoded);
+            }
+            Some(serde_json::Value::Null) | None => {}
+            Some(other) => {
+                row.insert(field.to_string(), other);
+            }
+        }
+    }
+    Ok(())
+}
+
 pub(crate) async fn save_game(
     game: &mut Game,
     db: &Surreal<Any>,
@@ -35,19 +96,7 @@ pub(crate) async fn save_game(
 
         let game_logs: Vec<GameLog> = logs
             .into_iter()
-            .map(|log| GameLog {
-                id: RecordId::new("message", log.identifier.as_str()),
-                identifier: log.identifier,
-                source: log.source,
-                game_day,
-                subject: log.subject,
-                timestamp: log.timestamp,
-                content: log.content,
-                phase: log.phase,
-                tick: log.tick,
-                emit_index: log.emit_index,
-                payload: serde_json::to_string(&log.payload).unwrap_or_else(|_| "null".to_string()),
-            })
+            .map(|log| GameLog::new(log, game_day))
             .collect();
 
         // Check current message count for this game
@@ -157,9 +206,9 @@ pub(crate) async fn save_game(
 
     let tribute_results = futures::future::join_all(game.tributes.iter().map(|tribute| async {
         let id = RecordId::new("tribute", tribute.identifier.as_str());
-        if tribute.is_alive() {
-            save_tribute_items(&tribute.items, id.clone(), db).await?;
-        }
+        // The dead too: their items went to their body, and leaving the
+        // `owns` edges behind would hand the items back on the next load.
+        save_tribute_items(&tribute.items, id.clone(), db).await?;
 
         let mut tribute_without_items = tribute.clone();
         tribute_without_items.items = vec![];
@@ -191,11 +240,29 @@ pub(crate) async fn save_game(
     // (same family of bugs as the externally-tagged-enum collapse called out
     // around the message payload above), so we use a plain UPDATE query that
     // names the fields we want written.
+    let settings = match EncodedGameSettings::new(game) {
+        Ok(settings) => settings,
+        Err(e) => {
+            let _ = db.query("ROLLBACK").await;
+            return Err(e);
+        }
+    };
+    let resting_phase = game.resting_phase.map(|phase| phase.to_string());
     if let Err(e) = db
-        .query("UPDATE $record_id SET day = $day, status = $status")
+        .query(
+            "UPDATE $record_id SET day = $day, resting_phase = $resting_phase, status = $status, \
+             config = $config, combat_tuning = $combat_tuning, sponsors = $sponsors, \
+             gamemaker = $gamemaker, alliances = $alliances",
+        )
         .bind(("record_id", game_identifier.clone()))
         .bind(("day", game.day.unwrap_or(0) as i64))
+        .bind(("resting_phase", resting_phase))
         .bind(("status", game.status.to_string()))
+        .bind(("config", settings.config))
+        .bind(("combat_tuning", settings.combat_tuning))
+        .bind(("sponsors", settings.sponsors))
+        .bind(("gamemaker", settings.gamemaker))
+        .bind(("alliances", settings.alliances))
         .await
     {
         let _ = db.query("ROLLBACK").await;
@@ -0,0 +1,331 @@
+//! Per-phase snapshots, rollback and forking.
+//!
+//! `run_game_cycles` stores the game's state at the end of every phase it
+//! runs in the `game_snapshot` table. `rollback_game` lets a game's owner
+//! put the game back to any of them, dropping the log, commentary and
+//! snapshots recorded after it and reseeding the game. `fork_game`
+//! branches a new game off one instead: the fork keeps the original's log
+//! up to that phase, plays on from there with a fresh seed, and is a game
+//! of its own, private to whoever forked it.
+//!
+//! `game_snapshot` is server-side only, so the helpers that touch it take
+//! the root connection; callers check who is asking first.
+
+use super::{GameLog, betting, persist, sponsorship};
+use crate::games::orders::is_game_owner;
+use crate::{AppError, AppState, AuthDb};
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use game::games::Game;
+use game::games::snapshots::GameSnapshot;
+use game::messages::GameMessage;
+use serde::{Deserialize, Serialize};
+use shared::messages::Phase;
+use shared::{ForkGame, RollbackGame};
+use surrealdb::Surreal;
+use surrealdb::engine::any::Any;
+use surrealdb_types::{RecordId, SerdeWrapper};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SnapshotRow {
+    day: u32,
+    phase: Phase,
+    /// JSON-encoded `Game`, like message payloads.
+    state: String,
+}
+
+fn snapshot_id(game_identifier: &str, day: u32, phase: Phase) -> RecordId {
+    RecordId::new("game_snapshot", format!("{game_identifier}_{day}_{phase}"))
+}
+
+/// Store snapshots taken while running a day, replacing any earlier ones
+/// for the same phases.
+pub(crate) async fn save_snapshots(
+    root: &Surreal<Any>,
+    snapshots: &[GameSnapshot],
+) -> Result<(), AppError> {
+    for snapshot in snapshots {
+        let state = serde_json::to_string(&snapshot.game).map_err(|e| {
+            AppError::InternalServerError(format!("Failed to encode snapshot: {e}"))
+        })?;
+        let game_identifier = snapshot.game.identifier.clone();
+        let rid = snapshot_id(&game_identifier, snapshot.day, snapshot.phase);
+        root.query(
+            "UPSERT $rid SET game_id = $game, day = $day, phase = $phase, state = $state, \
+             created_at = time::now()",
+        )
+        .bind(("rid", rid))
+        .bind(("game", game_identifier))
+        .bind(("day", snapshot.day))
+        .bind(("phase", snapshot.phase.to_string()))
+        .bind(("state", state))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to save snapshot: {e}")))?;
+    }
+    Ok(())
+}
+
+/// The game as it stood at the end of `phase` on `day`, if that phase was
+/// snapshotted.
+pub(crate) async fn load_snapshot(
+    root: &Surreal<Any>,
+    game_identifier: &str,
+    day: u32,
+    phase: Phase,
+) -> Result<Option<GameSnapshot>, AppError> {
+    let mut response = root
+        .query("SELECT day, phase, state FROM $rid")
+        .bind(("rid", snapshot_id(game_identifier, day, phase)))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch snapshot: {e}")))?;
+    let Some(row) = response
+        .take::<Option<SerdeWrapper<SnapshotRow>>>(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read snapshot: {e}")))?
+        .map(|w| w.0)
+    else {
+        return Ok(None);
+    };
+    let game: Game = serde_json::from_str(&row.state)
+        .map_err(|e| AppError::InternalServerError(format!("Failed to decode snapshot: {e}")))?;
+    Ok(Some(GameSnapshot {
+        day: row.day,
+        phase: row.phase,
+        game,
+    }))
+}
+
+/// The phases of a day after `phase`, as stored, for matching rows
+/// recorded later on the same day.
+pub(crate) fn later_phases(phase: Phase) -> Vec<String> {
+    Phase::all()
+        .into_iter()
+        .filter(|p| *p > phase)
+        .map(|p| p.to_string())
+        .collect()
+}
+
+/// Delete what a game recorded after `phase` on `day`: its messages,
+/// commentary, snapshots and decision traces. The announcers' tribute
+/// histories go too;
+/// they are rebuilt from the roster the next time the game advances.
+async fn truncate_after(
+    root: &Surreal<Any>,
+    game_identifier: &str,
+    day: u32,
+    phase: Phase,
+) -> Result<(), AppError> {
+    root.query(
+        r#"
+        DELETE message WHERE string::starts_with(subject, $game)
+            AND (game_day > $day OR (game_day = $day AND phase IN $later));
+        DELETE commentary_segments WHERE game_id = $game
+            AND (day > $day OR (day = $day AND phase IN $later));
+        DELETE game_snapshot WHERE game_id = $game
+            AND (day > $day OR (day = $day AND phase IN $later));
+        DELETE decision_trace WHERE game_id = $game
+            AND (day > $day OR (day = $day AND phase IN $later));
+        DELETE tribute_histories WHERE game_id = $game;
+        "#,
+    )
+    .bind(("game", game_identifier.to_string()))
+    .bind(("day", day))
+    .bind(("later", later_phases(phase)))
+    .await
+    .map_err(|e| AppError::DbError(format!("Failed to truncate game: {e}")))?;
+    Ok(())
+}
+
+/// Messages of a game's log up to and including `phase` on `day`, oldest
+/// first.
+async fn log_through(
+    db: &Surreal<Any>,
+    game_identifier: &str,
+    day: u32,
+    phase: Phase,
+) -> Result<Vec<GameLog>, AppError> {
+    let mut response = db
+        .query(
+            r#"SELECT * FROM message
+            WHERE string::starts_with(subject, $identifier) AND game_day <= $day
+            ORDER BY game_day, phase, tick, emit_index;"#,
+        )
+        .bind(("identifier", game_identifier.to_string()))
+        .bind(("day", day))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch logs: {e}")))?;
+    let rows: Vec<SerdeWrapper<GameLog>> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read logs: {e}")))?;
+    Ok(rows
+        .into_iter()
+        .map(|w| w.0)
+        .filter(|row| (row.game_day, row.phase) <= (day, phase))
+        .collect())
+}
+
+/// Roll a game back to the end of a past phase. Only the game's creator
+/// may.
+///
+/// Rolling back to the middle of a day leaves the game resting after that
+/// phase, to be stepped on from there. The game is reseeded, so the owner
+/// can't watch an outcome, roll back and bet on it; bets settled and gifts
+/// delivered after the rollback point are reopened and refunded. Orders
+/// are left as they are.
+pub async fn rollback_game(
+    Path(game_identifier): Path<Uuid>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+    Json(payload): Json<RollbackGame>,
+) -> Result<Json<Game>, AppError> {
+    let game_identifier = game_identifier.to_string();
+    if !is_game_owner(&db, &game_identifier).await? {
+        return Err(AppError::Unauthorized(
+            "Only the game's creator can roll it back".into(),
+        ));
+    }
+
+    let snapshot = load_snapshot(&state.db, &game_identifier, payload.day, payload.phase)
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "No snapshot of day {} {} for game {}",
+                payload.day, payload.phase, game_identifier
+            ))
+        })?;
+    let mut game = snapshot.restore();
+    game.seed = game::seeding::random_seed();
+
+    truncate_after(&state.db, &game_identifier, payload.day, payload.phase).await?;
+    betting::reopen_bets(&state.db, &game_identifier, payload.day, payload.phase).await?;
+    sponsorship::refund_gifts_after(&state.db, &game_identifier, payload.day, payload.phase)
+        .await?;
+    let _ = persist::save_game(&mut game, &db, &state.broadcaster).await?;
+    db.query("UPDATE $rid SET seed = $seed")
+        .bind(("rid", RecordId::new("game", game_identifier.as_str())))
+        .bind(("seed", game.seed))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to reseed game: {e}")))?;
+
+    Ok(Json(game))
+}
+
+/// Fork a game at the end of a past phase.
+///
+/// Anyone who can see the game may fork it. The fork gets new identifiers
+/// throughout, a new seed, and a copy of the log up to the fork point, and
+/// rests after the phase it was forked at. Player control is not carried
+/// over.
+pub async fn fork_game(
+    Path(game_identifier): Path<Uuid>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+    Json(payload): Json<ForkGame>,
+) -> Result<Response, AppError> {
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(format!("{}", e)))?;
+    let source_identifier = game_identifier.to_string();
+    // Goes through the caller's connection, so games they can't see 404.
+    let source = super::get_full_game(&source_identifier, &db).await?;
+
+    let snapshot = load_snapshot(&state.db, &source_identifier, payload.day, payload.phase)
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "No snapshot of day {} {} for game {}",
+                payload.day, payload.phase, source_identifier
+            ))
+        })?;
+
+    let mut fork = snapshot
+        .fork(game::seeding::random_seed())
+        .map_err(|e| AppError::InternalServerError(format!("Failed to fork game: {e}")))?;
+    fork.game.name = payload
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("{} (fork)", source.name));
+    fork.game.private = true;
+
+    // Same restricted body as `create_game_from_request`; `game` is
+    // SCHEMAFULL.
+    let game_rid = RecordId::new("game", fork.game.identifier.as_str());
+    let settings = persist::EncodedGameSettings::new(&fork.game)?;
+    let body = serde_json::json!({
+        "identifier": &fork.game.identifier,
+        "name": &fork.game.name,
+        "status": fork.game.status.to_string(),
+        "day": fork.game.day,
+        "private": true,
+        "seed": fork.game.seed,
+        "config": settings.config,
+        "combat_tuning": settings.combat_tuning,
+        "sponsors": settings.sponsors,
+        "gamemaker": settings.gamemaker,
+        "alliances": settings.alliances,
+        "roster_size": fork.game.config.tribute_count,
+        "district_count": fork.game.config.district_count,
+    });
+    db.query("UPSERT $rid CONTENT $body")
+        .bind(("rid", game_rid.clone()))
+        .bind(("body", body))
+        .await
+        .map_err(|e| AppError::InternalServerError(format!("Failed to create fork: {e}")))?;
+
+    let copied: Vec<GameLog> = log_through(&db, &source_identifier, payload.day, payload.phase)
+        .await?
+        .into_iter()
+        .map(|row| {
+            let game_day = row.game_day;
+            let message = fork
+                .remap_message(&GameMessage::from(row))
+                .map_err(|e| AppError::InternalServerError(format!("Failed to copy log: {e}")))?;
+            Ok(GameLog::new(message, game_day))
+        })
+        .collect::<Result<_, AppError>>()?;
+    if !copied.is_empty() {
+        let rows: Vec<SerdeWrapper<GameLog>> = copied.into_iter().map(SerdeWrapper).collect();
+        db.insert::<Vec<SerdeWrapper<GameLog>>>("message")
+            .content(rows)
+            .await
+            .map_err(|e| AppError::InternalServerError(format!("Failed to copy log: {e}")))?;
+    }
+
+    let _ = persist::save_game(&mut fork.game, &db, &state.broadcaster).await?;
+
+    for tribute in &fork.game.tributes {
+        let tribute_rid = RecordId::new("tribute", tribute.identifier.as_str());
+        db.query("RELATE $tribute->playing_in->$game")
+            .bind(("tribute", tribute_rid))
+            .bind(("game", game_rid.clone()))
+            .await
+            .map_err(|e| {
+                AppError::InternalServerError(format!("Failed to connect tribute to fork: {e}"))
+            })?;
+    }
+    for area in &fork.game.areas {
+        db.query("RELATE $game->areas->$area")
+            .bind(("game", game_rid.clone()))
+            .bind(("area", RecordId::new("area", area.identifier.as_str())))
+            .await
+            .map_err(|e| {
+                AppError::InternalServerError(format!("Failed to link fork and area: {e}"))
+            })?;
+    }
+
+    save_snapshots(&state.db, &[fork.game.snapshot()]).await?;
+
+    let location =
+        axum::http::HeaderValue::from_str(&format!("/api/games/{}", fork.game.identifier))
+            .map_err(|e| {
+                AppError::InternalServerError(format!("Invalid Location header: {}", e))
+            })?;
+    let mut response = (StatusCode::CREATED, Json(fork.game)).into_response();
+    response
+        .headers_mut()
+        .insert(axum::http::header::LOCATION, location);
+    Ok(response)
+}
@@ -0,0 +1,355 @@
+//! Player sponsorship.
+//!
+//! Every signed-in user who can see a game gets a sponsor wallet for it,
+//! opened on first use with `GameConfig::player_sponsor_budget`. Buying a
+//! gift charges the wallet at once and records the gift in the
+//! `sponsor_gift` ledger; `next_step` hands pending gifts to the engine as
+//! `Game::player_gifts` and settles the ledger afterwards, refunding gifts
+//! whose recipient died before they could be delivered and stamping the
+//! rest with the phase they arrived in, so a rollback past it can refund
+//! them. The same wallet funds bets; see `betting`.
+//!
+//! Both tables are server-side only: every write goes through the root
+//! connection after the handler has checked the request.
+
+use crate::games::orders::auth_user_id;
+use crate::games::snapshots::later_phases;
+use crate::{AppError, AppState, AuthDb};
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use game::games::Game;
+use game::sponsors::{PlayerGift, gift_price};
+use serde::{Deserialize, Serialize};
+use shared::GameStatus;
+use shared::messages::Phase;
+use shared::sponsors::{BuyGift, ITEM_COSTS, ItemKindTag, SponsorWallet};
+use surrealdb::Surreal;
+use surrealdb::engine::any::Any;
+use surrealdb_types::{RecordId, SerdeWrapper};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct WalletRow {
+    balance: u32,
+    spent: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiftRow {
+    identifier: String,
+    donor: String,
+    recipient: String,
+    item: ItemKindTag,
+}
+
+fn wallet_id(game_identifier: &str, user_id: &str) -> RecordId {
+    RecordId::new(
+        "sponsor_wallet",
+        format!("{game_identifier}_{}", user_id.replace(':', "_")),
+    )
+}
+
+fn wallet_response(row: WalletRow) -> SponsorWallet {
+    SponsorWallet {
+        balance: row.balance,
+        spent: row.spent,
+        catalog: ITEM_COSTS.to_vec(),
+    }
+}
+
+/// Fetch the caller's wallet for a game, opening it if this is their first
+/// visit.
+async fn open_wallet(
+    root: &Surreal<Any>,
+    game: &Game,
+    user_id: &str,
+) -> Result<(RecordId, WalletRow), AppError> {
+    let rid = wallet_id(&game.identifier, user_id);
+    let mut response = root
+        .query(
+            "UPSERT $rid SET game_id = $game, user_id = $user, \
+             balance = balance ?? $budget, spent = spent ?? 0 RETURN balance, spent",
+        )
+        .bind(("rid", rid.clone()))
+        .bind(("game", game.identifier.clone()))
+        .bind(("user", user_id.to_string()))
+        .bind(("budget", game.config.player_sponsor_budget))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to open wallet: {e}")))?;
+    let row = response
+        .take::<Option<SerdeWrapper<WalletRow>>>(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read wallet: {e}")))?
+        .ok_or_else(|| AppError::InternalServerError("Failed to open wallet".into()))?;
+    Ok((rid, row.0))
+}
+
+/// Take `amount` from a player's wallet, opening it first if needed.
+/// `None` when the balance does not cover it. The check and the charge are
+/// one statement, so two purchases racing each other cannot overdraw.
+pub(crate) async fn charge_wallet(
+    root: &Surreal<Any>,
+    game: &Game,
+    user_id: &str,
+    amount: u32,
+) -> Result<Option<(RecordId, SponsorWallet)>, AppError> {
+    let (rid, _) = open_wallet(root, game, user_id).await?;
+    let mut response = root
+        .query(
+            "UPDATE $rid SET balance -= $amount, spent += $amount \
+             WHERE balance >= $amount RETURN balance, spent",
+        )
+        .bind(("rid", rid.clone()))
+        .bind(("amount", amount))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to charge wallet: {e}")))?;
+    let wallet = response
+        .take::<Option<SerdeWrapper<WalletRow>>>(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read wallet: {e}")))?;
+    Ok(wallet.map(|w| (rid, wallet_response(w.0))))
+}
+
+/// Pay `amount` back into a wallet.
+pub(crate) async fn credit_wallet(
+    root: &Surreal<Any>,
+    wallet: RecordId,
+    amount: u32,
+) -> Result<(), AppError> {
+    root.query("UPDATE $rid SET balance += $amount")
+        .bind(("rid", wallet))
+        .bind(("amount", amount))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to credit wallet: {e}")))?;
+    Ok(())
+}
+
+/// Undo a `charge_wallet` whose purchase could not be recorded.
+pub(crate) async fn refund_charge(
+    root: &Surreal<Any>,
+    wallet: RecordId,
+    amount: u32,
+) -> Result<(), AppError> {
+    root.query("UPDATE $rid SET balance += $amount, spent -= $amount")
+        .bind(("rid", wallet))
+        .bind(("amount", amount))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| AppError::DbError(format!("Failed to refund wallet: {e}")))?;
+    Ok(())
+}
+
+/// The caller's sponsor wallet for a game, with the gift catalog.
+pub async fn sponsor_wallet(
+    Path(game_identifier): Path<Uuid>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+) -> Result<Json<SponsorWallet>, AppError> {
+    // Loading through the caller's connection keeps private games private.
+    let game = super::get_full_game(&game_identifier.to_string(), &db).await?;
+    let user_id = auth_user_id(&db).await?;
+    let (_, wallet) = open_wallet(&state.db, &game, &user_id).await?;
+    Ok(Json(wallet_response(wallet)))
+}
+
+/// Buy a gift from the catalog for a living tribute. It arrives during the
+/// next cycle the game runs.
+pub async fn buy_gift(
+    Path((game_identifier, tribute_identifier)): Path<(Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+    Json(payload): Json<BuyGift>,
+) -> Result<Json<SponsorWallet>, AppError> {
+    let game = super::get_full_game(&game_identifier.to_string(), &db).await?;
+    if game.status == GameStatus::Finished {
+        return Err(AppError::InvalidStatus("Game is finished".into()));
+    }
+    let tribute_identifier = tribute_identifier.to_string();
+    let tribute = game
+        .tributes
+        .iter()
+        .find(|t| t.identifier == tribute_identifier)
+        .ok_or_else(|| AppError::NotFound("Tribute not found".to_string()))?;
+    if !tribute.is_alive() {
+        return Err(AppError::BadRequest(format!("{} is dead", tribute.name)));
+    }
+
+    #[derive(Deserialize, Serialize)]
+    struct Caller {
+        id: RecordId,
+        username: String,
+    }
+    let mut response = db
+        .query("SELECT id, username FROM $auth")
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to query auth: {e}")))?;
+    let caller = response
+        .take::<Option<SerdeWrapper<Caller>>>(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read auth: {e}")))?
+        .map(|w| w.0)
+        .ok_or_else(|| AppError::Unauthorized("No authenticated session".into()))?;
+    let user_id = crate::rid_to_string(&caller.id);
+
+    let item = serde_json::to_value(payload.item)
+        .map_err(|e| AppError::InternalServerError(format!("Failed to encode gift: {e}")))?;
+    let price = gift_price(payload.item);
+    let (rid, wallet) = charge_wallet(&state.db, &game, &user_id, price)
+        .await?
+        .ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "A {:?} gift costs {price}; not enough funds",
+                payload.item
+            ))
+        })?;
+
+    let identifier = Uuid::new_v4().to_string();
+    let recorded = state
+        .db
+        .query(
+            "CREATE $gift CONTENT { identifier: $identifier, game_id: $game, wallet: $wallet, \
+             donor: $donor, recipient: $recipient, item: $item, cost: $price }",
+        )
+        .bind(("gift", RecordId::new("sponsor_gift", identifier.as_str())))
+        .bind(("identifier", identifier.clone()))
+        .bind(("game", game.identifier.clone()))
+        .bind(("wallet", rid.clone()))
+        .bind(("donor", caller.username))
+        .bind(("recipient", tribute_identifier))
+        .bind(("item", item))
+        .bind(("price", price))
+        .await
+        .and_then(|response| response.check());
+    if let Err(e) = recorded {
+        // The money is already gone; give it back rather than lose it.
+        refund_charge(&state.db, rid, price).await?;
+        return Err(AppError::DbError(format!("Failed to record gift: {e}")));
+    }
+
+    Ok(Json(wallet))
+}
+
+/// Queue the game's pending gifts on `Game::player_gifts`.
+pub(crate) async fn load_gifts(game: &mut Game, root: &Surreal<Any>) -> Result<(), AppError> {
+    let mut response = root
+        .query(
+            "SELECT identifier, donor, recipient, item FROM sponsor_gift \
+             WHERE game_id = $game AND delivered_day IS NONE",
+        )
+        .bind(("game", game.identifier.clone()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch gifts: {e}")))?;
+    let rows: Vec<serde_json::Value> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to take gifts: {e}")))?;
+
+    game.player_gifts = rows
+        .into_iter()
+        .filter_map(|row| serde_json::from_value::<GiftRow>(row).ok())
+        .map(|row| PlayerGift {
+            identifier: row.identifier,
+            donor: row.donor,
+            recipient: row.recipient,
+            item: row.item,
+        })
+        .collect();
+    Ok(())
+}
+
+/// Settle the gifts handed to the engine: refund the ones it could not
+/// deliver (still on `Game::player_gifts`) and mark the rest delivered in
+/// `phase` of `day`, the first the engine ran. Gifts bought while the day
+/// ran stay pending.
+pub(crate) async fn settle_gifts(
+    game: &Game,
+    loaded: Vec<String>,
+    (day, phase): (u32, Phase),
+    root: &Surreal<Any>,
+) -> Result<(), AppError> {
+    if loaded.is_empty() {
+        return Ok(());
+    }
+    #[derive(Debug, Deserialize, Serialize)]
+    struct UndeliveredGift {
+        id: RecordId,
+        wallet: RecordId,
+        cost: u32,
+    }
+
+    let refunds: Vec<String> = game
+        .player_gifts
+        .iter()
+        .map(|g| g.identifier.clone())
+        .collect();
+    let mut response = root
+        .query("SELECT id, wallet, cost FROM sponsor_gift WHERE identifier IN $refunds")
+        .bind(("refunds", refunds.clone()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch undelivered gifts: {e}")))?;
+    let undelivered: Vec<SerdeWrapper<UndeliveredGift>> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to take undelivered gifts: {e}")))?;
+
+    // A gift only goes once its cost is back in the wallet.
+    for gift in undelivered.into_iter().map(|w| w.0) {
+        refund_charge(root, gift.wallet, gift.cost).await?;
+        root.query("DELETE $gift")
+            .bind(("gift", gift.id))
+            .await
+            .and_then(|response| response.check())
+            .map_err(|e| AppError::DbError(format!("Failed to delete gift: {e}")))?;
+    }
+
+    let delivered: Vec<String> = loaded
+        .into_iter()
+        .filter(|identifier| !refunds.contains(identifier))
+        .collect();
+    root.query(
+        "UPDATE sponsor_gift SET delivered_day = $day, delivered_phase = $phase \
+         WHERE identifier IN $delivered",
+    )
+    .bind(("delivered", delivered))
+    .bind(("day", day))
+    .bind(("phase", phase.to_string()))
+    .await
+    .and_then(|response| response.check())
+    .map_err(|e| AppError::DbError(format!("Failed to settle gifts: {e}")))?;
+    Ok(())
+}
+
+/// Refund the gifts a rollback un-delivers: those delivered after `phase`
+/// on `day`.
+pub(crate) async fn refund_gifts_after(
+    root: &Surreal<Any>,
+    game_identifier: &str,
+    day: u32,
+    phase: Phase,
+) -> Result<(), AppError> {
+    #[derive(Debug, Deserialize, Serialize)]
+    struct DeliveredGift {
+        id: RecordId,
+        wallet: RecordId,
+        cost: u32,
+    }
+
+    let mut response = root
+        .query(
+            "SELECT id, wallet, cost FROM sponsor_gift WHERE game_id = $game \
+             AND (delivered_day > $day OR (delivered_day = $day AND delivered_phase IN $later))",
+        )
+        .bind(("game", game_identifier.to_string()))
+        .bind(("day", day))
+        .bind(("later", later_phases(phase)))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch delivered gifts: {e}")))?;
+    let delivered: Vec<SerdeWrapper<DeliveredGift>> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to take delivered gifts: {e}")))?;
+
+    for gift in delivered.into_iter().map(|w| w.0) {
+        refund_charge(root, gift.wallet, gift.cost).await?;
+        root.query("DELETE $gift")
+            .bind(("gift", gift.id))
+            .await
+            .and_then(|response| response.check())
+            .map_err(|e| AppError::DbError(format!("Failed to delete gift: {e}")))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! Per-tribute decision traces.
+//!
+//! `run_game_cycles` drains the traces the engine collected while running
+//! (one per tribute per phase, see `game::tributes::brains::trace`) into
+//! the `decision_trace` table. A tribute's traces are read back through
+//! `GET /api/games/{game}/tributes/{tribute}/decisions`, for its player
+//! and the game's creator only: a trace shows what the tribute believed.
+//!
+//! `decision_trace` is server-side only, so these helpers take the root
+//! connection; callers check who is asking first.
+
+use crate::AppError;
+use game::tributes::brains::trace::DecisionTrace;
+use serde::{Deserialize, Serialize};
+use shared::messages::Phase;
+use surrealdb::Surreal;
+use surrealdb::engine::any::Any;
+use surrealdb_types::{RecordId, SerdeWrapper};
+
+#[derive(Debug, Deserialize, Serialize)]
+struct TraceRow {
+    /// JSON-encoded `DecisionTrace`, like message payloads.
+    trace: String,
+}
+
+fn trace_id(tribute_identifier: &str, day: u32, phase: Phase) -> RecordId {
+    RecordId::new(
+        "decision_trace",
+        format!("{tribute_identifier}_{day}_{phase}"),
+    )
+}
+
+/// Store traces from the phases just run, replacing any earlier ones for
+/// the same tribute and phase (a phase replayed after a rollback).
+pub(crate) async fn save_traces(
+    root: &Surreal<Any>,
+    game_identifier: &str,
+    traces: &[DecisionTrace],
+) -> Result<(), AppError> {
+    for trace in traces {
+        let encoded = serde_json::to_string(trace).map_err(|e| {
+            AppError::InternalServerError(format!("Failed to encode decision trace: {e}"))
+        })?;
+        root.query(
+            "UPSERT $rid SET game_id = $game, tribute_id = $tribute, day = $day, \
+             phase = $phase, trace = $trace, created_at = time::now()",
+        )
+        .bind(("rid", trace_id(&trace.tribute, trace.day, trace.phase)))
+        .bind(("game", game_identifier.to_string()))
+        .bind(("tribute", trace.tribute.clone()))
+        .bind(("day", trace.day))
+        .bind(("phase", trace.phase.to_string()))
+        .bind(("trace", encoded))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to save decision trace: {e}")))?;
+    }
+    Ok(())
+}
+
+/// A tribute's traces, oldest first, optionally only those from `day`.
+pub(crate) async fn tribute_traces(
+    root: &Surreal<Any>,
+    tribute_identifier: &str,
+    day: Option<u32>,
+) -> Result<Vec<DecisionTrace>, AppError> {
+    let mut response = root
+        .query("SELECT trace FROM decision_trace WHERE tribute_id = $tribute")
+        .bind(("tribute", tribute_identifier.to_string()))
+        .await
+        .map_err(|e| AppError::DbError(format!("Failed to fetch decision traces: {e}")))?;
+    let rows: Vec<SerdeWrapper<TraceRow>> = response
+        .take(0)
+        .map_err(|e| AppError::DbError(format!("Failed to read decision traces: {e}")))?;
+    let mut traces = rows
+        .into_iter()
+        .map(|w| {
+            serde_json::from_str::<DecisionTrace>(&w.0.trace).map_err(|e| {
+                AppError::InternalServerError(format!("Failed to decode decision trace: {e}"))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    traces.retain(|t| day.is_none_or(|day| t.day == day));
+    // Phases are stored lowercase, so sorting in the query would be
+    // alphabetical.
+    traces.sort_by_key(|t| (t.day, t.phase));
+    Ok(traces)
+}
@@ -34,9 +34,78 @@ pub struct CreateGameRequest {
     #[serde(default)]
     pub private: Option<String>,
     #[serde(default)]
+    pub difficulty: Option<String>,
+    #[serde(default)]
+    pub item_quantity: Option<String>,
+    #[serde(default)]
+    pub event_frequency: Option<String>,
+    #[serde(default)]
+    pub trauma_enabled: Option<String>,
+    #[serde(default)]
+    pub phobias_enabled: Option<String>,
+    #[serde(default)]
+    pub fixations_enabled: Option<String>,
+    #[serde(default)]
+    pub addiction_enabled: Option<String>,
+    #[serde(default)]
+    pub gamemaker_enabled: Option<String>,
+    #[serde(default)]
+    pub contagions_enabled: Option<String>,
+    #[serde(default)]
+    pub tribute_count: Option<String>,
+    #[serde(default)]
+    pub district_count: Option<String>,
+    #[serde(default)]
+    pub arena_rings: Option<String>,
+    #[serde(default)]
     pub csrf_token: String,
 }
 
+impl CreateGameRequest {
+    /// Translate the form into the same `CreateGame` the JSON API takes.
+    /// Select values are enum variant names; unchecked boxes are absent
+    /// from the form, so a missing toggle means "off".
+    fn to_create_game(&self) -> Result<shared::CreateGame, String> {
+        fn preset<T: serde::de::DeserializeOwned>(
+            value: &Option<String>,
+        ) -> Result<Option<T>, String> {
+            match value.as_deref().filter(|v| !v.is_empty()) {
+                None => Ok(None),
+                Some(v) => serde_json::from_value(serde_json::Value::String(v.to_owned()))
+                    .map(Some)
+                    .map_err(|_| format!("Unknown option: {v}")),
+            }
+        }
+        let checked = |value: &Option<String>| Some(value.as_deref() == Some("true"));
+        let count = |value: &Option<String>| -> Result<Option<u32>, String> {
+            match value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+                None => Ok(None),
+                Some(v) => v
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| format!("Not a number: {v}")),
+            }
+        };
+
+        Ok(shared::CreateGame {
+            name: self.name.clone().filter(|n| !n.is_empty()),
+            item_quantity: preset(&self.item_quantity)?.unwrap_or_default(),
+            difficulty: preset(&self.difficulty)?.unwrap_or_default(),
+            event_frequency: preset(&self.event_frequency)?,
+            starting_health_range: None,
+            trauma_enabled: checked(&self.trauma_enabled),
+            phobias_enabled: checked(&self.phobias_enabled),
+            fixations_enabled: checked(&self.fixations_enabled),
+            addiction_enabled: checked(&self.addiction_enabled),
+            gamemaker_enabled: checked(&self.gamemaker_enabled),
+            contagions_enabled: checked(&self.contagions_enabled),
+            tribute_count: count(&self.tribute_count)?,
+            district_count: count(&self.district_count)?,
+            arena_rings: count(&self.arena_rings)?,
+        })
+    }
+}
+
 // ── HTMX page handlers ──────────────────────────────────────────────
 
 /// GET / — home page.
@@ -157,7 +226,7 @@ pub async fn game_detail_handler(
     let db = if !token.is_empty() {
         match authenticate_db(&state, &token).await {
             Ok(db) => db,
-            Err(_) => (*state.db).clone(),  // fallback to unauthenticated
+            Err(_) => (*state.db).clone(), // fallback to unauthenticated
         }
     } else {
         (*state.db).clone()
@@ -317,7 +386,11 @@ SELECT (
 
     // Day numbers: generate range 1..=game.day (includes all days, not just days with messages)
     let max_day = game.day.unwrap_or(0);
-    let day_numbers: Vec<u32> = if max_day > 0 { (1..=max_day).collect() } else { vec![] };
+    let day_numbers: Vec<u32> = if max_day > 0 {
+        (1..=max_day).collect()
+    } else {
+        vec![]
+    };
 
     // Pre-render event cards
     let mut event_cards = String::new();
@@ -650,7 +723,9 @@ pub async fn timeline_handler(
                     shared::messages::MessageKind::ItemFound
                     | shared::messages::MessageKind::ItemUsed
                     | shared::messages::MessageKind::ItemDropped
-                    | shared::messages::MessageKind::SponsorGift => "items",
+                    | shared::messages::MessageKind::SponsorGift
+                    | shared::messages::MessageKind::CorpseLeft
+                    | shared::messages::MessageKind::CorpseLooted => "items",
                     _ => "",
                 };
                 if kind_str != filter_str {
@@ -873,49 +948,16 @@ pub async fn create_game_post_handler(
         Err(redirect) => return redirect.into_response(),
     };
 
-    use game::games::Game;
-    let game = Game::default();
-    let game_identifier = uuid::Uuid::new_v4().to_string();
-    let game_name = form.name.filter(|n| !n.is_empty()).unwrap_or(game.name);
-    let is_private = form.private.is_some_and(|v| v == "true");
-
-    use surrealdb_types::RecordId;
-
-    let game_rid = RecordId::new("game", game_identifier.as_str());
-    let body = serde_json::json!({
-        "identifier": &game_identifier,
-        "name": &game_name,
-        "status": "NotStarted",
-        "day": null,
-        "private": is_private,
-    });
-
-    if user_db
-        .query("UPSERT $rid CONTENT $body")
-        .bind(("rid", game_rid.clone()))
-        .bind(("body", body))
-        .await
-        .is_err()
-    {
-        return Redirect::to("/games/new").into_response();
-    }
-
-    let tribute_futures = (0..24)
-        .map(|idx| api::tributes::create_tribute(None, &game_identifier, &user_db, idx % 12));
-    let tribute_results = futures::future::join_all(tribute_futures).await;
-    if tribute_results.into_iter().any(|r| r.is_err()) {
-        return Redirect::to("/games/new").into_response();
-    }
-
-    use game::areas::Area;
-    use strum::IntoEnumIterator;
-    let base_item_count = shared::ItemQuantity::default().base_item_count();
-    let area_futures = Area::iter()
-        .map(|area| api::games::create_area(&game_identifier, area, base_item_count, &user_db));
-    let area_results = futures::future::join_all(area_futures).await;
-    if area_results.into_iter().any(|r| r.is_err()) {
-        return Redirect::to("/games/new").into_response();
-    }
+    let is_private = form.private.as_deref() == Some("true");
+    let payload = match form.to_create_game() {
+        Ok(payload) if validator::Validate::validate(&payload).is_ok() => payload,
+        _ => return Redirect::to("/games/new").into_response(),
+    };
+    let game = match api::games::create_game_from_request(&payload, is_private, &user_db).await {
+        Ok(game) => game,
+        Err(_) => return Redirect::to("/games/new").into_response(),
+    };
+    let game_identifier = game.identifier;
 
     Redirect::to(&format!("/games/{game_identifier}")).into_response()
 }
@@ -28,6 +28,7 @@ impl Default for UploadConstraints {
 }
 
 /// Storage backend trait - allows swapping between local filesystem and S3/MinIO
+#[allow(clippy::double_must_use)]
 #[async_trait::async_trait]
 pub trait StorageBackend: Send + Sync {
     /// Save a file and return its public URL path
@@ -34,7 +34,7 @@ fn message_archetype(payload: &shared::messages::MessagePayload) -> &'static str
         AllianceFormed | AllianceProposed | AllianceDissolved | BetrayalTriggered
         | TrustShockBreak => "commentary",
         TributeMoved | TributeHidden | AreaClosed | AreaEvent => "commentary",
-        ItemFound | ItemUsed | ItemDropped | SponsorGift => "event",
+        ItemFound | ItemUsed | ItemDropped | SponsorGift | CorpseLeft | CorpseLooted => "event",
         TributeRested | TributeStarved | TributeDehydrated | SanityBreak | HungerBandChanged
         | ThirstBandChanged | StaminaBandChanged | ShelterSought | Foraged | Drank | Ate
         | TributeSlept | TributeWoke | CycleStart | CycleEnd | PhaseStarted | PhaseEnded
@@ -58,6 +58,16 @@ fn message_archetype(payload: &shared::messages::MessagePayload) -> &'static str
         | RescueAttempted
         | PartialRescueProgress => "event",
         SleepIncident => "commentary",
+        WeatherChanged => "event",
+        ContagionInfected | ContagionTransformed => "event",
+        FireballStrike
+        | MuttSwarmSpawned
+        | MuttSwarmAttack
+        | MuttSwarmDespawned
+        | ForceFieldShifted
+        | AreaSealEntryDamage
+        | ConvergencePointAnnounced
+        | ConvergencePointExpired => "event",
     }
 }
 
@@ -80,7 +90,7 @@ fn message_kind_label(payload: &shared::messages::MessagePayload) -> &'static st
         AllianceFormed | AllianceProposed | AllianceDissolved | BetrayalTriggered
         | TrustShockBreak => "Alliance",
         TributeMoved | TributeHidden | AreaClosed | AreaEvent => "Movement",
-        ItemFound | ItemUsed | ItemDropped => "Item",
+        ItemFound | ItemUsed | ItemDropped | CorpseLeft | CorpseLooted => "Item",
         SponsorGift => "Sponsor",
         TributeRested | TributeStarved | TributeDehydrated | SanityBreak | HungerBandChanged
         | ThirstBandChanged | StaminaBandChanged | ShelterSought | Foraged | Drank | Ate
@@ -105,6 +115,16 @@ fn message_kind_label(payload: &shared::messages::MessagePayload) -> &'static st
         | RescueAttempted
         | PartialRescueProgress => "Trapped",
         SleepIncident => "Sleep",
+        WeatherChanged => "Weather",
+        ContagionInfected | ContagionTransformed => "Contagion",
+        FireballStrike
+        | MuttSwarmSpawned
+        | MuttSwarmAttack
+        | MuttSwarmDespawned
+        | ForceFieldShifted
+        | AreaSealEntryDamage
+        | ConvergencePointAnnounced
+        | ConvergencePointExpired => "Gamemaker",
     }
 }
 
@@ -118,7 +138,9 @@ fn kind_color(payload: &shared::messages::MessagePayload) -> &'static str {
         AllianceFormed | AllianceProposed | AllianceDissolved | BetrayalTriggered
         | TrustShockBreak => "var(--info)",
         TributeMoved | TributeHidden | AreaClosed | AreaEvent => "var(--accent)",
-        ItemFound | ItemUsed | ItemDropped | SponsorGift => "var(--gold)",
+        ItemFound | ItemUsed | ItemDropped | SponsorGift | CorpseLeft | CorpseLooted => {
+            "var(--gold)"
+        }
         TributeRested | TributeStarved | TributeDehydrated | SanityBreak | HungerBandChanged
         | ThirstBandChanged | StaminaBandChanged | ShelterSought | Foraged | Drank | Ate
         | TributeSlept | TributeWoke | CycleStart | CycleEnd | PhaseStarted | PhaseEnded
@@ -142,6 +164,16 @@ fn kind_color(payload: &shared::messages::MessagePayload) -> &'static str {
         | RescueAttempted
         | PartialRescueProgress => "var(--warning)",
         SleepIncident => "var(--info)",
+        WeatherChanged => "var(--accent)",
+        ContagionInfected | ContagionTransformed => "var(--danger)",
+        FireballStrike
+        | MuttSwarmSpawned
+        | MuttSwarmAttack
+        | MuttSwarmDespawned
+        | ForceFieldShifted
+        | AreaSealEntryDamage
+        | ConvergencePointAnnounced
+        | ConvergencePointExpired => "var(--danger)",
     }
 }
 
@@ -423,6 +455,16 @@ pub fn render_tribute_detail(tribute: &game::tributes::Tribute, _game_id: &str)
         ));
     }
 
+    let goal_html = tribute
+        .current_goal()
+        .map(|goal| {
+            format!(
+                "<div class=\"card-goal\">Setting out to {}</div>",
+                html_escape(&goal)
+            )
+        })
+        .unwrap_or_default();
+
     format!(
         r#"<div class="detail-header">
           <div>
@@ -442,6 +484,14 @@ pub fn render_tribute_detail(tribute: &game::tributes::Tribute, _game_id: &str)
           <span style="color:{thirst_c}">{thirst}</span>
           <span style="color:{stamina_c}">{stamina}</span>
         </div>
+        <div class="card-emotions">
+          Feeling {emotion}
+          <span>Morale <span class="stat-val">{morale}</span></span>
+          <span>Aggression <span class="stat-val">{aggression}</span></span>
+          <span>Trust <span class="stat-val">{trust}</span></span>
+          <span>Composure <span class="stat-val">{composure}</span></span>
+        </div>
+        {goal}
         <div class="card-items">{items}</div>
         <div class="card-afflictions">{afflictions}</div>"#,
         name = html_escape(&tribute.name),
@@ -449,6 +499,12 @@ pub fn render_tribute_detail(tribute: &game::tributes::Tribute, _game_id: &str)
         strength = tribute.attributes.strength,
         defense = tribute.attributes.defense,
         intelligence = tribute.attributes.intelligence,
+        emotion = tribute.dominant_emotion(),
+        morale = tribute.emotions.morale,
+        aggression = tribute.emotions.aggression,
+        trust = tribute.emotions.trust,
+        composure = tribute.emotions.composure,
+        goal = goal_html,
         items = items_html,
         afflictions = afflictions_html,
     )
@@ -470,75 +526,48 @@ pub struct AllianceGroup {
     pub tributes: Vec<usize>,
 }
 
-pub fn build_alliance_groups(tributes: &[&game::tributes::Tribute]) -> Vec<AllianceGroup> {
-    let n = tributes.len();
-    let mut parent: Vec<usize> = (0..n).collect();
-
-    fn find(parent: &mut [usize], x: usize) -> usize {
-        if parent[x] != x {
-            parent[x] = find(parent, parent[x]);
-        }
-        parent[x]
-    }
-
-    fn union(parent: &mut [usize], a: usize, b: usize) {
-        let ra = find(parent, a);
-        let rb = find(parent, b);
-        if ra != rb {
-            parent[ra] = rb;
-        }
-    }
-
-    let id_to_idx: std::collections::HashMap<String, usize> = tributes
-        .iter()
-        .enumerate()
-        .map(|(i, t)| (t.id.to_string(), i))
-        .collect();
-
-    for (i, tribute) in tributes.iter().enumerate() {
-        for ally_id in &tribute.allies {
-            if let Some(&j) = id_to_idx.get(&ally_id.to_string()) {
-                union(&mut parent, i, j);
-            }
-        }
+/// One group per alliance on the game, leader first, and one `SOLO` group
+/// for each tribute outside them. Groups with more of their members alive
+/// come first.
+pub fn build_alliance_groups(
+    tributes: &[&game::tributes::Tribute],
+    alliances: &[game::tributes::alliances::Alliance],
+) -> Vec<AllianceGroup> {
+    let index_of = |id: &uuid::Uuid| tributes.iter().position(|t| t.id == *id);
+
+    let mut grouped = vec![false; tributes.len()];
+    let mut alliance_groups: Vec<AllianceGroup> = Vec::new();
+    for alliance in alliances {
+        let mut indices: Vec<usize> = std::iter::once(&alliance.leader)
+            .chain(alliance.members.iter().filter(|m| **m != alliance.leader))
+            .filter_map(index_of)
+            .collect();
+        indices.retain(|&i| !std::mem::replace(&mut grouped[i], true));
+        let Some(&leader) = indices.first() else {
+            continue;
+        };
+        alliance_groups.push(AllianceGroup {
+            name: format!("{}'S ALLIANCE", tributes[leader].name.to_uppercase()),
+            color: ALLIANCE_COLORS[alliance_groups.len() % ALLIANCE_COLORS.len()],
+            tributes: indices,
+        });
     }
-
-    let mut groups: std::collections::HashMap<usize, Vec<usize>> = std::collections::HashMap::new();
-    for i in 0..n {
-        let root = find(&mut parent, i);
-        groups.entry(root).or_default().push(i);
+    for (i, _) in grouped.iter().enumerate().filter(|(_, grouped)| !**grouped) {
+        alliance_groups.push(AllianceGroup {
+            name: "SOLO".to_string(),
+            color: ALLIANCE_COLORS[alliance_groups.len() % ALLIANCE_COLORS.len()],
+            tributes: vec![i],
+        });
     }
 
-    let mut alliance_groups: Vec<AllianceGroup> = groups
-        .into_iter()
-        .enumerate()
-        .map(|(color_idx, (_, indices))| {
-            let color = ALLIANCE_COLORS[color_idx % ALLIANCE_COLORS.len()];
-            let name = if indices.len() == 1 {
-                "SOLO".to_string()
-            } else {
-                format!("ALLIANCE {}", color_idx + 1)
-            };
-            AllianceGroup {
-                name,
-                color,
-                tributes: indices,
-            }
-        })
-        .collect();
-
-    alliance_groups.sort_by(|a, b| {
-        let a_alive = a
-            .tributes
-            .iter()
-            .filter(|&&i| tributes[i].is_alive())
-            .count();
-        let b_alive = b
-            .tributes
-            .iter()
-            .filter(|&&i| tributes[i].is_alive())
-            .count();
-        b_alive.cmp(&a_alive)
+    alliance_groups.sort_by_key(|group| {
+        std::cmp::Reverse(
+            group
+                .tributes
+                .iter()
+                .filter(|&&i| tributes[i].is_alive())
+                .count(),
+        )
     });
 
     alliance_groups
@@ -643,10 +672,8 @@ fn terrain_color(terrain: &game::terrain::BaseTerrain) -> &'static str {
 }
 
 const HEX_SIZE: f64 = 52.0;
-const HEX_H: f64 = 104.0;
-const HEX_W: f64 = 90.0;
-const CENTER_X: f64 = 160.0;
-const CENTER_Y: f64 = 150.0;
+/// Space between the outermost hexes and the edge of the viewBox.
+const MAP_PADDING: f64 = 10.0;
 
 fn hex_corners(cx: f64, cy: f64) -> [(f64, f64); 6] {
     let mut corners = [(0.0, 0.0); 6];
@@ -657,42 +684,41 @@ fn hex_corners(cx: f64, cy: f64) -> [(f64, f64); 6] {
     corners
 }
 
-fn hex_center(area_idx: usize) -> (f64, f64) {
-    match area_idx {
-        0 => (CENTER_X, CENTER_Y),
-        1 => (CENTER_X + HEX_W, CENTER_Y),
-        2 => (CENTER_X + HEX_W * 0.5, CENTER_Y - HEX_H * 0.75),
-        3 => (CENTER_X - HEX_W * 0.5, CENTER_Y - HEX_H * 0.75),
-        4 => (CENTER_X - HEX_W, CENTER_Y),
-        5 => (CENTER_X - HEX_W * 0.5, CENTER_Y + HEX_H * 0.75),
-        6 => (CENTER_X + HEX_W * 0.5, CENTER_Y + HEX_H * 0.75),
-        _ => (CENTER_X, CENTER_Y),
-    }
-}
-
 pub fn render_hex_map(
     areas: &[game::areas::AreaDetails],
     tributes: &[&game::tributes::Tribute],
 ) -> String {
-    let area_order = [
-        game::areas::Area::Cornucopia,
-        game::areas::Area::Sector1,
-        game::areas::Area::Sector2,
-        game::areas::Area::Sector3,
-        game::areas::Area::Sector4,
-        game::areas::Area::Sector5,
-        game::areas::Area::Sector6,
-    ];
-
     let area_map: std::collections::HashMap<game::areas::Area, &game::areas::AreaDetails> = areas
         .iter()
         .filter_map(|a| a.area.map(|area| (area, a)))
         .collect();
 
+    // Lay out whatever arena the game has; a game without areas yet shows
+    // the classic seven.
+    let mut area_order: Vec<game::areas::Area> = area_map.keys().copied().collect();
+    if area_order.is_empty() {
+        area_order = game::areas::arena::Arena::default().areas().collect();
+    }
+    area_order.sort();
+
+    let centers: Vec<(f64, f64)> = area_order
+        .iter()
+        .map(|area| area.axial().to_pixel(HEX_SIZE))
+        .collect();
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
+    for &(x, y) in &centers {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+    let extent = HEX_SIZE + MAP_PADDING;
+    let (min_x, min_y) = (min_x - extent, min_y - extent);
+    let (max_x, max_y) = (max_x + extent, max_y + extent);
+
     let mut hexes = String::new();
 
-    for (idx, area_type) in area_order.iter().enumerate() {
-        let (cx, cy) = hex_center(idx);
+    for (area_type, &(cx, cy)) in area_order.iter().zip(&centers) {
         let corners = hex_corners(cx, cy);
         let points: String = corners
             .iter()
@@ -705,6 +731,14 @@ pub fn render_hex_map(
             .map(|a| a.terrain.base)
             .unwrap_or(game::terrain::BaseTerrain::Clearing);
         let fill = terrain_color(&terrain);
+        let weather = area_map
+            .get(area_type)
+            .map(|a| a.weather.current)
+            .unwrap_or_default();
+        let weather_label = match weather {
+            game::areas::weather::Weather::Clear => String::new(),
+            other => other.to_string().to_uppercase(),
+        };
 
         let terrain_label = format!("{:?}", terrain).to_uppercase();
         let area_name = area_type.to_string();
@@ -716,8 +750,9 @@ pub fn render_hex_map(
 
         hexes.push_str(&format!(
             r#"<polygon points="{points}" fill="{fill}" stroke="var(--broad-border-strong)" stroke-width="2" opacity="0.85"/>
-            <text x="{cx}" y="{cy:.1}" text-anchor="middle" dominant-baseline="middle" fill="rgba(255,255,255,0.9)" font-size="9" font-family="var(--font-condensed)" font-weight="600" letter-spacing="1">{terrain_label}</text>
-            <text x="{cx}" y="{cy:.1}" text-anchor="middle" dominant-baseline="middle" fill="rgba(255,255,255,0.5)" font-size="7" font-family="var(--font-condensed)" dy="12">{area_name}</text>"#,
+            <text x="{cx:.1}" y="{cy:.1}" text-anchor="middle" dominant-baseline="middle" fill="rgba(255,255,255,0.9)" font-size="9" font-family="var(--font-condensed)" font-weight="600" letter-spacing="1">{terrain_label}</text>
+            <text x="{cx:.1}" y="{cy:.1}" text-anchor="middle" dominant-baseline="middle" fill="rgba(255,255,255,0.5)" font-size="7" font-family="var(--font-condensed)" dy="12">{area_name}</text>
+            <text x="{cx:.1}" y="{cy:.1}" text-anchor="middle" dominant-baseline="middle" fill="rgba(255,255,255,0.7)" font-size="7" font-family="var(--font-condensed)" dy="-12">{weather_label}</text>"#,
         ));
 
         // Tribute dots in this hex
@@ -745,11 +780,11 @@ pub fn render_hex_map(
         }
     }
 
-    let view_w = CENTER_X * 2.0 + HEX_W + 20.0;
-    let view_h = CENTER_Y * 2.0 + HEX_H + 20.0;
+    let view_w = max_x - min_x;
+    let view_h = max_y - min_y;
 
     format!(
-        r#"<svg viewBox="0 0 {view_w:.0} {view_h:.0}" xmlns="http://www.w3.org/2000/svg" class="hex-map">
+        r#"<svg viewBox="{min_x:.0} {min_y:.0} {view_w:.0} {view_h:.0}" xmlns="http://www.w3.org/2000/svg" class="hex-map">
           {hexes}
         </svg>"#,
     )
@@ -1,15 +1,23 @@
-use crate::games::game_tributes;
+use crate::games::orders::{self, auth_user_id, is_game_owner};
+use crate::games::sponsorship::buy_gift;
+use crate::games::traces;
+use crate::games::{game_tributes, get_full_game, get_game_status};
 use crate::storage::{UploadConstraints, validate_upload};
 use crate::{AppError, AppState, AuthDb};
-use axum::extract::{Extension, Multipart, Path, State};
+use axum::extract::{Extension, Multipart, Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{get, post};
+use axum::routing::{get, post, put};
 use axum::{Json, Router};
+use game::games::Game;
+use game::games::orders::{OrderError, TributeView};
 use game::items::Item;
 use game::messages::GameMessage;
 use game::tributes::Tribute;
+use game::tributes::actions::Action;
+use game::tributes::brains::trace::DecisionTrace;
 use serde::{Deserialize, Serialize};
-use shared::EditTribute;
+use shared::messages::Phase;
+use shared::{EditTribute, GameStatus};
 use std::sync::LazyLock;
 use surrealdb::Surreal;
 use surrealdb::engine::any::Any;
@@ -27,6 +35,11 @@ pub static TRIBUTES_ROUTER: LazyLock<Router<AppState>> = LazyLock::new(|| {
         )
         .route("/{identifier}/avatar", post(upload_avatar))
         .route("/{identifier}/log", get(tribute_log))
+        .route("/{identifier}/decisions", get(tribute_decisions))
+        .route("/{identifier}/controller", put(assign_controller))
+        .route("/{identifier}/view", get(tribute_view))
+        .route("/{identifier}/orders", put(submit_order))
+        .route("/{identifier}/gifts", post(buy_gift))
 });
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -37,6 +50,8 @@ pub struct TributeItemEdge {
     pub item: RecordId,
 }
 
+/// Reap a tribute (a random one unless given) from `district` into the
+/// game, refusing once the game's roster is full.
 pub async fn create_tribute(
     tribute: Option<Tribute>,
     game_identifier: &str,
@@ -44,20 +59,30 @@ pub async fn create_tribute(
     district: u32,
 ) -> Result<Tribute, AppError> {
     let game_id = RecordId::new("game", game_identifier.to_owned());
+    // Games created before the roster was configurable hold the classic 24.
     let mut tribute_count_resp = db
-        .query("RETURN count(SELECT id FROM playing_in WHERE out.identifier=$game)")
+        .query(
+            r#"
+        RETURN count(SELECT id FROM playing_in WHERE out.identifier=$game);
+        RETURN $game_rid.roster_size ?? $default_size;"#,
+        )
         .bind(("game", game_identifier.to_owned()))
+        .bind(("game_rid", game_id.clone()))
+        .bind(("default_size", shared::DEFAULT_TRIBUTE_COUNT))
         .await
         .map_err(|e| AppError::InternalServerError(format!("Failed to count tributes: {}", e)))?;
     let tribute_count: Option<u32> = tribute_count_resp.take(0).map_err(|e| {
         AppError::InternalServerError(format!("Failed to parse tribute count: {}", e))
     })?;
-    if tribute_count >= Some(24) {
+    let roster_size: Option<u32> = tribute_count_resp.take(1).map_err(|e| {
+        AppError::InternalServerError(format!("Failed to parse roster size: {}", e))
+    })?;
+    if tribute_count >= roster_size {
         return Err(AppError::GameFull("Game is full".to_string()));
     }
 
     let mut tribute = tribute.unwrap_or_else(Tribute::random);
-    tribute.district = district + 1;
+    tribute.district = district;
     tribute.statistics.game = game_identifier.to_owned();
 
     let id = RecordId::new("tribute", tribute.identifier.as_str());
@@ -291,3 +316,199 @@ pub async fn upload_avatar(
         })))
     }
 }
+
+/// Hand a tribute to a player, by email, or take it back with `null`.
+#[derive(Debug, Deserialize)]
+pub struct AssignController {
+    pub email: Option<String>,
+}
+
+/// An order for one phase of the coming day.
+#[derive(Debug, Deserialize)]
+pub struct SubmitOrder {
+    pub phase: Phase,
+    pub action: Action,
+}
+
+/// Only the game's creator can hand its tributes to players.
+pub async fn assign_controller(
+    Path((game_identifier, tribute_identifier)): Path<(Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+    Json(payload): Json<AssignController>,
+) -> Result<StatusCode, AppError> {
+    if !is_game_owner(&db, &game_identifier.to_string()).await? {
+        return Err(AppError::Unauthorized(
+            "Only the game's creator can assign tributes".into(),
+        ));
+    }
+    if get_game_status(&db, &game_identifier.to_string()).await? == GameStatus::Finished {
+        return Err(AppError::InvalidStatus("Game is finished".into()));
+    }
+
+    let controller = match payload.email {
+        Some(email) => {
+            #[derive(Deserialize, Serialize)]
+            struct UserId {
+                id: RecordId,
+            }
+            // User rows are only visible to themselves; look the player up
+            // with the root connection.
+            let mut response = state
+                .db
+                .query("SELECT id FROM user WHERE email = $email")
+                .bind(("email", email.clone()))
+                .await
+                .map_err(|e| AppError::DbError(format!("Failed to find user: {e}")))?;
+            let user = response
+                .take::<Option<SerdeWrapper<UserId>>>(0)
+                .map_err(|e| AppError::DbError(format!("Failed to read user: {e}")))?
+                .ok_or_else(|| AppError::NotFound(format!("No user with email {email}")))?;
+            Some(crate::rid_to_string(&user.0.id))
+        }
+        None => None,
+    };
+
+    let mut response = db
+        .query(
+            "UPDATE tribute SET controller = $controller \
+             WHERE identifier = $identifier AND ->playing_in->game.identifier CONTAINS $game",
+        )
+        .bind(("identifier", tribute_identifier.to_string()))
+        .bind(("game", game_identifier.to_string()))
+        .bind(("controller", controller))
+        .await
+        .map_err(|e| AppError::InternalServerError(format!("Failed to update tribute: {}", e)))?;
+    let updated: Vec<serde_json::Value> = response
+        .take(0)
+        .map_err(|e| AppError::InternalServerError(format!("Failed to check update: {}", e)))?;
+
+    if updated.is_empty() {
+        Err(AppError::NotFound("Tribute not found".to_string()))
+    } else {
+        Ok(StatusCode::OK)
+    }
+}
+
+/// Load a game with the orders already submitted for its coming day.
+///
+/// Goes through the root connection, so callers must check who's asking
+/// first: an invited player can't see a private game on their own.
+async fn game_with_orders(state: &AppState, game_identifier: &str) -> Result<Game, AppError> {
+    let mut game = get_full_game(game_identifier, &state.db).await?;
+    if game.status == GameStatus::Finished {
+        return Err(AppError::InvalidStatus("Game is finished".into()));
+    }
+    orders::load_orders(&mut game, &state.db).await?;
+    Ok(game)
+}
+
+/// What a tribute can see, for its player or the game's creator.
+pub async fn tribute_view(
+    Path((game_identifier, tribute_identifier)): Path<(Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+) -> Result<Json<TributeView>, AppError> {
+    let (game_identifier, tribute_identifier) =
+        (game_identifier.to_string(), tribute_identifier.to_string());
+
+    let caller = auth_user_id(&db).await?;
+    let controller =
+        orders::tribute_controller(&state.db, &game_identifier, &tribute_identifier).await?;
+    if controller.as_ref() != Some(&caller) && !is_game_owner(&db, &game_identifier).await? {
+        return Err(AppError::Unauthorized(
+            "Only the tribute's player can see through its eyes".into(),
+        ));
+    }
+
+    let game = game_with_orders(&state, &game_identifier).await?;
+    game.tribute_view(&tribute_identifier)
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound("Tribute not found".to_string()))
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DecisionParams {
+    /// Only the decisions from this day.
+    pub day: Option<u32>,
+}
+
+/// How a tribute decided on its actions, phase by phase, for its player or
+/// the game's creator.
+pub async fn tribute_decisions(
+    Path((game_identifier, tribute_identifier)): Path<(Uuid, Uuid)>,
+    Query(params): Query<DecisionParams>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+) -> Result<Json<Vec<DecisionTrace>>, AppError> {
+    let (game_identifier, tribute_identifier) =
+        (game_identifier.to_string(), tribute_identifier.to_string());
+    let caller = auth_user_id(&db).await?;
+    let controller =
+        orders::tribute_controller(&state.db, &game_identifier, &tribute_identifier).await?;
+    if controller.as_ref() != Some(&caller) && !is_game_owner(&db, &game_identifier).await? {
+        return Err(AppError::Unauthorized(
+            "Only the tribute's player can see how it decides".into(),
+        ));
+    }
+
+    traces::tribute_traces(&state.db, &tribute_identifier, params.day)
+        .await
+        .map(Json)
+}
+
+/// Order a player's tribute for one phase of the coming day. Returns the
+/// updated view so the client can see which phases still need orders.
+pub async fn submit_order(
+    Path((game_identifier, tribute_identifier)): Path<(Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(AuthDb(db)): Extension<AuthDb>,
+    Json(payload): Json<SubmitOrder>,
+) -> Result<Json<TributeView>, AppError> {
+    let (game_identifier, tribute_identifier) =
+        (game_identifier.to_string(), tribute_identifier.to_string());
+
+    let caller = auth_user_id(&db).await?;
+    let controller =
+        orders::tribute_controller(&state.db, &game_identifier, &tribute_identifier).await?;
+    if controller.as_ref() != Some(&caller) {
+        return Err(AppError::Unauthorized(
+            "Only the tribute's player can give it orders".into(),
+        ));
+    }
+
+    let mut game = game_with_orders(&state, &game_identifier).await?;
+    if !game.upcoming_phases().contains(&payload.phase) {
+        return Err(AppError::BadRequest(format!(
+            "The coming day has no {} phase",
+            payload.phase
+        )));
+    }
+
+    let action = game
+        .validate_order(&tribute_identifier, payload.phase, payload.action)
+        .map_err(|e| match e {
+            OrderError::TributeNotFound(_) => AppError::NotFound(e.to_string()),
+            e => AppError::BadRequest(e.to_string()),
+        })?;
+    orders::save_order(
+        &state.db,
+        &game,
+        &tribute_identifier,
+        payload.phase,
+        &action,
+        &caller,
+    )
+    .await?;
+
+    if let Some(tribute) = game
+        .tributes
+        .iter_mut()
+        .find(|t| t.identifier == tribute_identifier)
+    {
+        tribute.orders.insert(payload.phase, action);
+    }
+    game.tribute_view(&tribute_identifier)
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound("Tribute not found".to_string()))
+}
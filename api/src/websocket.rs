@@ -168,7 +168,7 @@ pub fn broadcast_game_started(broadcaster: &GameBroadcaster, game_id: &str, day:
 
 /// Synthesize and broadcast a lifecycle [`MessagePayload::GameEnded`] for a
 /// game that finished without an additional cycle being run (e.g. the
-/// 24-deaths-already early-finish path in `next_step`).
+/// whole-roster-dead early-finish path in `next_step`).
 pub fn broadcast_game_finished(
     broadcaster: &GameBroadcaster,
     game_id: &str,
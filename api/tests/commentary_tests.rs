@@ -216,6 +216,8 @@ fn tribute_digest(
         location: location.into(),
         allies: vec![],
         kill_streak: 0,
+        dominant_emotion: None,
+        goal: None,
         notable_events: vec![],
         highlights: vec![],
     }
@@ -500,3 +500,852 @@ async fn timeline_summary_returns_404_for_missing_game() {
 
     test_db.cleanup().await;
 }
+
+/// `config`, `combat_tuning`, NPC sponsors and the gamemaker survive a
+/// save/reload instead of falling back to defaults (and re-rolled sponsors)
+/// on every load.
+#[tokio::test]
+async fn test_game_settings_persist_across_cycles() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "settings_keeper").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Settings Game" }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Tune the stored game directly; nothing in the API edits these yet.
+    let config = game::config::GameConfig {
+        trauma_enabled: false,
+        gamemaker_enabled: true,
+        day_event_frequency: 0.75,
+        ..Default::default()
+    };
+    let tuning = game::tributes::combat_tuning::CombatTuning {
+        stamina_cost_attacker: 40,
+        ..Default::default()
+    };
+    test_db
+        .db
+        .query("UPDATE game SET config = $config, combat_tuning = $tuning WHERE identifier = $id")
+        .bind(("config", serde_json::to_string(&config).unwrap()))
+        .bind(("tuning", serde_json::to_string(&tuning).unwrap()))
+        .bind(("id", game_id.clone()))
+        .await
+        .unwrap();
+
+    // Start, then play a day: sponsors are spawned on the first cycle.
+    for _ in 0..2 {
+        server
+            .put(&format!("/api/games/{}/next", game_id))
+            .add_header("Authorization", user.auth_header())
+            .await
+            .assert_status_ok();
+    }
+
+    let loaded = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_eq!(loaded.config, config);
+    assert_eq!(loaded.combat_tuning, tuning);
+    assert!(!loaded.sponsors.is_empty(), "sponsors should be persisted");
+    assert_eq!(loaded.gamemaker.day, 1, "gamemaker should be persisted");
+
+    // The next day must carry on with the same roster rather than re-roll
+    // it: same Loyalist district, budgets only ever spent down.
+    server
+        .put(&format!("/api/games/{}/next", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+    let reloaded = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_eq!(reloaded.sponsors.len(), loaded.sponsors.len());
+    for (before, after) in loaded.sponsors.iter().zip(&reloaded.sponsors) {
+        assert_eq!(after.archetype, before.archetype);
+        assert_eq!(after.bound_district, before.bound_district);
+        assert!(after.budget_remaining <= before.budget_remaining);
+    }
+
+    test_db.cleanup().await;
+}
+
+/// Every `CreateGame` option reaches the stored game: the difficulty preset,
+/// the explicit overrides on top of it, and tribute starting health.
+#[tokio::test]
+async fn test_create_game_honors_options() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "options_creator").await;
+
+    let response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({
+            "name": "Options Game",
+            "item_quantity": "Scarce",
+            "difficulty": "Bloodbath",
+            "event_frequency": "Rare",
+            "starting_health_range": [50, 60],
+            "trauma_enabled": false,
+            "gamemaker_enabled": false,
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let loaded = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    let bloodbath = game::config::GameConfig::for_difficulty(shared::Difficulty::Bloodbath);
+    assert_eq!(
+        loaded.config.low_tribute_threshold,
+        bloodbath.low_tribute_threshold
+    );
+    assert!((loaded.config.day_event_frequency - 0.1).abs() < 0.001);
+    assert!(!loaded.config.trauma_enabled);
+    assert!(loaded.config.phobias_enabled);
+    assert!(!loaded.config.gamemaker_enabled);
+    assert_eq!(
+        loaded.combat_tuning,
+        game::tributes::combat_tuning::CombatTuning::for_difficulty(shared::Difficulty::Bloodbath)
+    );
+    assert_eq!(loaded.tributes.len(), 24);
+    for tribute in &loaded.tributes {
+        assert!(
+            (500..=600).contains(&tribute.blood),
+            "blood {} outside requested range",
+            tribute.blood
+        );
+    }
+    for area in &loaded.areas {
+        assert_eq!(area.items.len(), 1);
+    }
+
+    test_db.cleanup().await;
+}
+
+/// An impossible starting health range is rejected before anything is written.
+#[tokio::test]
+async fn test_create_game_rejects_bad_health_range() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "bad_range_creator").await;
+
+    let response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "starting_health_range": [80, 40] }))
+        .await;
+    response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    test_db.cleanup().await;
+}
+
+/// A smaller roster is reaped, spread over its districts, and the game
+/// counts as ready with it.
+#[tokio::test]
+async fn test_create_game_with_custom_roster() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "roster_creator").await;
+
+    let response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Quick Game", "tribute_count": 8 }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let loaded = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_eq!(loaded.config.tribute_count, 8);
+    assert_eq!(loaded.config.district_count, 4);
+    assert_eq!(loaded.tributes.len(), 8);
+    let districts: std::collections::BTreeSet<u32> =
+        loaded.tributes.iter().map(|t| t.district).collect();
+    assert_eq!(districts, (1..=4).collect());
+
+    let display = server
+        .get(&format!("/api/games/{}/display", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .json::<serde_json::Value>();
+    assert_eq!(display["ready"], json!(true));
+
+    test_db.cleanup().await;
+}
+
+/// Rosters outside 2-48 tributes, or with more districts than tributes,
+/// are rejected before anything is written.
+#[tokio::test]
+async fn test_create_game_rejects_bad_roster() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "bad_roster_creator").await;
+
+    for roster in [
+        json!({ "tribute_count": 1 }),
+        json!({ "tribute_count": 49 }),
+        json!({ "tribute_count": 4, "district_count": 6 }),
+    ] {
+        let response = server
+            .post("/api/games")
+            .add_header("Authorization", user.auth_header())
+            .json(&roster)
+            .await;
+        response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+    }
+
+    test_db.cleanup().await;
+}
+
+/// A fork taken mid-day starts from that phase's state under new
+/// identifiers, keeps the log up to the fork point, and leaves the original
+/// untouched.
+#[tokio::test]
+async fn test_fork_game() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "forker").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Original" }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Start, then play day 1.
+    for _ in 0..2 {
+        server
+            .put(&format!("/api/games/{}/next", game_id))
+            .add_header("Authorization", user.auth_header())
+            .await
+            .assert_status_ok();
+    }
+
+    // Day 1 has no dawn, so nothing was snapshotted there.
+    server
+        .post(&format!("/api/games/{}/fork", game_id))
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "day": 1, "phase": "dawn" }))
+        .await
+        .assert_status(axum::http::StatusCode::NOT_FOUND);
+
+    let fork_response = server
+        .post(&format!("/api/games/{}/fork", game_id))
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "day": 1, "phase": "dusk" }))
+        .await;
+    fork_response.assert_status(axum::http::StatusCode::CREATED);
+    let fork_id = fork_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_ne!(fork_id, game_id);
+
+    let original = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    let fork = api::games::get_full_game(&fork_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_eq!(fork.name, "Original (fork)");
+    assert_eq!(fork.day, Some(1));
+    assert_eq!(fork.resting_phase, Some(game::messages::Phase::Dusk));
+    assert_eq!(fork.tributes.len(), original.tributes.len());
+    let mut original_names: Vec<_> = original.tributes.iter().map(|t| &t.name).collect();
+    let mut fork_names: Vec<_> = fork.tributes.iter().map(|t| &t.name).collect();
+    original_names.sort();
+    fork_names.sort();
+    assert_eq!(fork_names, original_names);
+    for tribute in &fork.tributes {
+        assert!(
+            original
+                .tributes
+                .iter()
+                .all(|t| t.identifier != tribute.identifier)
+        );
+    }
+
+    let fork_log = server
+        .get(&format!("/api/games/{}/log", fork_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .json::<Vec<serde_json::Value>>();
+    assert!(!fork_log.is_empty());
+    assert!(
+        fork_log
+            .iter()
+            .all(|m| m["subject"].as_str().unwrap().starts_with(&fork_id))
+    );
+
+    test_db.cleanup().await;
+}
+
+/// Rolling back to the end of day 1 undoes day 2, log included, and the
+/// game plays on from there.
+#[tokio::test]
+async fn test_rollback_game() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "rewinder").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Rewind" }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Start, then play days 1 and 2.
+    for _ in 0..3 {
+        server
+            .put(&format!("/api/games/{}/next", game_id))
+            .add_header("Authorization", user.auth_header())
+            .await
+            .assert_status_ok();
+    }
+    let day_one_log = server
+        .get(&format!("/api/games/{}/log/1", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .json::<Vec<serde_json::Value>>();
+
+    let rollback_response = server
+        .post(&format!("/api/games/{}/rollback", game_id))
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "day": 1, "phase": "night" }))
+        .await;
+    rollback_response.assert_status_ok();
+    assert_eq!(
+        rollback_response.json::<serde_json::Value>()["day"],
+        json!(1)
+    );
+
+    let loaded = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_eq!(loaded.day, Some(1));
+    let log = server
+        .get(&format!("/api/games/{}/log", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .json::<Vec<serde_json::Value>>();
+    assert_eq!(log.len(), day_one_log.len());
+    assert!(log.iter().all(|m| m["game_day"] == json!(1)));
+
+    server
+        .put(&format!("/api/games/{}/next", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+    let replayed = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_eq!(replayed.day, Some(2));
+
+    test_db.cleanup().await;
+}
+
+/// Rolling back past a gift's delivery refunds it, and reseeds the game so
+/// the owner can't replay an outcome they've already seen.
+#[tokio::test]
+async fn test_rollback_refunds_later_gifts() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "regretful_sponsor").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Regret" }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Start, then play day 1.
+    for _ in 0..2 {
+        server
+            .put(&format!("/api/games/{}/next", game_id))
+            .add_header("Authorization", user.auth_header())
+            .await
+            .assert_status_ok();
+    }
+    let game = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    let seed = game.seed;
+    let recipient = game
+        .tributes
+        .iter()
+        .find(|t| t.is_alive())
+        .expect("someone should survive day 1")
+        .identifier
+        .clone();
+
+    let wallet = |server: &TestServer| {
+        server
+            .get(&format!("/api/games/{}/wallet", game_id))
+            .add_header("Authorization", user.auth_header())
+    };
+    let budget = wallet(&server).await.json::<serde_json::Value>()["balance"].clone();
+
+    server
+        .post(&format!(
+            "/api/games/{}/tributes/{}/gifts",
+            game_id, recipient
+        ))
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "item": "Food" }))
+        .await
+        .assert_status_ok();
+    server
+        .put(&format!("/api/games/{}/next", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+
+    server
+        .post(&format!("/api/games/{}/rollback", game_id))
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "day": 1, "phase": "night" }))
+        .await
+        .assert_status_ok();
+
+    let balance = wallet(&server).await.json::<serde_json::Value>()["balance"].clone();
+    assert_eq!(balance, budget);
+    let rolled_back = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_ne!(rolled_back.seed, seed);
+
+    test_db.cleanup().await;
+}
+
+/// A gift whose recipient dies before it arrives goes back to the donor.
+#[tokio::test]
+async fn test_gift_for_a_dead_tribute_is_refunded() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "mourning_sponsor").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Too Late" }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    server
+        .put(&format!("/api/games/{}/next", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+    let game = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    let recipient = game
+        .tributes
+        .iter()
+        .find(|t| t.is_alive())
+        .expect("someone should be alive after the start")
+        .identifier
+        .clone();
+
+    let wallet = |server: &TestServer| {
+        server
+            .get(&format!("/api/games/{}/wallet", game_id))
+            .add_header("Authorization", user.auth_header())
+    };
+    let budget = wallet(&server).await.json::<serde_json::Value>()["balance"].clone();
+
+    server
+        .post(&format!(
+            "/api/games/{}/tributes/{}/gifts",
+            game_id, recipient
+        ))
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "item": "Food" }))
+        .await
+        .assert_status_ok();
+    assert_ne!(
+        wallet(&server).await.json::<serde_json::Value>()["balance"],
+        budget
+    );
+
+    // The recipient dies before the next cycle can deliver the gift.
+    test_db
+        .db
+        .query("UPDATE tribute SET blood = 0, status = 'Dead' WHERE identifier = $id")
+        .bind(("id", recipient))
+        .await
+        .unwrap();
+    server
+        .put(&format!("/api/games/{}/next", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+
+    let balance = wallet(&server).await.json::<serde_json::Value>()["balance"].clone();
+    assert_eq!(balance, budget);
+    let pending: Option<usize> = test_db
+        .db
+        .query("RETURN count(SELECT id FROM sponsor_gift WHERE game_id = $game)")
+        .bind(("game", game_id.clone()))
+        .await
+        .unwrap()
+        .take(0)
+        .unwrap();
+    assert_eq!(pending, Some(0));
+
+    test_db.cleanup().await;
+}
+
+/// Stepping by phase plays one phase per call and rests in between; the
+/// day's last phase leaves the game at a day boundary again.
+#[tokio::test]
+async fn test_next_step_by_phase() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "stepper").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Steps", "tribute_count": 8 }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    server
+        .put(&format!("/api/games/{}/next", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+
+    // Day 1 has no dawn.
+    for (phase, resting) in [
+        ("day", json!("day")),
+        ("dusk", json!("dusk")),
+        ("night", json!(null)),
+    ] {
+        let response = server
+            .put(&format!("/api/games/{}/next?granularity=phase", game_id))
+            .add_header("Authorization", user.auth_header())
+            .await;
+        response.assert_status_ok();
+        let game = response.json::<serde_json::Value>();
+        assert_eq!(game["day"], json!(1));
+        assert_eq!(game["resting_phase"], resting);
+
+        let log = server
+            .get(&format!("/api/games/{}/log", game_id))
+            .add_header("Authorization", user.auth_header())
+            .await
+            .json::<Vec<serde_json::Value>>();
+        assert_eq!(log.last().unwrap()["phase"], json!(phase));
+    }
+
+    let loaded = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_eq!(loaded.day, Some(1));
+    assert_eq!(loaded.resting_phase, None);
+
+    // A whole-day step after a dawn plays out the rest of day 2.
+    server
+        .put(&format!("/api/games/{}/next?granularity=phase", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+    server
+        .put(&format!("/api/games/{}/next", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+    let loaded = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    assert_eq!(loaded.day, Some(2));
+    assert_eq!(loaded.resting_phase, None);
+
+    test_db.cleanup().await;
+}
+
+/// Running to the finish starts the game and stops at the day cap.
+#[tokio::test]
+async fn test_run_to_finish() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "runner").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Marathon", "tribute_count": 4 }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    server
+        .put(&format!("/api/games/{}/run?max_days=0", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    let response = server
+        .put(&format!("/api/games/{}/run?max_days=2", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await;
+    response.assert_status_ok();
+    let game = response.json::<serde_json::Value>();
+    assert_ne!(game["status"], json!("NotStarted"));
+    assert!(game["day"].as_u64().unwrap() <= 2);
+
+    let response = server
+        .put(&format!("/api/games/{}/run", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await;
+    response.assert_status_ok();
+    assert_eq!(
+        response.json::<serde_json::Value>()["status"],
+        json!("Finished")
+    );
+
+    test_db.cleanup().await;
+}
+
+/// The audit replays the log against the stored game: a fresh game agrees
+/// with its empty log, and a tribute killed behind the log's back shows up.
+#[tokio::test]
+async fn test_game_audit() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "auditor").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Audit" }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let audit = |server: &TestServer| {
+        server
+            .get(&format!("/api/games/{}/audit", game_id))
+            .add_header("Authorization", user.auth_header())
+    };
+    let drift = audit(&server).await.json::<Vec<serde_json::Value>>();
+    assert!(drift.is_empty(), "unexpected drift: {drift:?}");
+
+    let game = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    let victim = game.tributes[0].identifier.clone();
+    test_db
+        .db
+        .query("UPDATE tribute SET status = 'Dead' WHERE identifier = $id")
+        .bind(("id", victim.clone()))
+        .await
+        .unwrap();
+
+    let drift = audit(&server).await.json::<Vec<serde_json::Value>>();
+    assert_eq!(
+        drift,
+        vec![json!({ "kind": "unlogged_death", "tribute": victim })]
+    );
+
+    test_db.cleanup().await;
+}
+
+/// A tribute who dies carrying items leaves one body, however many days are
+/// played after: the items go with the body and don't reload onto the dead.
+#[tokio::test]
+async fn test_dead_tribute_leaves_one_body() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "undertaker").await;
+
+    let create_response = server
+        .post("/api/games")
+        .add_header("Authorization", user.auth_header())
+        .json(&json!({ "name": "Undertaker" }))
+        .await;
+    create_response.assert_status(axum::http::StatusCode::CREATED);
+    let game_id = create_response.json::<serde_json::Value>()["identifier"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    server
+        .put(&format!("/api/games/{}/next", game_id))
+        .add_header("Authorization", user.auth_header())
+        .await
+        .assert_status_ok();
+
+    // Kill a living tribute behind the engine's back, holding a spear.
+    let game = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    let victim = game
+        .tributes
+        .iter()
+        .find(|t| t.is_alive())
+        .expect("someone should be alive after the start")
+        .identifier
+        .clone();
+    let spear = game::items::Item::new_weapon("spear");
+    test_db
+        .db
+        .query(
+            "UPDATE $tribute SET blood = 0, status = 'RecentlyDead'; \
+             CREATE $item CONTENT $body; \
+             RELATE $tribute->owns->$item;",
+        )
+        .bind((
+            "tribute",
+            surrealdb_types::RecordId::new("tribute", victim.as_str()),
+        ))
+        .bind((
+            "item",
+            surrealdb_types::RecordId::new("item", spear.identifier.as_str()),
+        ))
+        .bind(("body", serde_json::to_value(&spear).unwrap()))
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+    for _ in 0..2 {
+        server
+            .put(&format!("/api/games/{}/next", game_id))
+            .add_header("Authorization", user.auth_header())
+            .await
+            .assert_status_ok();
+    }
+
+    let game = api::games::get_full_game(&game_id, &test_db.db)
+        .await
+        .unwrap();
+    let bodies = game
+        .areas
+        .iter()
+        .flat_map(|area| &area.corpses)
+        .filter(|corpse| corpse.tribute == victim)
+        .count();
+    // Picked clean, the body is gone; it must never be laid out twice.
+    assert!(bodies <= 1, "{bodies} bodies for one tribute");
+    let mut response = test_db
+        .db
+        .query("SELECT VALUE payload FROM message")
+        .await
+        .unwrap();
+    let payloads: Vec<String> = response.take(0).unwrap();
+    let laid_out = payloads
+        .iter()
+        .filter_map(|p| serde_json::from_str::<shared::messages::MessagePayload>(p).ok())
+        .filter(|p| {
+            matches!(p, shared::messages::MessagePayload::CorpseLeft { tribute, .. }
+                if tribute.identifier == victim)
+        })
+        .count();
+    assert_eq!(laid_out, 1);
+    let spears = game
+        .areas
+        .iter()
+        .flat_map(|area| {
+            area.corpses
+                .iter()
+                .flat_map(|corpse| &corpse.items)
+                .chain(&area.items)
+        })
+        .chain(game.tributes.iter().flat_map(|t| &t.items))
+        .filter(|item| item.identifier == spear.identifier)
+        .count();
+    // Looted and broken is fine; two of it is not.
+    assert!(spears <= 1, "the spear was duplicated {spears} times");
+
+    test_db.cleanup().await;
+}
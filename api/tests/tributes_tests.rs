@@ -270,6 +270,154 @@ async fn test_tribute_log() {
     test_db.cleanup().await;
 }
 
+/// Test that a played day leaves decision traces, filterable by day.
+#[tokio::test]
+async fn test_tribute_decisions() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let user = create_authenticated_user(&test_db, &server, "tribute_explainer").await;
+    let game_id = create_test_game(&server, &user).await;
+    let tributes = fetch_tributes(&server, &user, &game_id).await;
+
+    // Start the game, then play day 1.
+    for _ in 0..2 {
+        server
+            .put(&format!("/api/games/{}/next", game_id))
+            .add_header("Authorization", user.auth_header())
+            .await
+            .assert_status_ok();
+    }
+
+    let mut traced = 0;
+    for tribute in &tributes {
+        let tribute_id = tribute["identifier"].as_str().unwrap();
+        let response = server
+            .get(&format!(
+                "/api/games/{}/tributes/{}/decisions",
+                game_id, tribute_id
+            ))
+            .add_header("Authorization", user.auth_header())
+            .await;
+        response.assert_status_ok();
+        let decisions = response.json::<Vec<serde_json::Value>>();
+        for decision in &decisions {
+            assert_eq!(decision["tribute"], json!(tribute_id));
+            assert_eq!(decision["day"], json!(1));
+            assert!(!decision["steps"].as_array().unwrap().is_empty());
+        }
+        traced += decisions.len();
+
+        let later = server
+            .get(&format!(
+                "/api/games/{}/tributes/{}/decisions?day=2",
+                game_id, tribute_id
+            ))
+            .add_header("Authorization", user.auth_header())
+            .await
+            .json::<Vec<serde_json::Value>>();
+        assert!(later.is_empty());
+    }
+    assert!(traced > 0, "a played day should leave decision traces");
+
+    test_db.cleanup().await;
+}
+
+/// Test that a player the creator hands a tribute to can see through its
+/// eyes and order it, even though the game is private to its creator.
+#[tokio::test]
+async fn test_invited_controller_views_and_orders() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let owner = create_authenticated_user(&test_db, &server, "tribute_owner").await;
+    let player = create_authenticated_user(&test_db, &server, "tribute_player").await;
+    let game_id = create_test_game(&server, &owner).await;
+    let tribute_id = first_tribute_id(&server, &owner, &game_id).await;
+
+    // Not theirs yet.
+    server
+        .get(&format!(
+            "/api/games/{}/tributes/{}/view",
+            game_id, tribute_id
+        ))
+        .add_header("Authorization", player.auth_header())
+        .await
+        .assert_status(axum::http::StatusCode::UNAUTHORIZED);
+
+    server
+        .put(&format!(
+            "/api/games/{}/tributes/{}/controller",
+            game_id, tribute_id
+        ))
+        .add_header("Authorization", owner.auth_header())
+        .json(&json!({ "email": player.email }))
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .get(&format!(
+            "/api/games/{}/tributes/{}/view",
+            game_id, tribute_id
+        ))
+        .add_header("Authorization", player.auth_header())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .put(&format!(
+            "/api/games/{}/tributes/{}/orders",
+            game_id, tribute_id
+        ))
+        .add_header("Authorization", player.auth_header())
+        .json(&json!({ "phase": "day", "action": "Rest" }))
+        .await;
+    response.assert_status_ok();
+
+    test_db.cleanup().await;
+}
+
+/// Test that a creator can't hand out another game's tribute through their
+/// own game.
+#[tokio::test]
+async fn test_assign_controller_is_scoped_to_the_game() {
+    let test_db = TestDb::new().await;
+    let app_state = test_db.app_state();
+    let router = create_test_router(app_state);
+    let server = TestServer::new(router);
+
+    let owner = create_authenticated_user(&test_db, &server, "scoped_owner").await;
+    let player = create_authenticated_user(&test_db, &server, "scoped_player").await;
+    let game_id = create_test_game(&server, &owner).await;
+    let other_game_id = create_test_game(&server, &owner).await;
+    let other_tribute_id = first_tribute_id(&server, &owner, &other_game_id).await;
+
+    server
+        .put(&format!(
+            "/api/games/{}/tributes/{}/controller",
+            game_id, other_tribute_id
+        ))
+        .add_header("Authorization", owner.auth_header())
+        .json(&json!({ "email": player.email }))
+        .await
+        .assert_status(axum::http::StatusCode::NOT_FOUND);
+
+    server
+        .get(&format!(
+            "/api/games/{}/tributes/{}/view",
+            other_game_id, other_tribute_id
+        ))
+        .add_header("Authorization", player.auth_header())
+        .await
+        .assert_status(axum::http::StatusCode::UNAUTHORIZED);
+
+    test_db.cleanup().await;
+}
+
 /// Test that a tribute detail response includes the items relationship.
 #[tokio::test]
 async fn test_tribute_items() {
@@ -0,0 +1,131 @@
+//! Procedurally sized arenas: the Cornucopia and `rings` hex rings of
+//! sectors around it.
+//!
+//! An [`Arena`] only describes the shape of the map. The game's areas are
+//! still the `AreaDetails` it holds; [`Arena::generate`] builds those for a
+//! new game, rolling terrain for each sector under the usual balance
+//! constraint.
+
+use crate::areas::hex::{self, Axial};
+use crate::areas::{Area, AreaDetails};
+use crate::terrain::{TerrainType, enforce_balance_constraint};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// The shape of a game's arena.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Arena {
+    /// Hex rings of sectors around the Cornucopia.
+    pub rings: u32,
+}
+
+impl Default for Arena {
+    /// The classic arena: the Cornucopia and one ring of six sectors.
+    fn default() -> Self {
+        Self { rings: 1 }
+    }
+}
+
+impl Arena {
+    pub fn new(rings: u32) -> Self {
+        Self { rings }
+    }
+
+    /// Number of areas in the arena.
+    pub fn size(&self) -> usize {
+        hex::arena_size(self.rings) as usize
+    }
+
+    /// Every area, in spiral order starting at the Cornucopia.
+    pub fn areas(self) -> impl Iterator<Item = Area> {
+        (0..hex::arena_size(self.rings)).map(Area::from_index)
+    }
+
+    pub fn contains(&self, area: Area) -> bool {
+        area.ring() <= self.rings
+    }
+
+    /// Areas adjacent to `area` that are on this arena's map.
+    pub fn neighbors(&self, area: Area) -> Vec<Area> {
+        area.grid_neighbors()
+            .into_iter()
+            .filter(|neighbor| self.contains(*neighbor))
+            .collect()
+    }
+
+    /// Every area with its hex coordinate, in spiral order.
+    pub fn layout(&self) -> Vec<(Area, Axial)> {
+        hex::arena_layout(self.rings)
+    }
+
+    /// Fresh `AreaDetails` for every area. The Cornucopia is always safe
+    /// ground; the sectors roll random terrain, with harsh terrain capped
+    /// by [`enforce_balance_constraint`].
+    pub fn generate(&self, rng: &mut impl Rng) -> Vec<AreaDetails> {
+        let mut terrains: Vec<TerrainType> =
+            (1..self.size()).map(|_| TerrainType::random(rng)).collect();
+        enforce_balance_constraint(&mut terrains, rng);
+
+        let center =
+            AreaDetails::new_with_terrain(None, Area::CORNUCOPIA, TerrainType::random_safe(rng));
+        let sectors = self
+            .areas()
+            .skip(1)
+            .zip(terrains)
+            .map(|(area, terrain)| AreaDetails::new_with_terrain(None, area, terrain));
+        std::iter::once(center).chain(sectors).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::Harshness;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use shared::MAX_ARENA_RINGS;
+
+    #[test]
+    fn arena_sizes_grow_by_rings() {
+        assert_eq!(Arena::default().size(), 7);
+        assert_eq!(Arena::new(2).size(), 19);
+        assert_eq!(Arena::new(MAX_ARENA_RINGS).size(), 61);
+    }
+
+    #[test]
+    fn neighbors_stay_on_the_map() {
+        let arena = Arena::default();
+        assert_eq!(arena.neighbors(Area::CORNUCOPIA).len(), 6);
+        let mut sector1 = arena.neighbors(Area::SECTOR_1);
+        sector1.sort();
+        assert_eq!(
+            sector1,
+            vec![Area::CORNUCOPIA, Area::SECTOR_2, Area::SECTOR_6]
+        );
+
+        let larger = Arena::new(2);
+        assert_eq!(larger.neighbors(Area::SECTOR_1).len(), 6);
+        for area in larger.areas().filter(|a| a.ring() == 2) {
+            let count = larger.neighbors(area).len();
+            assert!(count == 3 || count == 4, "{area} has {count} neighbors");
+        }
+    }
+
+    #[test]
+    fn generate_assigns_balanced_terrain() {
+        let mut rng = SmallRng::seed_from_u64(16);
+        let areas = Arena::new(3).generate(&mut rng);
+        assert_eq!(areas.len(), 37);
+        assert_eq!(areas[0].area, Some(Area::CORNUCOPIA));
+        assert!(matches!(areas[0].terrain.base.harshness(), Harshness::Mild));
+        let harsh = areas
+            .iter()
+            .filter(|a| matches!(a.terrain.base.harshness(), Harshness::Harsh))
+            .count();
+        assert!(harsh <= 3);
+        for (details, area) in areas.iter().zip(Arena::new(3).areas()) {
+            assert_eq!(details.area, Some(area));
+            assert_eq!(details.name, area.to_string());
+        }
+    }
+}
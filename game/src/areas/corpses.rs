@@ -0,0 +1,106 @@
+//! Bodies left where tributes fell.
+//!
+//! A dead tribute keeps their gear. `Game::clean_up_recent_deaths` lays the
+//! body out in the area they died in, and tributes passing through strip it
+//! with `TakeItem` or `Search`, one item at a time and best item first.
+//! Stripped bodies are cleared away.
+
+use crate::areas::AreaDetails;
+use crate::items::{Item, ItemType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Corpse {
+    /// Identifier of the tribute the body belonged to.
+    pub tribute: String,
+    pub name: String,
+    #[serde(default)]
+    pub items: Vec<Item>,
+}
+
+/// How much a tribute wants an item off a body. Weapons first, then
+/// shields, then anything that heals or feeds.
+pub fn loot_value(item: &Item) -> u32 {
+    let effect = item.effect.max(0) as u32;
+    if item.current_durability == 0 {
+        return 0;
+    }
+    if item.is_weapon() {
+        10 + 3 * effect
+    } else if item.is_defensive() {
+        8 + 2 * effect
+    } else {
+        match item.item_type {
+            ItemType::Consumable => 4 + effect,
+            ItemType::Food(_) | ItemType::Water(_) => 4,
+            ItemType::Weapon => 2,
+        }
+    }
+}
+
+impl Corpse {
+    /// Total loot value still on the body.
+    pub fn loot_value(&self) -> u32 {
+        self.items.iter().map(loot_value).sum()
+    }
+
+    /// Take the most valuable item off the body.
+    pub fn take_best(&mut self) -> Option<Item> {
+        let index = self
+            .items
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, item)| loot_value(item))
+            .map(|(index, _)| index)?;
+        Some(self.items.swap_remove(index))
+    }
+}
+
+impl AreaDetails {
+    /// Loot value of every body in the area.
+    pub fn corpse_loot_value(&self) -> u32 {
+        self.corpses.iter().map(Corpse::loot_value).sum()
+    }
+
+    /// Take the best item off the richest body in the area, along with the
+    /// body's owner as `(identifier, name)`.
+    pub fn loot_corpse(&mut self) -> Option<(Item, String, String)> {
+        let corpse = self
+            .corpses
+            .iter_mut()
+            .filter(|c| !c.items.is_empty())
+            .max_by_key(|c| c.loot_value())?;
+        let item = corpse.take_best()?;
+        let (tribute, name) = (corpse.tribute.clone(), corpse.name.clone());
+        self.corpses.retain(|c| !c.items.is_empty());
+        Some((item, tribute, name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::areas::Area;
+
+    #[test]
+    fn bodies_are_stripped_best_item_first() {
+        let sword = Item::new_weapon("sword");
+        let potion = Item::default();
+        let mut area = AreaDetails::new(None, Area::SECTOR_1);
+        area.corpses.push(Corpse {
+            tribute: "cato".to_string(),
+            name: "Cato".to_string(),
+            items: vec![potion.clone(), sword.clone()],
+        });
+        assert!(area.corpse_loot_value() > loot_value(&potion));
+
+        let (first, owner, _) = area.loot_corpse().unwrap();
+        assert_eq!(first, sword);
+        assert_eq!(owner, "cato");
+        let (second, _, _) = area.loot_corpse().unwrap();
+        assert_eq!(second, potion);
+
+        assert!(area.corpses.is_empty());
+        assert!(area.loot_corpse().is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::areas::weather::Weather;
 use crate::terrain::BaseTerrain;
 use rand::RngExt;
 use rand::prelude::*;
@@ -90,10 +91,32 @@ impl AreaEvent {
 
     /// Generate a terrain-appropriate random event with weighted probabilities
     pub fn random_for_terrain(terrain: &BaseTerrain, rng: &mut impl Rng) -> AreaEvent {
+        Self::pick_weighted(&Self::terrain_weights(terrain), rng)
+    }
+
+    /// Like [`AreaEvent::random_for_terrain`], with the terrain weights
+    /// scaled by the area's current weather: storms favour floods and
+    /// landslides, heatwaves favour wildfire and drought, and so on.
+    pub fn random_for_weather(
+        terrain: &BaseTerrain,
+        weather: Weather,
+        rng: &mut impl Rng,
+    ) -> AreaEvent {
+        let weights: Vec<(AreaEvent, u32)> = Self::terrain_weights(terrain)
+            .into_iter()
+            .map(|(event, weight)| {
+                let scaled = weight * weather.event_weight_percent(&event);
+                (event, scaled)
+            })
+            .collect();
+        Self::pick_weighted(&weights, rng)
+    }
+
+    /// Terrain event weights (percentages out of 100).
+    fn terrain_weights(terrain: &BaseTerrain) -> Vec<(AreaEvent, u32)> {
         use BaseTerrain::*;
 
-        // Define weights for each terrain (percentages out of 100)
-        let weights: Vec<(AreaEvent, u32)> = match terrain {
+        match terrain {
             Desert => vec![
                 (AreaEvent::Sandstorm, 38),
                 (AreaEvent::Heatwave, 30),
@@ -183,15 +206,17 @@ impl AreaEvent {
                 (AreaEvent::Rockslide, 20),
                 (AreaEvent::Wildfire, 10),
             ],
-        };
+        }
+    }
 
+    fn pick_weighted(weights: &[(AreaEvent, u32)], rng: &mut impl Rng) -> AreaEvent {
         // Calculate total weight
         let total: u32 = weights.iter().map(|(_, w)| w).sum();
         let roll = rng.random_range(0..total);
 
         // Select event based on weighted random
         let mut cumulative = 0;
-        for (event, weight) in &weights {
+        for (event, weight) in weights {
             cumulative += weight;
             if roll < cumulative {
                 return event.clone();
@@ -484,6 +509,31 @@ mod tests {
         assert_eq!(counts.get(&AreaEvent::Blizzard).unwrap_or(&0), &0);
     }
 
+    #[test]
+    fn test_storm_biases_forest_toward_floods() {
+        let floods = |weather: Weather| {
+            let mut rng = SmallRng::seed_from_u64(0);
+            (0..200)
+                .filter(|_| {
+                    AreaEvent::random_for_weather(&BaseTerrain::Forest, weather, &mut rng)
+                        == AreaEvent::Flood
+                })
+                .count()
+        };
+        assert!(floods(Weather::Storm) > floods(Weather::Clear));
+        assert!(floods(Weather::Heatwave) < floods(Weather::Clear));
+    }
+
+    #[test]
+    fn test_weather_never_adds_events_the_terrain_lacks() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let event =
+                AreaEvent::random_for_weather(&BaseTerrain::Mountains, Weather::Storm, &mut rng);
+            assert_ne!(event, AreaEvent::Flood);
+        }
+    }
+
     #[test]
     fn test_mountains_generates_terrain_appropriate_events() {
         use std::collections::HashMap;
@@ -1,8 +1,10 @@
 //! Hex-grid math for the arena map.
 //!
-//! v1 layout is a flat 7-tile pointy-top hex cluster: Cornucopia in the
-//! center surrounded by 6 sectors numbered 1..6 clockwise starting at the
-//! top-right.
+//! An arena is a pointy-top hex cluster: Cornucopia in the center
+//! surrounded by `rings` rings of sectors. Sectors are numbered along a
+//! spiral — ring by ring outward, each ring clockwise starting at its
+//! top-right corner — so the one-ring arena is the original 7-tile layout
+//! with sectors 1..6 clockwise from the top-right.
 //!
 //! Coordinate system is *axial* (`q`, `r`); see
 //! https://www.redblobgames.com/grids/hexagons/ for the full reference.
@@ -27,22 +29,9 @@ impl Axial {
 
     /// The six neighbor coords in pointy-top axial order, starting at the
     /// **top-right** neighbor and going clockwise. Order matches the
-    /// numbering of [`default_layout`].
+    /// numbering of the first ring in [`arena_layout`].
     pub fn neighbors(self) -> [Axial; 6] {
-        // (Δq, Δr) for pointy-top, clockwise from top-right.
-        const DIRS: [(i32, i32); 6] = [
-            (1, -1), // top-right (Sector1)
-            (1, 0),  // right     (Sector2)
-            (0, 1),  // bot-right (Sector3)
-            (-1, 1), // bot-left  (Sector4)
-            (-1, 0), // left      (Sector5)
-            (0, -1), // top-left  (Sector6)
-        ];
-        let mut out = [Axial::new(0, 0); 6];
-        for (i, (dq, dr)) in DIRS.iter().enumerate() {
-            out[i] = Axial::new(self.q + dq, self.r + dr);
-        }
-        out
+        DIRECTIONS.map(|d| Axial::new(self.q + d.q, self.r + d.r))
     }
 
     /// Hex distance: `(|q1 - q2| + |q1 + r1 - q2 - r2| + |r1 - r2|) / 2`.
@@ -64,19 +53,64 @@ impl Axial {
     }
 }
 
-/// The 7-tile default layout, ordered to match the area's numeric label
-/// (`0` for Cornucopia, `1..6` for the surrounding sectors clockwise from
-/// top-right). The order in this slice **is** the numeric label.
-pub fn default_layout() -> [(Area, Axial); 7] {
-    [
-        (Area::Cornucopia, Axial::new(0, 0)),
-        (Area::Sector1, Axial::new(1, -1)),
-        (Area::Sector2, Axial::new(1, 0)),
-        (Area::Sector3, Axial::new(0, 1)),
-        (Area::Sector4, Axial::new(-1, 1)),
-        (Area::Sector5, Axial::new(-1, 0)),
-        (Area::Sector6, Axial::new(0, -1)),
-    ]
+/// Pointy-top axial directions, clockwise from top-right. Index `i` is
+/// also the direction of ring corner `i` from the center.
+const DIRECTIONS: [Axial; 6] = [
+    Axial::new(1, -1),
+    Axial::new(1, 0),
+    Axial::new(0, 1),
+    Axial::new(-1, 1),
+    Axial::new(-1, 0),
+    Axial::new(0, -1),
+];
+
+/// Number of tiles in an arena of `rings` rings around the center.
+pub fn arena_size(rings: u32) -> u32 {
+    1 + 3 * rings * (rings + 1)
+}
+
+/// Coordinate of the `index`th tile along the arena spiral. Index 0 is the
+/// center; ring `k` holds the `6k` indices after the `k - 1` inner rings.
+pub fn spiral_axial(index: u32) -> Axial {
+    if index == 0 {
+        return Axial::new(0, 0);
+    }
+    let mut ring = 1;
+    while arena_size(ring) <= index {
+        ring += 1;
+    }
+    let position = index - arena_size(ring - 1);
+    let side = (position / ring) as usize;
+    let step = (position % ring) as i32;
+    let ring = ring as i32;
+    // Walk clockwise from corner `side` towards corner `side + 1`.
+    let corner = DIRECTIONS[side];
+    let walk = DIRECTIONS[(side + 2) % 6];
+    Axial::new(
+        corner.q * ring + walk.q * step,
+        corner.r * ring + walk.r * step,
+    )
+}
+
+/// Inverse of [`spiral_axial`]: the spiral index of `axial`.
+pub fn spiral_index(axial: Axial) -> u32 {
+    let ring = axial.distance(Axial::new(0, 0)) as u32;
+    if ring == 0 {
+        return 0;
+    }
+    let first = arena_size(ring - 1);
+    (first..arena_size(ring))
+        .find(|&index| spiral_axial(index) == axial)
+        .expect("every tile of a ring is on the spiral")
+}
+
+/// Every tile of an arena with `rings` rings, ordered to match the area's
+/// numeric label (`0` for Cornucopia, then the sectors along the spiral).
+/// The order in this list **is** the numeric label.
+pub fn arena_layout(rings: u32) -> Vec<(Area, Axial)> {
+    (0..arena_size(rings))
+        .map(|index| (Area::from_index(index), spiral_axial(index)))
+        .collect()
 }
 
 /// Area-local sub-tile coordinate. Each area-hex is subdivided into 7
@@ -107,7 +141,7 @@ impl SubAxial {
 }
 
 /// The 7 sub-tile slots within a single area-hex, in the same numeric
-/// order as `default_layout()`: index 0 = center, 1..6 = ring clockwise
+/// order as the one-ring `arena_layout(1)`: index 0 = center, 1..6 = ring clockwise
 /// from top-right.
 pub const SUB_SLOTS: [SubAxial; 7] = [
     SubAxial::new(0, 0),
@@ -159,7 +193,7 @@ mod tests {
     #[test]
     fn distance_cornucopia_to_each_sector_is_one() {
         let center = Axial::new(0, 0);
-        for s in default_layout().iter().skip(1) {
+        for s in arena_layout(1).iter().skip(1) {
             assert_eq!(center.distance(s.1), 1, "sector {:?}", s.0);
         }
     }
@@ -189,16 +223,16 @@ mod tests {
     }
 
     #[test]
-    fn default_layout_has_seven_unique_axials() {
-        let layout = default_layout();
+    fn one_ring_layout_has_seven_unique_axials() {
+        let layout = arena_layout(1);
         let coords: HashSet<Axial> = layout.iter().map(|(_, ax)| *ax).collect();
         assert_eq!(coords.len(), 7);
     }
 
     #[test]
-    fn default_layout_first_is_cornucopia() {
-        assert_eq!(default_layout()[0].0, Area::Cornucopia);
-        assert_eq!(default_layout()[0].1, Axial::new(0, 0));
+    fn one_ring_layout_first_is_cornucopia() {
+        assert_eq!(arena_layout(1)[0].0, Area::CORNUCOPIA);
+        assert_eq!(arena_layout(1)[0].1, Axial::new(0, 0));
     }
 
     #[test]
@@ -210,7 +244,7 @@ mod tests {
 
     #[test]
     fn pixel_distances_between_layout_neighbors_are_consistent() {
-        let layout = default_layout();
+        let layout = arena_layout(1);
         let size = 60.0_f64;
         let center_px = Axial::new(0, 0).to_pixel(size);
         // Each surrounding sector should be the same pixel distance from
@@ -233,6 +267,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn one_ring_spiral_matches_classic_sectors() {
+        let layout = arena_layout(1);
+        let expected = [
+            (Area::SECTOR_1, Axial::new(1, -1)),
+            (Area::SECTOR_2, Axial::new(1, 0)),
+            (Area::SECTOR_3, Axial::new(0, 1)),
+            (Area::SECTOR_4, Axial::new(-1, 1)),
+            (Area::SECTOR_5, Axial::new(-1, 0)),
+            (Area::SECTOR_6, Axial::new(0, -1)),
+        ];
+        assert_eq!(&layout[1..], &expected);
+    }
+
+    #[test]
+    fn spiral_covers_each_ring_exactly_once() {
+        for rings in 0..=4 {
+            let layout = arena_layout(rings);
+            assert_eq!(layout.len() as u32, arena_size(rings));
+            let coords: HashSet<Axial> = layout.iter().map(|(_, ax)| *ax).collect();
+            assert_eq!(coords.len(), layout.len());
+            for (area, ax) in &layout {
+                assert!(ax.distance(Axial::new(0, 0)) as u32 <= rings);
+                assert_eq!(spiral_index(*ax), area.index());
+            }
+        }
+    }
+
     #[test]
     fn sub_slots_has_seven_unique_coords() {
         let set: HashSet<SubAxial> = SUB_SLOTS.iter().copied().collect();
@@ -1,3 +1,5 @@
+pub mod arena;
+pub mod corpses;
 pub mod events;
 pub mod forage;
 pub mod hex;
@@ -6,8 +8,10 @@ pub mod shelter;
 pub mod water;
 pub mod weather;
 
+use crate::areas::corpses::Corpse;
 use crate::areas::events::AreaEvent;
-use crate::areas::hex::{SUB_SLOTS, SubAxial};
+use crate::areas::hex::{Axial, SUB_SLOTS, SubAxial};
+use crate::areas::weather::AreaWeather;
 use crate::items::OwnsItems;
 use crate::items::{Item, ItemError};
 use crate::terrain::{BaseTerrain, TerrainType};
@@ -15,19 +19,23 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::FromStr;
-use strum_macros::EnumIter;
 use uuid::Uuid;
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumIter, Hash, Ord, PartialOrd, Default)]
-pub enum Area {
-    #[default]
-    Cornucopia,
-    Sector1,
-    Sector2,
-    Sector3,
-    Sector4,
-    Sector5,
-    Sector6,
+/// An area of the arena, identified by its position along the arena's hex
+/// spiral (see [`hex`]): index 0 is the Cornucopia, and sector `n` is index
+/// `n`. Arenas come in any number of rings, so areas are not a closed set;
+/// the constants below name the Cornucopia and the innermost ring.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+pub struct Area(u32);
+
+impl Area {
+    pub const CORNUCOPIA: Area = Area(0);
+    pub const SECTOR_1: Area = Area(1);
+    pub const SECTOR_2: Area = Area(2);
+    pub const SECTOR_3: Area = Area(3);
+    pub const SECTOR_4: Area = Area(4);
+    pub const SECTOR_5: Area = Area(5);
+    pub const SECTOR_6: Area = Area(6);
 }
 
 // Custom Serialize/Deserialize using Display/FromStr so the on-disk
@@ -63,14 +71,9 @@ impl<'de> Deserialize<'de> for Area {
 
 impl Display for Area {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Area::Cornucopia => f.write_str("Cornucopia"),
-            Area::Sector1 => f.write_str("Sector 1"),
-            Area::Sector2 => f.write_str("Sector 2"),
-            Area::Sector3 => f.write_str("Sector 3"),
-            Area::Sector4 => f.write_str("Sector 4"),
-            Area::Sector5 => f.write_str("Sector 5"),
-            Area::Sector6 => f.write_str("Sector 6"),
+        match self.0 {
+            0 => f.write_str("Cornucopia"),
+            n => write!(f, "Sector {n}"),
         }
     }
 }
@@ -85,40 +88,50 @@ impl FromStr for Area {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "cornucopia" => Ok(Area::Cornucopia),
-            "sector 1" | "sector1" => Ok(Area::Sector1),
-            "sector 2" | "sector2" => Ok(Area::Sector2),
-            "sector 3" | "sector3" => Ok(Area::Sector3),
-            "sector 4" | "sector4" => Ok(Area::Sector4),
-            "sector 5" | "sector5" => Ok(Area::Sector5),
-            "sector 6" | "sector6" => Ok(Area::Sector6),
-            _ => Err(format!("Invalid area: {}", s)),
+        let lower = s.to_lowercase();
+        if lower == "cornucopia" {
+            return Ok(Area::CORNUCOPIA);
         }
+        lower
+            .strip_prefix("sector")
+            .and_then(|n| n.trim_start().parse::<u32>().ok())
+            .filter(|&n| n > 0)
+            .map(Area)
+            .ok_or_else(|| format!("Invalid area: {}", s))
     }
 }
 
 impl Area {
-    /// Topological neighbors derived from the v1 hex layout: Cornucopia
-    /// touches all six sectors, each sector touches Cornucopia plus its
-    /// two adjacent sectors (clockwise/counter-clockwise wrap 1..6).
-    pub fn neighbors(&self) -> Vec<Area> {
-        match self {
-            Area::Cornucopia => vec![
-                Area::Sector1,
-                Area::Sector2,
-                Area::Sector3,
-                Area::Sector4,
-                Area::Sector5,
-                Area::Sector6,
-            ],
-            Area::Sector1 => vec![Area::Cornucopia, Area::Sector6, Area::Sector2],
-            Area::Sector2 => vec![Area::Cornucopia, Area::Sector1, Area::Sector3],
-            Area::Sector3 => vec![Area::Cornucopia, Area::Sector2, Area::Sector4],
-            Area::Sector4 => vec![Area::Cornucopia, Area::Sector3, Area::Sector5],
-            Area::Sector5 => vec![Area::Cornucopia, Area::Sector4, Area::Sector6],
-            Area::Sector6 => vec![Area::Cornucopia, Area::Sector5, Area::Sector1],
-        }
+    /// The area at `index` along the arena spiral.
+    pub const fn from_index(index: u32) -> Self {
+        Area(index)
+    }
+
+    /// Position along the arena spiral; also the area's numeric label.
+    pub fn index(&self) -> u32 {
+        self.0
+    }
+
+    /// Hex coordinate of the area on the arena map.
+    pub fn axial(&self) -> Axial {
+        hex::spiral_axial(self.0)
+    }
+
+    /// Which ring of the arena the area sits in (0 for the Cornucopia).
+    pub fn ring(&self) -> u32 {
+        self.axial().distance(Axial::new(0, 0)) as u32
+    }
+
+    /// The six hex-adjacent grid cells, clockwise from the top-right,
+    /// whether or not the arena reaches them: past the outer ring they are
+    /// off the map. Use [`arena::Arena::neighbors`] or filter against the
+    /// game's areas before sending anyone there.
+    pub fn grid_neighbors(&self) -> Vec<Area> {
+        self.axial()
+            .neighbors()
+            .into_iter()
+            .map(|axial| Area(hex::spiral_index(axial)))
+            .collect()
     }
 }
 
@@ -147,12 +160,18 @@ pub struct AreaDetails {
     /// Traps placed by tributes in this area.
     #[serde(default)]
     pub placed_traps: Vec<crate::tributes::traps::PlacedTrap>,
+    /// Bodies of tributes who died here, with whatever they still carry.
+    #[serde(default)]
+    pub corpses: Vec<Corpse>,
     /// Per-tribute sub-tile slot assignments within this area-hex.
     /// Presentation/positioning only — game logic operates at the area
     /// level. Keys are tribute identifiers; values are area-local sub
     /// coordinates from `hex::SUB_SLOTS`.
     #[serde(default)]
     pub tribute_slots: HashMap<String, SubAxial>,
+    /// Current weather, advanced once per phase.
+    #[serde(default)]
+    pub weather: AreaWeather,
 }
 
 fn default_terrain() -> TerrainType {
@@ -168,8 +187,10 @@ impl Default for AreaDetails {
             items: vec![],
             events: vec![],
             placed_traps: vec![],
+            corpses: vec![],
             terrain: TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap(),
             tribute_slots: HashMap::new(),
+            weather: AreaWeather::default(),
         }
     }
 }
@@ -217,8 +238,10 @@ impl AreaDetails {
             items: vec![],
             events: vec![],
             placed_traps: vec![],
+            corpses: vec![],
             terrain: TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap(),
             tribute_slots: HashMap::new(),
+            weather: AreaWeather::default(),
         }
     }
 
@@ -230,8 +253,10 @@ impl AreaDetails {
             items: vec![],
             events: vec![],
             placed_traps: vec![],
+            corpses: vec![],
             terrain,
             tribute_slots: HashMap::new(),
+            weather: AreaWeather::default(),
         }
     }
 
@@ -268,35 +293,54 @@ impl AreaDetails {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use strum::IntoEnumIterator;
+    use crate::areas::arena::Arena;
 
     #[test]
     fn from_str() {
         let area = Area::from_str("cornucopia");
-        assert_eq!(area.unwrap(), Area::Cornucopia);
+        assert_eq!(area.unwrap(), Area::CORNUCOPIA);
     }
 
     #[test]
     fn to_str() {
-        assert_eq!(Area::Cornucopia.to_string(), "Cornucopia");
+        assert_eq!(Area::CORNUCOPIA.to_string(), "Cornucopia");
     }
 
     #[test]
     fn iter() {
-        let areas: Vec<Area> = Area::iter().collect();
+        let areas: Vec<Area> = Arena::default().areas().collect();
         assert_eq!(areas.len(), 7);
-        assert_eq!(areas[0], Area::Cornucopia);
-        assert_eq!(areas[1], Area::Sector1);
-        assert_eq!(areas[2], Area::Sector2);
-        assert_eq!(areas[3], Area::Sector3);
-        assert_eq!(areas[4], Area::Sector4);
-        assert_eq!(areas[5], Area::Sector5);
-        assert_eq!(areas[6], Area::Sector6);
+        assert_eq!(areas[0], Area::CORNUCOPIA);
+        assert_eq!(areas[1], Area::SECTOR_1);
+        assert_eq!(areas[2], Area::SECTOR_2);
+        assert_eq!(areas[3], Area::SECTOR_3);
+        assert_eq!(areas[4], Area::SECTOR_4);
+        assert_eq!(areas[5], Area::SECTOR_5);
+        assert_eq!(areas[6], Area::SECTOR_6);
+    }
+
+    #[test]
+    fn outer_ring_sectors_round_trip() {
+        let area = Area::from_index(13);
+        assert_eq!(area.to_string(), "Sector 13");
+        assert_eq!(Area::from_str("sector13"), Ok(area));
+        assert_eq!(area.ring(), 2);
+        assert!(Area::from_str("Sector 0").is_err());
+        assert!(Area::from_str("Sector").is_err());
+    }
+
+    #[test]
+    fn neighbors_are_symmetric() {
+        for area in Arena::new(3).areas() {
+            for neighbor in area.grid_neighbors() {
+                assert!(neighbor.grid_neighbors().contains(&area));
+            }
+        }
     }
 
     #[test]
     fn add_item() {
-        let mut area_details = AreaDetails::new(None, Area::Sector4);
+        let mut area_details = AreaDetails::new(None, Area::SECTOR_4);
         let item = Item::new_random_weapon();
         area_details.add_item(item.clone());
         assert!(area_details.items.contains(&item));
@@ -304,7 +348,7 @@ mod tests {
 
     #[test]
     fn remove_item() {
-        let mut area_details = AreaDetails::new(None, Area::Sector4);
+        let mut area_details = AreaDetails::new(None, Area::SECTOR_4);
         let item = Item::new_random_weapon();
         area_details.add_item(item.clone());
         assert!(area_details.items.contains(&item));
@@ -314,7 +358,7 @@ mod tests {
 
     #[test]
     fn add_event() {
-        let mut area_details = AreaDetails::new(None, Area::Sector1);
+        let mut area_details = AreaDetails::new(None, Area::SECTOR_1);
         let event = AreaEvent::Wildfire;
         area_details.events.push(event.clone());
         assert!(area_details.events.contains(&event));
@@ -322,7 +366,7 @@ mod tests {
 
     #[test]
     fn process_events_closes_area() {
-        let mut area_details = AreaDetails::new(None, Area::Sector1);
+        let mut area_details = AreaDetails::new(None, Area::SECTOR_1);
         assert!(area_details.is_open());
         let event = AreaEvent::Wildfire;
         area_details.events.push(event.clone());
@@ -331,20 +375,20 @@ mod tests {
 
     #[test]
     fn partial_eq_with_reference() {
-        let area = Area::Cornucopia;
+        let area = Area::CORNUCOPIA;
         assert_eq!(area, &area);
     }
 
     #[test]
     fn assign_slot_returns_center_first() {
-        let mut a = AreaDetails::new(None, Area::Cornucopia);
+        let mut a = AreaDetails::new(None, Area::CORNUCOPIA);
         let s = a.assign_slot("t1");
         assert_eq!(s, SUB_SLOTS[0]);
     }
 
     #[test]
     fn assign_slot_is_idempotent_for_same_tribute() {
-        let mut a = AreaDetails::new(None, Area::Cornucopia);
+        let mut a = AreaDetails::new(None, Area::CORNUCOPIA);
         let s1 = a.assign_slot("t1");
         let s2 = a.assign_slot("t1");
         assert_eq!(s1, s2);
@@ -353,7 +397,7 @@ mod tests {
 
     #[test]
     fn assign_slot_gives_unique_slots_until_full() {
-        let mut a = AreaDetails::new(None, Area::Cornucopia);
+        let mut a = AreaDetails::new(None, Area::CORNUCOPIA);
         let mut slots = std::collections::HashSet::new();
         for i in 0..7 {
             let s = a.assign_slot(&format!("t{i}"));
@@ -364,7 +408,7 @@ mod tests {
 
     #[test]
     fn assign_slot_overflows_to_center_when_full() {
-        let mut a = AreaDetails::new(None, Area::Cornucopia);
+        let mut a = AreaDetails::new(None, Area::CORNUCOPIA);
         for i in 0..7 {
             a.assign_slot(&format!("t{i}"));
         }
@@ -374,7 +418,7 @@ mod tests {
 
     #[test]
     fn release_slot_frees_slot_for_reassignment() {
-        let mut a = AreaDetails::new(None, Area::Cornucopia);
+        let mut a = AreaDetails::new(None, Area::CORNUCOPIA);
         let original = a.assign_slot("t1");
         let released = a.release_slot("t1");
         assert_eq!(released, Some(original));
@@ -390,7 +434,7 @@ mod tests {
     /// `area.to_string()`).
     #[test]
     fn area_serde_roundtrip_uses_display_form() {
-        for variant in Area::iter() {
+        for variant in Arena::default().areas() {
             let json = serde_json::to_string(&variant).unwrap();
             // Display form: "Cornucopia", "Sector 1", ..., "Sector 6"
             assert_eq!(json, format!("\"{}\"", variant));
@@ -399,9 +443,9 @@ mod tests {
         }
         // Tolerant of the no-space form too (FromStr accepts both).
         let no_space: Area = serde_json::from_str("\"Sector1\"").unwrap();
-        assert_eq!(no_space, Area::Sector1);
+        assert_eq!(no_space, Area::SECTOR_1);
         // And case-insensitive (FromStr lowercases).
         let lower: Area = serde_json::from_str("\"cornucopia\"").unwrap();
-        assert_eq!(lower, Area::Cornucopia);
+        assert_eq!(lower, Area::CORNUCOPIA);
     }
 }
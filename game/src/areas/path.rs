@@ -1,5 +1,5 @@
-//! Area-level pathfinding: builds a graph over the v1 7-area hex topology
-//! and exposes a path-planning helper for tributes.
+//! Area-level pathfinding: builds a graph over the game's hex arena, of
+//! whatever size, and exposes a path-planning helper for tributes.
 //!
 //! Edge cost is a composite per the design decision (8pq Q2):
 //! `stamina_cost + harshness_penalty + closed_penalty`.
@@ -15,7 +15,6 @@ use crate::terrain::Harshness;
 use crate::tributes::actions::Action;
 use crate::tributes::{Tribute, calculate_stamina_cost};
 use std::collections::HashMap;
-use strum::IntoEnumIterator;
 
 /// Penalty added to any edge entering a closed area. Picked so that even
 /// the cheapest detour through 2 open areas is preferred over a single
@@ -25,9 +24,8 @@ pub const CLOSED_PENALTY: u32 = 1000;
 /// Snapshot of the area graph from one tribute's perspective at one
 /// moment in time. Built per planning call — do not cache across cycles.
 pub struct AreaGraph<'a> {
-    /// All known areas (whether represented in `area_details` or not).
-    /// Used to define the node set; missing details still produce a node
-    /// connected by topology, just with default-terrain cost.
+    /// The arena's areas: every area with details. Defines the node set;
+    /// hex neighbors outside it are off the map.
     pub areas: Vec<Area>,
     /// Tribute doing the planning. Used for stamina-cost computation.
     pub tribute: &'a Tribute,
@@ -46,7 +44,7 @@ impl<'a> AreaGraph<'a> {
             }
         }
         Self {
-            areas: Area::iter().collect(),
+            areas: details.keys().copied().collect(),
             tribute,
             details,
             closed: closed.iter().copied().collect(),
@@ -84,24 +82,25 @@ impl<'a> Graph for AreaGraph<'a> {
     type Cost = u32;
 
     fn neighbors(&self, node: Area) -> Vec<(Area, u32)> {
-        node.neighbors()
+        node.grid_neighbors()
             .into_iter()
+            .filter(|n| self.details.contains_key(n))
             .map(|n| (n, self.edge_cost(n)))
             .collect()
     }
 
     fn heuristic(&self, _from: Area, _to: Area) -> u32 {
-        // Hop-count is 0 or 1 in this 7-node graph (Cornucopia is
-        // adjacent to everything; sectors are at most 2 hops apart).
-        // A constant zero (Dijkstra) is admissible and optimal here.
+        // Edge costs depend on terrain and the tribute, so there is no
+        // useful lower bound per hop. A constant zero (Dijkstra) is
+        // admissible, and arenas are small enough for it to stay cheap.
         0
     }
 }
 
 /// Plan a stamina-aware path from `start` to `goal`. Returns the full
 /// path including endpoints and the total cost. Returns `None` only if
-/// `goal` is unreachable from `start` (impossible in the v1 topology
-/// since the graph is fully connected via Cornucopia).
+/// `goal` is unreachable from `start`, which a generated arena never is:
+/// its rings are contiguous around the Cornucopia.
 pub fn plan_path(
     areas: &[AreaDetails],
     closed: &[Area],
@@ -128,13 +127,13 @@ mod tests {
 
     fn standard_areas() -> Vec<AreaDetails> {
         vec![
-            area("c", Area::Cornucopia, BaseTerrain::Clearing),
-            area("s1", Area::Sector1, BaseTerrain::Forest),
-            area("s2", Area::Sector2, BaseTerrain::Mountains),
-            area("s3", Area::Sector3, BaseTerrain::Grasslands),
-            area("s4", Area::Sector4, BaseTerrain::Desert),
-            area("s5", Area::Sector5, BaseTerrain::Wetlands),
-            area("s6", Area::Sector6, BaseTerrain::Tundra),
+            area("c", Area::CORNUCOPIA, BaseTerrain::Clearing),
+            area("s1", Area::SECTOR_1, BaseTerrain::Forest),
+            area("s2", Area::SECTOR_2, BaseTerrain::Mountains),
+            area("s3", Area::SECTOR_3, BaseTerrain::Grasslands),
+            area("s4", Area::SECTOR_4, BaseTerrain::Desert),
+            area("s5", Area::SECTOR_5, BaseTerrain::Wetlands),
+            area("s6", Area::SECTOR_6, BaseTerrain::Tundra),
         ]
     }
 
@@ -146,8 +145,8 @@ mod tests {
     fn plan_to_self_returns_singleton() {
         let areas = standard_areas();
         let t = fresh_tribute();
-        let (path, cost) = plan_path(&areas, &[], &t, Area::Cornucopia, Area::Cornucopia).unwrap();
-        assert_eq!(path, vec![Area::Cornucopia]);
+        let (path, cost) = plan_path(&areas, &[], &t, Area::CORNUCOPIA, Area::CORNUCOPIA).unwrap();
+        assert_eq!(path, vec![Area::CORNUCOPIA]);
         assert_eq!(cost, 0);
     }
 
@@ -155,8 +154,8 @@ mod tests {
     fn plan_neighbor_is_two_node_path() {
         let areas = standard_areas();
         let t = fresh_tribute();
-        let (path, _) = plan_path(&areas, &[], &t, Area::Cornucopia, Area::Sector1).unwrap();
-        assert_eq!(path, vec![Area::Cornucopia, Area::Sector1]);
+        let (path, _) = plan_path(&areas, &[], &t, Area::CORNUCOPIA, Area::SECTOR_1).unwrap();
+        assert_eq!(path, vec![Area::CORNUCOPIA, Area::SECTOR_1]);
     }
 
     #[test]
@@ -165,11 +164,11 @@ mod tests {
         // The fastest route is Sector1 -> Cornucopia -> Sector4.
         let areas = standard_areas();
         let t = fresh_tribute();
-        let (path, _) = plan_path(&areas, &[], &t, Area::Sector1, Area::Sector4).unwrap();
+        let (path, _) = plan_path(&areas, &[], &t, Area::SECTOR_1, Area::SECTOR_4).unwrap();
         assert_eq!(path.len(), 3);
-        assert_eq!(path[0], Area::Sector1);
-        assert_eq!(path[1], Area::Cornucopia);
-        assert_eq!(path[2], Area::Sector4);
+        assert_eq!(path[0], Area::SECTOR_1);
+        assert_eq!(path[1], Area::CORNUCOPIA);
+        assert_eq!(path[2], Area::SECTOR_4);
     }
 
     #[test]
@@ -179,10 +178,10 @@ mod tests {
         // Sector2 + Sector3).
         let areas = standard_areas();
         let t = fresh_tribute();
-        let closed = [Area::Cornucopia];
-        let (path, cost) = plan_path(&areas, &closed, &t, Area::Sector1, Area::Sector4).unwrap();
+        let closed = [Area::CORNUCOPIA];
+        let (path, cost) = plan_path(&areas, &closed, &t, Area::SECTOR_1, Area::SECTOR_4).unwrap();
         assert!(
-            !path.contains(&Area::Cornucopia),
+            !path.contains(&Area::CORNUCOPIA),
             "path detoured around closed cornucopia: {path:?}"
         );
         assert!(
@@ -198,12 +197,34 @@ mod tests {
         // closed area and pay the penalty.
         let areas = standard_areas();
         let t = fresh_tribute();
-        let closed = [Area::Sector1];
-        let (path, cost) = plan_path(&areas, &closed, &t, Area::Cornucopia, Area::Sector1).unwrap();
-        assert_eq!(path, vec![Area::Cornucopia, Area::Sector1]);
+        let closed = [Area::SECTOR_1];
+        let (path, cost) =
+            plan_path(&areas, &closed, &t, Area::CORNUCOPIA, Area::SECTOR_1).unwrap();
+        assert_eq!(path, vec![Area::CORNUCOPIA, Area::SECTOR_1]);
         assert!(
             cost >= CLOSED_PENALTY,
             "expected closed-penalty in cost, got {cost}"
         );
     }
+
+    #[test]
+    fn plan_crosses_a_larger_arena() {
+        use crate::areas::arena::Arena;
+        use rand::SeedableRng;
+
+        let arena = Arena::new(3);
+        let areas = arena.generate(&mut rand::rngs::SmallRng::seed_from_u64(7));
+        let t = fresh_tribute();
+        // Opposite corners of the outer ring are six hops apart.
+        let start = Area::from_index(arena.size() as u32 - 9);
+        let goal = Area::from_index(arena.size() as u32 - 18);
+        assert_eq!(start.axial().distance(goal.axial()), 6);
+
+        let (path, _) = plan_path(&areas, &[], &t, start, goal).unwrap();
+        assert!(path.len() >= 7);
+        assert!(path.iter().all(|a| arena.contains(*a)));
+        for hop in path.windows(2) {
+            assert!(arena.neighbors(hop[0]).contains(&hop[1]));
+        }
+    }
 }
@@ -19,8 +19,8 @@ pub fn shelter_quality(terrain: BaseTerrain, weather: &Weather) -> u8 {
     };
 
     match weather {
-        Weather::Clear => base,
-        Weather::HeavyRain | Weather::Blizzard => base.saturating_sub(1),
+        Weather::Clear | Weather::Fog | Weather::LightRain | Weather::Snow => base,
+        Weather::HeavyRain | Weather::Storm | Weather::Blizzard => base.saturating_sub(1),
         Weather::Heatwave => match terrain {
             BaseTerrain::UrbanRuins
             | BaseTerrain::Mountains
@@ -18,8 +18,9 @@ pub fn water_source(terrain: BaseTerrain, weather: &Weather) -> u8 {
     };
 
     match weather {
-        Weather::Clear | Weather::Blizzard => base,
-        Weather::HeavyRain => match terrain {
+        Weather::Clear | Weather::Fog | Weather::Snow | Weather::Blizzard => base,
+        Weather::LightRain => (base + 1).min(3),
+        Weather::HeavyRain | Weather::Storm => match terrain {
             // Spec table: HeavyRain column.
             BaseTerrain::Wetlands => 3,
             BaseTerrain::Forest | BaseTerrain::Jungle => 3,
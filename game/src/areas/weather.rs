@@ -1,34 +1,314 @@
+use crate::areas::Area;
+use crate::areas::events::AreaEvent;
+use crate::terrain::BaseTerrain;
+use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+use strum::{EnumIter, IntoEnumIterator};
 
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+/// Atmospheric state of a single area. Persisted on `AreaDetails` and
+/// advanced once per phase by a terrain-conditioned Markov roll (see
+/// `2026-05-02-weather-system-design.md`).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize, EnumIter)]
 pub enum Weather {
     #[default]
     Clear,
+    Fog,
+    LightRain,
     HeavyRain,
-    Heatwave,
+    Storm,
+    Snow,
     Blizzard,
+    Heatwave,
+}
+
+impl Display for Weather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Weather::Clear => write!(f, "clear skies"),
+            Weather::Fog => write!(f, "fog"),
+            Weather::LightRain => write!(f, "light rain"),
+            Weather::HeavyRain => write!(f, "heavy rain"),
+            Weather::Storm => write!(f, "storm"),
+            Weather::Snow => write!(f, "snow"),
+            Weather::Blizzard => write!(f, "blizzard"),
+            Weather::Heatwave => write!(f, "heatwave"),
+        }
+    }
 }
 
-/// Stub producer. Always returns `Weather::Clear` until the full weather
-/// system (see `2026-05-02-weather-system-design.md`) replaces this.
-///
-/// Consumers must call this (not hardcode `Weather::Clear`) so the future
-/// weather implementation needs only a producer-side change.
-pub fn current_weather() -> Weather {
-    Weather::Clear
+impl FromStr for Weather {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "clear" | "clear skies" => Ok(Weather::Clear),
+            "fog" => Ok(Weather::Fog),
+            "light rain" | "lightrain" => Ok(Weather::LightRain),
+            "heavy rain" | "heavyrain" => Ok(Weather::HeavyRain),
+            "storm" => Ok(Weather::Storm),
+            "snow" => Ok(Weather::Snow),
+            "blizzard" => Ok(Weather::Blizzard),
+            "heatwave" => Ok(Weather::Heatwave),
+            _ => Err(format!("Invalid weather: {}", s)),
+        }
+    }
+}
+
+/// Persistence bonus: the current state's climate weight is multiplied by
+/// this before the transition roll, so weather lingers instead of
+/// flickering every phase.
+const PERSISTENCE_MULTIPLIER: u32 = 3;
+
+/// Largest severity jump a single transition may make. Clear skies can
+/// cloud into heavy rain in one phase but never straight into a storm.
+const MAX_ESCALATION: u8 = 2;
+
+impl Weather {
+    /// Severity rank: 0 calm, 1 mild, 2 heavy, 3 extreme.
+    pub fn severity_rank(&self) -> u8 {
+        match self {
+            Weather::Clear | Weather::Fog => 0,
+            Weather::LightRain | Weather::Snow | Weather::Heatwave => 1,
+            Weather::HeavyRain => 2,
+            Weather::Storm | Weather::Blizzard => 3,
+        }
+    }
+
+    /// Storms and blizzards.
+    pub fn is_extreme(&self) -> bool {
+        self.severity_rank() == 3
+    }
+
+    /// Percentage weight applied to an `AreaEvent`'s terrain weight while
+    /// this weather holds. Never zero, so every terrain event stays
+    /// possible; weather only shifts the odds.
+    pub fn event_weight_percent(&self, event: &AreaEvent) -> u32 {
+        use AreaEvent::*;
+        match (self, event) {
+            (Weather::Clear | Weather::Fog, _) => 100,
+            (Weather::LightRain, Flood) => 150,
+            (Weather::LightRain, Landslide) => 125,
+            (Weather::LightRain, Wildfire | Drought | Sandstorm) => 50,
+            (Weather::HeavyRain, Flood) => 300,
+            (Weather::HeavyRain, Landslide | Sinkhole) => 250,
+            (Weather::HeavyRain, Wildfire | Drought | Sandstorm | Heatwave) => 10,
+            (Weather::Storm, Flood) => 400,
+            (Weather::Storm, Landslide) => 300,
+            (Weather::Storm, Rockslide | Sinkhole) => 150,
+            (Weather::Storm, Wildfire | Sandstorm) => 50,
+            (Weather::Storm, Drought | Heatwave) => 10,
+            (Weather::Snow, Avalanche | Blizzard) => 200,
+            (Weather::Snow, Wildfire | Heatwave | Drought) => 20,
+            (Weather::Blizzard, Blizzard) => 400,
+            (Weather::Blizzard, Avalanche) => 300,
+            (Weather::Blizzard, Wildfire | Heatwave | Drought) => 10,
+            (Weather::Blizzard, Flood) => 50,
+            (Weather::Heatwave, Heatwave) => 300,
+            (Weather::Heatwave, Wildfire | Drought) => 250,
+            (Weather::Heatwave, Sandstorm) => 150,
+            (Weather::Heatwave, Flood | Blizzard) => 10,
+            (Weather::Heatwave, Avalanche) => 50,
+            _ => 100,
+        }
+    }
+}
+
+/// Relative likelihood of each weather state in a terrain's climate.
+/// Zero means the state cannot occur there (no snow in the desert, no
+/// heatwave on the tundra).
+pub fn climate_weight(terrain: BaseTerrain, weather: Weather) -> u32 {
+    use BaseTerrain::*;
+    // Clear, Fog, LightRain, HeavyRain, Storm, Snow, Blizzard, Heatwave
+    let row: [u32; 8] = match terrain {
+        Desert => [60, 0, 5, 3, 2, 0, 0, 30],
+        Badlands => [60, 3, 8, 4, 3, 0, 0, 22],
+        Tundra => [45, 12, 0, 0, 3, 28, 12, 0],
+        Mountains => [45, 12, 8, 5, 5, 17, 8, 0],
+        Highlands => [45, 15, 15, 8, 6, 7, 4, 0],
+        Forest => [50, 12, 20, 10, 6, 2, 0, 0],
+        Jungle => [35, 12, 25, 18, 10, 0, 0, 0],
+        Wetlands => [40, 20, 22, 12, 6, 0, 0, 0],
+        Grasslands => [55, 8, 15, 7, 5, 0, 0, 10],
+        Clearing => [55, 10, 15, 8, 5, 2, 0, 5],
+        UrbanRuins => [55, 12, 15, 8, 5, 3, 0, 2],
+        Geothermal => [50, 20, 12, 6, 4, 0, 0, 8],
+    };
+    let idx = match weather {
+        Weather::Clear => 0,
+        Weather::Fog => 1,
+        Weather::LightRain => 2,
+        Weather::HeavyRain => 3,
+        Weather::Storm => 4,
+        Weather::Snow => 5,
+        Weather::Blizzard => 6,
+        Weather::Heatwave => 7,
+    };
+    row[idx]
+}
+
+/// Weighted next-state candidates for `current` in `terrain`: every
+/// state the climate allows that is at most two severity ranks worse,
+/// with the current state boosted for persistence.
+pub fn transition_weights(terrain: BaseTerrain, current: Weather) -> Vec<(Weather, u32)> {
+    Weather::iter()
+        .filter(|w| w.severity_rank() <= current.severity_rank() + MAX_ESCALATION)
+        .map(|w| {
+            let base = climate_weight(terrain, w);
+            let weight = if w == current {
+                base * PERSISTENCE_MULTIPLIER
+            } else {
+                base
+            };
+            (w, weight)
+        })
+        .filter(|(_, weight)| *weight > 0)
+        .collect()
+}
+
+/// Per-area weather state.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AreaWeather {
+    pub current: Weather,
+    /// Phases since the last change; saturates rather than wrapping.
+    #[serde(default)]
+    pub phases_in_state: u8,
+}
+
+/// A single area's weather change, reported by `AreaWeather::advance`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WeatherChange {
+    pub area: Area,
+    pub from: Weather,
+    pub to: Weather,
+}
+
+impl AreaWeather {
+    /// Roll the next state for `terrain`. Returns the previous weather
+    /// when the state changed, `None` when it held.
+    pub fn advance(&mut self, terrain: BaseTerrain, rng: &mut impl Rng) -> Option<Weather> {
+        let weights = transition_weights(terrain, self.current);
+        let total: u32 = weights.iter().map(|(_, w)| w).sum();
+        // A climate with no reachable state (shouldn't happen: Clear is
+        // always allowed) leaves the weather alone.
+        if total == 0 {
+            self.phases_in_state = self.phases_in_state.saturating_add(1);
+            return None;
+        }
+
+        let roll = rng.random_range(0..total);
+        let mut cumulative = 0;
+        let mut next = self.current;
+        for (weather, weight) in &weights {
+            cumulative += weight;
+            if roll < cumulative {
+                next = *weather;
+                break;
+            }
+        }
+
+        if next == self.current {
+            self.phases_in_state = self.phases_in_state.saturating_add(1);
+            None
+        } else {
+            let previous = self.current;
+            self.current = next;
+            self.phases_in_state = 0;
+            Some(previous)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use rstest::rstest;
 
     #[test]
     fn weather_default_is_clear() {
         assert_eq!(Weather::default(), Weather::Clear);
+        assert_eq!(AreaWeather::default().current, Weather::Clear);
+    }
+
+    #[test]
+    fn display_round_trips_through_from_str() {
+        for weather in Weather::iter() {
+            assert_eq!(weather.to_string().parse::<Weather>(), Ok(weather));
+        }
+    }
+
+    #[rstest]
+    #[case(BaseTerrain::Desert, Weather::Snow)]
+    #[case(BaseTerrain::Desert, Weather::Blizzard)]
+    #[case(BaseTerrain::Tundra, Weather::Heatwave)]
+    #[case(BaseTerrain::Jungle, Weather::Snow)]
+    fn climate_forbids_implausible_weather(#[case] terrain: BaseTerrain, #[case] weather: Weather) {
+        assert_eq!(climate_weight(terrain, weather), 0);
+        for current in Weather::iter() {
+            assert!(
+                transition_weights(terrain, current)
+                    .iter()
+                    .all(|(w, _)| *w != weather)
+            );
+        }
+    }
+
+    #[test]
+    fn clear_never_jumps_straight_to_extreme() {
+        for terrain in BaseTerrain::iter() {
+            assert!(
+                transition_weights(terrain, Weather::Clear)
+                    .iter()
+                    .all(|(w, _)| !w.is_extreme())
+            );
+        }
+    }
+
+    #[test]
+    fn advance_reports_changes_and_resets_counter() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut weather = AreaWeather::default();
+        let mut changes = 0;
+        for _ in 0..200 {
+            let before = weather.current;
+            match weather.advance(BaseTerrain::Jungle, &mut rng) {
+                Some(previous) => {
+                    changes += 1;
+                    assert_eq!(previous, before);
+                    assert_ne!(weather.current, before);
+                    assert_eq!(weather.phases_in_state, 0);
+                }
+                None => assert_eq!(weather.current, before),
+            }
+            assert!(climate_weight(BaseTerrain::Jungle, weather.current) > 0);
+        }
+        assert!(changes > 0, "jungle weather should change over 200 phases");
+    }
+
+    #[test]
+    fn advance_is_deterministic_for_a_seed() {
+        let run = || {
+            let mut rng = SmallRng::seed_from_u64(99);
+            let mut weather = AreaWeather::default();
+            (0..50)
+                .map(|_| {
+                    weather.advance(BaseTerrain::Mountains, &mut rng);
+                    weather.current
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(), run());
     }
 
     #[test]
-    fn current_weather_stub_returns_clear() {
-        assert_eq!(current_weather(), Weather::Clear);
+    fn event_weights_are_never_zero() {
+        for weather in Weather::iter() {
+            for event in AreaEvent::iter() {
+                assert!(weather.event_weight_percent(&event) > 0);
+            }
+        }
     }
 }
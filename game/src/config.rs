@@ -1,9 +1,25 @@
+use crate::tributes::brains::tuning::BrainTuning;
+use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
+use shared::{CreateGame, DEFAULT_DISTRICT_COUNT, DEFAULT_TRIBUTE_COUNT, Difficulty};
 
 /// Configuration for game constants and tuning parameters.
 /// Centralizes magic numbers to enable runtime configuration and difficulty modes.
+///
+/// Fields missing from a stored or partial config take their default values.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct GameConfig {
+    // Roster
+    /// Tributes reaped for the game; the game is ready once all of them
+    /// are in, and over once all of them are dead
+    pub tribute_count: u32,
+    /// Districts the tributes are reaped from, in turn
+    pub district_count: u32,
+    /// Hex rings of sectors around the Cornucopia (1 is the classic
+    /// seven-area arena)
+    pub arena_rings: u32,
+
     // Game lifecycle constants (from games.rs)
     /// Tribute count threshold for area constriction
     pub low_tribute_threshold: u32,
@@ -27,32 +43,32 @@ pub struct GameConfig {
     pub fixations_enabled: bool,
     /// Enable addiction processing (brain layer, decay, observer tracking)
     pub addiction_enabled: bool,
+    /// Enable gamemaker interventions (fireballs, mutts, force-field shifts,
+    /// feasts); off by default, so games opt in
+    pub gamemaker_enabled: bool,
+    /// Enable supernatural contagions (patient-zero seeding, combat
+    /// transmission, transformation); off by default, for horror games
+    pub contagions_enabled: bool,
     /// Global multiplier for event severity (1.0 = normal, 2.0 = double damage)
     pub catastrophic_severity_multiplier: f64,
+    /// Seconds the API holds a day back for missing player orders before
+    /// the brains of player-controlled tributes take over
+    pub player_order_timeout_secs: u64,
+    /// Currency each player's sponsor wallet starts with in a game
+    pub player_sponsor_budget: u32,
 
-    // Tribute AI decision thresholds (from tributes/brains.rs)
-    /// Enemy count threshold for "few enemies" AI decisions
-    pub low_enemy_limit: u32,
-    /// Health threshold for low health AI decisions
-    pub low_health_limit: u32,
-    /// Health threshold for mid health AI decisions
-    pub mid_health_limit: u32,
-    /// Extreme low sanity threshold for desperate actions
-    pub extreme_low_sanity_limit: u32,
-    /// Low sanity threshold for impulsive actions
-    pub low_sanity_limit: u32,
-    /// Mid sanity threshold for cautious actions
-    pub mid_sanity_limit: u32,
-    /// Low movement threshold for exhaustion checks
-    pub low_movement_limit: u32,
-    /// High intelligence threshold for tactical decisions
-    pub high_intelligence_limit: u32,
-    /// Low intelligence threshold for reckless decisions
-    pub low_intelligence_limit: u32,
+    // Tribute AI
+    /// Weights and thresholds the built-in brain decides by. Its
+    /// personality baselines apply to tributes reaped for the game
+    pub brain: BrainTuning,
 
     // Tribute lifecycle constants (from tributes/mod.rs)
     /// Sanity level at which tributes may attempt suicide
     pub sanity_break_level: u32,
+    /// Lowest health (1-100) a tribute can start the game with
+    pub starting_health_min: u32,
+    /// Highest health (1-100) a tribute can start the game with
+    pub starting_health_max: u32,
 
     // Attribute maximums (from tributes/mod.rs)
     pub max_health: u32,
@@ -71,6 +87,11 @@ impl Default for GameConfig {
     /// Returns default configuration matching the original hardcoded values.
     fn default() -> Self {
         Self {
+            // Roster
+            tribute_count: DEFAULT_TRIBUTE_COUNT,
+            district_count: DEFAULT_DISTRICT_COUNT,
+            arena_rings: 1,
+
             // Game lifecycle
             low_tribute_threshold: 8,
             feast_weapon_count: 2,
@@ -83,21 +104,19 @@ impl Default for GameConfig {
             phobias_enabled: true,
             fixations_enabled: true,
             addiction_enabled: true,
+            gamemaker_enabled: false,
+            contagions_enabled: false,
             catastrophic_severity_multiplier: 1.0,
+            player_order_timeout_secs: 300,
+            player_sponsor_budget: 100,
 
             // Tribute AI
-            low_enemy_limit: 6,
-            low_health_limit: 20,
-            mid_health_limit: 40,
-            extreme_low_sanity_limit: 10,
-            low_sanity_limit: 20,
-            mid_sanity_limit: 35,
-            low_movement_limit: 10,
-            high_intelligence_limit: 35,
-            low_intelligence_limit: 80,
+            brain: BrainTuning::default(),
 
             // Tribute lifecycle
             sanity_break_level: 9,
+            starting_health_min: 100,
+            starting_health_max: 100,
 
             // Attribute maximums
             max_health: 100,
@@ -114,6 +133,89 @@ impl Default for GameConfig {
     }
 }
 
+impl GameConfig {
+    /// The configuration a difficulty preset starts from.
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        match difficulty {
+            Difficulty::Gentle => Self {
+                feast_weapon_count: 3,
+                feast_shield_count: 3,
+                feast_consumable_count: 6,
+                day_event_frequency: 0.1,
+                night_event_frequency: 0.05,
+                instant_death_enabled: false,
+                catastrophic_severity_multiplier: 0.5,
+                ..Self::default()
+            },
+            Difficulty::Standard => Self::default(),
+            Difficulty::Bloodbath => Self {
+                low_tribute_threshold: 12,
+                feast_weapon_count: 4,
+                feast_shield_count: 1,
+                feast_consumable_count: 2,
+                day_event_frequency: 0.5,
+                night_event_frequency: 0.25,
+                catastrophic_severity_multiplier: 1.5,
+                starting_health_min: 70,
+                ..Self::default()
+            },
+        }
+    }
+
+    /// Build a game's configuration from its creation request: the
+    /// difficulty preset, then every option the request sets explicitly.
+    pub fn from_create_game(request: &CreateGame) -> Self {
+        let mut config = Self::for_difficulty(request.difficulty);
+        (config.tribute_count, config.district_count) = request.roster();
+        if let Some(rings) = request.arena_rings {
+            config.arena_rings = rings;
+        }
+        if let Some(frequency) = request.event_frequency {
+            // Nights keep their usual half-rate relative to days.
+            let probability = f64::from(frequency.event_probability());
+            config.day_event_frequency = probability;
+            config.night_event_frequency = probability / 2.0;
+        }
+        if let Some((min, max)) = request.starting_health_range {
+            config.starting_health_min = min;
+            config.starting_health_max = max;
+        }
+        if let Some(enabled) = request.trauma_enabled {
+            config.trauma_enabled = enabled;
+        }
+        if let Some(enabled) = request.phobias_enabled {
+            config.phobias_enabled = enabled;
+        }
+        if let Some(enabled) = request.fixations_enabled {
+            config.fixations_enabled = enabled;
+        }
+        if let Some(enabled) = request.addiction_enabled {
+            config.addiction_enabled = enabled;
+        }
+        if let Some(enabled) = request.gamemaker_enabled {
+            config.gamemaker_enabled = enabled;
+        }
+        if let Some(enabled) = request.contagions_enabled {
+            config.contagions_enabled = enabled;
+        }
+        config
+    }
+
+    /// The district (1-based) of the `index`th tribute reaped. Districts
+    /// take turns, so every district is filled before any gets a second.
+    pub fn district_for(&self, index: u32) -> u32 {
+        index % self.district_count.max(1) + 1
+    }
+
+    /// Roll a starting blood reserve inside the starting health range.
+    /// Health is blood / 10, so 100 health is a full 1000 blood.
+    pub fn roll_starting_blood(&self, rng: &mut impl Rng) -> u32 {
+        let max = self.starting_health_max.clamp(1, 100);
+        let min = self.starting_health_min.clamp(1, max);
+        rng.random_range(min..=max) * 10
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,7 +226,8 @@ mod tests {
         assert_eq!(config.low_tribute_threshold, 8);
         assert_eq!(config.feast_weapon_count, 2);
         assert_eq!(config.max_health, 100);
-        assert_eq!(config.low_health_limit, 20);
+        assert_eq!(config.brain, BrainTuning::default());
+        assert_eq!((config.tribute_count, config.district_count), (24, 12));
     }
 
     #[test]
@@ -168,4 +271,77 @@ mod tests {
         assert_eq!(config.catastrophic_severity_multiplier, 2.0);
         assert_eq!(config.day_event_frequency, 0.5);
     }
+
+    #[test]
+    fn test_standard_difficulty_is_default() {
+        assert_eq!(
+            GameConfig::for_difficulty(Difficulty::Standard),
+            GameConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_create_game_options_override_difficulty() {
+        let request: CreateGame = serde_json::from_value(serde_json::json!({
+            "difficulty": "Bloodbath",
+            "event_frequency": "Rare",
+            "starting_health_range": [40, 60],
+            "trauma_enabled": false,
+            "addiction_enabled": false,
+            "contagions_enabled": true,
+        }))
+        .unwrap();
+        let config = GameConfig::from_create_game(&request);
+
+        assert!((config.day_event_frequency - 0.1).abs() < 1e-6);
+        assert!((config.night_event_frequency - 0.05).abs() < 1e-6);
+        assert_eq!(
+            (config.starting_health_min, config.starting_health_max),
+            (40, 60)
+        );
+        assert!(!config.trauma_enabled);
+        assert!(!config.addiction_enabled);
+        assert!(config.contagions_enabled);
+        // Untouched options keep the preset's values.
+        assert!(config.phobias_enabled);
+        assert_eq!(config.low_tribute_threshold, 12);
+    }
+
+    #[test]
+    fn test_create_game_sets_roster() {
+        let request: CreateGame = serde_json::from_value(serde_json::json!({
+            "tribute_count": 8,
+        }))
+        .unwrap();
+        let config = GameConfig::from_create_game(&request);
+        assert_eq!((config.tribute_count, config.district_count), (8, 4));
+        assert_eq!(config.arena_rings, 1);
+
+        let districts: Vec<u32> = (0..8).map(|i| config.district_for(i)).collect();
+        assert_eq!(districts, vec![1, 2, 3, 4, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_partial_config_fills_defaults() {
+        let config: GameConfig = serde_json::from_str(r#"{"trauma_enabled": false}"#).unwrap();
+        assert!(!config.trauma_enabled);
+        assert!(!config.contagions_enabled);
+        assert_eq!(config.starting_health_max, 100);
+    }
+
+    #[test]
+    fn test_roll_starting_blood_stays_in_range() {
+        use rand::SeedableRng;
+        let config = GameConfig {
+            starting_health_min: 50,
+            starting_health_max: 60,
+            ..GameConfig::default()
+        };
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(3);
+        for _ in 0..100 {
+            let blood = config.roll_starting_blood(&mut rng);
+            assert!((500..=600).contains(&blood));
+        }
+        assert_eq!(GameConfig::default().roll_starting_blood(&mut rng), 1000);
+    }
 }
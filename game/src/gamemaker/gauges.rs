@@ -0,0 +1,201 @@
+use crate::gamemaker::interventions::InterventionKind;
+use crate::gamemaker::profile::GamemakerProfile;
+use crate::messages::MessagePayload;
+use serde::{Deserialize, Serialize};
+
+const GAUGE_MAX: i16 = 100;
+
+/// Drama pressure at or above which the gamemaker wants to act.
+pub const DRAMA_TRIGGER: u8 = 60;
+/// Bloodthirst at or above which the gamemaker wants to act.
+pub const BLOODTHIRST_TRIGGER: u8 = 70;
+/// Chaos at or above which the gamemaker wants to act.
+pub const CHAOS_TRIGGER: u8 = 70;
+
+/// The gamemaker's read of the arena. Every gauge but `patience` runs
+/// 0–100: quiet phases push them up, the drama reported in the message
+/// stream pulls them back down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Gauges {
+    /// General "something should happen" pressure.
+    pub drama_pressure: u8,
+    /// Climbs while nobody dies; favours lethal interventions.
+    pub bloodthirst: u8,
+    /// Climbs while the arena is predictable; favours disruptive ones.
+    pub chaos: u8,
+    /// Phases since the last intervention.
+    pub patience: u8,
+}
+
+impl Default for Gauges {
+    fn default() -> Self {
+        Self::STARTING
+    }
+}
+
+fn adjust(gauge: &mut u8, delta: i16) {
+    *gauge = (*gauge as i16 + delta).clamp(0, GAUGE_MAX) as u8;
+}
+
+impl Gauges {
+    pub const STARTING: Self = Self {
+        drama_pressure: 0,
+        bloodthirst: 20,
+        chaos: 10,
+        patience: 0,
+    };
+
+    /// Background drift for one phase, applied before the gamemaker
+    /// decides whether to act.
+    pub fn tick(&mut self, profile: &GamemakerProfile, living_tributes: usize) {
+        let rise = if living_tributes <= profile.late_game_threshold {
+            (profile.pressure_rise as f32 * profile.late_game_multiplier).round() as i16
+        } else {
+            profile.pressure_rise as i16
+        };
+        adjust(&mut self.drama_pressure, rise);
+        adjust(&mut self.chaos, profile.chaos_rise as i16);
+        self.patience = self.patience.saturating_add(1);
+    }
+
+    /// React to the payloads emitted during one phase. Deaths, hazards and
+    /// fights bleed off pressure; a phase without a death whets the
+    /// gamemaker's appetite.
+    pub fn observe<'a>(
+        &mut self,
+        payloads: impl IntoIterator<Item = &'a MessagePayload>,
+        profile: &GamemakerProfile,
+    ) {
+        let mut deaths = 0;
+        for payload in payloads {
+            match payload {
+                MessagePayload::TributeKilled { .. } => {
+                    deaths += 1;
+                    adjust(&mut self.drama_pressure, -15);
+                    adjust(&mut self.bloodthirst, -20);
+                    adjust(&mut self.chaos, -5);
+                }
+                MessagePayload::AreaEvent { .. } => {
+                    adjust(&mut self.drama_pressure, -8);
+                    adjust(&mut self.bloodthirst, -2);
+                    adjust(&mut self.chaos, -10);
+                }
+                MessagePayload::Combat(_) => {
+                    adjust(&mut self.drama_pressure, -3);
+                    adjust(&mut self.bloodthirst, 2);
+                    adjust(&mut self.chaos, -2);
+                }
+                MessagePayload::BetrayalTriggered { .. }
+                | MessagePayload::AllianceDissolved { .. } => {
+                    adjust(&mut self.drama_pressure, -3);
+                    adjust(&mut self.chaos, -5);
+                }
+                _ => {}
+            }
+        }
+        if deaths == 0 {
+            adjust(&mut self.bloodthirst, profile.bloodthirst_rise as i16);
+        }
+    }
+
+    /// True when any gauge has crossed its trigger threshold.
+    pub fn triggered(&self) -> bool {
+        self.drama_pressure >= DRAMA_TRIGGER
+            || self.bloodthirst >= BLOODTHIRST_TRIGGER
+            || self.chaos >= CHAOS_TRIGGER
+    }
+
+    /// Settle the gauges after the gamemaker's own intervention. Kills it
+    /// causes are picked up separately by `observe`.
+    pub fn react_to(&mut self, kind: InterventionKind) {
+        let (drama, bloodthirst, chaos) = match kind {
+            InterventionKind::Fireball | InterventionKind::MuttPack => (-15, -10, -5),
+            InterventionKind::ForceFieldShift => (-10, 0, -20),
+            InterventionKind::ConvergencePoint => (-5, 0, 0),
+        };
+        adjust(&mut self.drama_pressure, drama);
+        adjust(&mut self.bloodthirst, bloodthirst);
+        adjust(&mut self.chaos, chaos);
+        self.patience = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gamemaker::profile::CASSANDRA;
+    use crate::messages::TributeRef;
+    use shared::afflictions::DeathCause;
+
+    fn killed() -> MessagePayload {
+        MessagePayload::TributeKilled {
+            victim: TributeRef {
+                identifier: "t1".into(),
+                name: "Rue".into(),
+            },
+            killer: None,
+            cause: DeathCause::Unknown,
+        }
+    }
+
+    #[test]
+    fn quiet_phases_build_pressure() {
+        let mut gauges = Gauges::STARTING;
+        for _ in 0..8 {
+            gauges.tick(&CASSANDRA, 24);
+            gauges.observe([], &CASSANDRA);
+        }
+        assert!(gauges.drama_pressure >= DRAMA_TRIGGER);
+        assert!(gauges.bloodthirst > Gauges::STARTING.bloodthirst);
+        assert_eq!(gauges.patience, 8);
+        assert!(gauges.triggered());
+    }
+
+    #[test]
+    fn late_game_pressure_rises_faster() {
+        let mut early = Gauges::STARTING;
+        let mut late = Gauges::STARTING;
+        early.tick(&CASSANDRA, 20);
+        late.tick(&CASSANDRA, 4);
+        assert!(late.drama_pressure > early.drama_pressure);
+    }
+
+    #[test]
+    fn deaths_drain_bloodthirst_and_pressure() {
+        let mut gauges = Gauges {
+            drama_pressure: 50,
+            bloodthirst: 60,
+            chaos: 40,
+            patience: 2,
+        };
+        gauges.observe(&[killed(), killed()], &CASSANDRA);
+        assert_eq!(gauges.drama_pressure, 20);
+        assert_eq!(gauges.bloodthirst, 20);
+        assert_eq!(gauges.chaos, 30);
+    }
+
+    #[test]
+    fn gauges_are_clamped() {
+        let mut gauges = Gauges {
+            drama_pressure: 98,
+            bloodthirst: 1,
+            chaos: 99,
+            patience: 0,
+        };
+        gauges.tick(&CASSANDRA, 24);
+        gauges.observe(&[killed()], &CASSANDRA);
+        assert_eq!(gauges.bloodthirst, 0);
+        assert!(gauges.drama_pressure <= 100);
+        assert!(gauges.chaos <= 100);
+    }
+
+    #[test]
+    fn intervening_resets_patience() {
+        let mut gauges = Gauges {
+            patience: 9,
+            ..Gauges::STARTING
+        };
+        gauges.react_to(InterventionKind::ForceFieldShift);
+        assert_eq!(gauges.patience, 0);
+    }
+}
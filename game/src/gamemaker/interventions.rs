@@ -0,0 +1,105 @@
+use crate::areas::Area;
+use crate::gamemaker::gauges::Gauges;
+use crate::messages::Lure;
+use crate::threats::animals::Animal;
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+/// The gamemaker's catalog of authored interventions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+#[serde(rename_all = "snake_case")]
+pub enum InterventionKind {
+    /// Area-wide fire strike; resolves immediately.
+    Fireball,
+    /// Releases a mutt swarm that attacks every phase until dawn.
+    MuttPack,
+    /// Seals low-traffic areas and releases earlier seals.
+    ForceFieldShift,
+    /// Announces a feast that pulls tributes toward one area.
+    ConvergencePoint,
+}
+
+impl InterventionKind {
+    /// How much the gamemaker wants this kind right now, before the
+    /// recent-use penalty. Higher wins.
+    pub fn score(&self, gauges: &Gauges, living_tributes: usize) -> i32 {
+        let drama = gauges.drama_pressure as i32;
+        let bloodthirst = gauges.bloodthirst as i32;
+        let chaos = gauges.chaos as i32;
+        match self {
+            InterventionKind::Fireball => bloodthirst * 2 + drama,
+            InterventionKind::MuttPack => bloodthirst + chaos + drama / 2,
+            InterventionKind::ForceFieldShift => {
+                // Sealing areas around a handful of survivors is overkill.
+                let endgame_penalty = if living_tributes <= 4 { 50 } else { 0 };
+                chaos * 3 + drama - endgame_penalty
+            }
+            InterventionKind::ConvergencePoint => {
+                let crowd_bonus = if living_tributes >= 6 { 15 } else { 0 };
+                drama * 2 + crowd_bonus
+            }
+        }
+    }
+}
+
+/// An intervention that outlives the phase it was launched in. Ticked at
+/// the start of every phase by `Game::run_gamemaker`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ActiveIntervention {
+    MuttSwarm {
+        area: Area,
+        animal: Animal,
+        members: u8,
+    },
+    SealedArea {
+        area: Area,
+        phases_left: u8,
+    },
+    ConvergencePoint {
+        area: Area,
+        lure: Lure,
+        phases_left: u8,
+    },
+}
+
+impl ActiveIntervention {
+    pub fn area(&self) -> Area {
+        match self {
+            ActiveIntervention::MuttSwarm { area, .. }
+            | ActiveIntervention::SealedArea { area, .. }
+            | ActiveIntervention::ConvergencePoint { area, .. } => *area,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bloodthirst_favours_fireball() {
+        let gauges = Gauges {
+            drama_pressure: 30,
+            bloodthirst: 90,
+            chaos: 10,
+            patience: 5,
+        };
+        let fireball = InterventionKind::Fireball.score(&gauges, 20);
+        assert!(fireball > InterventionKind::ForceFieldShift.score(&gauges, 20));
+        assert!(fireball > InterventionKind::ConvergencePoint.score(&gauges, 20));
+    }
+
+    #[test]
+    fn chaos_favours_force_field_until_the_endgame() {
+        let gauges = Gauges {
+            drama_pressure: 30,
+            bloodthirst: 20,
+            chaos: 90,
+            patience: 5,
+        };
+        let shift = InterventionKind::ForceFieldShift.score(&gauges, 20);
+        assert!(shift > InterventionKind::Fireball.score(&gauges, 20));
+        assert!(InterventionKind::ForceFieldShift.score(&gauges, 3) < shift);
+    }
+}
@@ -0,0 +1,206 @@
+//! The gamemaker: a Capitol storyteller that watches the message stream
+//! and imposes authored interventions when the arena gets too quiet
+//! (spec `2026-05-03-gamemaker-event-system-design.md`).
+//!
+//! This module holds the pure state and decision logic. Resolving an
+//! intervention against the arena lives on `Game` in
+//! `games/gamemaker.rs`.
+
+pub mod gauges;
+pub mod interventions;
+pub mod profile;
+
+pub use gauges::Gauges;
+pub use interventions::{ActiveIntervention, InterventionKind};
+pub use profile::{CASSANDRA, GamemakerProfile};
+
+use crate::areas::Area;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use strum::IntoEnumIterator;
+
+/// How many past interventions count toward the repeat penalty.
+const RECENT_WINDOW: usize = 6;
+
+/// Gamemaker state carried on `Game` and persisted with it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Gamemaker {
+    pub gauges: Gauges,
+    /// Most recent interventions, oldest first, capped at six.
+    pub recent_interventions: VecDeque<InterventionKind>,
+    /// Interventions launched on `day`.
+    pub interventions_today: u8,
+    /// Game day `interventions_today` counts toward.
+    pub day: u32,
+    /// Swarms, seals and convergence points still on the map.
+    pub active_effects: Vec<ActiveIntervention>,
+}
+
+impl Gamemaker {
+    pub fn profile(&self) -> &'static GamemakerProfile {
+        &CASSANDRA
+    }
+
+    /// Reset the daily intervention budget when `day` is a new day.
+    pub fn start_day(&mut self, day: u32) {
+        if day != self.day {
+            self.day = day;
+            self.interventions_today = 0;
+        }
+    }
+
+    /// Whether the gamemaker both may and wants to intervene this phase.
+    pub fn should_intervene(&self) -> bool {
+        let profile = self.profile();
+        self.interventions_today < profile.max_per_day
+            && self.active_effects.len() < profile.max_concurrent_effects
+            && self.gauges.patience >= profile.patience_threshold
+            && self.gauges.triggered()
+    }
+
+    /// Every intervention kind, best first. Ties are broken randomly.
+    pub fn ranked_candidates(
+        &self,
+        living_tributes: usize,
+        rng: &mut impl Rng,
+    ) -> Vec<InterventionKind> {
+        let penalty = self.profile().recent_penalty;
+        let mut kinds: Vec<InterventionKind> = InterventionKind::iter().collect();
+        kinds.shuffle(rng);
+        kinds.sort_by_key(|kind| {
+            let uses = self
+                .recent_interventions
+                .iter()
+                .filter(|recent| *recent == kind)
+                .count() as i32;
+            std::cmp::Reverse(kind.score(&self.gauges, living_tributes) - penalty * uses)
+        });
+        kinds
+    }
+
+    /// Book-keeping after an intervention has been launched.
+    pub fn record(&mut self, kind: InterventionKind) {
+        self.recent_interventions.push_back(kind);
+        while self.recent_interventions.len() > RECENT_WINDOW {
+            self.recent_interventions.pop_front();
+        }
+        self.interventions_today = self.interventions_today.saturating_add(1);
+        self.gauges.react_to(kind);
+    }
+
+    /// Areas currently sealed by the force field.
+    pub fn sealed_areas(&self) -> Vec<Area> {
+        self.active_effects
+            .iter()
+            .filter_map(|effect| match effect {
+                ActiveIntervention::SealedArea { area, .. } => Some(*area),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The area of the active convergence point, if any.
+    pub fn convergence_point(&self) -> Option<Area> {
+        self.active_effects.iter().find_map(|effect| match effect {
+            ActiveIntervention::ConvergencePoint { area, .. } => Some(*area),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::Lure;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn eager() -> Gamemaker {
+        Gamemaker {
+            gauges: Gauges {
+                drama_pressure: 80,
+                bloodthirst: 80,
+                chaos: 20,
+                patience: 10,
+            },
+            ..Gamemaker::default()
+        }
+    }
+
+    #[test]
+    fn patience_gates_intervention() {
+        let mut gamemaker = eager();
+        assert!(gamemaker.should_intervene());
+        gamemaker.gauges.patience = 0;
+        assert!(!gamemaker.should_intervene());
+    }
+
+    #[test]
+    fn daily_cap_resets_on_a_new_day() {
+        let mut gamemaker = eager();
+        gamemaker.start_day(2);
+        gamemaker.interventions_today = CASSANDRA.max_per_day;
+        assert!(!gamemaker.should_intervene());
+        gamemaker.start_day(2);
+        assert!(!gamemaker.should_intervene());
+        gamemaker.start_day(3);
+        assert!(gamemaker.should_intervene());
+    }
+
+    #[test]
+    fn active_effects_cap_blocks_intervention() {
+        let mut gamemaker = eager();
+        for area in [Area::SECTOR_1, Area::SECTOR_2] {
+            gamemaker
+                .active_effects
+                .push(ActiveIntervention::SealedArea {
+                    area,
+                    phases_left: 2,
+                });
+        }
+        assert!(!gamemaker.should_intervene());
+        assert_eq!(
+            gamemaker.sealed_areas(),
+            vec![Area::SECTOR_1, Area::SECTOR_2]
+        );
+    }
+
+    #[test]
+    fn recent_use_demotes_a_kind() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let mut gamemaker = eager();
+        let first = gamemaker.ranked_candidates(20, &mut rng)[0];
+        for _ in 0..3 {
+            gamemaker.recent_interventions.push_back(first);
+        }
+        assert_ne!(gamemaker.ranked_candidates(20, &mut rng)[0], first);
+    }
+
+    #[test]
+    fn record_caps_the_recent_window() {
+        let mut gamemaker = eager();
+        for _ in 0..10 {
+            gamemaker.record(InterventionKind::ConvergencePoint);
+        }
+        assert_eq!(gamemaker.recent_interventions.len(), RECENT_WINDOW);
+        assert_eq!(gamemaker.interventions_today, 10);
+        assert_eq!(gamemaker.gauges.patience, 0);
+    }
+
+    #[test]
+    fn convergence_point_is_found_among_effects() {
+        let mut gamemaker = Gamemaker::default();
+        assert_eq!(gamemaker.convergence_point(), None);
+        gamemaker
+            .active_effects
+            .push(ActiveIntervention::ConvergencePoint {
+                area: Area::SECTOR_4,
+                lure: Lure::Feast,
+                phases_left: 3,
+            });
+        assert_eq!(gamemaker.convergence_point(), Some(Area::SECTOR_4));
+    }
+}
@@ -0,0 +1,38 @@
+/// Storyteller personality: how fast the gauges climb and how often the
+/// gamemaker is allowed to act on them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GamemakerProfile {
+    pub name: &'static str,
+    /// Drama pressure gained per phase.
+    pub pressure_rise: u8,
+    /// Bloodthirst gained per phase in which nobody died.
+    pub bloodthirst_rise: u8,
+    /// Chaos gained per phase; drained by combat and hazards.
+    pub chaos_rise: u8,
+    /// Phases that must pass after an intervention before the next.
+    pub patience_threshold: u8,
+    /// Hard cap on interventions per game-day.
+    pub max_per_day: u8,
+    /// No new intervention while this many effects are still active.
+    pub max_concurrent_effects: usize,
+    /// Living-tribute count at or below which pressure rises faster.
+    pub late_game_threshold: usize,
+    /// Multiplier on `pressure_rise` in the late game.
+    pub late_game_multiplier: f32,
+    /// Score subtracted from a kind for each use in the recent window.
+    pub recent_penalty: i32,
+}
+
+/// Rising tension with paced escalation. The only profile shipped today.
+pub const CASSANDRA: GamemakerProfile = GamemakerProfile {
+    name: "Cassandra",
+    pressure_rise: 8,
+    bloodthirst_rise: 6,
+    chaos_rise: 4,
+    patience_threshold: 3,
+    max_per_day: 2,
+    max_concurrent_effects: 2,
+    late_game_threshold: 8,
+    late_game_multiplier: 1.5,
+    recent_penalty: 25,
+};
@@ -1,4 +1,51 @@
 use super::*;
+use crate::messages::{MessagePayload, MessageSource, Phase};
+use crate::output::GameOutput;
+use crate::tributes::alliances::{
+    Alliance, Watch, alliance_groups, choose_leader, leadership_score, watch_keeper,
+};
+
+/// A message about a tribute, queued until the borrows that built it are
+/// released.
+type Announcement = (Uuid, String, MessagePayload);
+
+/// The kinds of gear an alliance pools. A member keeps one of each and
+/// hands the rest to the stash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Supply {
+    Food,
+    Water,
+    Weapon,
+    Shield,
+}
+
+impl Supply {
+    fn of(item: &Item) -> Option<Supply> {
+        if item.current_durability == 0 {
+            None
+        } else if item.item_type.is_food() {
+            Some(Supply::Food)
+        } else if item.item_type.is_water() {
+            Some(Supply::Water)
+        } else if item.is_weapon() {
+            Some(Supply::Weapon)
+        } else if item.is_defensive() {
+            Some(Supply::Shield)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `tribute` is short of this supply.
+    fn wanted_by(self, tribute: &Tribute) -> bool {
+        let wanting = match self {
+            Supply::Food => tribute.hunger > 0,
+            Supply::Water => tribute.thirst > 0,
+            Supply::Weapon | Supply::Shield => true,
+        };
+        wanting && !tribute.items.iter().any(|i| Supply::of(i) == Some(self))
+    }
+}
 
 impl Game {
     /// Drain the alliance event queue accumulated during the current cycle.
@@ -17,7 +64,7 @@ impl Game {
 
         // Collect drained events into a local Vec so we can release the
         // borrow on `self.alliance_events` before mutating `self.tributes`.
-        let drained: Vec<AllianceEvent> = self.alliance_events.drain(..).collect();
+        let drained: Vec<AllianceEvent> = std::mem::take(&mut self.alliance_events);
 
         for ev in drained {
             match ev {
@@ -254,3 +301,307 @@ impl Game {
         }
     }
 }
+
+impl Game {
+    /// Bring `alliances` in step with the ally graph after it has changed.
+    /// Each group of two or more living tributes is an alliance; a group
+    /// that grew out of an existing alliance keeps its id, stash and, while
+    /// they are still in it, its leader. Announces the alliances that fell
+    /// apart and the members who split away. The stash of an alliance that
+    /// is gone goes to a surviving member, or onto the ground where its
+    /// leader was.
+    pub fn sync_alliances(&mut self, rng: &mut impl Rng) {
+        let links: Vec<(Uuid, &[Uuid])> = self
+            .tributes
+            .iter()
+            .filter(|t| t.is_alive())
+            .map(|t| (t.id, t.allies.as_slice()))
+            .collect();
+        let groups = alliance_groups(&links);
+
+        // Each group carries on the alliance it shares most members with,
+        // the leader's side winning a tie.
+        let mut claimed = vec![false; groups.len()];
+        let mut carried_on = Vec::new();
+        let mut gone = Vec::new();
+        for alliance in std::mem::take(&mut self.alliances) {
+            let successor = groups
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !claimed[*i])
+                .map(|(i, group)| {
+                    let overlap = group.iter().filter(|m| alliance.contains(m)).count();
+                    (i, (overlap, group.contains(&alliance.leader)))
+                })
+                .filter(|(_, (overlap, _))| *overlap > 0)
+                .max_by(|(a_i, a), (b_i, b)| a.cmp(b).then_with(|| b_i.cmp(a_i)));
+            match successor {
+                Some((i, _)) => {
+                    claimed[i] = true;
+                    carried_on.push((alliance, i));
+                }
+                None => gone.push(alliance),
+            }
+        }
+
+        let mut announcements: Vec<Announcement> = Vec::new();
+        let mut alliances = Vec::new();
+        for (mut alliance, i) in carried_on {
+            let members = &groups[i];
+            if !members.contains(&alliance.leader) {
+                alliance.leader = self.strongest_leader(members);
+            }
+            let Some(leader) = self.tributes.iter().find(|t| t.id == alliance.leader) else {
+                continue;
+            };
+            for departed in self
+                .tributes
+                .iter()
+                .filter(|t| t.is_alive() && alliance.contains(&t.id) && !members.contains(&t.id))
+            {
+                announcements.push((
+                    departed.id,
+                    GameOutput::AllianceSplit(&departed.name, &leader.name).to_string(),
+                    MessagePayload::AllianceDissolved {
+                        members: vec![tribute_ref(departed), tribute_ref(leader)],
+                        reason: format!("{} split away", departed.name),
+                    },
+                ));
+            }
+            alliance.members = members.clone();
+            alliances.push(alliance);
+        }
+        let day = self.day.unwrap_or_default();
+        for (i, group) in groups.iter().enumerate() {
+            if !claimed[i] {
+                alliances.push(Alliance {
+                    id: crate::seeding::uuid_from_rng(rng),
+                    leader: self.strongest_leader(group),
+                    members: group.clone(),
+                    stash: Vec::new(),
+                    formed_day: day,
+                });
+            }
+        }
+
+        for alliance in gone {
+            let survivors: Vec<&Tribute> = self
+                .tributes
+                .iter()
+                .filter(|t| t.is_alive() && alliance.contains(&t.id))
+                .collect();
+            // Survivors still together went over to a bigger alliance
+            // rather than falling out.
+            let merged = groups
+                .iter()
+                .any(|g| survivors.iter().filter(|t| g.contains(&t.id)).count() > 1);
+            if !merged && let Some(leader) = self.tributes.iter().find(|t| t.id == alliance.leader)
+            {
+                let reason = if survivors.len() < 2 {
+                    "too few of them are left alive"
+                } else {
+                    "its members went their own ways"
+                };
+                announcements.push((
+                    leader.id,
+                    GameOutput::AllianceDissolved(&leader.name).to_string(),
+                    MessagePayload::AllianceDissolved {
+                        members: survivors.iter().map(|t| tribute_ref(t)).collect(),
+                        reason: reason.to_string(),
+                    },
+                ));
+            }
+            self.hand_down_stash(alliance, &mut announcements);
+        }
+        self.alliances = alliances;
+        self.announce(announcements);
+    }
+
+    /// Members gathered with their leader pool their supplies: each hands
+    /// the stash whatever they carry beyond one of each kind, then takes
+    /// what they are short of. Sleepers keep their packs shut.
+    pub fn share_alliance_stashes(&mut self) {
+        let mut announcements: Vec<Announcement> = Vec::new();
+        for alliance in &mut self.alliances {
+            let Some(camp) = self
+                .tributes
+                .iter()
+                .find(|t| t.id == alliance.leader && t.is_alive())
+                .map(|t| t.area)
+            else {
+                continue;
+            };
+            let mut present: Vec<usize> = self
+                .tributes
+                .iter()
+                .enumerate()
+                .filter(|(_, t)| {
+                    alliance.contains(&t.id) && t.is_alive() && !t.sleeping && t.area == camp
+                })
+                .map(|(i, _)| i)
+                .collect();
+            present.sort_by_key(|&i| self.tributes[i].id);
+
+            for &i in &present {
+                let tribute = &mut self.tributes[i];
+                let mut carried: Vec<Supply> = Vec::new();
+                let mut kept = Vec::new();
+                for item in std::mem::take(&mut tribute.items) {
+                    match Supply::of(&item) {
+                        Some(kind) if carried.contains(&kind) => {
+                            announcements.push((
+                                tribute.id,
+                                GameOutput::StashDeposit(&tribute.name, &item.name).to_string(),
+                                MessagePayload::ItemDropped {
+                                    tribute: tribute_ref(tribute),
+                                    item: item_ref(&item),
+                                    area: area_ref(camp),
+                                },
+                            ));
+                            alliance.stash.push(item);
+                        }
+                        Some(kind) => {
+                            carried.push(kind);
+                            kept.push(item);
+                        }
+                        None => kept.push(item),
+                    }
+                }
+                tribute.items = kept;
+            }
+            for &i in &present {
+                let tribute = &mut self.tributes[i];
+                for kind in [Supply::Food, Supply::Water, Supply::Weapon, Supply::Shield] {
+                    if !kind.wanted_by(tribute) {
+                        continue;
+                    }
+                    let Some(at) = alliance
+                        .stash
+                        .iter()
+                        .position(|item| Supply::of(item) == Some(kind))
+                    else {
+                        continue;
+                    };
+                    let item = alliance.stash.remove(at);
+                    announcements.push((
+                        tribute.id,
+                        GameOutput::StashWithdrawal(&tribute.name, &item.name).to_string(),
+                        MessagePayload::ItemFound {
+                            tribute: tribute_ref(tribute),
+                            item: item_ref(&item),
+                            area: area_ref(camp),
+                        },
+                    ));
+                    tribute.add_item(item);
+                }
+            }
+        }
+        self.announce(announcements);
+    }
+
+    /// Hand out this phase's alliance roles. Followers gather where their
+    /// leader is headed, or where the leader is, instead of making plans of
+    /// their own. Members sharing an area take turns keeping watch, one per
+    /// phase, over the rest.
+    pub fn assign_alliance_roles(&mut self, day: u32, phase: Phase) {
+        let rotation = day.saturating_sub(1) * 4 + phase.ord() as u32;
+        let mut roles: HashMap<Uuid, (Option<Area>, Option<Watch>)> = HashMap::new();
+        for alliance in &self.alliances {
+            let members: Vec<&Tribute> = self
+                .tributes
+                .iter()
+                .filter(|t| t.is_alive() && alliance.contains(&t.id))
+                .collect();
+            let rally = members
+                .iter()
+                .find(|t| t.id == alliance.leader)
+                .map(|leader| leader.plan.as_ref().map_or(leader.area, |p| p.destination));
+            for member in &members {
+                let sharing: Vec<(Uuid, bool)> = members
+                    .iter()
+                    .filter(|t| t.area == member.area)
+                    .map(|t| (t.id, t.sleeping))
+                    .collect();
+                let watch = watch_keeper(&sharing, rotation).map(|keeper| {
+                    if keeper == member.id {
+                        Watch::Keeping
+                    } else {
+                        Watch::Guarded
+                    }
+                });
+                let rally = rally.filter(|_| member.id != alliance.leader);
+                roles.insert(member.id, (rally, watch));
+            }
+        }
+
+        for tribute in self.tributes.iter_mut() {
+            let (rally, watch) = roles.remove(&tribute.id).unwrap_or_default();
+            tribute.rally = rally;
+            tribute.watch = watch;
+            if rally.is_some() {
+                tribute.plan = None;
+            }
+        }
+    }
+
+    /// Give the stash of an alliance that is gone to a surviving member,
+    /// its leader first, or leave it where the leader was.
+    fn hand_down_stash(&mut self, alliance: Alliance, announcements: &mut Vec<Announcement>) {
+        if alliance.stash.is_empty() {
+            return;
+        }
+        let heir = std::iter::once(&alliance.leader)
+            .chain(&alliance.members)
+            .find_map(|id| {
+                self.tributes
+                    .iter()
+                    .position(|t| t.id == *id && t.is_alive())
+            });
+        if let Some(i) = heir {
+            let tribute = &mut self.tributes[i];
+            for item in alliance.stash {
+                announcements.push((
+                    tribute.id,
+                    GameOutput::StashWithdrawal(&tribute.name, &item.name).to_string(),
+                    MessagePayload::ItemFound {
+                        tribute: tribute_ref(tribute),
+                        item: item_ref(&item),
+                        area: area_ref(tribute.area),
+                    },
+                ));
+                tribute.add_item(item);
+            }
+        } else if let Some(area) = self
+            .tributes
+            .iter()
+            .find(|t| t.id == alliance.leader)
+            .map(|t| t.area)
+            && let Some(details) = self.get_area_details_mut(area)
+        {
+            details.items.extend(alliance.stash);
+        }
+    }
+
+    /// The member the rest most readily follow.
+    fn strongest_leader(&self, members: &[Uuid]) -> Uuid {
+        let candidates: Vec<(Uuid, u32)> = self
+            .tributes
+            .iter()
+            .filter(|t| members.contains(&t.id))
+            .map(|t| (t.id, leadership_score(&t.traits, &t.attributes)))
+            .collect();
+        choose_leader(&candidates).unwrap_or(members[0])
+    }
+
+    fn announce(&mut self, announcements: Vec<Announcement>) {
+        for (subject, line, payload) in announcements {
+            let Some(tribute) = self.tributes.iter().find(|t| t.id == subject) else {
+                continue;
+            };
+            let source = MessageSource::Tribute(tribute.identifier.clone());
+            let name = tribute.name.clone();
+            let tick = self.tick_counter.next();
+            self.push_message(source, name, line, payload, tick);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use super::*;
+use crate::messages::{MessagePayload, MessageSource, Phase, TributeRef};
+use crate::tributes::AfflictionDraft;
+use crate::tributes::afflictions::AcquireResolution;
+use crate::tributes::afflictions::contagion::contagion_draft;
+use rand::rngs::SmallRng;
+use shared::afflictions::{AfflictionKind, AfflictionSource, Contagion, DeathCause, Severity};
+
+/// Each contagion gets one patient zero when the games open.
+const CONTAGIONS: [Contagion; 3] = [
+    Contagion::Lycanthropy,
+    Contagion::Vampirism,
+    Contagion::ZombiePlague,
+];
+
+/// Blood a vampire loses for every Dawn or Day phase spent in the open.
+const SUNLIGHT_DAMAGE: u32 = 100;
+
+impl Game {
+    /// Top-of-phase contagion step. The opening phase of the games gives
+    /// each contagion a patient zero among the living tributes; every
+    /// phase, incubating contagions that have run their course take hold.
+    pub(super) fn advance_contagions(&mut self, phase: Phase, rng: &mut SmallRng) {
+        if !self.config.contagions_enabled {
+            return;
+        }
+        let day = self.day.unwrap_or(1);
+        if day == 1 && phase == Phase::Day {
+            self.seed_patient_zeros(rng);
+        }
+
+        let transformed: Vec<(TributeRef, Contagion)> = self
+            .tributes
+            .iter_mut()
+            .filter(|t| t.is_alive())
+            .filter_map(|t| t.advance_contagion(day).map(|c| (tribute_ref(t), c)))
+            .collect();
+        for (tribute, contagion) in transformed {
+            let content = crate::output::GameOutput::ContagionTransformed(&tribute.name, contagion)
+                .to_string();
+            let payload = MessagePayload::ContagionTransformed {
+                tribute: tribute.clone(),
+                contagion,
+            };
+            self.push_tribute_message(&tribute, content, payload);
+        }
+    }
+
+    fn seed_patient_zeros(&mut self, rng: &mut SmallRng) {
+        let mut candidates: Vec<usize> = (0..self.tributes.len())
+            .filter(|&i| self.tributes[i].is_alive())
+            .collect();
+        candidates.shuffle(rng);
+        for (contagion, index) in CONTAGIONS.into_iter().zip(candidates) {
+            let tribute = &mut self.tributes[index];
+            let resolution = tribute
+                .try_acquire_affliction(contagion_draft(contagion, AfflictionSource::Gamemaker));
+            if matches!(resolution, AcquireResolution::Reject(_)) {
+                continue;
+            }
+            let tribute = tribute_ref(tribute);
+            let content =
+                crate::output::GameOutput::ContagionInfected(&tribute.name, contagion, None)
+                    .to_string();
+            let payload = MessagePayload::ContagionInfected {
+                tribute: tribute.clone(),
+                source: None,
+                contagion,
+            };
+            self.push_tribute_message(&tribute, content, payload);
+        }
+    }
+
+    /// End-of-phase sunlight check: a transformed vampire that spent Dawn
+    /// or Day neither hidden nor asleep is burned, one severity step per
+    /// phase, and loses `SUNLIGHT_DAMAGE` blood.
+    pub(super) fn burn_exposed_vampires(&mut self, phase: Phase) {
+        if !self.config.contagions_enabled || !matches!(phase, Phase::Dawn | Phase::Day) {
+            return;
+        }
+        let mut burned: Vec<(TributeRef, Option<Severity>, bool)> = Vec::new();
+        for tribute in self.tributes.iter_mut().filter(|t| {
+            t.is_alive()
+                && t.transformed_contagion() == Some(Contagion::Vampirism)
+                && !t.attributes.is_hidden
+                && !t.sleeping
+        }) {
+            let severity = match tribute.afflictions.get(&(AfflictionKind::Burned, None)) {
+                None => Severity::Mild,
+                Some(existing) if existing.severity == Severity::Mild => Severity::Moderate,
+                Some(_) => Severity::Severe,
+            };
+            let resolution = tribute.try_acquire_affliction(AfflictionDraft {
+                kind: AfflictionKind::Burned,
+                body_part: None,
+                severity,
+                source: AfflictionSource::Environmental,
+                trapped_metadata: None,
+            });
+            let acquired =
+                (!matches!(resolution, AcquireResolution::Reject(_))).then_some(severity);
+
+            tribute.blood = tribute.blood.saturating_sub(SUNLIGHT_DAMAGE);
+            let died = tribute.blood == 0;
+            if died {
+                tribute.statistics.killed_by = Some(DeathCause::Fire.to_string());
+                tribute.status = TributeStatus::RecentlyDead;
+            }
+            burned.push((tribute_ref(tribute), acquired, died));
+        }
+
+        for (tribute, acquired, died) in burned {
+            let content = crate::output::GameOutput::SunlightBurn(&tribute.name).to_string();
+            if let Some(severity) = acquired {
+                let payload = MessagePayload::AfflictionAcquired {
+                    tribute_id: tribute.identifier.to_string(),
+                    affliction: AfflictionKind::Burned.to_string(),
+                    severity: severity.to_string(),
+                };
+                self.push_tribute_message(&tribute, content, payload);
+            }
+            if died {
+                let content = crate::output::GameOutput::TributeDeath(&tribute.name).to_string();
+                let payload = MessagePayload::TributeKilled {
+                    victim: tribute.clone(),
+                    killer: None,
+                    cause: DeathCause::Fire,
+                };
+                self.push_tribute_message(&tribute, content, payload);
+            }
+        }
+    }
+
+    fn push_tribute_message(
+        &mut self,
+        tribute: &TributeRef,
+        content: String,
+        payload: MessagePayload,
+    ) {
+        let source = MessageSource::Tribute(tribute.identifier.to_string());
+        let subject = format!("tribute:{}", tribute.identifier);
+        let tick = self.tick_counter.next();
+        self.push_message(source, subject, content, payload, tick);
+    }
+}
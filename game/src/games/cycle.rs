@@ -1,14 +1,18 @@
 use super::*;
+use crate::areas::corpses::Corpse;
 use crate::areas::{Area, AreaDetails};
 use crate::items::{Item, OwnsItems};
 use crate::messages::{AreaRef, ItemRef, MessagePayload, TributeRef};
+use crate::tributes::alliances::Watch;
+use crate::tributes::beliefs::Surroundings;
+use crate::tributes::brains::strategy::BrainStrategy;
 use crate::tributes::events::TributeEvent;
+use crate::tributes::goals::Planning;
 use crate::tributes::incidents::{SleepIncident, SleepShelter, apply_sleep_incident};
 use crate::tributes::statuses::TributeStatus;
 use crate::tributes::{
     ActionSuggestion, EncounterContext, EnvironmentContext, Tribute, calculate_stamina_cost,
 };
-use rand::SeedableRng;
 use rand::rngs::SmallRng;
 use rand::seq::SliceRandom;
 use shared::messages::SleepIncidentKind;
@@ -38,12 +42,27 @@ impl Game {
                 probability: Some(0.5),
             }),
             (Some(3), true) => Some(ActionSuggestion {
-                action: Action::Move(Some(Area::Cornucopia)),
+                action: Action::Move(Some(Area::CORNUCOPIA)),
                 probability: Some(0.75),
             }),
-            (_, _) => None,
+            // Otherwise an active gamemaker feast draws tributes in.
+            (_, _) => self
+                .gamemaker
+                .convergence_point()
+                .map(|area| ActionSuggestion {
+                    action: Action::Move(Some(area)),
+                    probability: Some(super::gamemaker::CONVERGENCE_PULL),
+                }),
         };
 
+        // Force-field seals close their areas for the cycle.
+        let mut closed_areas = closed_areas;
+        for area in self.gamemaker.sealed_areas() {
+            if !closed_areas.contains(&area) {
+                closed_areas.push(area);
+            }
+        }
+
         let mut area_details_map = HashMap::with_capacity(self.areas.len());
         for (i, area_detail) in self.areas.iter().enumerate() {
             if let Some(area) = &area_detail.area {
@@ -59,15 +78,6 @@ impl Game {
                 .push(tribute);
         }
 
-        // Per-area living-tribute density. Threaded into `EnvironmentContext`
-        // so `Brain::choose_destination` can apply a per-enemy crowd penalty
-        // and disperse crowded areas without a call-site escape hatch
-        // (hangrier_games-4wnj).
-        let enemy_density: HashMap<Area, u32> = tributes_by_area
-            .iter()
-            .map(|(area, tributes)| (*area, tributes.len() as u32))
-            .collect();
-
         CycleContext {
             is_day: day,
             phase,
@@ -75,7 +85,6 @@ impl Game {
             action_suggestion,
             area_details_map,
             tributes_by_area,
-            enemy_density,
             combat_tuning_snapshot: self.combat_tuning.clone(),
             all_areas_snapshot: self.areas.clone(),
             closed_areas,
@@ -100,7 +109,6 @@ impl Game {
             action_suggestion,
             area_details_map,
             tributes_by_area,
-            enemy_density,
             combat_tuning_snapshot,
             all_areas_snapshot,
             closed_areas,
@@ -117,6 +125,15 @@ impl Game {
         // actions in the same phase cannot act.
         let mut tributes_to_act: Vec<usize> = Vec::new();
 
+        // Everyone hears the cannons: the fallen drop out of the living's
+        // beliefs.
+        let fallen: Vec<Uuid> = self
+            .tributes
+            .iter()
+            .filter(|t| !t.is_alive())
+            .map(|t| t.id)
+            .collect();
+
         for (idx, tribute) in self.tributes.iter_mut().enumerate() {
             if !tribute.is_alive() {
                 // Newly-dead tributes (status=RecentlyDead going into this
@@ -140,8 +157,13 @@ impl Game {
                 continue;
             }
 
+            tribute.beliefs.fade();
+            for id in &fallen {
+                tribute.beliefs.forget_tribute(id);
+            }
+
             if !rng.random_bool(tribute.attributes.luck as f64 / 100.0) {
-                tribute.events.push(TributeEvent::random());
+                tribute.events.push(TributeEvent::random(rng));
             }
 
             // Survival tick (spec §6, §7). Each living tribute, once per
@@ -151,7 +173,6 @@ impl Game {
             // Loot drop is handled centrally by clean_up_recent_deaths
             // after the cycle ends.
             {
-                use crate::areas::weather::current_weather;
                 use crate::messages::{MessagePayload, TributeRef};
                 use crate::tributes::survival::{
                     apply_dehydration_drain, apply_starvation_drain, hunger_band, thirst_band,
@@ -159,7 +180,11 @@ impl Game {
                 };
                 use shared::afflictions::DeathCause;
 
-                let weather = current_weather();
+                let weather = area_details_map
+                    .get(&tribute.area)
+                    .and_then(|&i| all_areas_snapshot.get(i))
+                    .map(|a| a.weather.current)
+                    .unwrap_or_default();
                 let phase_index: u32 = self.day.unwrap_or(1) * 2 + u32::from(!day);
                 let sheltered = tribute
                     .sheltered_until
@@ -459,11 +484,14 @@ impl Game {
                 // incidents (theft, relocation, animal, ally abandonment,
                 // limb injury) interrupt sleep immediately. Flavor-only
                 // incidents (annoying) are remembered for the natural wake.
-                let biome = area_details_map
+                let (biome, weather) = area_details_map
                     .get(&tribute.area)
                     .and_then(|&idx| self.areas.get(idx))
-                    .map(|a| a.terrain.base)
-                    .unwrap_or(crate::terrain::types::BaseTerrain::Clearing);
+                    .map(|a| (a.terrain.base, a.weather.current))
+                    .unwrap_or((
+                        crate::terrain::types::BaseTerrain::Clearing,
+                        crate::areas::weather::Weather::Clear,
+                    ));
                 let phase_index: u32 = self.day.unwrap_or(1) * 4 + phase.ord() as u32;
                 let is_sheltered = tribute
                     .sheltered_until
@@ -472,6 +500,7 @@ impl Game {
                     rng,
                     phase,
                     biome,
+                    weather,
                     is_sheltered,
                     tribute
                         .sleep_shelter
@@ -769,6 +798,61 @@ impl Game {
             }
         }
 
+        // ── Alliances ───────────────────────────────────────────────
+        // Groups settle who is in them and pool their supplies before
+        // anyone decides what to do.
+        self.sync_alliances(rng);
+        self.share_alliance_stashes();
+
+        // ── Perception ──────────────────────────────────────────────
+        // Awake tributes take in their surroundings before anyone acts;
+        // brains decide on these beliefs, not on the arena itself. What
+        // they see may interrupt their plans.
+        for &idx in &tributes_to_act {
+            let tribute = &self.tributes[idx];
+            let Some(here) = area_details_map
+                .get(&tribute.area)
+                .map(|&i| &all_areas_snapshot[i])
+            else {
+                continue;
+            };
+            let neighbours = tribute
+                .area
+                .grid_neighbors()
+                .into_iter()
+                .filter_map(|area| area_details_map.get(&area))
+                .map(|&i| &all_areas_snapshot[i])
+                .collect();
+            let surroundings = Surroundings {
+                phase,
+                here,
+                neighbours,
+                tributes_by_area: &tributes_by_area,
+                closed_areas: &closed_areas,
+            };
+            self.tributes[idx].perceive(&surroundings);
+
+            // Plans and tuning belong to the built-in brain; other brains
+            // don't read them.
+            let tribute = &mut self.tributes[idx];
+            tribute.brain.tuning = self.config.brain.clone();
+            if tribute.strategy == BrainStrategy::Builtin
+                && self.custom_brains.get(&tribute.id).is_none()
+            {
+                tribute.review_plan(&Planning {
+                    day: current_day,
+                    phase,
+                    areas: &all_areas_snapshot,
+                    tributes_by_area: &tributes_by_area,
+                });
+            } else {
+                tribute.plan = None;
+            }
+        }
+
+        // Followers go where their leader goes; allies take turns on watch.
+        self.assign_alliance_roles(current_day, phase);
+
         // Sort by initiative so faster tributes act first (tm6a).
         tributes_to_act.sort_by_cached_key(|&idx| {
             let agility = self.tributes[idx].attributes.agility;
@@ -786,11 +870,16 @@ impl Game {
                 .tributes
                 .iter()
                 .filter(|t| {
-                    t.is_alive() && t.sleeping && t.area == tribute_area && !t.items.is_empty()
+                    t.is_alive()
+                        && t.sleeping
+                        && t.area == tribute_area
+                        && !t.items.is_empty()
+                        && t.watch != Some(Watch::Guarded)
                 })
                 .map(|t| (t.id, t.name.clone()))
                 .collect();
 
+            let custom_brain = self.custom_brains.get(&self.tributes[idx].id);
             let tribute = &mut self.tributes[idx];
 
             // Liveness gate: tribute may have been killed by an earlier
@@ -817,7 +906,7 @@ impl Game {
             // Build available destinations BEFORE taking mutable borrow of area_details
             let available_destinations = tribute
                 .area
-                .neighbors()
+                .grid_neighbors()
                 .into_iter()
                 .filter_map(|neighbor_area| {
                     // Find the AreaDetails for this neighbor
@@ -849,10 +938,10 @@ impl Game {
                 closed_areas: &closed_areas,
                 available_destinations,
                 all_areas: &all_areas_snapshot,
-                enemy_density: &enemy_density,
                 current_day,
                 combat_tuning: &combat_tuning_snapshot,
                 sleeping_nearby,
+                custom_brain: custom_brain.as_deref(),
             };
 
             // Get nearby tributes using the pre-computed map
@@ -863,13 +952,15 @@ impl Game {
                     None => &ev,
                 }
             };
-            let nearby_tributes_count = nearby_tributes.len() as u32;
-
+            // Only the tributes it saw this phase are there as far as it
+            // knows.
             let targets: Vec<Tribute> = nearby_tributes
                 .iter()
-                .filter(|t| t.is_visible() && t.identifier != tribute.identifier)
+                .filter(|t| t.identifier != tribute.identifier)
+                .filter(|t| tribute.beliefs.sees(&t.id, tribute.area))
                 .cloned()
                 .collect();
+            let nearby_tributes_count = targets.len() as u32 + 1;
 
             let encounter_context = EncounterContext {
                 nearby_tributes_count,
@@ -912,6 +1003,9 @@ impl Game {
                 ));
             }
             drained_alliance_events.append(&mut tribute.drain_alliance_events());
+            if let Some(trace) = tribute.decision.take() {
+                self.decision_traces.push(trace);
+            }
 
             // Collect pending theft from sleeping tribute (ls5a).
             // The pending_theft_target was set by act_take_item during
@@ -1082,6 +1176,7 @@ impl Game {
             self.alliance_events.append(&mut drained_alliance_events);
             self.process_alliance_events(rng);
         }
+        self.sync_alliances(rng);
         Ok(())
     }
 
@@ -1176,33 +1271,43 @@ impl Game {
             // may deliver items to affected tributes.
             let gifts = crate::sponsors::resolve_gifts(self, &all_events, rng);
             for gift in gifts {
-                let recipient_id = match &gift.payload {
-                    shared::messages::MessagePayload::SponsorGift { recipient, .. } => {
-                        recipient.identifier.clone()
-                    }
-                    _ => unreachable!(),
-                };
-                let line =
-                    crate::output::GameOutput::SponsorGift(recipient_id.as_ref(), &gift.item)
-                        .to_string();
-                let source = crate::messages::MessageSource::Game(self.identifier.clone());
-                let subject = format!("sponsor_gift:{recipient_id}");
-                let tick = self.tick_counter.next();
-                let payload = gift.payload;
-                if let Some(tribute) = self
-                    .tributes
-                    .iter_mut()
-                    .find(|t| recipient_id == t.identifier)
-                {
-                    tribute.add_item(gift.item);
-                }
-                self.push_message(source, subject, line, payload, tick);
+                self.deliver_gift(gift);
             }
         }
 
+        // Player gifts bought since the last cycle go through the same
+        // delivery as NPC ones.
+        for gift in crate::sponsors::resolve_player_gifts(self, rng) {
+            self.deliver_gift(gift);
+        }
+
         Ok(())
     }
 
+    /// Hand a sponsor gift to its recipient and announce it.
+    fn deliver_gift(&mut self, gift: crate::sponsors::GiftResult) {
+        let recipient_id = match &gift.payload {
+            shared::messages::MessagePayload::SponsorGift { recipient, .. } => {
+                recipient.identifier.clone()
+            }
+            _ => unreachable!(),
+        };
+        let line =
+            crate::output::GameOutput::SponsorGift(recipient_id.as_ref(), &gift.item).to_string();
+        let source = crate::messages::MessageSource::Game(self.identifier.clone());
+        let subject = format!("sponsor_gift:{recipient_id}");
+        let tick = self.tick_counter.next();
+        let payload = gift.payload;
+        if let Some(tribute) = self
+            .tributes
+            .iter_mut()
+            .find(|t| recipient_id == t.identifier)
+        {
+            tribute.add_item(gift.item);
+        }
+        self.push_message(source, subject, line, payload, tick);
+    }
+
     /// Runs a cycle of the game, either day or night.
     /// 1. Announce area events.
     /// 2. Open an area if there are no open areas.
@@ -1211,22 +1316,24 @@ impl Game {
     /// 5. Close more areas by spawning more events if the tributes are getting low.
     /// 6. Run the tribute cycle.
     /// 7. Update the tributes in the game.
-    pub(super) fn do_a_cycle(&mut self, phase: crate::messages::Phase) -> Result<(), GameError> {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
-
+    pub(super) fn do_a_cycle(
+        &mut self,
+        phase: crate::messages::Phase,
+        rng: &mut SmallRng,
+    ) -> Result<(), GameError> {
         // Announce area events
         self.announce_area_events()?;
 
         // If there are no open areas, we need to open one.
-        self.ensure_open_area();
+        self.ensure_open_area(rng);
 
         // Trigger any events for this cycle
-        self.trigger_cycle_events(phase, &mut rng)?;
+        self.trigger_cycle_events(phase, rng)?;
 
         // If the tribute count is low, constrain them by closing areas.
-        self.constrain_areas(&mut rng)?;
+        self.constrain_areas(rng)?;
 
-        self.tributes.shuffle(&mut rng);
+        self.tributes.shuffle(rng);
         let closed_areas: Vec<Area> = self
             .closed_areas()
             .iter()
@@ -1238,7 +1345,7 @@ impl Game {
 
         self.run_tribute_cycle(
             phase,
-            &mut rng,
+            rng,
             closed_areas,
             living_tributes,
             living_tributes_count,
@@ -1247,7 +1354,7 @@ impl Game {
     }
 
     /// Any tributes who have died in the current cycle will be moved to the "dead" list,
-    /// and their items will be added to the area they died in.
+    /// and whatever they carried is left on their body in the area they died in.
     pub(super) fn clean_up_recent_deaths(&mut self) {
         let tribute_count = self.tributes.len();
 
@@ -1256,23 +1363,63 @@ impl Game {
             if self.tributes[i].is_alive() {
                 continue;
             }
-            let tribute_items: Vec<Item> = self.tributes[i].items.clone();
-
             if self.tributes[i].status == TributeStatus::RecentlyDead {
                 self.tributes[i].statistics.day_killed = self.day;
-                let tribute_area = self.tributes[i].area;
+            }
 
-                if let Some(area) = self.get_area_details_mut(tribute_area) {
-                    for item in tribute_items {
-                        area.add_item(item.clone());
-                    }
-                }
+            // Tributes killed mid-phase may already be marked Dead, so go by
+            // what they still carry rather than by status.
+            let items = std::mem::take(&mut self.tributes[i].items);
+            if !items.is_empty() {
+                self.leave_corpse(i, items);
             }
 
             self.tributes[i].dies();
         }
     }
 
+    /// Lay out a dead tribute's body, with their items, where they fell.
+    fn leave_corpse(&mut self, index: usize, items: Vec<Item>) {
+        let tribute = &self.tributes[index];
+        let tribute_ref = TributeRef {
+            identifier: tribute.identifier.clone().into(),
+            name: tribute.name.clone(),
+        };
+        let area = tribute.area;
+        let area_ref = AreaRef {
+            identifier: area.to_string().into(),
+            name: area.to_string(),
+        };
+        let item_refs = items
+            .iter()
+            .map(|item| ItemRef {
+                identifier: item.identifier.clone().into(),
+                name: item.name.clone(),
+            })
+            .collect();
+        let Some(area_details) = self.get_area_details_mut(area) else {
+            return;
+        };
+        area_details.corpses.push(Corpse {
+            tribute: tribute_ref.identifier.to_string(),
+            name: tribute_ref.name.clone(),
+            items,
+        });
+
+        let content =
+            crate::output::GameOutput::TributeCorpseLeft(&tribute_ref.name, &area_ref.name)
+                .to_string();
+        let source = crate::messages::MessageSource::Tribute(tribute_ref.identifier.to_string());
+        let subject = format!("tribute:{}", tribute_ref.identifier);
+        let payload = MessagePayload::CorpseLeft {
+            tribute: tribute_ref,
+            area: area_ref,
+            items: item_refs,
+        };
+        let tick = self.tick_counter.next();
+        self.push_message(source, subject, content, payload, tick);
+    }
+
     /// Get a mutable reference to the area details for a given area.
     pub(super) fn get_area_details_mut(&mut self, area: Area) -> Option<&mut AreaDetails> {
         self.areas.iter_mut().find(|ad| ad.area == Some(area))
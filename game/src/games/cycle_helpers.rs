@@ -1,10 +1,21 @@
 use super::*;
 use crate::areas::events::AreaEvent;
+use crate::areas::weather::{Weather, WeatherChange};
 use crate::areas::{Area, AreaDetails};
 use crate::items::Item;
 use rand::rngs::SmallRng;
 use std::collections::HashMap;
 
+/// How many of one feast item kind to drop: 1..=`max`, or none when the
+/// config disables that kind with a zero.
+fn feast_roll(max: u32, rng: &mut SmallRng) -> u32 {
+    if max == 0 {
+        0
+    } else {
+        rng.random_range(1..=max)
+    }
+}
+
 impl Game {
     pub(super) fn run_trauma_producers(&mut self, _phase: crate::messages::Phase) {
         crate::tributes::afflictions::producers::run_trauma_producers(self);
@@ -54,10 +65,80 @@ impl Game {
         Ok(())
     }
 
+    /// Advance every area's weather by one phase, returning the changes
+    /// for [`Game::announce_weather_changes`]. The opening phase of Day 1
+    /// is skipped so the bloodbath plays out under clear skies.
+    pub(super) fn advance_weather(
+        &mut self,
+        phase: crate::messages::Phase,
+        rng: &mut SmallRng,
+    ) -> Vec<WeatherChange> {
+        if self.day.unwrap_or(1) == 1 && phase == crate::messages::Phase::Day {
+            return Vec::new();
+        }
+
+        let mut changes: Vec<WeatherChange> = Vec::new();
+        for area_details in self.areas.iter_mut() {
+            let Some(area) = area_details.area else {
+                continue;
+            };
+            if let Some(from) = area_details.weather.advance(area_details.terrain.base, rng) {
+                changes.push(WeatherChange {
+                    area,
+                    from,
+                    to: area_details.weather.current,
+                });
+            }
+        }
+        changes
+    }
+
+    /// Announce weather changes in each affected area's channel.
+    pub(super) fn announce_weather_changes(&mut self, changes: Vec<WeatherChange>) {
+        for change in changes {
+            let area_name = change.area.to_string();
+            let (from, to) = (change.from.to_string(), change.to.to_string());
+            let content =
+                crate::output::GameOutput::WeatherChanged(&area_name, &from, &to).to_string();
+            let payload = crate::messages::MessagePayload::WeatherChanged {
+                area: crate::messages::AreaRef {
+                    identifier: area_name.clone().into(),
+                    name: area_name.clone(),
+                },
+                from,
+                to,
+            };
+            let tick = self.tick_counter.boundary();
+            self.push_message(
+                crate::messages::MessageSource::Area(area_name.clone()),
+                format!("area:{}", area_name),
+                content,
+                payload,
+                tick,
+            );
+        }
+    }
+
+    /// One-line summary of notable weather for `PhaseStarted`, e.g.
+    /// "storm in Sector 2, fog in Sector 5". `None` when every area is
+    /// clear.
+    pub(super) fn weather_summary(&self) -> Option<String> {
+        let notable: Vec<String> = self
+            .areas
+            .iter()
+            .filter(|a| a.weather.current != Weather::Clear)
+            .filter_map(|a| {
+                a.area
+                    .map(|area| format!("{} in {}", a.weather.current, area))
+            })
+            .collect();
+        (!notable.is_empty()).then(|| notable.join(", "))
+    }
+
     /// Ensures at least one area is open. If not, opens a random area by clearing its events.
-    pub(super) fn ensure_open_area(&mut self) {
-        if self.random_open_area().is_none()
-            && let Some(area) = self.random_area()
+    pub(super) fn ensure_open_area(&mut self, rng: &mut SmallRng) {
+        if self.random_open_area(rng).is_none()
+            && let Some(area) = self.random_area(rng)
         {
             area.events.clear();
         }
@@ -71,11 +152,13 @@ impl Game {
     ) -> Result<(), GameError> {
         use crate::messages::Phase;
         let frequency = match phase {
-            Phase::Day => DAY_EVENT_FREQUENCY,
-            Phase::Night => NIGHT_EVENT_FREQUENCY,
+            Phase::Day => self.config.day_event_frequency,
+            Phase::Night => self.config.night_event_frequency,
             // Substrate-only: Dawn/Dusk are silent in PR1. PR2 redistributes.
             Phase::Dawn | Phase::Dusk => return Ok(()),
         };
+        // Out-of-range stored configs shouldn't panic `random_bool`.
+        let frequency = frequency.clamp(0.0, 1.0);
         let day = phase == Phase::Day;
 
         // Collect events to trigger (avoid borrow conflicts)
@@ -86,8 +169,12 @@ impl Game {
         if !day || ![1, 3].contains(&self.day.unwrap_or(1)) {
             for area_details in self.areas.iter_mut() {
                 if rng.random_bool(frequency) {
-                    // Generate terrain-appropriate event
-                    let area_event = AreaEvent::random_for_terrain(&area_details.terrain.base, rng);
+                    // Generate an event fitting the terrain and current weather
+                    let area_event = AreaEvent::random_for_weather(
+                        &area_details.terrain.base,
+                        area_details.weather.current,
+                        rng,
+                    );
                     let area = area_details.area.unwrap();
 
                     // Add event to area
@@ -114,16 +201,16 @@ impl Game {
             && let Some(area_details) = self
                 .areas
                 .iter_mut()
-                .find(|ad| ad.area == Some(Area::Cornucopia))
+                .find(|ad| ad.area == Some(Area::CORNUCOPIA))
         {
-            for _ in 0..rng.random_range(1..=FEAST_WEAPON_COUNT) {
-                area_details.add_item(Item::new_random_weapon());
+            for _ in 0..feast_roll(self.config.feast_weapon_count, rng) {
+                area_details.add_item(Item::new_random_weapon_with_rng(rng));
             }
-            for _ in 0..rng.random_range(1..=FEAST_SHIELD_COUNT) {
-                area_details.add_item(Item::new_random_shield());
+            for _ in 0..feast_roll(self.config.feast_shield_count, rng) {
+                area_details.add_item(Item::new_random_shield_with_rng(rng));
             }
-            for _ in 0..rng.random_range(1..=FEAST_CONSUMABLE_COUNT) {
-                area_details.add_item(Item::new_random_consumable());
+            for _ in 0..feast_roll(self.config.feast_consumable_count, rng) {
+                area_details.add_item(Item::new_random_consumable_with_rng(rng));
             }
         }
         Ok(())
@@ -133,12 +220,12 @@ impl Game {
     /// We achieve this by spawning events in open areas.
     pub(super) fn constrain_areas(&mut self, rng: &mut SmallRng) -> Result<(), GameError> {
         let tribute_count = self.living_tributes_count() as u32;
-        let odds = tribute_count as f64 / 24.0;
+        let odds = (tribute_count as f64 / self.config.tribute_count.max(1) as f64).min(1.0);
         let mut area_events: HashMap<String, (AreaDetails, Vec<AreaEvent>)> = HashMap::new();
 
-        if (1..LOW_TRIBUTE_THRESHOLD).contains(&tribute_count) {
+        if (1..self.config.low_tribute_threshold).contains(&tribute_count) {
             // If there is an open area, close it.
-            if let Some(area_details) = self.random_open_area() {
+            if let Some(area_details) = self.random_open_area(rng) {
                 let event = AreaEvent::random(rng);
                 let area_name = area_details.area.unwrap().to_string();
                 area_events.insert(area_name, (area_details.clone(), vec![event.clone()]));
@@ -146,7 +233,7 @@ impl Game {
 
             if rng.random_bool(odds) {
                 // Assuming there's still an open area.
-                if let Some(area_details) = self.random_open_area() {
+                if let Some(area_details) = self.random_open_area(rng) {
                     let event = AreaEvent::random(rng);
                     let area_name = area_details.area.unwrap().to_string();
                     if area_events.contains_key(&area_name) {
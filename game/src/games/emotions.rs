@@ -0,0 +1,83 @@
+use super::*;
+use crate::messages::MessagePayload;
+use crate::tributes::emotions::EmotionalStimulus;
+
+impl Game {
+    /// Let every living tribute's emotions settle toward baseline, then
+    /// react to the payloads emitted since `from`: killers, the deceased's
+    /// allies, bystanders in the victim's area, both sides of a betrayal,
+    /// new allies and sponsor-gift recipients.
+    pub(super) fn apply_emotional_fallout(&mut self, from: usize) {
+        for tribute in self.tributes.iter_mut().filter(|t| t.is_alive()) {
+            tribute.emotions.settle();
+        }
+
+        let mut stimuli: Vec<(String, EmotionalStimulus)> = Vec::new();
+        for message in self.messages.iter().skip(from) {
+            match &message.payload {
+                MessagePayload::TributeKilled { victim, killer, .. } => {
+                    let killer = killer
+                        .as_ref()
+                        .map(|k| k.identifier.to_string())
+                        .filter(|k| victim.identifier != *k);
+                    if let Some(killer) = &killer {
+                        stimuli.push((killer.clone(), EmotionalStimulus::KilledTribute));
+                    }
+                    let Some(deceased) = self
+                        .tributes
+                        .iter()
+                        .find(|t| victim.identifier == t.identifier)
+                    else {
+                        continue;
+                    };
+                    for tribute in &self.tributes {
+                        if tribute.id == deceased.id || Some(&tribute.identifier) == killer.as_ref()
+                        {
+                            continue;
+                        }
+                        if deceased.allies.contains(&tribute.id) {
+                            stimuli.push((tribute.identifier.clone(), EmotionalStimulus::AllyDied));
+                        } else if tribute.area == deceased.area {
+                            stimuli.push((
+                                tribute.identifier.clone(),
+                                EmotionalStimulus::WitnessedDeath,
+                            ));
+                        }
+                    }
+                }
+                MessagePayload::BetrayalTriggered { betrayer, victim } => {
+                    stimuli.push((victim.identifier.to_string(), EmotionalStimulus::Betrayed));
+                    stimuli.push((
+                        betrayer.identifier.to_string(),
+                        EmotionalStimulus::BetrayedAlly,
+                    ));
+                }
+                MessagePayload::AllianceFormed { members } => {
+                    for member in members {
+                        stimuli.push((
+                            member.identifier.to_string(),
+                            EmotionalStimulus::AllianceFormed,
+                        ));
+                    }
+                }
+                MessagePayload::SponsorGift { recipient, .. } => {
+                    stimuli.push((
+                        recipient.identifier.to_string(),
+                        EmotionalStimulus::SponsorGift,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        for (identifier, stimulus) in stimuli {
+            if let Some(tribute) = self
+                .tributes
+                .iter_mut()
+                .find(|t| t.identifier == identifier && t.is_alive())
+            {
+                tribute.feel(stimulus);
+            }
+        }
+    }
+}
@@ -0,0 +1,551 @@
+use super::*;
+use crate::gamemaker::{ActiveIntervention, InterventionKind};
+use crate::messages::{DespawnReason, ItemRef, Lure, MessagePayload, TributeRef};
+use crate::threats::animals::Animal;
+use crate::tributes::AfflictionDraft;
+use rand::rngs::SmallRng;
+use shared::afflictions::{AfflictionKind, AfflictionSource, DeathCause, Severity};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Animals the gamemakers breed into mutts.
+const MUTT_KINDS: [Animal; 5] = [
+    Animal::Wolf,
+    Animal::Hyena,
+    Animal::Cougar,
+    Animal::Baboon,
+    Animal::TrackerJacker,
+];
+const MUTT_MEMBERS: RangeInclusive<u8> = 3..=6;
+/// Evade DC is this plus the swarm's member count.
+const MUTT_BASE_DC: u32 = 10;
+/// Blood lost per member per unit of `Animal::damage`.
+const MUTT_DAMAGE_SCALE: u32 = 8;
+/// Evasion bonus for a tribute sleeping in shelter.
+const MUTT_SHELTER_BONUS: u32 = 5;
+/// Evasion bonus for a hidden tribute.
+const MUTT_HIDDEN_BONUS: u32 = 2;
+/// Beating the evade DC by this much kills one mutt.
+const MUTT_CULL_MARGIN: u32 = 5;
+
+/// Dodge DC for a fireball; shelter and hiding don't help.
+const FIREBALL_DC: u32 = 14;
+const FIREBALL_HIT_DAMAGE: RangeInclusive<u32> = 400..=800;
+const FIREBALL_GRAZE_DAMAGE: RangeInclusive<u32> = 100..=250;
+
+/// Phases a force-field seal holds, counting the warning phase.
+const SEAL_PHASES: u8 = 4;
+/// Blood lost per phase by a tribute still inside a sealed area.
+const SEAL_DAMAGE: u32 = 150;
+/// A force-field shift never leaves fewer areas than this unsealed.
+const MIN_OPEN_AREAS: usize = 3;
+
+/// Phases a convergence point stays on the map.
+const CONVERGENCE_PHASES: u8 = 3;
+const FEAST_ITEMS: RangeInclusive<u32> = 2..=4;
+/// Odds a tribute is nudged toward an active convergence point.
+pub(super) const CONVERGENCE_PULL: f64 = 0.5;
+
+/// Mark a tribute as killed by the gamemakers.
+fn gamemaker_kill(tribute: &mut Tribute) {
+    tribute.blood = 0;
+    tribute.statistics.killed_by = Some(DeathCause::Gamemaker.to_string());
+    tribute.status = TributeStatus::RecentlyDead;
+}
+
+fn gamemaker_affliction(tribute: &mut Tribute, kind: AfflictionKind, severity: Severity) {
+    tribute.try_acquire_affliction(AfflictionDraft {
+        kind,
+        body_part: None,
+        severity,
+        source: AfflictionSource::Gamemaker,
+        trapped_metadata: None,
+    });
+}
+
+impl Game {
+    /// The gamemaker's turn at the top of a phase: drift the gauges, tick
+    /// swarms, seals and convergence points, then launch a new
+    /// intervention if the gauges demand one.
+    pub(super) fn run_gamemaker(&mut self, phase: crate::messages::Phase, rng: &mut SmallRng) {
+        if !self.config.gamemaker_enabled {
+            return;
+        }
+        let living_tributes = self.living_tributes_count();
+        self.gamemaker.start_day(self.day.unwrap_or(1));
+        let profile = self.gamemaker.profile();
+        self.gamemaker.gauges.tick(profile, living_tributes);
+
+        self.tick_gamemaker_effects(phase, rng);
+
+        if !self.gamemaker.should_intervene() {
+            return;
+        }
+        for kind in self.gamemaker.ranked_candidates(living_tributes, rng) {
+            if self.launch_intervention(kind, rng) {
+                self.gamemaker.record(kind);
+                break;
+            }
+        }
+    }
+
+    /// Feed this phase's messages (from index `from` on) to the gauges.
+    pub(super) fn observe_for_gamemaker(&mut self, from: usize) {
+        if !self.config.gamemaker_enabled {
+            return;
+        }
+        let profile = self.gamemaker.profile();
+        let payloads = self.messages.iter().skip(from).map(|m| &m.payload);
+        self.gamemaker.gauges.observe(payloads, profile);
+    }
+
+    /// Launch `kind`. Returns false when it has no valid target, so the
+    /// caller can fall through to the next candidate.
+    fn launch_intervention(&mut self, kind: InterventionKind, rng: &mut SmallRng) -> bool {
+        match kind {
+            InterventionKind::Fireball => self.launch_fireball(rng),
+            InterventionKind::MuttPack => self.launch_mutt_pack(rng),
+            InterventionKind::ForceFieldShift => self.launch_force_field_shift(rng),
+            InterventionKind::ConvergencePoint => self.launch_convergence_point(rng),
+        }
+    }
+
+    /// Living tributes per area, including empty areas.
+    fn area_occupancy(&self) -> BTreeMap<Area, usize> {
+        let mut occupancy: BTreeMap<Area, usize> = self
+            .areas
+            .iter()
+            .filter_map(|a| a.area)
+            .map(|a| (a, 0))
+            .collect();
+        for tribute in self.tributes.iter().filter(|t| t.is_alive()) {
+            *occupancy.entry(tribute.area).or_default() += 1;
+        }
+        occupancy
+    }
+
+    fn launch_fireball(&mut self, rng: &mut SmallRng) -> bool {
+        let mut occupied: Vec<(Area, usize)> = self
+            .area_occupancy()
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        occupied.shuffle(rng);
+        let Some((area, _)) = occupied.into_iter().max_by_key(|(_, count)| *count) else {
+            return false;
+        };
+
+        let mut victims: Vec<TributeRef> = Vec::new();
+        let mut survivors: Vec<TributeRef> = Vec::new();
+        for tribute in self
+            .tributes
+            .iter_mut()
+            .filter(|t| t.is_alive() && t.area == area)
+        {
+            let dodge = rng.random_range(1..=20) + tribute.attributes.agility / 10;
+            let (damage, severity) = if dodge >= FIREBALL_DC {
+                (rng.random_range(FIREBALL_GRAZE_DAMAGE), Severity::Mild)
+            } else {
+                (rng.random_range(FIREBALL_HIT_DAMAGE), Severity::Severe)
+            };
+            tribute.blood = tribute.blood.saturating_sub(damage);
+            if tribute.blood == 0 {
+                gamemaker_kill(tribute);
+                victims.push(tribute_ref(tribute));
+            } else {
+                gamemaker_affliction(tribute, AfflictionKind::Burned, severity);
+                survivors.push(tribute_ref(tribute));
+            }
+        }
+
+        let area_name = area.to_string();
+        let content = crate::output::GameOutput::FireballStrike(&area_name).to_string();
+        let payload = MessagePayload::FireballStrike {
+            area: area_ref(area),
+            victims: victims.clone(),
+            survivors,
+        };
+        self.push_area_message(area, content, payload);
+        for victim in victims {
+            self.push_gamemaker_death(victim, area);
+        }
+        true
+    }
+
+    fn launch_mutt_pack(&mut self, rng: &mut SmallRng) -> bool {
+        let occupied: Vec<Area> = self
+            .area_occupancy()
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(area, _)| area)
+            .collect();
+        let Some(&area) = occupied.choose(rng) else {
+            return false;
+        };
+        let animal = MUTT_KINDS.choose(rng).cloned().unwrap_or(Animal::Wolf);
+        let members = rng.random_range(MUTT_MEMBERS);
+
+        let area_name = area.to_string();
+        let content =
+            crate::output::GameOutput::MuttSwarmSpawned(&area_name, &animal.plural(), members)
+                .to_string();
+        let payload = MessagePayload::MuttSwarmSpawned {
+            area: area_ref(area),
+            kind: animal.to_string(),
+            members,
+        };
+        self.push_area_message(area, content, payload);
+
+        // The swarm strikes as soon as it lands.
+        let remaining = self.mutt_swarm_attacks(area, &animal, members, rng);
+        if remaining == 0 {
+            self.announce_mutt_despawn(area, &animal, DespawnReason::NoMembersLeft);
+        } else {
+            self.gamemaker
+                .active_effects
+                .push(ActiveIntervention::MuttSwarm {
+                    area,
+                    animal,
+                    members: remaining,
+                });
+        }
+        true
+    }
+
+    /// Seal the one or two emptiest sectors and release any earlier seals.
+    fn launch_force_field_shift(&mut self, rng: &mut SmallRng) -> bool {
+        let previously_sealed = self.gamemaker.sealed_areas();
+        let convergence = self.gamemaker.convergence_point();
+        let open_count = self
+            .areas
+            .iter()
+            .filter(|a| a.is_open() && a.area.is_some_and(|a| !previously_sealed.contains(&a)))
+            .count();
+
+        let mut candidates: Vec<(Area, usize)> = self
+            .area_occupancy()
+            .into_iter()
+            .filter(|(area, _)| {
+                *area != Area::CORNUCOPIA
+                    && !previously_sealed.contains(area)
+                    && convergence != Some(*area)
+                    && self
+                        .areas
+                        .iter()
+                        .any(|a| a.area == Some(*area) && a.is_open())
+            })
+            .collect();
+        let close_count = rng
+            .random_range(1..=2usize)
+            .min(open_count.saturating_sub(MIN_OPEN_AREAS))
+            .min(candidates.len());
+        if close_count == 0 {
+            return false;
+        }
+        candidates.shuffle(rng);
+        candidates.sort_by_key(|(_, count)| *count);
+        let closed: Vec<Area> = candidates
+            .into_iter()
+            .take(close_count)
+            .map(|(area, _)| area)
+            .collect();
+
+        self.gamemaker
+            .active_effects
+            .retain(|effect| !matches!(effect, ActiveIntervention::SealedArea { .. }));
+        for &area in &closed {
+            self.gamemaker
+                .active_effects
+                .push(ActiveIntervention::SealedArea {
+                    area,
+                    phases_left: SEAL_PHASES,
+                });
+        }
+        self.announce_force_field_shift(&closed, &previously_sealed);
+        true
+    }
+
+    /// Announce a feast in the emptiest unsealed area and stock it.
+    fn launch_convergence_point(&mut self, rng: &mut SmallRng) -> bool {
+        if self.gamemaker.convergence_point().is_some() {
+            return false;
+        }
+        let sealed = self.gamemaker.sealed_areas();
+        let mut candidates: Vec<(Area, usize)> = self
+            .area_occupancy()
+            .into_iter()
+            .filter(|(area, _)| !sealed.contains(area))
+            .collect();
+        candidates.shuffle(rng);
+        let arena = self.arena();
+        let Some((area, _)) = candidates
+            .into_iter()
+            .min_by_key(|(area, count)| (*count, std::cmp::Reverse(arena.neighbors(*area).len())))
+        else {
+            return false;
+        };
+        let Some(area_details) = self.get_area_details_mut(area) else {
+            return false;
+        };
+
+        let mut items: Vec<ItemRef> = Vec::new();
+        for _ in 0..rng.random_range(FEAST_ITEMS) {
+            let item = Item::new_random_with_rng(None, rng);
+            items.push(ItemRef {
+                identifier: item.identifier.clone().into(),
+                name: item.name.clone(),
+            });
+            area_details.add_item(item);
+        }
+        self.gamemaker
+            .active_effects
+            .push(ActiveIntervention::ConvergencePoint {
+                area,
+                lure: Lure::Feast,
+                phases_left: CONVERGENCE_PHASES,
+            });
+
+        let area_name = area.to_string();
+        let content = crate::output::GameOutput::FeastAnnounced(&area_name).to_string();
+        let payload = MessagePayload::ConvergencePointAnnounced {
+            area: area_ref(area),
+            lure: Lure::Feast,
+            items,
+        };
+        self.push_area_message(area, content, payload);
+        true
+    }
+
+    /// Advance every active effect by one phase: swarms attack (or leave
+    /// at dawn), seals burn whoever stayed inside, feasts run down.
+    fn tick_gamemaker_effects(&mut self, phase: crate::messages::Phase, rng: &mut SmallRng) {
+        let effects = std::mem::take(&mut self.gamemaker.active_effects);
+        let mut kept: Vec<ActiveIntervention> = Vec::with_capacity(effects.len());
+        let mut released: Vec<Area> = Vec::new();
+
+        for effect in effects {
+            match effect {
+                ActiveIntervention::MuttSwarm {
+                    area,
+                    animal,
+                    members,
+                } => {
+                    if phase == crate::messages::Phase::Dawn {
+                        self.announce_mutt_despawn(area, &animal, DespawnReason::Morning);
+                        continue;
+                    }
+                    let remaining = self.mutt_swarm_attacks(area, &animal, members, rng);
+                    if remaining == 0 {
+                        self.announce_mutt_despawn(area, &animal, DespawnReason::NoMembersLeft);
+                    } else {
+                        kept.push(ActiveIntervention::MuttSwarm {
+                            area,
+                            animal,
+                            members: remaining,
+                        });
+                    }
+                }
+                ActiveIntervention::SealedArea { area, phases_left } => {
+                    self.seal_damage(area);
+                    if phases_left <= 1 {
+                        released.push(area);
+                    } else {
+                        kept.push(ActiveIntervention::SealedArea {
+                            area,
+                            phases_left: phases_left - 1,
+                        });
+                    }
+                }
+                ActiveIntervention::ConvergencePoint {
+                    area,
+                    lure,
+                    phases_left,
+                } => {
+                    if phases_left <= 1 {
+                        let area_name = area.to_string();
+                        let content = crate::output::GameOutput::FeastOver(&area_name).to_string();
+                        let payload = MessagePayload::ConvergencePointExpired {
+                            area: area_ref(area),
+                            lure,
+                        };
+                        self.push_area_message(area, content, payload);
+                    } else {
+                        kept.push(ActiveIntervention::ConvergencePoint {
+                            area,
+                            lure,
+                            phases_left: phases_left - 1,
+                        });
+                    }
+                }
+            }
+        }
+
+        self.gamemaker.active_effects = kept;
+        if !released.is_empty() {
+            self.announce_force_field_shift(&[], &released);
+        }
+    }
+
+    /// Every living tribute in `area` tries to evade the swarm. Returns
+    /// the swarm's surviving member count.
+    fn mutt_swarm_attacks(
+        &mut self,
+        area: Area,
+        animal: &Animal,
+        members: u8,
+        rng: &mut SmallRng,
+    ) -> u8 {
+        let mut members = members;
+        let mut hits: Vec<(TributeRef, u32, bool)> = Vec::new();
+        for tribute in self
+            .tributes
+            .iter_mut()
+            .filter(|t| t.is_alive() && t.area == area)
+        {
+            if members == 0 {
+                break;
+            }
+            let mut evade = rng.random_range(1..=20) + tribute.attributes.agility / 10;
+            if tribute.sleep_shelter.is_some() {
+                evade += MUTT_SHELTER_BONUS;
+            }
+            if tribute.attributes.is_hidden {
+                evade += MUTT_HIDDEN_BONUS;
+            }
+            let dc = MUTT_BASE_DC + u32::from(members);
+            if evade >= dc {
+                if evade >= dc + MUTT_CULL_MARGIN {
+                    members -= 1;
+                }
+                continue;
+            }
+
+            let damage = animal.damage() * u32::from(members) * MUTT_DAMAGE_SCALE;
+            tribute.blood = tribute.blood.saturating_sub(damage);
+            let killed = tribute.blood == 0;
+            if killed {
+                gamemaker_kill(tribute);
+            } else if *animal == Animal::TrackerJacker {
+                gamemaker_affliction(tribute, AfflictionKind::Poisoned, Severity::Moderate);
+            }
+            hits.push((tribute_ref(tribute), damage, killed));
+        }
+
+        let animals = animal.plural();
+        for (victim, damage, killed) in hits {
+            let content =
+                crate::output::GameOutput::MuttSwarmAttack(&victim.name, &animals, damage)
+                    .to_string();
+            let payload = MessagePayload::MuttSwarmAttack {
+                area: area_ref(area),
+                kind: animal.to_string(),
+                victim: victim.clone(),
+                damage,
+                killed,
+            };
+            self.push_area_message(area, content, payload);
+            if killed {
+                self.push_gamemaker_death(victim, area);
+            }
+        }
+        members
+    }
+
+    /// Burn every living tribute still inside a sealed area.
+    fn seal_damage(&mut self, area: Area) {
+        let mut burned: Vec<(TributeRef, bool)> = Vec::new();
+        for tribute in self
+            .tributes
+            .iter_mut()
+            .filter(|t| t.is_alive() && t.area == area)
+        {
+            tribute.blood = tribute.blood.saturating_sub(SEAL_DAMAGE);
+            let killed = tribute.blood == 0;
+            if killed {
+                gamemaker_kill(tribute);
+            } else {
+                gamemaker_affliction(tribute, AfflictionKind::Electrocuted, Severity::Mild);
+            }
+            burned.push((tribute_ref(tribute), killed));
+        }
+
+        let area_name = area.to_string();
+        for (tribute, killed) in burned {
+            let content =
+                crate::output::GameOutput::AreaSealDamage(&tribute.name, &area_name, SEAL_DAMAGE)
+                    .to_string();
+            let payload = MessagePayload::AreaSealEntryDamage {
+                area: area_ref(area),
+                tribute: tribute.clone(),
+                damage: SEAL_DAMAGE,
+            };
+            self.push_area_message(area, content, payload);
+            if killed {
+                self.push_gamemaker_death(tribute, area);
+            }
+        }
+    }
+
+    fn announce_mutt_despawn(&mut self, area: Area, animal: &Animal, reason: DespawnReason) {
+        let area_name = area.to_string();
+        let content =
+            crate::output::GameOutput::MuttSwarmDespawned(&area_name, &animal.plural()).to_string();
+        let payload = MessagePayload::MuttSwarmDespawned {
+            area: area_ref(area),
+            kind: animal.to_string(),
+            reason,
+        };
+        self.push_area_message(area, content, payload);
+    }
+
+    fn announce_force_field_shift(&mut self, closed: &[Area], opened: &[Area]) {
+        let join = |areas: &[Area]| {
+            areas
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let content =
+            crate::output::GameOutput::ForceFieldShifted(&join(closed), &join(opened)).to_string();
+        let payload = MessagePayload::ForceFieldShifted {
+            closed: closed.iter().map(|a| area_ref(*a)).collect(),
+            opened: opened.iter().map(|a| area_ref(*a)).collect(),
+        };
+        let game_id = self.identifier.clone();
+        let tick = self.tick_counter.next();
+        self.push_message(
+            crate::messages::MessageSource::Game(game_id.clone()),
+            format!("game:{}", game_id),
+            content,
+            payload,
+            tick,
+        );
+    }
+
+    fn push_area_message(&mut self, area: Area, content: String, payload: MessagePayload) {
+        let area_name = area.to_string();
+        let tick = self.tick_counter.next();
+        self.push_message(
+            crate::messages::MessageSource::Area(area_name.clone()),
+            format!("area:{}", area_name),
+            content,
+            payload,
+            tick,
+        );
+    }
+
+    fn push_gamemaker_death(&mut self, victim: TributeRef, area: Area) {
+        let area_name = area.to_string();
+        let content = crate::output::GameOutput::DiedInArea(&victim.name, &area_name).to_string();
+        let source = crate::messages::MessageSource::Tribute(victim.identifier.to_string());
+        let subject = format!("tribute:{}", victim.identifier);
+        let payload = MessagePayload::TributeKilled {
+            victim,
+            killer: None,
+            cause: DeathCause::Gamemaker,
+        };
+        let tick = self.tick_counter.next();
+        self.push_message(source, subject, content, payload, tick);
+    }
+}
@@ -34,7 +34,8 @@ impl Game {
     /// transient cycle state. The `tick` argument is supplied by the
     /// caller because some sites (cycle announcements, area events)
     /// emit at the phase boundary (`tick = 0`) while per-tribute
-    /// action emissions advance the tick counter.
+    /// action emissions advance the tick counter. The message identifier
+    /// is derived from the game seed, so replays produce the same ids.
     pub(crate) fn push_message(
         &mut self,
         source: crate::messages::MessageSource,
@@ -55,7 +56,7 @@ impl Game {
         } else {
             format!("{}:{}", self.identifier, subject)
         };
-        let msg = crate::messages::GameMessage::new(
+        let mut msg = crate::messages::GameMessage::new(
             source,
             game_day,
             self.current_phase,
@@ -65,6 +66,14 @@ impl Game {
             content,
             payload,
         );
+        msg.identifier = crate::seeding::message_identifier(
+            self.seed,
+            &self.identifier,
+            game_day,
+            self.current_phase,
+            self.emit_index,
+        )
+        .to_string();
         self.messages.push(msg);
         self.emit_index = self.emit_index.saturating_add(1);
     }
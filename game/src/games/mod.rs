@@ -1,13 +1,16 @@
+use crate::areas::arena::Arena;
 use crate::areas::events::AreaEvent;
 use crate::areas::{Area, AreaDetails};
 use crate::items::Item;
 use crate::items::OwnsItems;
+use crate::messages::{AreaRef, ItemRef, TributeRef};
 use crate::tributes::actions::Action;
 use crate::tributes::statuses::TributeStatus;
 use crate::tributes::{ActionSuggestion, Tribute};
 use rand::Rng;
 use rand::RngExt;
 use rand::prelude::*;
+use rand::rngs::SmallRng;
 use serde::{Deserialize, Serialize};
 use shared::GameStatus;
 use std::cmp::PartialEq;
@@ -16,8 +19,13 @@ use std::fmt::Display;
 use uuid::Uuid;
 
 pub mod alliances;
+pub mod contagion;
 pub mod cycle_helpers;
+pub mod emotions;
+pub mod gamemaker;
 pub mod messages;
+pub mod orders;
+pub mod snapshots;
 pub mod sponsors;
 
 /// Stamina restored per phase to a sleeping tribute (PR2c.1, bd-9sjj).
@@ -47,6 +55,30 @@ fn area_event_to_kind(ev: &AreaEvent) -> shared::messages::AreaEventKind {
     }
 }
 
+/// The `TributeRef` a message payload names a tribute by.
+fn tribute_ref(tribute: &Tribute) -> TributeRef {
+    TributeRef {
+        identifier: tribute.identifier.clone().into(),
+        name: tribute.name.clone(),
+    }
+}
+
+/// The `ItemRef` a message payload names an item by.
+fn item_ref(item: &Item) -> ItemRef {
+    ItemRef {
+        identifier: item.identifier.clone().into(),
+        name: item.name.clone(),
+    }
+}
+
+/// The `AreaRef` a message payload names an area by.
+fn area_ref(area: Area) -> AreaRef {
+    AreaRef {
+        identifier: area.to_string().into(),
+        name: area.to_string(),
+    }
+}
+
 /// Generate a human-readable line for trauma-related messages.
 fn format_trauma_message(payload: &crate::messages::MessagePayload, tribute_name: &str) -> String {
     use crate::messages::MessagePayload;
@@ -204,13 +236,6 @@ impl From<String> for GameError {
     }
 }
 
-const LOW_TRIBUTE_THRESHOLD: u32 = 8;
-const FEAST_WEAPON_COUNT: u32 = 2;
-const FEAST_SHIELD_COUNT: u32 = 2;
-const FEAST_CONSUMABLE_COUNT: u32 = 4;
-const DAY_EVENT_FREQUENCY: f64 = 1.0 / 4.0;
-const NIGHT_EVENT_FREQUENCY: f64 = 1.0 / 8.0;
-
 /// Per-period tick counter. Resets to 0 at every phase boundary.
 /// Phase-boundary side-effect messages get tick=0.
 /// First action in a phase gets tick=1.
@@ -247,6 +272,10 @@ pub struct Game {
     pub name: String,
     pub status: GameStatus,
     pub day: Option<u32>,
+    /// The phase `day` stopped after when the game is advanced one phase
+    /// at a time; `None` once the day has played out.
+    #[serde(default)]
+    pub resting_phase: Option<crate::messages::Phase>,
     #[serde(default)]
     pub areas: Vec<AreaDetails>,
     #[serde(default)]
@@ -286,6 +315,41 @@ pub struct Game {
     /// Lazily spawned on first cycle for backward-compat with pre-sponsorship games.
     #[serde(default)]
     pub sponsors: Vec<shared::sponsors::Sponsor>,
+
+    /// Root seed for every random roll the engine makes. Two games with the
+    /// same seed and starting state produce the same event stream. Games
+    /// saved before seeding existed pick up a fresh seed on load.
+    #[serde(default = "crate::seeding::random_seed")]
+    pub seed: u64,
+
+    /// The storyteller that imposes interventions when the arena goes
+    /// quiet. See spec `2026-05-03-gamemaker-event-system-design.md`.
+    #[serde(default)]
+    pub gamemaker: crate::gamemaker::Gamemaker,
+
+    /// Alliance groups over the tributes' `allies` graph, each with a
+    /// leader and a shared stash. Kept in step with the graph by
+    /// `sync_alliances`.
+    #[serde(default)]
+    pub alliances: Vec<crate::tributes::alliances::Alliance>,
+
+    /// Brains supplied from outside the engine for individual tributes,
+    /// e.g. tournament strategies in the simulator. Transient, never
+    /// persisted.
+    #[serde(skip, default)]
+    pub custom_brains: crate::tributes::brains::strategy::CustomBrains,
+
+    /// Gifts players bought since the last cycle, delivered at the end of
+    /// the next one. Loaded by the API from its gift ledger; never persisted
+    /// with the game.
+    #[serde(skip, default)]
+    pub player_gifts: Vec<crate::sponsors::PlayerGift>,
+
+    /// How each tribute decided on its action, one trace per tribute per
+    /// phase, since the API last drained them. Transient, never persisted
+    /// with the game.
+    #[serde(skip, default)]
+    pub decision_traces: Vec<crate::tributes::brains::trace::DecisionTrace>,
 }
 
 fn default_phase() -> crate::messages::Phase {
@@ -314,6 +378,7 @@ impl Default for Game {
             name,
             status: Default::default(),
             day: None,
+            resting_phase: None,
             areas: vec![],
             tributes: vec![],
             private: true,
@@ -325,6 +390,12 @@ impl Default for Game {
             emit_index: 0,
             combat_tuning: crate::tributes::combat_tuning::CombatTuning::default(),
             sponsors: vec![],
+            seed: crate::seeding::random_seed(),
+            gamemaker: Default::default(),
+            alliances: vec![],
+            custom_brains: Default::default(),
+            player_gifts: vec![],
+            decision_traces: vec![],
         }
     }
 }
@@ -376,9 +447,6 @@ struct CycleContext {
     /// Owned per-area tribute snapshots used to build `EncounterContext`
     /// without re-borrowing `self.tributes` during the executor loop.
     tributes_by_area: HashMap<Area, Vec<Tribute>>,
-    /// Per-area living-tribute density (4wnj). Read by
-    /// `Brain::choose_destination` as a crowd penalty.
-    enemy_density: HashMap<Area, u32>,
     /// Cached combat tuning so the executor never has to re-borrow `self`.
     combat_tuning_snapshot: crate::tributes::combat_tuning::CombatTuning,
     /// Read-only snapshot of every area for multi-hop pathfinding.
@@ -448,14 +516,37 @@ impl Game {
         }
     }
 
+    /// The shape of the game's arena.
+    pub fn arena(&self) -> Arena {
+        Arena::new(self.config.arena_rings)
+    }
+
+    /// Fresh areas for a new game's arena, with terrain rolled from the
+    /// game's seed so the same seed always lays out the same map.
+    pub fn generate_areas(&self) -> Vec<AreaDetails> {
+        self.arena()
+            .generate(&mut SmallRng::seed_from_u64(self.seed))
+    }
+
+    /// A fresh roster for a new game: the config's tribute count, reaped
+    /// from its districts in turn and drawn from the game's seed.
+    pub fn reap_tributes(&self) -> Vec<Tribute> {
+        let mut rng = crate::seeding::reaping_rng(self.seed);
+        (0..self.config.tribute_count)
+            .map(|idx| {
+                Tribute::random_for_config(&self.config, self.config.district_for(idx), &mut rng)
+            })
+            .collect()
+    }
+
     /// Returns a random area from the game.
-    fn random_area(&mut self) -> Option<&mut AreaDetails> {
-        self.areas.choose_mut(&mut rand::rng())
+    fn random_area(&mut self, rng: &mut impl Rng) -> Option<&mut AreaDetails> {
+        self.areas.choose_mut(rng)
     }
 
     /// Returns a random open area from the game.
-    fn random_open_area(&self) -> Option<AreaDetails> {
-        self.open_areas().choose(&mut rand::rng()).cloned()
+    fn random_open_area(&self, rng: &mut impl Rng) -> Option<AreaDetails> {
+        self.open_areas().choose(rng).cloned()
     }
 
     /// Returns a vec of open areas.
@@ -514,6 +605,10 @@ impl Game {
         if self.is_new_day_boundary(phase) {
             self.day = Some(self.day.unwrap_or(0) + 1);
         }
+        // Afflictions are stamped with the tribute's own day.
+        for tribute in self.tributes.iter_mut() {
+            tribute.game_day = self.day.map(i64::from);
+        }
 
         // Clear all events from the previous cycle
         for area in self.areas.iter_mut() {
@@ -575,7 +670,7 @@ impl Game {
         let phase_payload = crate::messages::MessagePayload::PhaseStarted {
             day: current_day,
             phase,
-            weather_summary: None,
+            weather_summary: self.weather_summary(),
         };
         self.push_message(
             crate::messages::MessageSource::Game(game_id),
@@ -905,7 +1000,7 @@ impl Game {
                     }
 
                     if result.reward_item.is_some() {
-                        let item = Item::new_random_consumable();
+                        let item = Item::new_random_consumable_with_rng(rng);
                         let item_name = item.name.clone();
                         tribute.items.push(item);
                         pending_messages.push((
@@ -945,36 +1040,69 @@ impl Game {
         self.tick_counter.reset();
         self.emit_index = 0;
 
-        // Check if the game is over, and if so, end it.
-        self.check_for_winner()?;
+        // A finished game has nothing left to play.
+        if self.status == GameStatus::Finished {
+            return Ok(());
+        }
 
         self.prepare_cycle(phase)?;
+        let phase_start = self.messages.len();
+        let mut rng = crate::seeding::phase_rng(self.seed, self.day.unwrap_or(1), phase);
+        let weather_changes = self.advance_weather(phase, &mut rng);
         self.announce_cycle_start(phase)?;
-        self.do_a_cycle(phase)?;
+        self.announce_weather_changes(weather_changes);
+        self.run_gamemaker(phase, &mut rng);
+        self.advance_contagions(phase, &mut rng);
+        self.do_a_cycle(phase, &mut rng)?;
+        self.burn_exposed_vampires(phase);
+        self.observe_for_gamemaker(phase_start);
+        self.apply_emotional_fallout(phase_start);
         self.run_trauma_producers(phase);
         self.announce_cycle_end(phase)?;
 
         // Clean up any deaths
         self.clean_up_recent_deaths();
+
+        // Check if the game is over, and if so, end it with this phase, so
+        // the crowned winner is the one left standing.
+        self.check_for_winner()?;
         Ok(())
     }
 
     /// Run every phase of the next game-day in canonical order. Day 1 has
-    /// no Dawn (per spec §3); Day 2+ runs all four phases.
+    /// no Dawn (per spec §3); Day 2+ runs all four phases. A game resting
+    /// mid-day plays out the rest of that day instead.
     pub fn run_full_day(&mut self) -> Result<(), GameError> {
-        use crate::messages::Phase;
-        let next_day = self.day.unwrap_or(0) + 1;
-        let phases: &[Phase] = if next_day <= 1 {
-            &[Phase::Day, Phase::Dusk, Phase::Night]
-        } else {
-            &[Phase::Dawn, Phase::Day, Phase::Dusk, Phase::Night]
-        };
-        for &p in phases {
+        self.run_full_day_with(|_| {})
+    }
+
+    /// [`run_full_day`](Game::run_full_day), calling `after_phase` with the
+    /// game as it stands at the end of each phase, e.g. to snapshot it.
+    pub fn run_full_day_with(
+        &mut self,
+        mut after_phase: impl FnMut(&Game),
+    ) -> Result<(), GameError> {
+        let next_day = self.upcoming_day();
+        for &p in self.upcoming_phases() {
             self.run_phase(p)?;
+            after_phase(self);
         }
         self.day = Some(next_day);
+        self.resting_phase = None;
         Ok(())
     }
+
+    /// Run only the next phase of the game and rest after it. Returns the
+    /// phase that was played. Night ends the day, so the game rests at a
+    /// day boundary after it like it does after `run_full_day`.
+    pub fn run_next_phase(&mut self) -> Result<crate::messages::Phase, GameError> {
+        let next_day = self.upcoming_day();
+        let phase = self.upcoming_phases()[0];
+        self.run_phase(phase)?;
+        self.day = Some(next_day);
+        self.resting_phase = (phase != crate::messages::Phase::Night).then_some(phase);
+        Ok(phase)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,305 @@
+//! Player-controlled tributes.
+//!
+//! A tribute with a `controller` takes its action for a phase from
+//! `Tribute::orders` instead of its brain. The API stores orders as players
+//! submit them and loads them onto the tributes before running a day; a
+//! phase with no order falls back to the brain, so a player who walks away
+//! never stalls the game for good. Orders go through `validate_order`
+//! first, and players read the arena through `tribute_view` rather than the
+//! full game state.
+
+use super::*;
+use crate::areas::events::AreaEvent;
+use crate::areas::weather::Weather;
+use crate::messages::Phase;
+use crate::terrain::BaseTerrain;
+use crate::tributes::afflictions::visible_afflictions_to;
+use shared::afflictions::Affliction;
+
+/// Why an order was refused.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderError {
+    TributeNotFound(String),
+    NotControlled(String),
+    TributeDead(String),
+    /// The action is one the engine only ever chooses itself (frozen,
+    /// flashback, ...).
+    NotAnOrder(String),
+    UnreachableArea(Area),
+    ItemNotCarried(String),
+}
+
+impl Display for OrderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderError::TributeNotFound(id) => write!(f, "Tribute not found: {id}"),
+            OrderError::NotControlled(name) => write!(f, "{name} is not player-controlled"),
+            OrderError::TributeDead(name) => write!(f, "{name} is dead"),
+            OrderError::NotAnOrder(action) => write!(f, "`{action}` cannot be ordered"),
+            OrderError::UnreachableArea(area) => {
+                write!(f, "{area} is not an open neighbouring area")
+            }
+            OrderError::ItemNotCarried(id) => write!(f, "Item not carried: {id}"),
+        }
+    }
+}
+
+impl std::error::Error for OrderError {}
+
+/// What a player sees of the arena through their tribute's eyes.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TributeView {
+    pub identifier: String,
+    pub name: String,
+    pub status: TributeStatus,
+    pub area: AreaView,
+    pub neighbours: Vec<NeighbourView>,
+    /// Other living tributes in the area that are not hidden.
+    pub visible_tributes: Vec<VisibleTribute>,
+    pub inventory: Vec<Item>,
+    pub afflictions: Vec<Affliction>,
+    pub blood: u32,
+    pub stamina: u32,
+    pub max_stamina: u32,
+    pub hunger: u8,
+    pub thirst: u8,
+    pub sanity: u32,
+    pub is_hidden: bool,
+    /// Phases of the next day still without an order.
+    pub phases_awaiting_orders: Vec<Phase>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AreaView {
+    pub area: Area,
+    pub terrain: BaseTerrain,
+    pub weather: Weather,
+    pub events: Vec<AreaEvent>,
+    pub items: Vec<Item>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct NeighbourView {
+    pub area: Area,
+    pub terrain: BaseTerrain,
+    pub open: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct VisibleTribute {
+    pub identifier: String,
+    pub name: String,
+    pub district: u32,
+    pub is_ally: bool,
+    /// Only the afflictions this tribute could see from across the area.
+    pub afflictions: Vec<Affliction>,
+}
+
+impl Game {
+    /// Phases the next call to `run_full_day` will play: the rest of the
+    /// current day when the game rests mid-day, otherwise all of the next.
+    pub fn upcoming_phases(&self) -> &'static [Phase] {
+        const FIRST_DAY: &[Phase] = &[Phase::Day, Phase::Dusk, Phase::Night];
+        const EVERY_DAY: &[Phase] = &[Phase::Dawn, Phase::Day, Phase::Dusk, Phase::Night];
+        match self.resting_phase {
+            Some(resting) => {
+                let phases = if self.day.unwrap_or(0) <= 1 {
+                    FIRST_DAY
+                } else {
+                    EVERY_DAY
+                };
+                let next = phases
+                    .iter()
+                    .position(|p| *p == resting)
+                    .map_or(0, |i| i + 1);
+                &phases[next..]
+            }
+            None if self.day.unwrap_or(0) == 0 => FIRST_DAY,
+            None => EVERY_DAY,
+        }
+    }
+
+    /// The day `upcoming_phases` belong to.
+    pub fn upcoming_day(&self) -> u32 {
+        match self.resting_phase {
+            Some(_) => self.day.unwrap_or(0),
+            None => self.day.unwrap_or(0) + 1,
+        }
+    }
+
+    /// Living player-controlled tributes that have not ordered every phase
+    /// still to play of the coming day.
+    pub fn awaiting_orders(&self) -> Vec<&Tribute> {
+        let phases = self.upcoming_phases();
+        self.tributes
+            .iter()
+            .filter(|t| t.is_alive() && t.controller.is_some())
+            .filter(|t| phases.iter().any(|p| !t.orders.contains_key(p)))
+            .collect()
+    }
+
+    fn is_area_open(&self, area: Area) -> bool {
+        !self.gamemaker.sealed_areas().contains(&area)
+            && self
+                .areas
+                .iter()
+                .find(|a| a.area == Some(area))
+                .is_some_and(|a| a.is_open())
+    }
+
+    /// Check a player's order for `phase` against the tribute's situation.
+    /// A move is checked from where the moves already ordered for earlier
+    /// phases leave the tribute. Items are matched by identifier and
+    /// replaced with the carried copy, so an item taken from an older view
+    /// still resolves.
+    pub fn validate_order(
+        &self,
+        tribute_identifier: &str,
+        phase: Phase,
+        action: Action,
+    ) -> Result<Action, OrderError> {
+        let tribute = self
+            .tributes
+            .iter()
+            .find(|t| t.identifier == tribute_identifier)
+            .ok_or_else(|| OrderError::TributeNotFound(tribute_identifier.to_string()))?;
+        if tribute.controller.is_none() {
+            return Err(OrderError::NotControlled(tribute.name.clone()));
+        }
+        if !tribute.is_alive() {
+            return Err(OrderError::TributeDead(tribute.name.clone()));
+        }
+
+        let carried = |item: Option<Item>| -> Result<Option<Item>, OrderError> {
+            item.map(|item| {
+                tribute
+                    .items
+                    .iter()
+                    .find(|i| i.identifier == item.identifier)
+                    .cloned()
+                    .ok_or(OrderError::ItemNotCarried(item.identifier))
+            })
+            .transpose()
+        };
+
+        match action {
+            Action::Move(Some(area)) => {
+                let from = self
+                    .upcoming_phases()
+                    .iter()
+                    .take_while(|p| **p != phase)
+                    .filter_map(|p| match tribute.orders.get(p) {
+                        Some(Action::Move(Some(area))) => Some(*area),
+                        _ => None,
+                    })
+                    .last()
+                    .unwrap_or(tribute.area);
+                if self.arena().neighbors(from).contains(&area) && self.is_area_open(area) {
+                    Ok(Action::Move(Some(area)))
+                } else {
+                    Err(OrderError::UnreachableArea(area))
+                }
+            }
+            Action::UseItem(item @ Some(_)) => Ok(Action::UseItem(carried(item)?)),
+            Action::Eat(item) => Ok(Action::Eat(carried(item)?)),
+            Action::DrinkItem(item) => Ok(Action::DrinkItem(carried(item)?)),
+            Action::Sleep { duration_phases } => Ok(Action::Sleep {
+                duration_phases: duration_phases.clamp(1, 4),
+            }),
+            action @ (Action::None
+            | Action::Rest
+            | Action::Attack
+            | Action::Hide
+            | Action::TakeItem
+            | Action::ProposeAlliance
+            | Action::SeekShelter
+            | Action::Forage
+            | Action::DrinkFromTerrain
+            | Action::SetTrap { .. }
+            | Action::Search) => Ok(action),
+            action @ (Action::Move(None)
+            | Action::UseItem(None)
+            | Action::Frozen
+            | Action::Flashback { .. }
+            | Action::Avoidance
+            | Action::SearchForSubstance { .. }
+            | Action::Rescue { .. }) => Err(OrderError::NotAnOrder(action.to_string())),
+        }
+    }
+
+    /// The player's view of a tribute's surroundings.
+    pub fn tribute_view(&self, tribute_identifier: &str) -> Option<TributeView> {
+        let tribute = self
+            .tributes
+            .iter()
+            .find(|t| t.identifier == tribute_identifier)?;
+        let details = self.areas.iter().find(|a| a.area == Some(tribute.area));
+
+        let area = AreaView {
+            area: tribute.area,
+            terrain: details.map_or(BaseTerrain::Clearing, |d| d.terrain.base),
+            weather: details.map_or(Weather::Clear, |d| d.weather.current),
+            events: details.map(|d| d.events.clone()).unwrap_or_default(),
+            items: details.map(|d| d.items.clone()).unwrap_or_default(),
+        };
+        let neighbours = self
+            .arena()
+            .neighbors(tribute.area)
+            .into_iter()
+            .map(|area| NeighbourView {
+                area,
+                terrain: self
+                    .areas
+                    .iter()
+                    .find(|a| a.area == Some(area))
+                    .map_or(BaseTerrain::Clearing, |d| d.terrain.base),
+                open: self.is_area_open(area),
+            })
+            .collect();
+        let visible_tributes = self
+            .tributes
+            .iter()
+            .filter(|t| {
+                t.identifier != tribute.identifier
+                    && t.is_alive()
+                    && t.area == tribute.area
+                    && t.is_visible()
+            })
+            .map(|t| VisibleTribute {
+                identifier: t.identifier.clone(),
+                name: t.name.clone(),
+                district: t.district,
+                is_ally: tribute.allies.contains(&t.id),
+                afflictions: visible_afflictions_to(tribute, t)
+                    .into_iter()
+                    .cloned()
+                    .collect(),
+            })
+            .collect();
+        let phases_awaiting_orders = self
+            .upcoming_phases()
+            .iter()
+            .copied()
+            .filter(|p| !tribute.orders.contains_key(p))
+            .collect();
+
+        Some(TributeView {
+            identifier: tribute.identifier.clone(),
+            name: tribute.name.clone(),
+            status: tribute.status.clone(),
+            area,
+            neighbours,
+            visible_tributes,
+            inventory: tribute.items.clone(),
+            afflictions: tribute.afflictions.values().cloned().collect(),
+            blood: tribute.blood,
+            stamina: tribute.stamina,
+            max_stamina: tribute.max_stamina,
+            hunger: tribute.hunger,
+            thirst: tribute.thirst,
+            sanity: tribute.effective_sanity(),
+            is_hidden: tribute.attributes.is_hidden,
+            phases_awaiting_orders,
+        })
+    }
+}
@@ -0,0 +1,167 @@
+//! Per-phase snapshots, rollback and forking.
+//!
+//! A [`GameSnapshot`] is a game's full state as it stood at the end of one
+//! phase. The API stores one after every phase it runs. A game can be
+//! rolled back to any of them with [`GameSnapshot::restore`], or a new
+//! game branched off one with [`GameSnapshot::fork`]: the fork starts
+//! from that state under fresh identifiers and a new seed, and plays on
+//! independently of the original.
+
+use super::*;
+use crate::messages::{GameMessage, Phase};
+
+/// A game's state at the end of `phase` on `day`. Transient state (the
+/// message buffer, pending gifts, decision traces, custom brains) is not
+/// part of it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub day: u32,
+    pub phase: Phase,
+    pub game: Game,
+}
+
+impl Game {
+    /// Snapshot the game as it stands now, at the end of its current phase.
+    /// A game that hasn't played a day yet stands at the end of night 0.
+    pub fn snapshot(&self) -> GameSnapshot {
+        let mut game = self.clone();
+        game.messages.clear();
+        game.player_gifts.clear();
+        game.decision_traces.clear();
+        let (day, phase) = match self.day {
+            Some(day) if day > 0 => (day, self.current_phase),
+            _ => (0, Phase::Night),
+        };
+        GameSnapshot { day, phase, game }
+    }
+}
+
+impl GameSnapshot {
+    /// The game as it stood at this snapshot, to carry on from in place of
+    /// its current state. A snapshot from the middle of a day restores to
+    /// a game resting after that phase.
+    pub fn restore(&self) -> Game {
+        let mut game = self.game.clone();
+        game.current_phase = self.phase;
+        game.resting_phase = self.resting_phase();
+        game
+    }
+
+    /// The phase a game restored from this snapshot rests after, if the
+    /// snapshot falls mid-day.
+    fn resting_phase(&self) -> Option<Phase> {
+        (self.day > 0 && self.phase != Phase::Night).then_some(self.phase)
+    }
+
+    /// Branch a new game off this snapshot, reseeded with `seed`.
+    ///
+    /// Every identifier in the state (the game's, its tributes', areas'
+    /// and items') is re-minted so the fork can be stored next to the
+    /// original; [`Fork::remap_message`] applies the same renaming to
+    /// messages copied over from the original's log. Player control is
+    /// dropped: the fork plays out on the brains alone.
+    pub fn fork(&self, seed: u64) -> Result<Fork, GameError> {
+        let mut game = self.game.clone();
+        game.seed = seed;
+        for tribute in game.tributes.iter_mut() {
+            tribute.controller = None;
+            tribute.orders.clear();
+        }
+
+        let mut renamer = Renamer {
+            identifiers: HashMap::new(),
+            rng: SmallRng::seed_from_u64(seed),
+        };
+        let state = serde_json::to_value(&game)
+            .map_err(|e| GameError::from(format!("Failed to encode snapshot: {e}")))?;
+        let mut game: Game = serde_json::from_value(renamer.remap_value(state))
+            .map_err(|e| GameError::from(format!("Failed to decode snapshot: {e}")))?;
+        game.current_phase = self.phase;
+        game.resting_phase = self.resting_phase();
+        Ok(Fork { game, renamer })
+    }
+}
+
+/// A game branched off a [`GameSnapshot`], with the renaming that produced
+/// it.
+pub struct Fork {
+    pub game: Game,
+    renamer: Renamer,
+}
+
+impl Fork {
+    /// A message from the original game's log, rewritten to belong to the
+    /// fork: its own identifier and every one it mentions are renamed the
+    /// same way the fork's state was.
+    pub fn remap_message(&mut self, message: &GameMessage) -> Result<GameMessage, GameError> {
+        let value = serde_json::to_value(message)
+            .map_err(|e| GameError::from(format!("Failed to encode message: {e}")))?;
+        serde_json::from_value(self.renamer.remap_value(value))
+            .map_err(|e| GameError::from(format!("Failed to decode message: {e}")))
+    }
+}
+
+/// Renames identifiers consistently: the same original always maps to the
+/// same new one.
+struct Renamer {
+    /// Original identifier to the fork's.
+    identifiers: HashMap<Uuid, Uuid>,
+    rng: SmallRng,
+}
+
+impl Renamer {
+    fn remap_value(&mut self, value: serde_json::Value) -> serde_json::Value {
+        use serde_json::Value;
+        match value {
+            Value::String(s) => Value::String(self.remap_str(&s)),
+            Value::Array(values) => {
+                Value::Array(values.into_iter().map(|v| self.remap_value(v)).collect())
+            }
+            // Keys too: several maps are keyed by tribute identifier.
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(k, v)| (self.remap_str(&k), self.remap_value(v)))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    /// Replace every UUID in `text`, minting a new one the first time each
+    /// is seen.
+    fn remap_str(&mut self, text: &str) -> String {
+        const UUID_LEN: usize = 36;
+        let bytes = text.as_bytes();
+        let mut out = String::with_capacity(text.len());
+        let mut copied = 0;
+        let mut i = 0;
+        while i + UUID_LEN <= bytes.len() {
+            let candidate = &bytes[i..i + UUID_LEN];
+            if is_uuid_shaped(candidate)
+                && let Ok(uuid) = Uuid::try_parse_ascii(candidate)
+            {
+                let rng = &mut self.rng;
+                let renamed = *self
+                    .identifiers
+                    .entry(uuid)
+                    .or_insert_with(|| crate::seeding::uuid_from_rng(rng));
+                out.push_str(&text[copied..i]);
+                out.push_str(&renamed.to_string());
+                i += UUID_LEN;
+                copied = i;
+            } else {
+                i += 1;
+            }
+        }
+        out.push_str(&text[copied..]);
+        out
+    }
+}
+
+/// Hyphenated 8-4-4-4-12 hex digits.
+fn is_uuid_shaped(bytes: &[u8]) -> bool {
+    bytes.iter().enumerate().all(|(i, b)| match i {
+        8 | 13 | 18 | 23 => *b == b'-',
+        _ => b.is_ascii_hexdigit(),
+    })
+}
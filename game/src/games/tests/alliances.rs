@@ -1,4 +1,5 @@
 use super::*;
+use crate::tributes::alliances::Watch;
 
 #[test]
 fn process_alliance_events_betrayal_removes_pair_on_victim_side() {
@@ -95,7 +96,7 @@ fn run_tribute_cycle_drains_tribute_alliance_events_into_game_queue() {
     );
 
     let mut game = create_test_game_with_tributes(vec![tribute1.clone(), tribute2.clone()]);
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     game.areas.push(area);
     let closed_areas = game
         .areas
@@ -135,14 +136,14 @@ fn run_tribute_cycle_forms_alliance_between_compatible_same_area_tributes() {
     t2.district = 1;
     t1.traits = vec![Trait::Friendly];
     t2.traits = vec![Trait::Friendly];
-    t1.area = Area::Cornucopia;
-    t2.area = Area::Cornucopia;
+    t1.area = Area::CORNUCOPIA;
+    t2.area = Area::CORNUCOPIA;
 
     let id1 = t1.id;
     let id2 = t2.id;
 
     let mut game = create_test_game_with_tributes(vec![t1.clone(), t2.clone()]);
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     game.areas.push(area);
     let closed_areas = game
         .areas
@@ -187,8 +188,8 @@ fn run_tribute_cycle_treacherous_tribute_betrays_same_area_ally_when_timer_elaps
     betrayer.allies.push(victim.id);
     victim.allies.push(betrayer.id);
     betrayer.turns_since_last_betrayal = TREACHEROUS_BETRAYAL_INTERVAL;
-    betrayer.area = Area::Cornucopia;
-    victim.area = Area::Cornucopia;
+    betrayer.area = Area::CORNUCOPIA;
+    victim.area = Area::CORNUCOPIA;
     betrayer.district = 1;
     victim.district = 2;
 
@@ -196,7 +197,7 @@ fn run_tribute_cycle_treacherous_tribute_betrays_same_area_ally_when_timer_elaps
     let vid = victim.id;
 
     let mut game = create_test_game_with_tributes(vec![betrayer.clone(), victim.clone()]);
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     game.areas.push(area);
     let closed_areas = game
         .areas
@@ -235,17 +236,17 @@ fn run_tribute_cycle_treacherous_no_betrayal_without_same_area_ally_resets_timer
     loner.traits = vec![Trait::Treacherous];
     other.traits = vec![Trait::Tough];
     loner.turns_since_last_betrayal = TREACHEROUS_BETRAYAL_INTERVAL;
-    loner.area = Area::Sector1;
-    other.area = Area::Sector4;
+    loner.area = Area::SECTOR_1;
+    other.area = Area::SECTOR_4;
     loner.district = 5;
     other.district = 6;
     let lid = loner.id;
 
     let mut game = create_test_game_with_tributes(vec![loner.clone(), other.clone()]);
     game.areas
-        .push(AreaDetails::new(Some("Hill".to_string()), Area::Sector1));
+        .push(AreaDetails::new(Some("Hill".to_string()), Area::SECTOR_1));
     game.areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Sector4));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::SECTOR_4));
     let closed_areas = game
         .areas
         .iter()
@@ -282,8 +283,8 @@ fn run_tribute_cycle_enqueues_death_recorded_for_recently_dead_ally() {
     deceased.allies.push(survivor.id);
     deceased.blood = 0;
     deceased.status = TributeStatus::RecentlyDead;
-    deceased.area = Area::Cornucopia;
-    survivor.area = Area::Cornucopia;
+    deceased.area = Area::CORNUCOPIA;
+    survivor.area = Area::CORNUCOPIA;
     deceased.district = 11;
     survivor.district = 12;
 
@@ -292,7 +293,7 @@ fn run_tribute_cycle_enqueues_death_recorded_for_recently_dead_ally() {
 
     let mut game = create_test_game_with_tributes(vec![deceased.clone(), survivor.clone()]);
     game.areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA));
     let living = game.living_tributes();
     let closed_areas: Vec<Area> = vec![];
 
@@ -330,9 +331,9 @@ fn run_tribute_cycle_three_way_preserves_existing_alliance() {
     c.traits = vec![Trait::LoneWolf];
     a.allies.push(b.id);
     b.allies.push(a.id);
-    a.area = Area::Cornucopia;
-    b.area = Area::Cornucopia;
-    c.area = Area::Cornucopia;
+    a.area = Area::CORNUCOPIA;
+    b.area = Area::CORNUCOPIA;
+    c.area = Area::CORNUCOPIA;
     a.district = 1;
     b.district = 2;
     c.district = 3;
@@ -343,7 +344,7 @@ fn run_tribute_cycle_three_way_preserves_existing_alliance() {
 
     let mut game = create_test_game_with_tributes(vec![a.clone(), b.clone(), c.clone()]);
     game.areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA));
     let living = game.living_tributes();
     let closed_areas: Vec<Area> = vec![];
 
@@ -383,9 +384,9 @@ fn run_tribute_cycle_consumes_recently_killed_by_for_combat_death() {
     deceased.status = TributeStatus::RecentlyDead;
     deceased.recently_killed_by = Some(kid);
 
-    deceased.area = Area::Cornucopia;
-    killer.area = Area::Cornucopia;
-    survivor.area = Area::Cornucopia;
+    deceased.area = Area::CORNUCOPIA;
+    killer.area = Area::CORNUCOPIA;
+    survivor.area = Area::CORNUCOPIA;
     deceased.district = 11;
     killer.district = 2;
     survivor.district = 12;
@@ -393,7 +394,7 @@ fn run_tribute_cycle_consumes_recently_killed_by_for_combat_death() {
     let mut game =
         create_test_game_with_tributes(vec![deceased.clone(), killer.clone(), survivor.clone()]);
     game.areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA));
     let living = game.living_tributes();
     let closed_areas: Vec<Area> = vec![];
 
@@ -438,14 +439,14 @@ fn run_tribute_cycle_environmental_death_emits_killer_none() {
     deceased.status = TributeStatus::RecentlyDead;
     assert!(deceased.recently_killed_by.is_none());
 
-    deceased.area = Area::Cornucopia;
-    survivor.area = Area::Cornucopia;
+    deceased.area = Area::CORNUCOPIA;
+    survivor.area = Area::CORNUCOPIA;
     deceased.district = 11;
     survivor.district = 12;
 
     let mut game = create_test_game_with_tributes(vec![deceased.clone(), survivor.clone()]);
     game.areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA));
     let living = game.living_tributes();
     let closed_areas: Vec<Area> = vec![];
 
@@ -478,14 +479,14 @@ fn alliance_formation_emits_message_with_alliance_formed_kind() {
     t2.district = 1;
     t1.traits = vec![Trait::Friendly];
     t2.traits = vec![Trait::Friendly];
-    t1.area = Area::Cornucopia;
-    t2.area = Area::Cornucopia;
+    t1.area = Area::CORNUCOPIA;
+    t2.area = Area::CORNUCOPIA;
 
     let base = create_test_game_with_tributes(vec![t1.clone(), t2.clone()]);
     let mut game_with_area = base.clone();
     game_with_area
         .areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA));
     let closed_areas: Vec<Area> = vec![];
 
     let mut hit: Option<crate::messages::GameMessage> = None;
@@ -537,14 +538,14 @@ fn betrayal_emits_message_with_betrayal_triggered_kind() {
     betrayer.allies.push(victim.id);
     victim.allies.push(betrayer.id);
     betrayer.turns_since_last_betrayal = TREACHEROUS_BETRAYAL_INTERVAL;
-    betrayer.area = Area::Cornucopia;
-    victim.area = Area::Cornucopia;
+    betrayer.area = Area::CORNUCOPIA;
+    victim.area = Area::CORNUCOPIA;
     betrayer.district = 1;
     victim.district = 2;
 
     let mut game = create_test_game_with_tributes(vec![betrayer.clone(), victim.clone()]);
     game.areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA));
     let closed_areas: Vec<Area> = vec![];
 
     let mut rng = SmallRng::seed_from_u64(313);
@@ -582,14 +583,14 @@ fn alliance_formed_message_content_matches_game_event_display() {
     t2.district = 1;
     t1.traits = vec![Trait::Friendly];
     t2.traits = vec![Trait::Friendly];
-    t1.area = Area::Cornucopia;
-    t2.area = Area::Cornucopia;
+    t1.area = Area::CORNUCOPIA;
+    t2.area = Area::CORNUCOPIA;
 
     let base = create_test_game_with_tributes(vec![t1.clone(), t2.clone()]);
     let mut game_with_area = base.clone();
     game_with_area
         .areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA));
     let closed_areas: Vec<Area> = vec![];
 
     let mut hit: Option<crate::messages::GameMessage> = None;
@@ -659,8 +660,8 @@ fn betrayal_triggered_message_content_matches_game_event_display() {
     betrayer.allies.push(victim.id);
     victim.allies.push(betrayer.id);
     betrayer.turns_since_last_betrayal = TREACHEROUS_BETRAYAL_INTERVAL;
-    betrayer.area = Area::Cornucopia;
-    victim.area = Area::Cornucopia;
+    betrayer.area = Area::CORNUCOPIA;
+    victim.area = Area::CORNUCOPIA;
     betrayer.district = 1;
     victim.district = 2;
 
@@ -671,7 +672,7 @@ fn betrayal_triggered_message_content_matches_game_event_display() {
 
     let mut game = create_test_game_with_tributes(vec![betrayer.clone(), victim.clone()]);
     game.areas
-        .push(AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia));
+        .push(AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA));
     let closed_areas: Vec<Area> = vec![];
 
     let mut rng = SmallRng::seed_from_u64(313);
@@ -739,3 +740,191 @@ fn dead_tribute_has_no_movement_event_after_death_in_same_period() {
         "no TributeMoved for B should appear after B's TributeKilled in the same period"
     );
 }
+
+// ---- Alliance groups --------------------------------------------------
+
+/// Tributes in the Cornucopia whose leadership rises with their index.
+fn band(n: usize) -> Vec<Tribute> {
+    (0..n)
+        .map(|i| {
+            let mut t = create_tribute(&format!("T{i}"), true);
+            t.traits.clear();
+            t.attributes = Attributes {
+                intelligence: 10 * (i as u32 + 1),
+                ..Attributes::default()
+            };
+            t
+        })
+        .collect()
+}
+
+fn ally(tributes: &mut [Tribute], a: usize, b: usize) {
+    let (a_id, b_id) = (tributes[a].id, tributes[b].id);
+    tributes[a].allies.push(b_id);
+    tributes[b].allies.push(a_id);
+}
+
+fn unally(game: &mut Game, a: usize, b: usize) {
+    let (a_id, b_id) = (game.tributes[a].id, game.tributes[b].id);
+    game.tributes[a].allies.retain(|x| *x != b_id);
+    game.tributes[b].allies.retain(|x| *x != a_id);
+}
+
+fn dissolutions(game: &Game) -> Vec<(Vec<String>, String)> {
+    game.messages
+        .iter()
+        .filter_map(|m| match &m.payload {
+            crate::messages::MessagePayload::AllianceDissolved { members, reason } => Some((
+                members.iter().map(|r| r.name.clone()).collect(),
+                reason.clone(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn food() -> Item {
+    Item {
+        name: "bread".to_string(),
+        item_type: crate::items::ItemType::Food(2),
+        ..Item::default()
+    }
+}
+
+#[test]
+fn allied_tributes_form_one_group_led_by_the_strongest() {
+    let mut tributes = band(4);
+    ally(&mut tributes, 0, 1);
+    ally(&mut tributes, 1, 2);
+    let ids: Vec<Uuid> = tributes.iter().map(|t| t.id).collect();
+    let mut game = create_test_game_with_tributes(tributes);
+
+    game.sync_alliances(&mut SmallRng::seed_from_u64(1));
+
+    assert_eq!(game.alliances.len(), 1);
+    let alliance = &game.alliances[0];
+    let mut members = ids[..3].to_vec();
+    members.sort();
+    assert_eq!(alliance.members, members);
+    assert_eq!(alliance.leader, ids[2]);
+    assert!(dissolutions(&game).is_empty());
+}
+
+#[test]
+fn an_alliance_outlives_its_leader() {
+    let mut tributes = band(3);
+    ally(&mut tributes, 0, 1);
+    ally(&mut tributes, 1, 2);
+    let ids: Vec<Uuid> = tributes.iter().map(|t| t.id).collect();
+    let mut game = create_test_game_with_tributes(tributes);
+    let mut rng = SmallRng::seed_from_u64(2);
+    game.sync_alliances(&mut rng);
+    let id = game.alliances[0].id;
+    game.alliances[0].stash.push(food());
+
+    game.tributes[2].dies();
+    game.sync_alliances(&mut rng);
+
+    assert_eq!(game.alliances.len(), 1);
+    assert_eq!(game.alliances[0].id, id);
+    assert_eq!(game.alliances[0].leader, ids[1]);
+    assert_eq!(game.alliances[0].stash.len(), 1);
+}
+
+#[test]
+fn a_broken_link_splits_the_alliance() {
+    let mut tributes = band(4);
+    ally(&mut tributes, 0, 1);
+    ally(&mut tributes, 1, 2);
+    ally(&mut tributes, 2, 3);
+    let mut game = create_test_game_with_tributes(tributes);
+    let mut rng = SmallRng::seed_from_u64(3);
+    game.sync_alliances(&mut rng);
+    let leader = game.alliances[0].leader;
+    assert_eq!(leader, game.tributes[3].id);
+
+    unally(&mut game, 1, 2);
+    game.sync_alliances(&mut rng);
+
+    assert_eq!(game.alliances.len(), 2);
+    let kept = game.alliances.iter().find(|a| a.leader == leader).unwrap();
+    assert!(kept.contains(&game.tributes[2].id));
+    let split = dissolutions(&game);
+    assert_eq!(split.len(), 2, "one notice per member who left");
+    assert!(
+        split
+            .iter()
+            .all(|(members, _)| members.contains(&"T3".to_string()))
+    );
+}
+
+#[test]
+fn a_dissolved_alliance_leaves_its_stash_with_a_survivor() {
+    let mut tributes = band(2);
+    ally(&mut tributes, 0, 1);
+    let mut game = create_test_game_with_tributes(tributes);
+    let mut rng = SmallRng::seed_from_u64(4);
+    game.sync_alliances(&mut rng);
+    game.alliances[0].stash.push(food());
+
+    game.tributes[1].dies();
+    game.sync_alliances(&mut rng);
+
+    assert!(game.alliances.is_empty());
+    assert_eq!(
+        dissolutions(&game),
+        vec![(
+            vec!["T0".to_string()],
+            "too few of them are left alive".to_string()
+        )]
+    );
+    assert!(game.tributes[0].items.iter().any(|i| i.name == "bread"));
+}
+
+#[test]
+fn members_with_the_leader_pool_their_spares() {
+    let mut tributes = band(2);
+    ally(&mut tributes, 0, 1);
+    tributes[0].items = vec![food(), food()];
+    tributes[1].items.clear();
+    tributes[1].hunger = 3;
+    let mut game = create_test_game_with_tributes(tributes);
+    game.sync_alliances(&mut SmallRng::seed_from_u64(5));
+
+    game.share_alliance_stashes();
+
+    assert_eq!(game.tributes[0].items.len(), 1);
+    assert_eq!(game.tributes[1].items.len(), 1);
+    assert!(game.alliances[0].stash.is_empty());
+}
+
+#[test]
+fn followers_rally_on_the_leader_and_take_turns_on_watch() {
+    let mut tributes = band(3);
+    ally(&mut tributes, 0, 1);
+    ally(&mut tributes, 1, 2);
+    tributes[2].area = Area::SECTOR_1;
+    let mut game = create_test_game_with_tributes(tributes);
+    game.sync_alliances(&mut SmallRng::seed_from_u64(6));
+
+    game.assign_alliance_roles(1, crate::messages::Phase::Night);
+
+    assert_eq!(game.tributes[2].rally, None, "the leader leads");
+    assert_eq!(game.tributes[0].rally, Some(Area::SECTOR_1));
+    assert_eq!(game.tributes[1].rally, Some(Area::SECTOR_1));
+    assert_eq!(game.tributes[2].watch, None, "no one to guard");
+    let watches = [game.tributes[0].watch, game.tributes[1].watch];
+    assert!(watches.contains(&Some(Watch::Keeping)));
+    assert!(watches.contains(&Some(Watch::Guarded)));
+
+    let keeper = game
+        .tributes
+        .iter()
+        .position(|t| t.watch == Some(Watch::Keeping));
+    game.assign_alliance_roles(1, crate::messages::Phase::Dawn);
+    let next = game
+        .tributes
+        .iter()
+        .position(|t| t.watch == Some(Watch::Keeping));
+    assert_ne!(keeper, next, "the watch rotates each phase");
+}
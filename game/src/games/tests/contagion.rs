@@ -0,0 +1,107 @@
+use super::*;
+use crate::messages::{MessagePayload, Phase};
+use crate::tributes::afflictions::contagion::contagion_draft;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use shared::afflictions::{AfflictionKind, AfflictionSource, Contagion, Severity};
+
+fn contagion_game(tributes: Vec<Tribute>) -> Game {
+    let mut game = create_test_game_with_tributes(tributes);
+    game.config.contagions_enabled = true;
+    game
+}
+
+fn transformed_vampire(name: &str) -> Tribute {
+    let mut tribute = create_tribute(name, true);
+    let mut draft = contagion_draft(Contagion::Vampirism, AfflictionSource::Gamemaker);
+    draft.severity = Severity::Severe;
+    tribute.try_acquire_affliction(draft);
+    tribute
+}
+
+#[test]
+fn contagions_stay_dormant_unless_enabled() {
+    let tributes = (0..4)
+        .map(|i| create_tribute(&format!("T{i}"), true))
+        .collect();
+    let mut game = create_test_game_with_tributes(tributes);
+    let mut rng = SmallRng::seed_from_u64(7);
+
+    game.advance_contagions(Phase::Day, &mut rng);
+
+    assert!(game.tributes.iter().all(|t| t.contagion().is_none()));
+    assert!(game.messages.is_empty());
+}
+
+#[test]
+fn opening_day_seeds_one_patient_zero_per_contagion() {
+    let tributes = (0..6)
+        .map(|i| create_tribute(&format!("T{i}"), true))
+        .collect();
+    let mut game = contagion_game(tributes);
+    // `prepare_cycle` stamps each tribute's day before contagions advance.
+    for tribute in game.tributes.iter_mut() {
+        tribute.game_day = Some(1);
+    }
+    let mut rng = SmallRng::seed_from_u64(7);
+
+    game.advance_contagions(Phase::Day, &mut rng);
+
+    let mut carried: Vec<Contagion> = game
+        .tributes
+        .iter()
+        .filter_map(|t| t.contagion())
+        .map(|(contagion, severity)| {
+            assert_eq!(severity, Severity::Mild);
+            contagion
+        })
+        .collect();
+    carried.sort_by_key(|c| c.to_string());
+    assert_eq!(
+        carried,
+        vec![
+            Contagion::Lycanthropy,
+            Contagion::Vampirism,
+            Contagion::ZombiePlague
+        ]
+    );
+    let seeded = game
+        .messages
+        .iter()
+        .filter(|m| {
+            matches!(
+                m.payload,
+                MessagePayload::ContagionInfected { source: None, .. }
+            )
+        })
+        .count();
+    assert_eq!(seeded, 3);
+}
+
+#[test]
+fn exposed_vampires_burn_and_hidden_ones_do_not() {
+    let exposed = transformed_vampire("Exposed");
+    let mut hidden = transformed_vampire("Hidden");
+    hidden.attributes.is_hidden = true;
+    let mut game = contagion_game(vec![exposed, hidden]);
+
+    game.burn_exposed_vampires(Phase::Day);
+    game.burn_exposed_vampires(Phase::Night);
+
+    let exposed = &game.tributes[0];
+    let hidden = &game.tributes[1];
+    assert_eq!(exposed.blood, 900);
+    assert_eq!(
+        exposed
+            .afflictions
+            .get(&(AfflictionKind::Burned, None))
+            .map(|a| a.severity),
+        Some(Severity::Mild)
+    );
+    assert_eq!(hidden.blood, 1000);
+    assert!(
+        !hidden
+            .afflictions
+            .contains_key(&(AfflictionKind::Burned, None))
+    );
+}
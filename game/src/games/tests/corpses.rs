@@ -0,0 +1,87 @@
+use super::*;
+use crate::messages::MessagePayload;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+fn armed(name: &str, area: Area) -> Tribute {
+    let mut tribute = create_tribute(name, true);
+    tribute.area = area;
+    tribute.items.push(Item::new_weapon("sword"));
+    tribute
+}
+
+#[test]
+fn the_dead_leave_their_gear_on_the_body() {
+    // One tribute died this phase; the other was already promoted to Dead
+    // mid-phase before clean-up got to them. Both keep what they carried.
+    let mut fresh = armed("Cato", Area::SECTOR_1);
+    fresh.status = TributeStatus::RecentlyDead;
+    fresh.blood = 0;
+    let mut promoted = armed("Marvel", Area::SECTOR_2);
+    promoted.status = TributeStatus::Dead;
+    promoted.blood = 0;
+    let sword = fresh.items[0].clone();
+
+    let mut game = create_test_game_with_tributes(vec![fresh, promoted]);
+    game.areas = vec![
+        AreaDetails::new(None, Area::SECTOR_1),
+        AreaDetails::new(None, Area::SECTOR_2),
+    ];
+    game.clean_up_recent_deaths();
+
+    for tribute in &game.tributes {
+        assert_eq!(tribute.status, TributeStatus::Dead);
+        assert!(tribute.items.is_empty());
+    }
+    assert_eq!(game.areas[0].corpses.len(), 1);
+    assert_eq!(game.areas[0].corpses[0].name, "Cato");
+    assert_eq!(game.areas[0].corpses[0].items, vec![sword]);
+    assert_eq!(game.areas[1].corpses.len(), 1);
+    assert_eq!(
+        game.messages
+            .iter()
+            .filter(|m| matches!(m.payload, MessagePayload::CorpseLeft { .. }))
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn tributes_scavenge_bodies_and_seek_them_out() {
+    let mut scavenger = create_tribute("Rue", true);
+    // Fixed so the identifier-hashed trap setting never preempts looting.
+    scavenger.identifier = "safe-test-id".into();
+    scavenger.area = Area::SECTOR_1;
+    let mut victim = armed("Cato", Area::SECTOR_2);
+    victim.status = TributeStatus::RecentlyDead;
+    victim.blood = 0;
+
+    let mut game = create_test_game_with_tributes(vec![scavenger.clone(), victim]);
+    game.areas = vec![
+        AreaDetails::new(None, Area::SECTOR_1),
+        AreaDetails::new(None, Area::SECTOR_2),
+    ];
+    game.clean_up_recent_deaths();
+
+    // The body draws the scavenger over from an otherwise identical area.
+    let density = HashMap::new();
+    let goal = scavenger
+        .brain
+        .choose_destination(&game.areas, &scavenger, &density);
+    assert_eq!(goal, Some(Area::SECTOR_2));
+
+    // Standing over it, they loot instead of moving on.
+    scavenger.area = Area::SECTOR_2;
+    let mut rng = SmallRng::seed_from_u64(3);
+    let action = scavenger.brain.act(
+        &scavenger,
+        0,
+        &[],
+        &game.areas,
+        &[],
+        &density,
+        crate::messages::Phase::Day,
+        &mut rng,
+    );
+    assert_eq!(action, Action::TakeItem);
+}
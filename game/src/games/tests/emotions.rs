@@ -0,0 +1,96 @@
+use super::*;
+use crate::messages::{MessagePayload, MessageSource, TributeRef};
+use crate::tributes::emotions::{DominantEmotion, Emotions};
+use shared::afflictions::DeathCause;
+
+/// A living tribute with no traits, so setbacks land at face value.
+fn plain(name: &str, is_alive: bool) -> Tribute {
+    let mut tribute = create_tribute(name, is_alive);
+    tribute.traits.clear();
+    tribute
+}
+
+fn tribute_ref(tribute: &Tribute) -> TributeRef {
+    TributeRef {
+        identifier: tribute.identifier.clone().into(),
+        name: tribute.name.clone(),
+    }
+}
+
+fn push(game: &mut Game, payload: MessagePayload) {
+    let tick = game.tick_counter.next();
+    game.push_message(
+        MessageSource::Game(game.identifier.clone()),
+        "test".to_string(),
+        String::new(),
+        payload,
+        tick,
+    );
+}
+
+fn emotions_of(game: &Game, name: &str) -> Emotions {
+    game.tributes
+        .iter()
+        .find(|t| t.name == name)
+        .unwrap()
+        .emotions
+}
+
+#[test]
+fn a_death_moves_killer_allies_and_bystanders() {
+    let mut victim = plain("Victim", true);
+    let killer = plain("Killer", true);
+    let ally = plain("Ally", true);
+    let bystander = plain("Bystander", true);
+    let mut faraway = plain("Faraway", true);
+    victim.allies.push(ally.id);
+    victim.status = TributeStatus::RecentlyDead;
+    faraway.area = Area::SECTOR_3;
+    let payload = MessagePayload::TributeKilled {
+        victim: tribute_ref(&victim),
+        killer: Some(tribute_ref(&killer)),
+        cause: DeathCause::Unknown,
+    };
+    let mut game = create_test_game_with_tributes(vec![victim, killer, ally, bystander, faraway]);
+    push(&mut game, payload);
+
+    game.apply_emotional_fallout(0);
+
+    assert_eq!(emotions_of(&game, "Killer").morale, 70);
+    assert_eq!(emotions_of(&game, "Killer").aggression, 40);
+    let ally = game.tributes.iter().find(|t| t.name == "Ally").unwrap();
+    assert_eq!(ally.dominant_emotion(), DominantEmotion::Despairing);
+    assert_eq!(emotions_of(&game, "Bystander").composure, 50);
+    assert_eq!(emotions_of(&game, "Faraway"), Emotions::BASELINE);
+    assert_eq!(emotions_of(&game, "Victim"), Emotions::BASELINE);
+}
+
+#[test]
+fn betrayal_and_gifts_are_felt_by_their_subjects() {
+    let betrayer = plain("Betrayer", true);
+    let victim = plain("Victim", true);
+    let betrayal = MessagePayload::BetrayalTriggered {
+        betrayer: tribute_ref(&betrayer),
+        victim: tribute_ref(&victim),
+    };
+    let mut game = create_test_game_with_tributes(vec![betrayer, victim]);
+    push(&mut game, betrayal);
+
+    game.apply_emotional_fallout(0);
+
+    assert_eq!(emotions_of(&game, "Victim").trust, 15);
+    assert_eq!(emotions_of(&game, "Betrayer").trust, 40);
+    let victim = game.tributes.iter().find(|t| t.name == "Victim").unwrap();
+    assert_eq!(victim.dominant_emotion(), DominantEmotion::Distrustful);
+}
+
+#[test]
+fn emotions_settle_between_phases() {
+    let mut shaken = plain("Shaken", true);
+    shaken.emotions.composure = 0;
+    let mut game = create_test_game_with_tributes(vec![shaken]);
+
+    game.apply_emotional_fallout(0);
+
+    assert_eq!(emotions_of(&game, "Shaken").composure, 6);
+}
@@ -0,0 +1,240 @@
+use super::*;
+use crate::gamemaker::{ActiveIntervention, Gauges};
+use crate::messages::{MessagePayload, Phase};
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use shared::afflictions::{AfflictionKind, AfflictionSource, DeathCause};
+
+/// A game with every area, the given number of tributes in each and the
+/// gamemaker switched on.
+fn arena(placements: &[(Area, usize)]) -> Game {
+    let mut tributes = Vec::new();
+    for (area, count) in placements {
+        for i in 0..*count {
+            let mut tribute = create_tribute(&format!("{area}-{i}"), true);
+            tribute.area = *area;
+            tributes.push(tribute);
+        }
+    }
+    let mut game = create_test_game_with_tributes(tributes);
+    game.config.gamemaker_enabled = true;
+    for area in game.arena().areas() {
+        game.areas.push(AreaDetails::new(None, area));
+    }
+    game
+}
+
+fn eager(gauges: Gauges) -> crate::gamemaker::Gamemaker {
+    crate::gamemaker::Gamemaker {
+        gauges: Gauges {
+            patience: 10,
+            ..gauges
+        },
+        day: 1,
+        ..Default::default()
+    }
+}
+
+fn payloads(game: &Game) -> Vec<&MessagePayload> {
+    game.messages.iter().map(|m| &m.payload).collect()
+}
+
+#[test]
+fn bloodthirsty_gamemaker_fireballs_the_busiest_area() {
+    let mut game = arena(&[(Area::SECTOR_1, 3), (Area::SECTOR_2, 1)]);
+    game.gamemaker = eager(Gauges {
+        bloodthirst: 100,
+        drama_pressure: 0,
+        chaos: 0,
+        patience: 0,
+    });
+    let mut rng = SmallRng::seed_from_u64(5);
+    game.run_gamemaker(Phase::Day, &mut rng);
+
+    let strike = payloads(&game)
+        .into_iter()
+        .find_map(|p| match p {
+            MessagePayload::FireballStrike {
+                area,
+                victims,
+                survivors,
+            } => Some((area.name.clone(), victims.len(), survivors.len())),
+            _ => None,
+        })
+        .expect("fireball should fire");
+    assert_eq!(strike.0, "Sector 1");
+    assert_eq!(strike.1 + strike.2, 3);
+    assert_eq!(game.gamemaker.interventions_today, 1);
+    assert_eq!(game.gamemaker.gauges.patience, 0);
+
+    for tribute in game.tributes.iter().filter(|t| t.area == Area::SECTOR_1) {
+        if tribute.is_alive() {
+            assert!(tribute.afflictions.values().any(|a| {
+                a.kind == AfflictionKind::Burned && a.source == AfflictionSource::Gamemaker
+            }));
+        } else {
+            assert_eq!(tribute.status, TributeStatus::RecentlyDead);
+        }
+    }
+    let deaths = payloads(&game)
+        .into_iter()
+        .filter(|p| {
+            matches!(
+                p,
+                MessagePayload::TributeKilled {
+                    cause: DeathCause::Gamemaker,
+                    ..
+                }
+            )
+        })
+        .count();
+    assert_eq!(deaths, strike.1);
+}
+
+#[test]
+fn force_field_seals_areas_then_releases_them() {
+    let mut game = arena(&[(Area::CORNUCOPIA, 4), (Area::SECTOR_1, 2)]);
+    game.gamemaker = eager(Gauges {
+        chaos: 100,
+        drama_pressure: 0,
+        bloodthirst: 0,
+        patience: 0,
+    });
+    let mut rng = SmallRng::seed_from_u64(9);
+    game.run_gamemaker(Phase::Day, &mut rng);
+
+    let sealed = game.gamemaker.sealed_areas();
+    assert!(!sealed.is_empty() && sealed.len() <= 2);
+    assert!(!sealed.contains(&Area::CORNUCOPIA));
+    // The emptiest sectors go first, so occupied Sector 1 is spared.
+    assert!(!sealed.contains(&Area::SECTOR_1));
+    assert!(payloads(&game).iter().any(|p| matches!(
+        p,
+        MessagePayload::ForceFieldShifted { closed, opened } if closed.len() == sealed.len() && opened.is_empty()
+    )));
+
+    let ctx = game.build_cycle_context(Phase::Day, vec![], game.living_tributes(), 6);
+    for area in &sealed {
+        assert!(ctx.closed_areas.contains(area));
+    }
+
+    // A tribute who lingers inside a seal is burned every phase.
+    let lingerer = game
+        .tributes
+        .iter_mut()
+        .find(|t| t.area == Area::SECTOR_1)
+        .unwrap();
+    lingerer.area = sealed[0];
+    let lingerer_id = lingerer.identifier.clone();
+
+    game.messages.clear();
+    game.gamemaker.gauges = Gauges::STARTING;
+    for _ in 0..4 {
+        game.run_gamemaker(Phase::Night, &mut rng);
+    }
+    assert!(game.gamemaker.sealed_areas().is_empty());
+    let burns = payloads(&game)
+        .into_iter()
+        .filter(|p| matches!(p, MessagePayload::AreaSealEntryDamage { tribute, .. } if tribute.identifier == lingerer_id))
+        .count();
+    assert_eq!(burns, 4);
+    assert!(payloads(&game).iter().any(|p| matches!(
+        p,
+        MessagePayload::ForceFieldShifted { closed, opened } if closed.is_empty() && !opened.is_empty()
+    )));
+}
+
+#[test]
+fn convergence_point_stocks_a_feast_and_pulls_tributes() {
+    let mut game = arena(&[(Area::CORNUCOPIA, 3), (Area::SECTOR_1, 3)]);
+    game.day = Some(2);
+    game.gamemaker = eager(Gauges {
+        drama_pressure: 100,
+        bloodthirst: 0,
+        chaos: 0,
+        patience: 0,
+    });
+    let mut rng = SmallRng::seed_from_u64(2);
+    game.run_gamemaker(Phase::Day, &mut rng);
+
+    let area = game
+        .gamemaker
+        .convergence_point()
+        .expect("feast should be announced");
+    assert_ne!(area, Area::CORNUCOPIA);
+    assert_ne!(area, Area::SECTOR_1);
+    let items = payloads(&game)
+        .into_iter()
+        .find_map(|p| match p {
+            MessagePayload::ConvergencePointAnnounced { items, .. } => Some(items.len()),
+            _ => None,
+        })
+        .unwrap();
+    assert!(items >= 2);
+    assert_eq!(game.get_area_details_mut(area).unwrap().items.len(), items);
+
+    let ctx = game.build_cycle_context(Phase::Day, vec![], game.living_tributes(), 6);
+    let suggestion = ctx.action_suggestion.expect("feast should pull tributes");
+    assert_eq!(suggestion.action, Action::Move(Some(area)));
+}
+
+#[test]
+fn mutt_swarms_leave_at_dawn() {
+    let mut game = arena(&[(Area::SECTOR_3, 1)]);
+    game.gamemaker
+        .active_effects
+        .push(ActiveIntervention::MuttSwarm {
+            area: Area::SECTOR_3,
+            animal: crate::threats::animals::Animal::Wolf,
+            members: 4,
+        });
+    let mut rng = SmallRng::seed_from_u64(4);
+    game.run_gamemaker(Phase::Dawn, &mut rng);
+
+    assert!(game.gamemaker.active_effects.is_empty());
+    assert!(payloads(&game).iter().any(|p| matches!(
+        p,
+        MessagePayload::MuttSwarmDespawned {
+            reason: crate::messages::DespawnReason::Morning,
+            ..
+        }
+    )));
+}
+
+#[test]
+fn disabled_gamemaker_stays_silent() {
+    let mut game = arena(&[(Area::SECTOR_1, 3)]);
+    game.config.gamemaker_enabled = false;
+    game.gamemaker = eager(Gauges {
+        bloodthirst: 100,
+        drama_pressure: 100,
+        chaos: 100,
+        patience: 0,
+    });
+    let before = game.gamemaker.clone();
+    let mut rng = SmallRng::seed_from_u64(1);
+    game.run_gamemaker(Phase::Day, &mut rng);
+    game.observe_for_gamemaker(0);
+
+    assert!(game.messages.is_empty());
+    assert_eq!(game.gamemaker, before);
+}
+
+#[test]
+fn quiet_days_eventually_provoke_an_intervention() {
+    let mut game = arena(&[(Area::SECTOR_1, 2), (Area::SECTOR_4, 2)]);
+    game.day = None;
+    let mut rng = SmallRng::seed_from_u64(8);
+    for day in 1..=4 {
+        game.day = Some(day);
+        for phase in [Phase::Dawn, Phase::Day, Phase::Dusk, Phase::Night] {
+            let start = game.messages.len();
+            game.run_gamemaker(phase, &mut rng);
+            game.observe_for_gamemaker(start);
+        }
+    }
+    assert!(
+        !game.gamemaker.recent_interventions.is_empty(),
+        "sixteen quiet phases should trip a gauge"
+    );
+}
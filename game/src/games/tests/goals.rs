@@ -0,0 +1,39 @@
+use super::*;
+use crate::messages::Phase;
+use crate::terrain::{BaseTerrain, TerrainType};
+use crate::tributes::goals::Goal;
+
+#[test]
+fn parched_tributes_set_out_for_the_water_they_know_of() {
+    let parched = |name: &str| {
+        let mut tribute = create_tribute(name, true);
+        tribute.thirst = 2;
+        tribute
+    };
+    let mut game = create_test_game_with_tributes(vec![parched("Rue"), parched("Thresh")]);
+    // Desert everywhere but one wetlands area out on the rim.
+    let areas: Vec<Area> = game.arena().areas().collect();
+    let wet = *areas.last().unwrap();
+    for area in areas {
+        let base = if area == wet {
+            BaseTerrain::Wetlands
+        } else {
+            BaseTerrain::Desert
+        };
+        game.areas.push(AreaDetails::new_with_terrain(
+            None,
+            area,
+            TerrainType::new(base, vec![]).unwrap(),
+        ));
+    }
+
+    game.run_phase(Phase::Day).unwrap();
+
+    for tribute in game.tributes.iter().filter(|t| t.is_alive()) {
+        let plan = tribute.plan.as_ref().expect("a plan for water");
+        assert_eq!(plan.goal, Goal::SecureWater);
+        assert_eq!(plan.destination, wet);
+        assert_eq!(plan.day, 1);
+        assert!(tribute.current_goal().unwrap().starts_with("secure water"));
+    }
+}
@@ -24,7 +24,7 @@ fn test_announce_cycle_end() {
 #[test]
 fn test_announce_area_events() {
     let mut game = Game::new("Test Game");
-    let mut area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let mut area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     let mut rng = rand::rng();
     area.events.push(AreaEvent::random(&mut rng));
     area.events.push(AreaEvent::random(&mut rng));
@@ -34,7 +34,7 @@ fn test_announce_area_events() {
     let _ = game.announce_area_events();
 
     assert_eq!(game.messages.len(), 3);
-    let area_name = Area::Cornucopia.to_string();
+    let area_name = Area::CORNUCOPIA.to_string();
     for msg in &game.messages {
         assert_eq!(
             msg.source,
@@ -7,6 +7,7 @@ pub(crate) fn create_test_game_with_tributes(tributes: Vec<Tribute>) -> Game {
         name: "Test Game".to_string(),
         status: GameStatus::InProgress,
         day: Some(1),
+        resting_phase: None,
         areas: vec![],
         tributes,
         private: true,
@@ -18,6 +19,12 @@ pub(crate) fn create_test_game_with_tributes(tributes: Vec<Tribute>) -> Game {
         emit_index: 0,
         combat_tuning: crate::tributes::combat_tuning::CombatTuning::default(),
         sponsors: vec![],
+        seed: 0,
+        gamemaker: Default::default(),
+        alliances: vec![],
+        custom_brains: Default::default(),
+        player_gifts: vec![],
+        decision_traces: vec![],
     }
 }
 
@@ -34,5 +41,14 @@ pub(crate) fn create_tribute(name: &str, is_alive: bool) -> Tribute {
 }
 
 mod alliances;
+mod contagion;
+mod corpses;
+mod emotions;
+mod gamemaker;
+mod goals;
 mod messaging;
+mod orders;
+mod snapshots;
+mod sponsors;
 mod survival;
+mod weather;
@@ -0,0 +1,211 @@
+use super::*;
+use crate::games::orders::OrderError;
+use crate::messages::{MessagePayload, Phase};
+
+fn arena(tributes: Vec<Tribute>) -> Game {
+    let mut game = create_test_game_with_tributes(tributes);
+    for area in game.arena().areas() {
+        game.areas.push(AreaDetails::new(None, area));
+    }
+    game
+}
+
+fn controlled(name: &str) -> Tribute {
+    let mut tribute = create_tribute(name, true);
+    tribute.controller = Some("user:player".to_string());
+    tribute
+}
+
+#[test]
+fn controlled_tributes_await_an_order_for_every_phase() {
+    let mut player = controlled("Player");
+    let ai = create_tribute("Ai", true);
+    let mut dead = controlled("Dead");
+    dead.status = TributeStatus::Dead;
+    let mut game = arena(vec![player.clone(), ai, dead]);
+
+    assert_eq!(
+        game.upcoming_phases(),
+        &[Phase::Dawn, Phase::Day, Phase::Dusk, Phase::Night]
+    );
+    let awaiting: Vec<&str> = game
+        .awaiting_orders()
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect();
+    assert_eq!(awaiting, vec!["Player"]);
+
+    for phase in game.upcoming_phases() {
+        player.orders.insert(*phase, Action::Rest);
+    }
+    game.tributes[0] = player;
+    assert!(game.awaiting_orders().is_empty());
+}
+
+#[test]
+fn orders_are_checked_against_the_tribute_situation() {
+    let player = controlled("Player");
+    let ai = create_tribute("Ai", true);
+    let (player_id, ai_id) = (player.identifier.clone(), ai.identifier.clone());
+    let game = arena(vec![player, ai]);
+
+    assert_eq!(
+        game.validate_order(&player_id, Phase::Day, Action::Move(Some(Area::SECTOR_1))),
+        Ok(Action::Move(Some(Area::SECTOR_1)))
+    );
+    assert!(matches!(
+        game.validate_order(&ai_id, Phase::Day, Action::Rest),
+        Err(OrderError::NotControlled(_))
+    ));
+    assert!(matches!(
+        game.validate_order(&player_id, Phase::Day, Action::Frozen),
+        Err(OrderError::NotAnOrder(_))
+    ));
+    assert!(matches!(
+        game.validate_order(
+            &player_id,
+            Phase::Day,
+            Action::UseItem(Some(Item::new_random(None)))
+        ),
+        Err(OrderError::ItemNotCarried(_))
+    ));
+}
+
+#[test]
+fn an_order_replaces_the_brain_for_its_phase() {
+    let mut player = controlled("Player");
+    player
+        .orders
+        .insert(Phase::Day, Action::Sleep { duration_phases: 2 });
+    let others = (0..3).map(|i| create_tribute(&format!("T{i}"), true));
+    let mut game = arena(std::iter::once(player).chain(others).collect());
+
+    game.run_phase(Phase::Day).unwrap();
+
+    // Company may wake the player again, so look for the sleep itself.
+    let slept = game.messages.iter().any(|m| {
+        matches!(
+            &m.payload,
+            MessagePayload::TributeSlept { tribute, .. } if tribute.name == "Player"
+        )
+    });
+    assert!(slept);
+    assert!(game.tributes[0].orders.is_empty());
+}
+
+#[test]
+fn every_decision_is_traced() {
+    let mut player = controlled("Player");
+    player.orders.insert(Phase::Day, Action::Rest);
+    let player_id = player.identifier.clone();
+    let others = (0..3).map(|i| create_tribute(&format!("T{i}"), true));
+    let mut game = arena(std::iter::once(player).chain(others).collect());
+
+    game.run_phase(Phase::Day).unwrap();
+
+    let ordered = game
+        .decision_traces
+        .iter()
+        .find(|t| t.tribute == player_id)
+        .expect("the player's order is traced");
+    assert_eq!(ordered.brain, "order");
+    assert_eq!(ordered.action, Action::Rest);
+    for trace in &game.decision_traces {
+        assert_eq!(trace.phase, Phase::Day);
+        let last = trace.reasoning.steps.last().expect("at least one step");
+        assert_eq!(last.action, trace.action);
+        if trace.tribute != player_id {
+            assert_eq!(trace.brain, "builtin");
+        }
+    }
+    assert!(game.tributes.iter().all(|t| t.decision.is_none()));
+}
+
+#[test]
+fn view_shows_only_what_the_tribute_can_see() {
+    let player = controlled("Player");
+    let rival = create_tribute("Rival", true);
+    let mut lurker = create_tribute("Lurker", true);
+    lurker.attributes.is_hidden = true;
+    let mut faraway = create_tribute("Faraway", true);
+    faraway.area = Area::SECTOR_4;
+    let player_id = player.identifier.clone();
+    let game = arena(vec![player, rival, lurker, faraway]);
+
+    let view = game.tribute_view(&player_id).unwrap();
+    assert_eq!(view.area.area, Area::CORNUCOPIA);
+    let visible: Vec<&str> = view
+        .visible_tributes
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect();
+    assert_eq!(visible, vec!["Rival"]);
+    assert_eq!(
+        view.neighbours.len(),
+        game.arena().neighbors(Area::CORNUCOPIA).len()
+    );
+    assert_eq!(view.phases_awaiting_orders.len(), 4);
+}
+
+#[test]
+fn a_move_is_checked_from_where_earlier_moves_leave_the_tribute() {
+    let mut player = controlled("Player");
+    player
+        .orders
+        .insert(Phase::Dawn, Action::Move(Some(Area::SECTOR_1)));
+    let player_id = player.identifier.clone();
+    let game = arena(vec![player, create_tribute("Ai", true)]);
+
+    // Sector4 borders the Cornucopia but not Sector1, where dawn's move ends.
+    assert_eq!(
+        game.validate_order(&player_id, Phase::Day, Action::Move(Some(Area::SECTOR_4))),
+        Err(OrderError::UnreachableArea(Area::SECTOR_4))
+    );
+    assert_eq!(
+        game.validate_order(&player_id, Phase::Day, Action::Move(Some(Area::CORNUCOPIA))),
+        Ok(Action::Move(Some(Area::CORNUCOPIA)))
+    );
+    // Dawn itself still starts from the Cornucopia.
+    assert_eq!(
+        game.validate_order(&player_id, Phase::Dawn, Action::Move(Some(Area::SECTOR_4))),
+        Ok(Action::Move(Some(Area::SECTOR_4)))
+    );
+}
+
+#[test]
+fn a_move_chain_runs_and_a_stale_move_falls_back_to_the_brain() {
+    let mut player = controlled("Player");
+    player
+        .orders
+        .insert(Phase::Dawn, Action::Move(Some(Area::SECTOR_1)));
+    player
+        .orders
+        .insert(Phase::Day, Action::Move(Some(Area::CORNUCOPIA)));
+    let player_id = player.identifier.clone();
+    let mut rival = create_tribute("Rival", true);
+    rival.area = Area::SECTOR_4;
+    let mut game = arena(vec![player, rival]);
+
+    let brain = |game: &Game| {
+        game.decision_traces
+            .iter()
+            .rfind(|t| t.tribute == player_id)
+            .map(|t| t.brain.clone())
+    };
+
+    game.run_phase(Phase::Dawn).unwrap();
+    assert_eq!(brain(&game).as_deref(), Some("order"));
+    assert_eq!(game.tributes[0].area, Area::SECTOR_1);
+    game.run_phase(Phase::Day).unwrap();
+    assert_eq!(brain(&game).as_deref(), Some("order"));
+    assert_eq!(game.tributes[0].area, Area::CORNUCOPIA);
+
+    // Pushed back to Sector1, a move to Sector4 no longer leads next door.
+    game.tributes[0].area = Area::SECTOR_1;
+    game.tributes[0]
+        .orders
+        .insert(Phase::Dusk, Action::Move(Some(Area::SECTOR_4)));
+    game.run_phase(Phase::Dusk).unwrap();
+    assert_eq!(brain(&game).as_deref(), Some("builtin"));
+    assert!(game.tributes[0].orders.is_empty());
+}
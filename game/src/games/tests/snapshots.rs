@@ -0,0 +1,154 @@
+use super::*;
+use crate::items::Item;
+use crate::messages::{MessageSource, Phase};
+
+fn arena_game() -> Game {
+    let tributes = (0..6)
+        .map(|i| create_tribute(&format!("T{i}"), true))
+        .collect();
+    let mut game = create_test_game_with_tributes(tributes);
+    game.identifier = Uuid::new_v4().to_string();
+    for area in game.arena().areas() {
+        game.areas.push(AreaDetails::new(None, area));
+    }
+    game.areas[0].items.push(Item::new_random(None));
+    for tribute in game.tributes.iter_mut() {
+        tribute.statistics.game = game.identifier.clone();
+        tribute.items.push(Item::new_random(None));
+    }
+    game
+}
+
+#[test]
+fn full_day_reports_every_phase() {
+    let mut game = arena_game();
+    game.day = Some(1);
+    let mut phases = Vec::new();
+    game.run_full_day_with(|g| phases.push(g.snapshot().phase))
+        .unwrap();
+    assert_eq!(phases, Phase::all());
+}
+
+#[test]
+fn stepping_phase_by_phase_rests_between_phases() {
+    let mut game = arena_game();
+    let mut played = Vec::new();
+    for _ in Phase::all() {
+        played.push(game.run_next_phase().unwrap());
+        assert_eq!(game.day, Some(2));
+    }
+    assert_eq!(played, Phase::all());
+    assert_eq!(game.resting_phase, None);
+    assert_eq!(game.upcoming_day(), 3);
+}
+
+#[test]
+fn full_day_finishes_a_day_resting_mid_way() {
+    let mut game = arena_game();
+    game.run_next_phase().unwrap();
+    game.run_next_phase().unwrap();
+    assert_eq!(game.resting_phase, Some(Phase::Day));
+    assert_eq!(game.upcoming_phases(), [Phase::Dusk, Phase::Night]);
+
+    let mut phases = Vec::new();
+    game.run_full_day_with(|g| phases.push(g.current_phase))
+        .unwrap();
+    assert_eq!(phases, [Phase::Dusk, Phase::Night]);
+    assert_eq!(game.day, Some(2));
+    assert_eq!(game.resting_phase, None);
+}
+
+#[test]
+fn restoring_mid_day_rests_after_the_snapshot_phase() {
+    let mut game = arena_game();
+    let mut snapshots = Vec::new();
+    game.run_full_day_with(|g| snapshots.push(g.snapshot()))
+        .unwrap();
+
+    let dusk = snapshots[2].restore();
+    assert_eq!(dusk.resting_phase, Some(Phase::Dusk));
+    assert_eq!(dusk.upcoming_phases(), [Phase::Night]);
+    assert_eq!(dusk.upcoming_day(), 2);
+
+    let night = snapshots[3].restore();
+    assert_eq!(night.resting_phase, None);
+    assert_eq!(night.upcoming_day(), 3);
+}
+
+#[test]
+fn fork_renames_every_identifier() {
+    let mut game = arena_game();
+    game.current_phase = Phase::Dusk;
+    game.tributes[0].controller = Some("user:player".to_string());
+    let snapshot = game.snapshot();
+    let fork = snapshot.fork(7).unwrap();
+
+    assert_eq!(fork.game.seed, 7);
+    assert_eq!(fork.game.current_phase, Phase::Dusk);
+    assert_ne!(fork.game.identifier, game.identifier);
+    assert!(fork.game.tributes[0].controller.is_none());
+    for (old, new) in game.tributes.iter().zip(&fork.game.tributes) {
+        assert_eq!(old.name, new.name);
+        assert_ne!(old.identifier, new.identifier);
+        assert_eq!(new.id.to_string(), new.identifier);
+        assert_eq!(new.statistics.game, fork.game.identifier);
+        assert_ne!(old.items[0].identifier, new.items[0].identifier);
+    }
+    for (old, new) in game.areas.iter().zip(&fork.game.areas) {
+        assert_eq!(old.area, new.area);
+        assert_ne!(old.identifier, new.identifier);
+    }
+}
+
+#[test]
+fn forked_messages_follow_the_renaming() {
+    let game = arena_game();
+    let tribute = &game.tributes[0];
+    let message = crate::messages::GameMessage::new(
+        MessageSource::Tribute(tribute.identifier.clone()),
+        1,
+        Phase::Day,
+        1,
+        0,
+        format!("{}:tribute:{}", game.identifier, tribute.identifier),
+        format!("{} rests.", tribute.name),
+        crate::messages::MessagePayload::TributeRested {
+            tribute: crate::messages::TributeRef {
+                identifier: tribute.identifier.clone().into(),
+                name: tribute.name.clone(),
+            },
+            hp_restored: 0,
+        },
+    );
+
+    let mut fork = game.snapshot().fork(7).unwrap();
+    let copied = fork.remap_message(&message).unwrap();
+    let forked_tribute = &fork.game.tributes[0].identifier;
+    assert_ne!(copied.identifier, message.identifier);
+    assert_eq!(
+        copied.subject,
+        format!("{}:tribute:{}", fork.game.identifier, forked_tribute)
+    );
+    assert_eq!(copied.content, message.content);
+    assert_eq!(
+        copied.source,
+        MessageSource::Tribute(forked_tribute.clone())
+    );
+}
+
+#[test]
+fn unplayed_game_snapshots_as_night_zero() {
+    let mut game = arena_game();
+    game.day = None;
+    let snapshot = game.snapshot();
+    assert_eq!((snapshot.day, snapshot.phase), (0, Phase::Night));
+
+    // Nothing is left to play of night 0; the next day is day 1.
+    let restored = snapshot.restore();
+    assert_eq!(restored.resting_phase, None);
+    assert_eq!(restored.upcoming_day(), 1);
+    assert_eq!(
+        restored.upcoming_phases(),
+        [Phase::Day, Phase::Dusk, Phase::Night]
+    );
+}
@@ -0,0 +1,49 @@
+use super::*;
+use crate::messages::MessagePayload;
+use crate::sponsors::{PlayerGift, gift_price, resolve_player_gifts};
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use shared::sponsors::{ITEM_COSTS, ItemKindTag};
+
+fn gift(identifier: &str, recipient: &Tribute, item: ItemKindTag) -> PlayerGift {
+    PlayerGift {
+        identifier: identifier.to_string(),
+        donor: "Haymitch".to_string(),
+        recipient: recipient.identifier.clone(),
+        item,
+    }
+}
+
+#[test]
+fn player_gifts_are_priced_from_the_catalog() {
+    for (tag, cost) in ITEM_COSTS {
+        assert_eq!(gift_price(*tag), *cost);
+    }
+}
+
+#[test]
+fn player_gifts_reach_living_tributes_and_wait_on_dead_ones() {
+    let living = create_tribute("Living", true);
+    let dead = create_tribute("Dead", false);
+    let mut game = create_test_game_with_tributes(vec![living.clone(), dead.clone()]);
+    game.player_gifts = vec![
+        gift("g1", &living, ItemKindTag::Shield),
+        gift("g2", &dead, ItemKindTag::Food),
+    ];
+    let mut rng = SmallRng::seed_from_u64(3);
+
+    let delivered = resolve_player_gifts(&mut game, &mut rng);
+
+    assert_eq!(delivered.len(), 1);
+    assert!(matches!(
+        &delivered[0].payload,
+        MessagePayload::SponsorGift { recipient, donor, .. }
+            if recipient.name == "Living" && donor == "Haymitch"
+    ));
+    assert!(delivered[0].item.is_defensive());
+    // Left queued so the API can refund it.
+    assert_eq!(
+        game.player_gifts,
+        vec![gift("g2", &dead, ItemKindTag::Food)]
+    );
+}
@@ -95,14 +95,14 @@ fn initiative_liveness_gate_still_works() {
     killer.blood = 1000;
     killer.attributes.strength = 50;
     killer.attributes.agility = 100;
-    killer.area = Area::Cornucopia;
+    killer.area = Area::CORNUCOPIA;
 
     let mut victim = Tribute::new("Victim".to_string(), None, None);
     victim.blood = 10;
     victim.attributes.strength = 1;
     victim.attributes.defense = 1;
     victim.attributes.agility = 1;
-    victim.area = Area::Cornucopia;
+    victim.area = Area::CORNUCOPIA;
 
     game.tributes.clear();
     game.tributes.push(killer);
@@ -133,15 +133,15 @@ fn attributes_new_includes_agility() {
 #[test]
 fn test_random_open_area() {
     let mut game = Game::new("Test Game");
-    let area1 = AreaDetails::new(Some("Lake".to_string()), Area::Sector1);
-    let area2 = AreaDetails::new(Some("Forest".to_string()), Area::Sector4);
+    let area1 = AreaDetails::new(Some("Lake".to_string()), Area::SECTOR_1);
+    let area2 = AreaDetails::new(Some("Forest".to_string()), Area::SECTOR_4);
     game.areas.push(area1);
     game.areas.push(area2.clone());
-    assert!(game.random_area().is_some());
-    let mut rng = rand::rng();
+    let mut rng = SmallRng::seed_from_u64(11);
+    assert!(game.random_area(&mut rng).is_some());
     let event = AreaEvent::random(&mut rng);
     game.areas[0].events.push(event.clone());
-    assert_eq!(game.random_open_area().unwrap(), area2);
+    assert_eq!(game.random_open_area(&mut rng).unwrap(), area2);
 }
 
 #[test]
@@ -209,7 +209,7 @@ fn test_check_game_state_continues() {
 fn test_prepare_cycle() {
     use crate::messages::Phase;
     let mut game = Game::new("Test Game");
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Sector1);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::SECTOR_1);
     let mut rng = rand::rng();
     let event = AreaEvent::random(&mut rng);
     game.day = Some(1);
@@ -231,8 +231,8 @@ fn test_trigger_cycle_events() {}
 #[test]
 fn test_constrain_areas() {
     let mut game = Game::new("Test Game");
-    let area1 = AreaDetails::new(Some("Lake".to_string()), Area::Sector1);
-    let area2 = AreaDetails::new(Some("Forest".to_string()), Area::Sector4);
+    let area1 = AreaDetails::new(Some("Lake".to_string()), Area::SECTOR_1);
+    let area2 = AreaDetails::new(Some("Forest".to_string()), Area::SECTOR_4);
     game.areas.push(area1);
     game.areas.push(area2);
 
@@ -244,7 +244,7 @@ fn test_constrain_areas() {
     let mut rng = SmallRng::seed_from_u64(0);
     let _ = game.constrain_areas(&mut rng);
 
-    assert!(game.random_open_area().is_some());
+    assert!(game.random_open_area(&mut rng).is_some());
     assert_eq!(game.open_areas().len(), 1);
     assert_eq!(game.closed_areas().len(), 1);
 }
@@ -255,7 +255,7 @@ fn test_run_tribute_cycle() {
     let tribute2 = create_tribute("Tribute2", true);
 
     let mut game = create_test_game_with_tributes(vec![tribute1.clone(), tribute2.clone()]);
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     game.areas.push(area);
     let closed_areas = game
         .areas
@@ -282,8 +282,8 @@ fn test_run_tribute_cycle() {
 #[test]
 fn test_open_and_closed_areas() {
     let mut game = Game::new("Test Game");
-    let area1 = AreaDetails::new(Some("Lake".to_string()), Area::Sector1);
-    let area2 = AreaDetails::new(Some("Forest".to_string()), Area::Sector4);
+    let area1 = AreaDetails::new(Some("Lake".to_string()), Area::SECTOR_1);
+    let area2 = AreaDetails::new(Some("Forest".to_string()), Area::SECTOR_4);
     game.areas.push(area1);
     game.areas.push(area2);
 
@@ -300,21 +300,21 @@ fn test_open_and_closed_areas() {
 #[test]
 fn test_ensure_open_area() {
     let mut game = Game::new("Test Game");
-    let area1 = AreaDetails::new(Some("Lake".to_string()), Area::Sector1);
-    let area2 = AreaDetails::new(Some("Forest".to_string()), Area::Sector4);
+    let area1 = AreaDetails::new(Some("Lake".to_string()), Area::SECTOR_1);
+    let area2 = AreaDetails::new(Some("Forest".to_string()), Area::SECTOR_4);
     game.areas.push(area1);
     game.areas.push(area2);
 
-    assert!(game.random_open_area().is_some());
+    let mut rng = SmallRng::seed_from_u64(11);
+    assert!(game.random_open_area(&mut rng).is_some());
 
-    let mut rng = rand::rng();
     game.areas[0].events.push(AreaEvent::random(&mut rng));
     game.areas[1].events.push(AreaEvent::random(&mut rng));
 
-    assert!(game.random_open_area().is_none());
+    assert!(game.random_open_area(&mut rng).is_none());
 
-    game.ensure_open_area();
-    assert!(game.random_open_area().is_some());
+    game.ensure_open_area(&mut rng);
+    assert!(game.random_open_area(&mut rng).is_some());
 }
 
 #[test]
@@ -362,7 +362,7 @@ fn sleeping_tribute_naturally_wakes_after_duration_emits_tribute_woke() {
     t.cycles_awake = 9;
     t.stamina = 50;
     let mut game = create_test_game_with_tributes(vec![t.clone()]);
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     game.areas.push(area);
     let mut rng = SmallRng::seed_from_u64(42);
     let _ = game.run_tribute_cycle(Phase::Night, &mut rng, vec![], vec![t], 1);
@@ -395,7 +395,7 @@ fn sleeping_tribute_regenerates_stamina_each_phase() {
     t.max_stamina = 100;
     let prior = t.stamina;
     let mut game = create_test_game_with_tributes(vec![t.clone()]);
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     game.areas.push(area);
     let mut rng = SmallRng::seed_from_u64(42);
     let _ = game.run_tribute_cycle(Phase::Night, &mut rng, vec![], vec![t], 1);
@@ -435,7 +435,7 @@ fn sleeping_wounded_tribute_does_not_regen_hp() {
     );
     let prior_hp = t.effective_health();
     let mut game = create_test_game_with_tributes(vec![t.clone()]);
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     game.areas.push(area);
     let mut rng = SmallRng::seed_from_u64(42);
     let _ = game.run_tribute_cycle(Phase::Night, &mut rng, vec![], vec![t], 1);
@@ -455,7 +455,7 @@ fn cycles_awake_does_not_increment_while_sleeping() {
     t.sleep_remaining = 3;
     t.cycles_awake = 4;
     let mut game = create_test_game_with_tributes(vec![t.clone()]);
-    let area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     game.areas.push(area);
     let mut rng = SmallRng::seed_from_u64(42);
     let _ = game.run_tribute_cycle(Phase::Night, &mut rng, vec![], vec![t], 1);
@@ -473,7 +473,7 @@ fn area_event_interrupts_sleeping_tribute() {
     t.stamina = 10;
     let prior_stamina = t.stamina;
     let mut game = create_test_game_with_tributes(vec![t.clone()]);
-    let mut area = AreaDetails::new(Some("Lake".to_string()), Area::Cornucopia);
+    let mut area = AreaDetails::new(Some("Lake".to_string()), Area::CORNUCOPIA);
     area.events.push(AreaEvent::Wildfire);
     game.areas.push(area);
     let mut rng = SmallRng::seed_from_u64(42);
@@ -0,0 +1,74 @@
+use super::*;
+use crate::areas::weather::Weather;
+use crate::messages::{MessagePayload, Phase};
+use crate::terrain::{BaseTerrain, TerrainType};
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+fn jungle_game() -> Game {
+    let mut game = create_test_game_with_tributes(vec![]);
+    game.day = None;
+    for area in game.arena().areas() {
+        let terrain = TerrainType::new(BaseTerrain::Jungle, vec![]).unwrap();
+        game.areas
+            .push(AreaDetails::new_with_terrain(None, area, terrain));
+    }
+    game
+}
+
+#[test]
+fn opening_phase_keeps_clear_skies() {
+    let mut game = jungle_game();
+    game.day = Some(1);
+    let mut rng = SmallRng::seed_from_u64(3);
+    for _ in 0..20 {
+        assert!(game.advance_weather(Phase::Day, &mut rng).is_empty());
+    }
+    assert!(
+        game.areas
+            .iter()
+            .all(|a| a.weather.current == Weather::Clear)
+    );
+    assert_eq!(game.weather_summary(), None);
+}
+
+#[test]
+fn weather_changes_are_announced_per_area() {
+    let mut game = jungle_game();
+    game.day = Some(2);
+    let mut rng = SmallRng::seed_from_u64(11);
+    let mut announced = 0;
+    for _ in 0..20 {
+        let changes = game.advance_weather(Phase::Night, &mut rng);
+        for change in &changes {
+            assert_ne!(change.from, change.to);
+            let details = game.get_area_details_mut(change.area).unwrap();
+            assert_eq!(details.weather.current, change.to);
+        }
+        announced += changes.len();
+        game.announce_weather_changes(changes);
+    }
+    assert!(
+        announced > 0,
+        "jungle weather should shift within 20 phases"
+    );
+
+    let payloads: Vec<_> = game
+        .messages
+        .iter()
+        .filter(|m| matches!(m.payload, MessagePayload::WeatherChanged { .. }))
+        .collect();
+    assert_eq!(payloads.len(), announced);
+    if let MessagePayload::WeatherChanged { from, to, .. } = &payloads[0].payload {
+        assert!(from.parse::<Weather>().is_ok());
+        assert!(to.parse::<Weather>().is_ok());
+    }
+}
+
+#[test]
+fn phase_start_summarises_notable_weather() {
+    let mut game = jungle_game();
+    game.areas[2].weather.current = Weather::Storm;
+    let summary = game.weather_summary().unwrap();
+    assert!(summary.contains("storm in Sector 2"), "{summary}");
+}
@@ -7,8 +7,7 @@ use strum::IntoEnumIterator;
 
 impl ItemRarity {
     /// Roll for item rarity using weighted distribution.
-    pub fn random() -> ItemRarity {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+    pub fn random(rng: &mut impl Rng) -> ItemRarity {
         let roll: f32 = rng.random();
 
         if roll < 0.60 {
@@ -24,8 +23,7 @@ impl ItemRarity {
 }
 
 impl ItemType {
-    pub fn random() -> ItemType {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+    pub fn random(rng: &mut impl Rng) -> ItemType {
         // Weighted distribution: weapons and consumables remain dominant;
         // food and water enter the spawn pool but are rarer (spec).
         match rng.random_range(0..10) {
@@ -38,32 +36,39 @@ impl ItemType {
 }
 
 impl Attribute {
-    pub fn random() -> Attribute {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
-        Attribute::iter().choose(&mut rng).unwrap()
+    pub fn random(rng: &mut impl Rng) -> Attribute {
+        Attribute::iter().choose(rng).unwrap()
     }
 }
 
+fn entropy_rng() -> SmallRng {
+    SmallRng::from_rng(&mut rand::rng())
+}
+
 impl Item {
     pub fn new_random(name: Option<&str>) -> Item {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+        Self::new_random_with_rng(name, &mut entropy_rng())
+    }
 
-        let item_type = ItemType::random();
+    /// [`Item::new_random`] driven by a caller-supplied RNG. The engine uses
+    /// the `_with_rng` constructors so seeded games mint identical items.
+    pub fn new_random_with_rng(name: Option<&str>, rng: &mut impl Rng) -> Item {
+        let item_type = ItemType::random(rng);
         let is_shield = rng.random_bool(0.5);
 
         match (item_type, name) {
-            (ItemType::Consumable, Some(name)) => Self::new_consumable(name),
-            (ItemType::Consumable, None) => Self::new_random_consumable(),
+            (ItemType::Consumable, Some(name)) => Self::new_consumable_with_rng(name, rng),
+            (ItemType::Consumable, None) => Self::new_random_consumable_with_rng(rng),
             (ItemType::Weapon, Some(name)) => match is_shield {
-                false => Self::new_weapon(name),
-                true => Self::new_shield(name),
+                false => Self::new_weapon_with_rng(name, rng),
+                true => Self::new_shield_with_rng(name, rng),
             },
             (ItemType::Weapon, None) => match is_shield {
-                false => Self::new_random_weapon(),
-                true => Self::new_random_shield(),
+                false => Self::new_random_weapon_with_rng(rng),
+                true => Self::new_random_shield_with_rng(rng),
             },
-            (ItemType::Food(n), name) => Self::new_food(name, n),
-            (ItemType::Water(n), name) => Self::new_water(name, n),
+            (ItemType::Food(n), name) => Self::new_food(name, n).reidentified(rng),
+            (ItemType::Water(n), name) => Self::new_water(name, n).reidentified(rng),
         }
     }
 
@@ -88,7 +93,15 @@ impl Item {
     /// let item = Item::new_random_with_terrain(BaseTerrain::UrbanRuins, None);
     /// ```
     pub fn new_random_with_terrain(terrain: BaseTerrain, name: Option<&str>) -> Item {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+        Self::new_random_with_terrain_and_rng(terrain, name, &mut entropy_rng())
+    }
+
+    /// [`Item::new_random_with_terrain`] driven by a caller-supplied RNG.
+    pub fn new_random_with_terrain_and_rng(
+        terrain: BaseTerrain,
+        name: Option<&str>,
+        rng: &mut impl Rng,
+    ) -> Item {
         let weights = terrain.item_weights();
 
         // Use weighted random selection based on terrain
@@ -97,28 +110,30 @@ impl Item {
         if roll < weights.weapons {
             // Generate weapon
             match name {
-                Some(n) => Self::new_weapon(n),
-                None => Self::new_random_weapon(),
+                Some(n) => Self::new_weapon_with_rng(n, rng),
+                None => Self::new_random_weapon_with_rng(rng),
             }
         } else if roll < weights.weapons + weights.shields {
             // Generate shield
             match name {
-                Some(n) => Self::new_shield(n),
-                None => Self::new_random_shield(),
+                Some(n) => Self::new_shield_with_rng(n, rng),
+                None => Self::new_random_shield_with_rng(rng),
             }
         } else {
             // Generate consumable
             match name {
-                Some(n) => Self::new_consumable(n),
-                None => Self::new_random_consumable(),
+                Some(n) => Self::new_consumable_with_rng(n, rng),
+                None => Self::new_random_consumable_with_rng(rng),
             }
         }
     }
 
     pub fn new_weapon(name: &str) -> Item {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+        Self::new_weapon_with_rng(name, &mut entropy_rng())
+    }
 
-        let rarity = ItemRarity::random();
+    pub fn new_weapon_with_rng(name: &str, rng: &mut impl Rng) -> Item {
+        let rarity = ItemRarity::random(rng);
         let attribute = Attribute::Strength;
         let (min, max) = rarity.effect_range();
         let effect = rng.random_range(min..=max);
@@ -133,39 +148,51 @@ impl Item {
             attribute,
             effect,
         )
+        .reidentified(rng)
     }
 
     pub fn new_random_weapon() -> Item {
-        let name = generate_weapon_name();
-        Item::new_weapon(name.as_str())
+        Self::new_random_weapon_with_rng(&mut entropy_rng())
+    }
+
+    pub fn new_random_weapon_with_rng(rng: &mut impl Rng) -> Item {
+        let name = generate_weapon_name(rng);
+        Item::new_weapon_with_rng(name.as_str(), rng)
     }
 
     pub fn new_consumable(name: &str) -> Item {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+        Self::new_consumable_with_rng(name, &mut entropy_rng())
+    }
 
-        let rarity = ItemRarity::random();
-        let attribute = Attribute::random();
+    pub fn new_consumable_with_rng(name: &str, rng: &mut impl Rng) -> Item {
+        let rarity = ItemRarity::random(rng);
+        let attribute = Attribute::random(rng);
         let (min, max) = rarity.effect_range();
         let effect = rng.random_range(min..=max);
 
-        Item::new(name, ItemType::Consumable, rarity, 1, attribute, effect)
+        Item::new(name, ItemType::Consumable, rarity, 1, attribute, effect).reidentified(rng)
     }
 
     pub fn new_random_consumable() -> Item {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
-        let rarity = ItemRarity::random();
-        let attribute = Attribute::random();
+        Self::new_random_consumable_with_rng(&mut entropy_rng())
+    }
+
+    pub fn new_random_consumable_with_rng(rng: &mut impl Rng) -> Item {
+        let rarity = ItemRarity::random(rng);
+        let attribute = Attribute::random(rng);
         let name = attribute.consumable_name();
         let (min, max) = rarity.effect_range();
         let effect = rng.random_range(min..=max);
 
-        Item::new(&name, ItemType::Consumable, rarity, 1, attribute, effect)
+        Item::new(&name, ItemType::Consumable, rarity, 1, attribute, effect).reidentified(rng)
     }
 
     pub fn new_shield(name: &str) -> Item {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+        Self::new_shield_with_rng(name, &mut entropy_rng())
+    }
 
-        let rarity = ItemRarity::random();
+    pub fn new_shield_with_rng(name: &str, rng: &mut impl Rng) -> Item {
+        let rarity = ItemRarity::random(rng);
         let item_type = ItemType::Weapon;
         let attribute = Attribute::Defense;
         let (min, max) = rarity.effect_range();
@@ -173,12 +200,23 @@ impl Item {
         let (dur_min, dur_max) = rarity.shield_durability_range();
         let durability = rng.random_range(dur_min..=dur_max);
 
-        Item::new(name, item_type, rarity, durability, attribute, effect)
+        Item::new(name, item_type, rarity, durability, attribute, effect).reidentified(rng)
     }
 
     pub fn new_random_shield() -> Item {
-        let name = generate_shield_name();
-        Item::new_shield(name.as_str())
+        Self::new_random_shield_with_rng(&mut entropy_rng())
+    }
+
+    pub fn new_random_shield_with_rng(rng: &mut impl Rng) -> Item {
+        let name = generate_shield_name(rng);
+        Item::new_shield_with_rng(name.as_str(), rng)
+    }
+
+    /// Replace the entropy-derived identifier from [`Item::new`] with one
+    /// drawn from `rng`.
+    pub fn reidentified(mut self, rng: &mut impl Rng) -> Item {
+        self.identifier = crate::seeding::uuid_from_rng(rng).to_string();
+        self
     }
 
     /// Construct a Food item carrying `value` hunger-debt relief. `name` is
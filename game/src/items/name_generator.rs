@@ -19,16 +19,14 @@ const WEAPON_ADJECTIVES: &[&str] = &[
     "steel", "stone",
 ];
 
-pub fn generate_shield_name() -> String {
-    let mut rng = SmallRng::from_rng(&mut rand::rng());
-    let adjective = SHIELD_ADJECTIVES.choose(&mut rng).unwrap().to_owned();
+pub fn generate_shield_name(rng: &mut impl Rng) -> String {
+    let adjective = SHIELD_ADJECTIVES.choose(rng).unwrap().to_owned();
     format!("{} {}", adjective, "shield")
 }
 
-pub fn generate_weapon_name() -> String {
-    let mut rng = SmallRng::from_rng(&mut rand::rng());
-    let adjective = WEAPON_ADJECTIVES.choose(&mut rng).unwrap().to_owned();
-    let noun = WEAPON_NOUNS.choose(&mut rng).unwrap().to_owned();
+pub fn generate_weapon_name(rng: &mut impl Rng) -> String {
+    let adjective = WEAPON_ADJECTIVES.choose(rng).unwrap().to_owned();
+    let noun = WEAPON_NOUNS.choose(rng).unwrap().to_owned();
     format!("{} {}", adjective, noun)
 }
 
@@ -38,13 +36,13 @@ mod tests {
 
     #[test]
     fn shield_name() {
-        let name = generate_shield_name();
+        let name = generate_shield_name(&mut rand::rng());
         assert!(name.contains(" shield"));
     }
 
     #[test]
     fn weapon_name() {
-        let name = generate_weapon_name();
+        let name = generate_weapon_name(&mut rand::rng());
         assert!(name.contains(" "));
 
         let mut name = name.as_str().split(" ");
@@ -160,7 +160,7 @@ fn item_type_from_str_invalid() {
 
 #[test]
 fn random_item_type() {
-    let item_type = ItemType::random();
+    let item_type = ItemType::random(&mut rand::rng());
     assert!(matches!(
         item_type,
         ItemType::Weapon | ItemType::Consumable | ItemType::Food(_) | ItemType::Water(_)
@@ -169,7 +169,7 @@ fn random_item_type() {
 
 #[test]
 fn random_attribute() {
-    let attribute = Attribute::random();
+    let attribute = Attribute::random(&mut rand::rng());
     assert!(Attribute::iter().any(|a| a == attribute.clone()));
 }
 
@@ -218,7 +218,7 @@ fn attribute_to_consumable_name(#[case] attribute: Attribute, #[case] expected:
 fn rarity_random_distribution() {
     // Test that random() returns valid rarities
     for _ in 0..100 {
-        let rarity = ItemRarity::random();
+        let rarity = ItemRarity::random(&mut rand::rng());
         assert!(
             [
                 ItemRarity::Common,
@@ -2,12 +2,16 @@ pub mod areas;
 pub mod config;
 pub mod districts;
 pub mod events;
+pub mod gamemaker;
 pub mod games;
 pub mod items;
 pub mod messages;
+pub mod odds;
 pub mod output;
 pub mod pathfinding;
 pub mod phases;
+pub mod replay;
+pub mod seeding;
 pub mod sponsors;
 pub mod terrain;
 pub mod threats;
@@ -13,8 +13,8 @@
 
 pub use shared::ids::{AreaId, ItemId, TributeId};
 pub use shared::messages::{
-    AreaEventKind, AreaRef, CombatEngagement, CombatOutcome, GameMessage, ItemRef, MessageKind,
-    MessagePayload, MessageSource, ParsePhaseError, Phase, TributeRef,
+    AreaEventKind, AreaRef, CombatEngagement, CombatOutcome, DespawnReason, GameMessage, ItemRef,
+    Lure, MessageKind, MessagePayload, MessageSource, ParsePhaseError, Phase, TributeRef,
 };
 
 use crate::terrain::{BaseTerrain, Harshness, Visibility};
@@ -0,0 +1,354 @@
+//! Odds for the spectator betting markets.
+//!
+//! Every market is priced from the game as it stands: each living tribute
+//! gets a strength score from its blood, stamina, wounds and other
+//! afflictions, sanity, gear and living allies. Winner odds split the field
+//! by strength, first-blood odds by weakness, and survival odds compound a
+//! per-day death hazard that grows as a tribute falls behind the field.
+//! The API reprices after every phase it persists and settles bets with
+//! [`settle`] as the `TributeKilled` and `GameEnded` messages come in.
+
+use crate::games::Game;
+use crate::tributes::Tribute;
+use crate::tributes::wounds::MAX_BLOOD;
+use shared::betting::{Market, MarketOdds, OddsBoard};
+use shared::messages::MessagePayload;
+
+/// Share of every stake the house keeps.
+pub const HOUSE_MARGIN: f64 = 0.1;
+/// Lowest decimal odds offered, so even a sure thing pays something.
+pub const MIN_PAYOUT: f64 = 1.05;
+/// Chance an average tribute dies on a given day.
+const DAILY_HAZARD: f64 = 0.2;
+/// Survival markets listed on the board, in days from now. Any later day can
+/// still be priced on request.
+const BOARD_SURVIVAL_HORIZONS: [u32; 2] = [1, 3];
+
+/// How likely a tribute is to come out on top, relative to the others.
+/// Zero for the dead.
+pub fn strength(game: &Game, tribute: &Tribute) -> f64 {
+    if !tribute.is_alive() {
+        return 0.0;
+    }
+    let blood = tribute.blood as f64 / MAX_BLOOD as f64;
+    let stamina = if tribute.max_stamina == 0 {
+        0.0
+    } else {
+        tribute.stamina as f64 / tribute.max_stamina as f64
+    };
+    let sanity = tribute.effective_sanity().min(100) as f64 / 100.0;
+    let affliction_load: u32 = tribute
+        .afflictions
+        .values()
+        .map(|a| u32::from(a.severity.ordinal()) + 1)
+        .sum();
+
+    let weapons = tribute
+        .items
+        .iter()
+        .filter(|i| i.is_weapon())
+        .count()
+        .min(2);
+    let shields = tribute
+        .items
+        .iter()
+        .filter(|i| i.is_defensive())
+        .count()
+        .min(1);
+    let supplies = tribute
+        .items
+        .iter()
+        .filter(|i| i.is_consumable() || i.item_type.is_food() || i.item_type.is_water())
+        .count()
+        .min(4);
+    let gear = 1.0 + 0.3 * weapons as f64 + 0.15 * shields as f64 + 0.05 * supplies as f64;
+
+    let allies = game
+        .tributes
+        .iter()
+        .filter(|t| t.is_alive() && tribute.allies.contains(&t.id))
+        .count();
+
+    (0.25 + 0.75 * blood)
+        * (0.5 + 0.5 * stamina)
+        * (0.5 + 0.5 * sanity)
+        * 0.85_f64.powi(affliction_load as i32)
+        * gear
+        * (1.0 + 0.15 * allies as f64)
+}
+
+/// Decimal odds for a win probability, after the house margin.
+pub fn payout_for(probability: f64) -> f64 {
+    if probability <= 0.0 {
+        return MIN_PAYOUT;
+    }
+    ((1.0 - HOUSE_MARGIN) / probability).max(MIN_PAYOUT)
+}
+
+impl Game {
+    /// Price a market, or `None` while it cannot be bet on: unknown or dead
+    /// tribute, finished game, first blood already spilled, or a survival
+    /// day that has already begun.
+    pub fn price(&self, market: &Market) -> Option<MarketOdds> {
+        if self.status == shared::GameStatus::Finished {
+            return None;
+        }
+        let tribute = self
+            .tributes
+            .iter()
+            .find(|t| t.identifier == market.tribute() && t.is_alive())?;
+        let strengths: Vec<f64> = self
+            .tributes
+            .iter()
+            .filter(|t| t.is_alive())
+            .map(|t| strength(self, t).max(f64::EPSILON))
+            .collect();
+        let own = strength(self, tribute).max(f64::EPSILON);
+
+        let probability = match market {
+            Market::Winner { .. } => own / strengths.iter().sum::<f64>(),
+            Market::FirstBlood { .. } => {
+                if self.tributes.iter().any(|t| !t.is_alive()) {
+                    return None;
+                }
+                (1.0 / own) / strengths.iter().map(|s| 1.0 / s).sum::<f64>()
+            }
+            Market::SurvivesToDay { day, .. } => {
+                let today = self.day.unwrap_or(0);
+                if *day <= today {
+                    return None;
+                }
+                let mean = strengths.iter().sum::<f64>() / strengths.len() as f64;
+                let hazard = (DAILY_HAZARD * mean / own).clamp(0.02, 0.9);
+                (1.0 - hazard).powi((day - today) as i32)
+            }
+        };
+
+        Some(MarketOdds {
+            market: market.clone(),
+            tribute_name: tribute.name.clone(),
+            probability,
+            payout: payout_for(probability),
+        })
+    }
+
+    /// Every open market, winner first, then first blood and survival.
+    pub fn odds_board(&self) -> OddsBoard {
+        let today = self.day.unwrap_or(0);
+        let living = self.tributes.iter().filter(|t| t.is_alive());
+        let markets = living
+            .flat_map(|t| {
+                let tribute = t.identifier.clone();
+                [
+                    Market::Winner {
+                        tribute: tribute.clone(),
+                    },
+                    Market::FirstBlood {
+                        tribute: tribute.clone(),
+                    },
+                ]
+                .into_iter()
+                .chain(BOARD_SURVIVAL_HORIZONS.map(|days| Market::SurvivesToDay {
+                    tribute: tribute.clone(),
+                    day: today + days,
+                }))
+            })
+            .filter_map(|market| self.price(&market))
+            .collect();
+        OddsBoard {
+            day: today,
+            markets,
+        }
+    }
+}
+
+/// How a message from day `day` settles a bet on `market`: `Some(true)` if
+/// it won, `Some(false)` if it lost, `None` if it is still open. Messages
+/// must be fed in the order they were emitted, stopping at the first
+/// `Some`.
+pub fn settle(market: &Market, payload: &MessagePayload, day: u32) -> Option<bool> {
+    let tribute = market.tribute();
+    let is = |identifier: &shared::ids::TributeId| identifier.as_str() == tribute;
+    match (market, payload) {
+        (Market::Winner { .. }, MessagePayload::GameEnded { winner }) => {
+            Some(winner.as_ref().is_some_and(|w| is(&w.identifier)))
+        }
+        (Market::Winner { .. }, MessagePayload::TributeKilled { victim, .. }) => {
+            is(&victim.identifier).then_some(false)
+        }
+        // Whoever dies first settles every first-blood bet.
+        (Market::FirstBlood { .. }, MessagePayload::TributeKilled { victim, .. }) => {
+            Some(is(&victim.identifier))
+        }
+        (Market::SurvivesToDay { day: target, .. }, _) if day >= *target => Some(true),
+        (Market::SurvivesToDay { .. }, MessagePayload::TributeKilled { victim, .. }) => {
+            is(&victim.identifier).then_some(false)
+        }
+        (Market::SurvivesToDay { .. }, MessagePayload::GameEnded { winner }) => winner
+            .as_ref()
+            .is_some_and(|w| is(&w.identifier))
+            .then_some(true),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::Item;
+    use shared::messages::TributeRef;
+
+    fn game_with(tributes: Vec<Tribute>) -> Game {
+        Game {
+            tributes,
+            ..Game::default()
+        }
+    }
+
+    fn tref(tribute: &Tribute) -> TributeRef {
+        TributeRef {
+            identifier: tribute.identifier.clone().into(),
+            name: tribute.name.clone(),
+        }
+    }
+
+    #[test]
+    fn armed_healthy_tributes_are_favourites() {
+        let mut armed = Tribute::new("Armed".to_string(), None, None);
+        armed.items.push(Item::new_weapon("sword"));
+        let mut bleeding = Tribute::new("Bleeding".to_string(), None, None);
+        bleeding.blood = MAX_BLOOD / 5;
+        let game = game_with(vec![armed.clone(), bleeding.clone()]);
+
+        let win = |t: &Tribute| {
+            game.price(&Market::Winner {
+                tribute: t.identifier.clone(),
+            })
+            .unwrap()
+        };
+        let (armed_odds, bleeding_odds) = (win(&armed), win(&bleeding));
+        assert!(armed_odds.probability > bleeding_odds.probability);
+        assert!(armed_odds.payout < bleeding_odds.payout);
+        assert!((armed_odds.probability + bleeding_odds.probability - 1.0).abs() < 1e-9);
+
+        let first_blood = game
+            .price(&Market::FirstBlood {
+                tribute: bleeding.identifier.clone(),
+            })
+            .unwrap();
+        assert!(first_blood.probability > 0.5);
+    }
+
+    #[test]
+    fn closed_markets_have_no_price() {
+        let alive = Tribute::new("Alive".to_string(), None, None);
+        let mut dead = Tribute::new("Dead".to_string(), None, None);
+        dead.dies();
+        let mut game = game_with(vec![alive.clone(), dead.clone()]);
+        game.day = Some(2);
+
+        let tribute = alive.identifier.clone();
+        assert!(
+            game.price(&Market::Winner {
+                tribute: dead.identifier.clone()
+            })
+            .is_none()
+        );
+        assert!(
+            game.price(&Market::FirstBlood {
+                tribute: tribute.clone()
+            })
+            .is_none()
+        );
+        assert!(
+            game.price(&Market::SurvivesToDay {
+                tribute: tribute.clone(),
+                day: 2
+            })
+            .is_none()
+        );
+        let later = |day| {
+            game.price(&Market::SurvivesToDay {
+                tribute: tribute.clone(),
+                day,
+            })
+            .unwrap()
+            .probability
+        };
+        assert!(later(3) > later(5));
+    }
+
+    #[test]
+    fn kills_and_the_final_message_settle_bets() {
+        let winner = Tribute::new("Winner".to_string(), None, None);
+        let loser = Tribute::new("Loser".to_string(), None, None);
+        let killed = MessagePayload::TributeKilled {
+            victim: tref(&loser),
+            killer: Some(tref(&winner)),
+            cause: shared::afflictions::DeathCause::Tribute("spear".into()),
+        };
+        let ended = MessagePayload::GameEnded {
+            winner: Some(tref(&winner)),
+        };
+        let on = |t: &Tribute| t.identifier.clone();
+
+        assert_eq!(
+            settle(
+                &Market::FirstBlood {
+                    tribute: on(&loser)
+                },
+                &killed,
+                1
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            settle(
+                &Market::FirstBlood {
+                    tribute: on(&winner)
+                },
+                &killed,
+                1
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            settle(
+                &Market::Winner {
+                    tribute: on(&loser)
+                },
+                &killed,
+                1
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            settle(
+                &Market::Winner {
+                    tribute: on(&winner)
+                },
+                &killed,
+                1
+            ),
+            None
+        );
+        assert_eq!(
+            settle(
+                &Market::Winner {
+                    tribute: on(&winner)
+                },
+                &ended,
+                2
+            ),
+            Some(true)
+        );
+
+        let survives = Market::SurvivesToDay {
+            tribute: on(&winner),
+            day: 5,
+        };
+        assert_eq!(settle(&survives, &killed, 3), None);
+        assert_eq!(settle(&survives, &ended, 3), Some(true));
+        assert_eq!(settle(&survives, &killed, 5), Some(true));
+    }
+}
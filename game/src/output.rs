@@ -2,7 +2,7 @@ use crate::items::Item;
 use crate::threats::animals::Animal;
 use indefinite::indefinite;
 use indefinite::indefinite_capitalized;
-use shared::afflictions::TrapKind;
+use shared::afflictions::{Contagion, TrapKind};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
@@ -36,6 +36,10 @@ pub enum GameOutput<'a> {
     TributeHide(&'a str),
     TributeTravel(&'a str, &'a str, &'a str),
     TributeTakeItem(&'a str, &'a str),
+    /// A dead tribute's gear is left on their body: tribute, area.
+    TributeCorpseLeft(&'a str, &'a str),
+    /// Looter, item, and whose body it came off.
+    TributeLootsCorpse(&'a str, &'a str, &'a str),
     TributeCannotUseItem(&'a str, &'a str),
     TributeUseItem(&'a str, &'a Item),
     TributeTravelTooTired(&'a str, &'a str),
@@ -90,6 +94,27 @@ pub enum GameOutput<'a> {
     AreaEvent(&'a str, &'a str),
     AreaClose(&'a str),
     AreaOpen(&'a str),
+    /// Area name, previous weather, new weather.
+    WeatherChanged(&'a str, &'a str, &'a str),
+    FireballStrike(&'a str),
+    /// Area name, animal plural, member count.
+    MuttSwarmSpawned(&'a str, &'a str, u8),
+    /// Tribute name, animal plural, blood lost.
+    MuttSwarmAttack(&'a str, &'a str, u32),
+    /// Area name, animal plural.
+    MuttSwarmDespawned(&'a str, &'a str),
+    /// Comma-joined sealed areas, comma-joined released areas.
+    ForceFieldShifted(&'a str, &'a str),
+    /// Tribute name, area name, blood lost.
+    AreaSealDamage(&'a str, &'a str, u32),
+    FeastAnnounced(&'a str),
+    FeastOver(&'a str),
+    /// Tribute name, contagion, carrier name (`None` for patient zero).
+    ContagionInfected(&'a str, Contagion, Option<&'a str>),
+    /// Tribute name, contagion.
+    ContagionTransformed(&'a str, Contagion),
+    /// Vampire name.
+    SunlightBurn(&'a str),
     TrappedInArea(&'a str, &'a str),
     DiedInArea(&'a str, &'a str),
     TributeDeath(&'a str),
@@ -101,6 +126,10 @@ pub enum GameOutput<'a> {
     AllianceFormed(&'a str, &'a str, &'a str), // tribute_a, tribute_b, deciding factor
     BetrayalTriggered(&'a str, &'a str),       // betrayer, victim
     TrustShockBreak(&'a str),                  // shaken tribute
+    AllianceDissolved(&'a str),                // leader
+    AllianceSplit(&'a str, &'a str),           // departing tribute, leader
+    StashDeposit(&'a str, &'a str),            // tribute, item
+    StashWithdrawal(&'a str, &'a str),         // tribute, item
 }
 
 impl<'a> Display for GameOutput<'a> {
@@ -174,6 +203,17 @@ impl<'a> Display for GameOutput<'a> {
                 let object = indefinite(item);
                 write!(f, "🔨 {} takes {}", tribute, object)
             }
+            GameOutput::TributeCorpseLeft(tribute, area) => {
+                write!(
+                    f,
+                    "🪦 {}'s body lies in {}, still carrying gear",
+                    tribute, area
+                )
+            }
+            GameOutput::TributeLootsCorpse(tribute, item, corpse) => {
+                let object = indefinite(item);
+                write!(f, "🦴 {} takes {} off {}'s body", tribute, object, corpse)
+            }
             GameOutput::TributeCannotUseItem(tribute, item) => {
                 let object = indefinite(item);
                 write!(f, "❌ {} cannot use {}", tribute, object)
@@ -405,6 +445,103 @@ impl<'a> Display for GameOutput<'a> {
                 let area_name = area.replace("The ", "");
                 write!(f, "=== 🔔 The {} is habitable again ===", area_name)
             }
+            GameOutput::WeatherChanged(area, from, to) => {
+                let area_name = area.replace("The ", "");
+                write!(
+                    f,
+                    "=== 🌦️ The weather in {} turns from {} to {} ===",
+                    area_name, from, to
+                )
+            }
+            GameOutput::FireballStrike(area) => {
+                let area_name = area.replace("The ", "");
+                write!(
+                    f,
+                    "=== 🔥 The Gamemakers rain fire on the {} ===",
+                    area_name
+                )
+            }
+            GameOutput::MuttSwarmSpawned(area, animals, members) => {
+                let area_name = area.replace("The ", "");
+                write!(
+                    f,
+                    "=== 🐺 The Gamemakers release {} mutt {} into the {} ===",
+                    members, animals, area_name
+                )
+            }
+            GameOutput::MuttSwarmAttack(tribute, animals, damage) => {
+                write!(
+                    f,
+                    "🐺 Mutt {} maul {} (-{} blood)",
+                    animals, tribute, damage
+                )
+            }
+            GameOutput::MuttSwarmDespawned(area, animals) => {
+                let area_name = area.replace("The ", "");
+                write!(
+                    f,
+                    "=== 🐺 The mutt {} withdraw from the {} ===",
+                    animals, area_name
+                )
+            }
+            GameOutput::ForceFieldShifted(sealed, released) => {
+                match (sealed.is_empty(), released.is_empty()) {
+                    (false, true) => {
+                        write!(f, "=== 🛡️ The force field shifts, sealing {} ===", sealed)
+                    }
+                    (true, _) => write!(f, "=== 🛡️ The force field lifts from {} ===", released),
+                    (false, false) => write!(
+                        f,
+                        "=== 🛡️ The force field shifts, sealing {} and releasing {} ===",
+                        sealed, released
+                    ),
+                }
+            }
+            GameOutput::AreaSealDamage(tribute, area, damage) => {
+                let area_name = area.replace("The ", "");
+                write!(
+                    f,
+                    "⚡ {} is scorched by the force field in the {} (-{} blood)",
+                    tribute, area_name, damage
+                )
+            }
+            GameOutput::FeastAnnounced(area) => {
+                let area_name = area.replace("The ", "");
+                write!(
+                    f,
+                    "=== 🍖 The Gamemakers announce a feast in the {} ===",
+                    area_name
+                )
+            }
+            GameOutput::FeastOver(area) => {
+                let area_name = area.replace("The ", "");
+                write!(f, "=== 🍖 The feast in the {} is over ===", area_name)
+            }
+            GameOutput::ContagionInfected(tribute, contagion, carrier) => {
+                let how = match contagion {
+                    Contagion::Lycanthropy => "a werewolf's bite",
+                    Contagion::Vampirism => "a vampire's bite",
+                    Contagion::ZombiePlague => "a zombie's bite",
+                };
+                match carrier {
+                    Some(carrier) => {
+                        write!(f, "🩸 {} carries {} away from {}", tribute, how, carrier)
+                    }
+                    None => write!(f, "🩸 The Gamemakers have given {} {}", tribute, how),
+                }
+            }
+            GameOutput::ContagionTransformed(tribute, contagion) => match contagion {
+                Contagion::Lycanthropy => {
+                    write!(f, "🐺 {} has become a werewolf", tribute)
+                }
+                Contagion::Vampirism => write!(f, "🧛 {} has become a vampire", tribute),
+                Contagion::ZombiePlague => {
+                    write!(f, "🧟 {} has risen as a zombie", tribute)
+                }
+            },
+            GameOutput::SunlightBurn(tribute) => {
+                write!(f, "☀️ {} burns in the sunlight", tribute)
+            }
             GameOutput::TrappedInArea(tribute, area) => {
                 let area_name = area.replace("The ", "");
                 write!(f, "💥 {} is trapped in the {}.", tribute, area_name)
@@ -445,6 +582,22 @@ impl<'a> Display for GameOutput<'a> {
                     shaken
                 )
             }
+            GameOutput::AllianceDissolved(leader) => {
+                write!(f, "{}'s alliance has dissolved.", leader)
+            }
+            GameOutput::AllianceSplit(tribute, leader) => {
+                write!(f, "{} splits from {}'s alliance.", tribute, leader)
+            }
+            GameOutput::StashDeposit(tribute, item) => {
+                write!(f, "🎒 {} adds {} to the alliance's stash.", tribute, item)
+            }
+            GameOutput::StashWithdrawal(tribute, item) => {
+                write!(
+                    f,
+                    "🎒 {} takes {} from the alliance's stash.",
+                    tribute, item
+                )
+            }
         }
     }
 }
@@ -477,6 +630,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_alliance_dissolved_and_split() {
+        assert_eq!(
+            GameOutput::AllianceDissolved("Cato").to_string(),
+            "Cato's alliance has dissolved."
+        );
+        assert_eq!(
+            GameOutput::AllianceSplit("Glimmer", "Cato").to_string(),
+            "Glimmer splits from Cato's alliance."
+        );
+    }
+
     #[test]
     fn display_tribute_wakes_from_incident() {
         let s = GameOutput::TributeWakesFromIncident(
@@ -86,9 +86,9 @@ pub fn derive_light_level(phase: Phase, biome: BaseTerrain, weather: Weather) ->
         Phase::Night => LightLevel::Dark,
     };
     let after_weather = match weather {
-        Weather::Clear | Weather::Heatwave => baseline,
-        Weather::HeavyRain => darken(baseline),
-        Weather::Blizzard => darken(darken(baseline)),
+        Weather::Clear | Weather::Heatwave | Weather::LightRain | Weather::Snow => baseline,
+        Weather::Fog | Weather::HeavyRain => darken(baseline),
+        Weather::Storm | Weather::Blizzard => darken(darken(baseline)),
     };
     match biome {
         BaseTerrain::Jungle | BaseTerrain::Forest => darken(after_weather),
@@ -161,10 +161,10 @@ fn candidate_probabilities(
         (Day, Tundra, _) => 0.05,
         // Blizzard anywhere
         (_, _, Blizzard) => 0.20,
-        // Spec §6.2: Night + temperate + storm → small chance mild
-        // Frozen. Treat HeavyRain as the storm proxy for now.
+        (Night, _, Snow) => 0.10,
+        // Spec §6.2: Night + temperate + storm → small chance mild Frozen.
         (Night, Mountains | Highlands, _) => 0.15,
-        (Night, _, HeavyRain) => 0.05,
+        (Night, _, HeavyRain | Storm) => 0.05,
         _ => 0.0,
     };
     if cold > 0.0 {
@@ -188,8 +188,8 @@ fn candidate_probabilities(
     // ---- Sickness from exposure ------------------------------------
     let sick = match (phase, biome, weather) {
         // Spec §6.2: Day + jungle + storm → moderate chance Sick
-        (Day, Jungle, HeavyRain) => 0.25,
-        (_, Wetlands, HeavyRain) => 0.15,
+        (Day, Jungle, HeavyRain | Storm) => 0.25,
+        (_, Wetlands, HeavyRain | Storm) => 0.15,
         (Night, Jungle | Wetlands, _) => 0.05,
         _ => 0.0,
     };
@@ -0,0 +1,508 @@
+//! Rebuilding game state from the event log.
+//!
+//! The engine keeps its state in [`Game`] and the API persists it row by
+//! row; the message log is written alongside as a record of what happened.
+//! [`LogView`] folds that log back into the state it implies — where each
+//! tribute was last seen, who has died, what they carry, who they are allied
+//! with and what ails them — and [`LogView::check`] compares it with a game
+//! to report where the two have drifted apart.
+//!
+//! The log records changes, not starting state, so the view only knows what
+//! some message has said: a tribute's starting gear, for instance, is never
+//! mentioned. Whatever the view doesn't know is never reported as drift.
+
+use crate::games::Game;
+use crate::messages::{GameMessage, Phase};
+use serde::{Deserialize, Serialize};
+use shared::combat_beat::{SwingOutcome, WearOutcomeReport};
+use shared::messages::{
+    CombatOutcome, DrinkSource, ItemRef, MessagePayload, SleepIncidentKind, TributeRef,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// What the log says about one tribute.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TributeView {
+    pub name: String,
+    /// Area the tribute was last seen in; `None` until a message places
+    /// them, or after one moves them somewhere it doesn't name.
+    pub area: Option<String>,
+    pub dead: bool,
+    /// Items the log has put in the tribute's hands and not since taken out
+    /// of them. Using an item may use it up, so a used item is dropped from
+    /// here too.
+    pub items: BTreeSet<String>,
+    pub allies: BTreeSet<String>,
+    /// Affliction kind to its last logged severity, if any was logged.
+    pub afflictions: BTreeMap<String, Option<String>>,
+}
+
+/// Game state as rebuilt from the event log, keyed by tribute identifier.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LogView {
+    pub tributes: BTreeMap<String, TributeView>,
+    /// Day and phase of the last message applied.
+    pub through: Option<(u32, Phase)>,
+}
+
+/// One way a game's state disagrees with its log.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Drift {
+    /// The log mentions a tribute the game doesn't have.
+    UnknownTribute { tribute: String },
+    /// The log has the tribute killed but the game has them alive.
+    AliveAfterDeath { tribute: String },
+    /// The game has the tribute dead but the log never killed them.
+    UnloggedDeath { tribute: String },
+    Position {
+        tribute: String,
+        logged: String,
+        actual: String,
+    },
+    /// The log left an item with the tribute that the game doesn't have.
+    MissingItem { tribute: String, item: String },
+    /// The tribute's living allies differ.
+    Allies {
+        tribute: String,
+        logged: Vec<String>,
+        actual: Vec<String>,
+    },
+    /// The log gave the tribute an affliction the game doesn't have.
+    MissingAffliction { tribute: String, affliction: String },
+}
+
+impl LogView {
+    /// Fold `messages`, oldest first, into a view.
+    pub fn replay<'a>(messages: impl IntoIterator<Item = &'a GameMessage>) -> Self {
+        let mut view = LogView::default();
+        for message in messages {
+            view.apply(message);
+        }
+        view
+    }
+
+    /// Fold one more message into the view.
+    pub fn apply(&mut self, message: &GameMessage) {
+        self.through = Some((message.game_day, message.phase));
+        match &message.payload {
+            MessagePayload::TributeKilled { victim, .. } => self.tribute(victim).dead = true,
+            MessagePayload::TributeBledOut { tribute } => self.tribute(tribute).dead = true,
+            MessagePayload::TributeDiedWhileTrapped { tribute, .. } => {
+                self.tribute_by_id(tribute).dead = true
+            }
+            MessagePayload::FireballStrike { victims, .. } => {
+                for victim in victims {
+                    self.tribute(victim).dead = true;
+                }
+            }
+            MessagePayload::MuttSwarmAttack {
+                victim,
+                killed: true,
+                ..
+            } => self.tribute(victim).dead = true,
+            MessagePayload::Combat(engagement) => match engagement.outcome {
+                CombatOutcome::Killed => self.tribute(&engagement.target).dead = true,
+                // Fleeing may or may not leave the area.
+                CombatOutcome::TargetFled => self.tribute(&engagement.target).area = None,
+                CombatOutcome::AttackerFled => self.tribute(&engagement.attacker).area = None,
+                CombatOutcome::Wounded | CombatOutcome::Stalemate => {}
+            },
+            MessagePayload::CombatSwing(beat) => {
+                for wear in &beat.wear {
+                    if wear.outcome == WearOutcomeReport::Broken {
+                        self.take_item(&wear.owner, &wear.item);
+                    }
+                }
+                match beat.outcome {
+                    SwingOutcome::Kill { .. } => self.tribute(&beat.target).dead = true,
+                    SwingOutcome::AttackerDied { .. }
+                    | SwingOutcome::FumbleDeath { .. }
+                    | SwingOutcome::Suicide { .. } => self.tribute(&beat.attacker).dead = true,
+                    _ => {}
+                }
+            }
+
+            MessagePayload::TributeMoved { tribute, to, .. } => {
+                self.tribute(tribute).area = Some(to.identifier.to_string())
+            }
+            MessagePayload::TributeHidden { tribute, area }
+            | MessagePayload::ShelterSought { tribute, area, .. }
+            | MessagePayload::Foraged { tribute, area, .. } => {
+                self.tribute(tribute).area = Some(area.identifier.to_string())
+            }
+
+            MessagePayload::ItemFound {
+                tribute,
+                item,
+                area,
+            }
+            | MessagePayload::CorpseLooted {
+                looter: tribute,
+                item,
+                area,
+                ..
+            } => {
+                let view = self.tribute(tribute);
+                view.area = Some(area.identifier.to_string());
+                view.items.insert(item.identifier.to_string());
+            }
+            MessagePayload::SponsorGift {
+                recipient, item, ..
+            } => {
+                self.tribute(recipient)
+                    .items
+                    .insert(item.identifier.to_string());
+            }
+            MessagePayload::ItemDropped {
+                tribute,
+                item,
+                area,
+            } => {
+                self.take_item(tribute, item);
+                self.tribute(tribute).area = Some(area.identifier.to_string());
+            }
+            MessagePayload::ItemUsed { tribute, item }
+            | MessagePayload::Ate { tribute, item, .. } => self.take_item(tribute, item),
+            MessagePayload::Drank {
+                tribute,
+                source: DrinkSource::Item { item },
+                ..
+            } => self.take_item(tribute, item),
+            MessagePayload::CorpseLeft { tribute, items, .. } => {
+                for item in items {
+                    self.take_item(tribute, item);
+                }
+            }
+            MessagePayload::SleepIncident { tribute, kind, .. } => match kind {
+                // The log doesn't say what was taken, or where to.
+                SleepIncidentKind::Theft => self.tribute(tribute).items.clear(),
+                SleepIncidentKind::Relocation => self.tribute(tribute).area = None,
+                _ => {}
+            },
+
+            MessagePayload::AllianceFormed { members } => {
+                for a in members {
+                    for b in members {
+                        if a.identifier != b.identifier {
+                            self.tribute(a).allies.insert(b.identifier.to_string());
+                        }
+                    }
+                }
+            }
+            MessagePayload::AllianceDissolved { members, .. } => {
+                for a in members {
+                    for b in members {
+                        self.tribute(a).allies.remove(b.identifier.as_ref());
+                    }
+                }
+            }
+            MessagePayload::BetrayalTriggered {
+                betrayer: a,
+                victim: b,
+            }
+            | MessagePayload::TrustShockBreak {
+                tribute: a,
+                partner: b,
+            } => {
+                self.tribute(a).allies.remove(b.identifier.as_ref());
+                self.tribute(b).allies.remove(a.identifier.as_ref());
+            }
+
+            MessagePayload::AfflictionAcquired {
+                tribute_id,
+                affliction,
+                severity,
+            } => {
+                self.tribute_by_id(tribute_id)
+                    .afflictions
+                    .insert(affliction.clone(), Some(severity.clone()));
+            }
+            MessagePayload::AfflictionProgressed {
+                tribute_id,
+                affliction,
+                to_severity,
+                ..
+            } => {
+                self.tribute_by_id(tribute_id)
+                    .afflictions
+                    .insert(affliction.clone(), Some(to_severity.clone()));
+            }
+            MessagePayload::AfflictionCascaded {
+                tribute_id,
+                to_affliction,
+                ..
+            } => {
+                self.tribute_by_id(tribute_id)
+                    .afflictions
+                    .entry(to_affliction.clone())
+                    .or_insert(None);
+            }
+            MessagePayload::AfflictionHealed {
+                tribute_id,
+                affliction,
+            } => {
+                self.tribute_by_id(tribute_id)
+                    .afflictions
+                    .remove(affliction);
+            }
+            _ => {}
+        }
+    }
+
+    /// Every way `game` disagrees with this view, tribute by tribute.
+    /// Items, allies and afflictions are only compared for the living; the
+    /// dead have left theirs behind.
+    pub fn check(&self, game: &Game) -> Vec<Drift> {
+        let mut drift = Vec::new();
+        for identifier in self.tributes.keys() {
+            if !game.tributes.iter().any(|t| &t.identifier == identifier) {
+                drift.push(Drift::UnknownTribute {
+                    tribute: identifier.clone(),
+                });
+            }
+        }
+
+        let living: BTreeSet<String> = game
+            .living_tributes()
+            .iter()
+            .map(|t| t.identifier.clone())
+            .collect();
+        for tribute in &game.tributes {
+            let identifier = &tribute.identifier;
+            let view = self.tributes.get(identifier);
+            let logged_dead = view.is_some_and(|v| v.dead);
+            match (tribute.is_alive(), logged_dead) {
+                (true, true) => drift.push(Drift::AliveAfterDeath {
+                    tribute: identifier.clone(),
+                }),
+                (false, false) => drift.push(Drift::UnloggedDeath {
+                    tribute: identifier.clone(),
+                }),
+                _ => {}
+            }
+            let Some(view) = view else { continue };
+            if !tribute.is_alive() {
+                continue;
+            }
+
+            if let Some(area) = &view.area
+                && *area != tribute.area.to_string()
+            {
+                drift.push(Drift::Position {
+                    tribute: identifier.clone(),
+                    logged: area.clone(),
+                    actual: tribute.area.to_string(),
+                });
+            }
+            for item in &view.items {
+                if !tribute.items.iter().any(|i| &i.identifier == item) {
+                    drift.push(Drift::MissingItem {
+                        tribute: identifier.clone(),
+                        item: item.clone(),
+                    });
+                }
+            }
+
+            let logged: Vec<String> = view
+                .allies
+                .iter()
+                .filter(|a| living.contains(*a))
+                .cloned()
+                .collect();
+            let actual: BTreeSet<String> = tribute
+                .allies
+                .iter()
+                .map(|a| a.to_string())
+                .filter(|a| living.contains(a))
+                .collect();
+            let actual: Vec<String> = actual.into_iter().collect();
+            if logged != actual {
+                drift.push(Drift::Allies {
+                    tribute: identifier.clone(),
+                    logged,
+                    actual,
+                });
+            }
+
+            for affliction in view.afflictions.keys() {
+                if !tribute
+                    .afflictions
+                    .values()
+                    .any(|a| a.kind.to_string() == *affliction)
+                {
+                    drift.push(Drift::MissingAffliction {
+                        tribute: identifier.clone(),
+                        affliction: affliction.clone(),
+                    });
+                }
+            }
+        }
+        drift
+    }
+
+    fn tribute(&mut self, tribute: &TributeRef) -> &mut TributeView {
+        let view = self.tribute_by_id(tribute.identifier.as_ref());
+        if view.name.is_empty() {
+            view.name = tribute.name.clone();
+        }
+        view
+    }
+
+    /// For the payloads that carry a bare identifier and no name.
+    fn tribute_by_id(&mut self, identifier: &str) -> &mut TributeView {
+        self.tributes.entry(identifier.to_string()).or_default()
+    }
+
+    fn take_item(&mut self, tribute: &TributeRef, item: &ItemRef) {
+        self.tribute(tribute).items.remove(item.identifier.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::Item;
+    use crate::messages::MessageSource;
+    use crate::tributes::Tribute;
+    use shared::messages::AreaRef;
+
+    fn tref(tribute: &Tribute) -> TributeRef {
+        TributeRef {
+            identifier: tribute.identifier.clone().into(),
+            name: tribute.name.clone(),
+        }
+    }
+
+    fn aref(name: &str) -> AreaRef {
+        AreaRef {
+            identifier: name.into(),
+            name: name.to_string(),
+        }
+    }
+
+    fn iref(item: &Item) -> ItemRef {
+        ItemRef {
+            identifier: item.identifier.clone().into(),
+            name: item.name.clone(),
+        }
+    }
+
+    fn message(payload: MessagePayload) -> GameMessage {
+        GameMessage::new(
+            MessageSource::Game("game".to_string()),
+            1,
+            Phase::Day,
+            0,
+            0,
+            "game".to_string(),
+            String::new(),
+            payload,
+        )
+    }
+
+    #[test]
+    fn replay_tracks_tributes_through_the_log() {
+        let a = Tribute::new("A".to_string(), None, None);
+        let b = Tribute::new("B".to_string(), None, None);
+        let knife = Item::new_weapon("knife");
+        let log = [
+            message(MessagePayload::TributeMoved {
+                tribute: tref(&a),
+                from: aref("Cornucopia"),
+                to: aref("Sector 1"),
+            }),
+            message(MessagePayload::ItemFound {
+                tribute: tref(&a),
+                item: iref(&knife),
+                area: aref("Sector 1"),
+            }),
+            message(MessagePayload::AllianceFormed {
+                members: vec![tref(&a), tref(&b)],
+            }),
+            message(MessagePayload::AfflictionAcquired {
+                tribute_id: a.identifier.clone(),
+                affliction: "burned".to_string(),
+                severity: "mild".to_string(),
+            }),
+            message(MessagePayload::TributeKilled {
+                victim: tref(&b),
+                killer: None,
+                cause: shared::afflictions::DeathCause::Fire,
+            }),
+        ];
+
+        let view = LogView::replay(&log);
+        let seen = &view.tributes[&a.identifier];
+        assert_eq!(seen.name, "A");
+        assert_eq!(seen.area.as_deref(), Some("Sector 1"));
+        assert!(!seen.dead);
+        assert!(seen.items.contains(&knife.identifier));
+        assert!(seen.allies.contains(&b.identifier));
+        assert_eq!(seen.afflictions["burned"].as_deref(), Some("mild"));
+        assert!(view.tributes[&b.identifier].dead);
+        assert_eq!(view.through, Some((1, Phase::Day)));
+
+        let dropped = message(MessagePayload::ItemDropped {
+            tribute: tref(&a),
+            item: iref(&knife),
+            area: aref("Sector 2"),
+        });
+        let view = LogView::replay(log.iter().chain([&dropped]));
+        let seen = &view.tributes[&a.identifier];
+        assert!(seen.items.is_empty());
+        assert_eq!(seen.area.as_deref(), Some("Sector 2"));
+    }
+
+    #[test]
+    fn check_reports_drift() {
+        let mut a = Tribute::new("A".to_string(), None, None);
+        let b = Tribute::new("B".to_string(), None, None);
+        let mut c = Tribute::new("C".to_string(), None, None);
+        let knife = Item::new_weapon("knife");
+        let log = [
+            message(MessagePayload::SponsorGift {
+                recipient: tref(&a),
+                item: iref(&knife),
+                donor: "Sponsor".to_string(),
+            }),
+            message(MessagePayload::TributeKilled {
+                victim: tref(&b),
+                killer: None,
+                cause: shared::afflictions::DeathCause::Fire,
+            }),
+        ];
+        let view = LogView::replay(&log);
+
+        // The gift never reached A, B is alive and C died off the record.
+        c.dies();
+        let game = Game {
+            tributes: vec![a.clone(), b.clone(), c.clone()],
+            ..Game::default()
+        };
+        let drift = view.check(&game);
+        assert!(drift.contains(&Drift::MissingItem {
+            tribute: a.identifier.clone(),
+            item: knife.identifier.clone(),
+        }));
+        assert!(drift.contains(&Drift::AliveAfterDeath {
+            tribute: b.identifier.clone(),
+        }));
+        assert!(drift.contains(&Drift::UnloggedDeath {
+            tribute: c.identifier.clone(),
+        }));
+        assert_eq!(drift.len(), 3);
+
+        a.items.push(knife);
+        let game = Game {
+            tributes: vec![a],
+            ..Game::default()
+        };
+        assert_eq!(
+            view.check(&game),
+            [Drift::UnknownTribute {
+                tribute: b.identifier.clone(),
+            }]
+        );
+    }
+}
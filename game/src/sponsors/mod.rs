@@ -291,11 +291,10 @@ pub fn resolve_gifts(
                 .position(|s| s.id == sponsor.id)
                 .unwrap();
 
-            let Some(item) = pick_gift(&game.sponsors[sponsor_idx], rng) else {
+            let Some((item, cost)) = pick_gift(&game.sponsors[sponsor_idx], rng) else {
                 continue;
             };
 
-            let cost = item_cost(&item);
             if cost > game.sponsors[sponsor_idx].budget_remaining {
                 continue;
             }
@@ -323,13 +322,13 @@ pub fn resolve_gifts(
     results
 }
 
-/// Pick a gift item for a sponsor, biased by archetype preferences.
-/// Returns `None` if the sponsor can't afford anything.
-fn pick_gift(sponsor: &shared::sponsors::Sponsor, rng: &mut impl Rng) -> Option<Item> {
-    let catalog = gift_catalog();
+/// Pick a gift item for a sponsor, biased by archetype preferences, with
+/// its cost. Returns `None` if the sponsor can't afford anything.
+fn pick_gift(sponsor: &shared::sponsors::Sponsor, rng: &mut impl Rng) -> Option<(Item, u32)> {
+    let catalog = gift_catalog(rng);
     let affordable: Vec<_> = catalog
-        .iter()
-        .filter(|(_item, cost)| *cost <= sponsor.budget_remaining)
+        .into_iter()
+        .filter(|(_tag, _item, cost)| *cost <= sponsor.budget_remaining)
         .collect();
 
     if affordable.is_empty() {
@@ -339,103 +338,26 @@ fn pick_gift(sponsor: &shared::sponsors::Sponsor, rng: &mut impl Rng) -> Option<
     let prefs = archetype(sponsor.archetype).gift_preferences;
     let weights: Vec<u32> = affordable
         .iter()
-        .map(|(item, _)| {
-            let tag = item_kind_tag(item);
+        .map(|(tag, _, _)| {
             prefs
                 .iter()
-                .find_map(|(t, w)| (*t == tag).then_some(*w))
+                .find_map(|(t, w)| (t == tag).then_some(*w))
                 .unwrap_or(1)
         })
         .collect();
 
     let idx = weighted_index(rng, &weights)?;
-    let (item, _) = affordable[idx];
-    Some(item.clone())
-}
-
-/// Map an `Item` to its `ItemKindTag` for gift-preference lookup.
-fn item_kind_tag(item: &Item) -> ItemKindTag {
-    use crate::items::ItemType;
-    match &item.item_type {
-        ItemType::Food(_) => ItemKindTag::Food,
-        ItemType::Water(_) => ItemKindTag::Water,
-        ItemType::Consumable => match item.attribute {
-            crate::items::Attribute::Health | crate::items::Attribute::Sanity => {
-                ItemKindTag::Bandage
-            }
-            crate::items::Attribute::Defense => ItemKindTag::Antidote,
-            _ => ItemKindTag::Bandage,
-        },
-        ItemType::Weapon => {
-            if item.rarity == crate::items::ItemRarity::Rare
-                || item.rarity == crate::items::ItemRarity::Legendary
-            {
-                ItemKindTag::WeaponRare
-            } else {
-                ItemKindTag::WeaponBasic
-            }
-        }
-    }
+    let (_, item, cost) = affordable.into_iter().nth(idx)?;
+    Some((item, cost))
 }
 
-/// Cost of an item, looked up from `ITEM_COSTS` by tag.
-fn item_cost(item: &Item) -> u32 {
-    let tag = item_kind_tag(item);
+/// Full gift catalog: a `(tag, item, cost)` entry for every `ITEM_COSTS`
+/// line.
+fn gift_catalog(rng: &mut impl Rng) -> Vec<(ItemKindTag, Item, u32)> {
     ITEM_COSTS
         .iter()
-        .find_map(|(t, c)| (*t == tag).then_some(*c))
-        .unwrap_or(10)
-}
-
-/// Full gift catalog: `(Item, cost)` pairs.
-#[allow(clippy::vec_init_then_push)]
-fn gift_catalog() -> Vec<(Item, u32)> {
-    use crate::items::{Attribute, ItemRarity};
-
-    let mut catalog = Vec::new();
-
-    // Food
-    catalog.push((Item::new_food(None, 5), 5));
-    catalog.push((Item::new_food(None, 3), 5));
-
-    // Water
-    catalog.push((Item::new_water(None, 3), 5));
-    catalog.push((Item::new_water(None, 2), 5));
-
-    // Bandage (consumable healing)
-    let mut bandage = Item::new_consumable("bandage");
-    bandage.attribute = Attribute::Health;
-    catalog.push((bandage, 10));
-
-    // Antidote
-    let mut antidote = Item::new_consumable("antidote");
-    antidote.attribute = Attribute::Defense;
-    catalog.push((antidote, 18));
-
-    // Map (signal-like consumable)
-    let mut map = Item::new_consumable("map");
-    map.attribute = Attribute::Movement;
-    catalog.push((map, 12));
-
-    // Signal
-    let mut signal = Item::new_consumable("signal flare");
-    signal.attribute = Attribute::Bravery;
-    catalog.push((signal, 20));
-
-    // Weapon basic
-    let weapon = Item::new_weapon("spear");
-    catalog.push((weapon, 25));
-
-    // Weapon rare
-    let mut rare_weapon = Item::new_weapon("golden spear");
-    rare_weapon.rarity = ItemRarity::Rare;
-    catalog.push((rare_weapon, 45));
-
-    // Shield
-    let shield = Item::new_shield("wooden shield");
-    catalog.push((shield, 30));
-
-    catalog
+        .map(|&(tag, cost)| (tag, gift_item(tag, rng), cost))
+        .collect()
 }
 
 /// Weighted random index selection.
@@ -454,6 +376,103 @@ fn weighted_index(rng: &mut impl Rng, weights: &[u32]) -> Option<usize> {
     None
 }
 
+// ---------- Player sponsorship ----------
+
+/// A gift a player bought from the `ITEM_COSTS` catalog. The API charges
+/// the player's wallet at purchase and queues the gift on
+/// `Game::player_gifts`; the next cycle delivers it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PlayerGift {
+    /// Purchase identifier, so the API can tell delivered gifts apart.
+    pub identifier: String,
+    /// Name shown as the gift's donor.
+    pub donor: String,
+    /// Identifier of the receiving tribute.
+    pub recipient: String,
+    pub item: ItemKindTag,
+}
+
+/// Catalog price of a gift.
+pub fn gift_price(tag: ItemKindTag) -> u32 {
+    ITEM_COSTS
+        .iter()
+        .find_map(|(t, c)| (*t == tag).then_some(*c))
+        .unwrap_or(10)
+}
+
+/// The item a player receives for a catalog entry.
+pub fn gift_item(tag: ItemKindTag, rng: &mut impl Rng) -> Item {
+    use crate::items::{Attribute, ItemRarity};
+
+    match tag {
+        ItemKindTag::Food => Item::new_food(None, 5).reidentified(rng),
+        ItemKindTag::Water => Item::new_water(None, 3).reidentified(rng),
+        ItemKindTag::Bandage => {
+            let mut bandage = Item::new_consumable_with_rng("bandage", rng);
+            bandage.attribute = Attribute::Health;
+            bandage
+        }
+        ItemKindTag::Antidote => {
+            let mut antidote = Item::new_consumable_with_rng("antidote", rng);
+            antidote.attribute = Attribute::Defense;
+            antidote
+        }
+        ItemKindTag::Map => {
+            let mut map = Item::new_consumable_with_rng("map", rng);
+            map.attribute = Attribute::Movement;
+            map
+        }
+        ItemKindTag::Signal => {
+            let mut signal = Item::new_consumable_with_rng("signal flare", rng);
+            signal.attribute = Attribute::Bravery;
+            signal
+        }
+        ItemKindTag::WeaponBasic => Item::new_weapon_with_rng("spear", rng),
+        ItemKindTag::WeaponRare => {
+            let mut rare_weapon = Item::new_weapon_with_rng("golden spear", rng);
+            rare_weapon.rarity = ItemRarity::Rare;
+            rare_weapon
+        }
+        ItemKindTag::Shield => Item::new_shield_with_rng("wooden shield", rng),
+    }
+}
+
+/// Turn queued player gifts into deliveries. Gifts for tributes that are
+/// no longer alive stay on `Game::player_gifts` so the API can refund them.
+pub fn resolve_player_gifts(game: &mut Game, rng: &mut impl Rng) -> Vec<GiftResult> {
+    let (deliverable, undeliverable): (Vec<_>, Vec<_>) = std::mem::take(&mut game.player_gifts)
+        .into_iter()
+        .partition(|gift| {
+            game.tributes
+                .iter()
+                .any(|t| t.identifier == gift.recipient && t.is_alive())
+        });
+    game.player_gifts = undeliverable;
+
+    deliverable
+        .into_iter()
+        .filter_map(|gift| {
+            let tribute = game
+                .tributes
+                .iter()
+                .find(|t| t.identifier == gift.recipient)?;
+            let item = gift_item(gift.item, rng);
+            let payload = MessagePayload::SponsorGift {
+                recipient: shared::messages::TributeRef {
+                    identifier: tribute.identifier.clone().into(),
+                    name: tribute.name.clone(),
+                },
+                item: ItemRef {
+                    identifier: item.identifier.clone().into(),
+                    name: item.name.clone(),
+                },
+                donor: gift.donor,
+            };
+            Some(GiftResult { payload, item })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use shared::audience::AudienceEvent;
@@ -36,9 +36,8 @@ impl Animal {
         }
     }
 
-    pub fn random() -> Animal {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
-        Animal::iter().choose(&mut rng).unwrap()
+    pub fn random(rng: &mut impl Rng) -> Animal {
+        Animal::iter().choose(rng).unwrap()
     }
 
     pub fn damage(&self) -> u32 {
@@ -176,7 +175,7 @@ mod tests {
 
     #[test]
     fn random_animal() {
-        let animal = Animal::random();
+        let animal = Animal::random(&mut rand::rng());
         assert_eq!(animal, Animal::from_str(&animal.to_string()).unwrap());
     }
 
@@ -28,6 +28,9 @@ pub enum RejectReason {
     InfectedRequiresWoundedAncestor,
     /// New severity is not strictly greater than existing same-key severity.
     NotStrictlyHigherSeverity,
+    /// A tribute carries at most one contagion; a different one can't
+    /// take hold.
+    AlreadyCarriesContagion,
 }
 
 /// Decide what happens when `new` is offered to a tribute who already carries
@@ -99,6 +102,15 @@ pub fn can_acquire(
         return AcquireResolution::Reject(RejectReason::InfectedRequiresWoundedAncestor);
     }
 
+    // Rule: a tribute carries at most one contagion.
+    if let AfflictionKind::Contagion(contagion) = &new.kind
+        && existing
+            .keys()
+            .any(|(k, _)| matches!(k, AfflictionKind::Contagion(c) if c != contagion))
+    {
+        return AcquireResolution::Reject(RejectReason::AlreadyCarriesContagion);
+    }
+
     // Rule: Same-key collision → upgrade if strictly higher severity.
     if let Some(prev) = existing.get(&new_key) {
         return if new.severity > prev.severity {
@@ -479,6 +491,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn one_contagion_per_tribute() {
+        use shared::afflictions::Contagion;
+        let existing = map(vec![affl(
+            AfflictionKind::Contagion(Contagion::Vampirism),
+            None,
+            Severity::Mild,
+        )]);
+        let other = affl(
+            AfflictionKind::Contagion(Contagion::ZombiePlague),
+            None,
+            Severity::Mild,
+        );
+        assert_eq!(
+            can_acquire(&existing, &other),
+            AcquireResolution::Reject(RejectReason::AlreadyCarriesContagion)
+        );
+        // The same contagion still takes hold (incubating → transformed).
+        let transformed = affl(
+            AfflictionKind::Contagion(Contagion::Vampirism),
+            None,
+            Severity::Severe,
+        );
+        assert!(matches!(
+            can_acquire(&existing, &transformed),
+            AcquireResolution::Upgrade(_)
+        ));
+    }
+
     // ── Proptest properties ──────────────────────────────────────────────
 
     use proptest::prelude::*;
@@ -0,0 +1,95 @@
+//! Supernatural contagions: lycanthropy, vampirism and zombie plague.
+//!
+//! A contagion is caught as `Mild` (incubating) from a transformed
+//! carrier's blow — see `combat::inflict_table::lookup_contagion_inflict`
+//! — and takes hold as `Severe` once `INCUBATION_DAYS` have passed. From
+//! then on `brains::contagion_override` drives the tribute. Seeding,
+//! transformation and sunlight live in `games::contagion`.
+
+use crate::tributes::{AfflictionDraft, Tribute};
+use shared::afflictions::{AfflictionKind, AfflictionSource, Contagion, Severity};
+
+/// Game days a caught contagion incubates before it takes hold.
+pub const INCUBATION_DAYS: u32 = 1;
+
+/// Draft for a freshly caught (incubating) contagion.
+pub fn contagion_draft(contagion: Contagion, source: AfflictionSource) -> AfflictionDraft {
+    AfflictionDraft {
+        kind: AfflictionKind::Contagion(contagion),
+        body_part: None,
+        severity: Severity::Mild,
+        source,
+        trapped_metadata: None,
+    }
+}
+
+impl Tribute {
+    /// The contagion this tribute carries, incubating or transformed.
+    pub fn contagion(&self) -> Option<(Contagion, Severity)> {
+        self.afflictions.values().find_map(|a| match a.kind {
+            AfflictionKind::Contagion(contagion) => Some((contagion, a.severity)),
+            _ => None,
+        })
+    }
+
+    /// The contagion this tribute has transformed under, if any. Only
+    /// transformed carriers pass a contagion on or act under its override.
+    pub fn transformed_contagion(&self) -> Option<Contagion> {
+        self.contagion()
+            .filter(|(_, severity)| *severity == Severity::Severe)
+            .map(|(contagion, _)| contagion)
+    }
+
+    /// Let an incubating contagion take hold once `INCUBATION_DAYS` have
+    /// passed since it was caught. Returns the contagion on the day it
+    /// transforms the tribute.
+    pub fn advance_contagion(&mut self, day: u32) -> Option<Contagion> {
+        let affliction = self.afflictions.values_mut().find(|a| {
+            matches!(a.kind, AfflictionKind::Contagion(_)) && a.severity < Severity::Severe
+        })?;
+        if day < affliction.acquired_cycle + INCUBATION_DAYS {
+            return None;
+        }
+        affliction.severity = Severity::Severe;
+        affliction.last_progressed_cycle = day;
+        match affliction.kind {
+            AfflictionKind::Contagion(contagion) => Some(contagion),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn infected(contagion: Contagion, day: u32) -> Tribute {
+        let mut tribute = Tribute::new("Carrier".to_string(), None, None);
+        tribute.game_day = Some(day as i64);
+        tribute.try_acquire_affliction(contagion_draft(contagion, AfflictionSource::Gamemaker));
+        tribute
+    }
+
+    #[test]
+    fn contagion_incubates_before_taking_hold() {
+        let mut tribute = infected(Contagion::Vampirism, 2);
+        assert_eq!(
+            tribute.contagion(),
+            Some((Contagion::Vampirism, Severity::Mild))
+        );
+        assert_eq!(tribute.transformed_contagion(), None);
+
+        assert_eq!(tribute.advance_contagion(2), None);
+        assert_eq!(tribute.advance_contagion(3), Some(Contagion::Vampirism));
+        assert_eq!(tribute.transformed_contagion(), Some(Contagion::Vampirism));
+        // Transformation happens once.
+        assert_eq!(tribute.advance_contagion(4), None);
+    }
+
+    #[test]
+    fn healthy_tribute_has_no_contagion() {
+        let mut tribute = Tribute::new("Healthy".to_string(), None, None);
+        assert_eq!(tribute.contagion(), None);
+        assert_eq!(tribute.advance_contagion(5), None);
+    }
+}
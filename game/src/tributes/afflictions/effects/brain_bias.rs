@@ -80,6 +80,7 @@ fn base_bias(kind: AfflictionKind) -> (f64, f64, f64, f64, f64) {
         | AfflictionKind::Electrocuted
         | AfflictionKind::Phobia(_)
         | AfflictionKind::Addiction(_)
+        | AfflictionKind::Contagion(_)
         | AfflictionKind::Trapped(_) => (1.0, 1.0, 1.0, 1.0, 1.0),
         // Fixation: push toward target
         // Tribute fixation → reduced combat_avoid (want to engage)
@@ -67,6 +67,7 @@ fn base_penalties(kind: AfflictionKind) -> (i32, i32, i32, i32, i32, f64, i32, i
         | AfflictionKind::Phobia(_)
         | AfflictionKind::Fixation(_)
         | AfflictionKind::Addiction(_)
+        | AfflictionKind::Contagion(_)
         | AfflictionKind::Trapped(_) => (0, 0, 0, 0, 0, 0.0, 0, 0),
     }
 }
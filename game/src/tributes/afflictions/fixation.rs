@@ -7,14 +7,13 @@ use shared::afflictions::{
     FixationTarget, Severity, ThwartReason,
 };
 use std::collections::{BTreeMap, BTreeSet, HashMap};
-use strum::IntoEnumIterator;
 
 use crate::areas::Area;
 use crate::items::Item;
 use crate::messages::MessagePayload;
 use crate::tributes::Tribute;
+use rand::Rng;
 use rand::RngExt;
-use rand::SeedableRng;
 use rand::prelude::IndexedRandom;
 use rand::rngs::SmallRng;
 use uuid::Uuid;
@@ -49,9 +48,10 @@ pub fn count_by_target_kind(
     (tribute_count, item_count, area_count)
 }
 
-/// ~5% chance per tribute to spawn with an innate fixation on a random area.
-/// Respects MAX_FIXATIONS and MAX_FIXATIONS_PER_TARGET_KIND.
-pub fn roll_spawn_fixations(tribute: &mut Tribute, rng: &mut SmallRng) {
+/// ~5% chance per tribute to spawn with an innate fixation on a random area
+/// of the arena (`areas`). Respects MAX_FIXATIONS and
+/// MAX_FIXATIONS_PER_TARGET_KIND.
+pub fn roll_spawn_fixations(tribute: &mut Tribute, areas: &[Area], rng: &mut SmallRng) {
     if count_fixations(&tribute.afflictions) >= MAX_FIXATIONS {
         return;
     }
@@ -67,7 +67,6 @@ pub fn roll_spawn_fixations(tribute: &mut Tribute, rng: &mut SmallRng) {
     }
 
     // Pick a random area as fixation target
-    let areas: Vec<Area> = Area::iter().collect();
     if let Some(area) = areas.choose(rng) {
         let target = FixationTarget::Area(area.to_string());
         let key = (AfflictionKind::Fixation(target.clone()), None);
@@ -95,9 +94,7 @@ pub fn roll_spawn_fixations(tribute: &mut Tribute, rng: &mut SmallRng) {
 
 /// ~10% chance to acquire an item fixation when a tribute picks up an item.
 /// Respects MAX_FIXATIONS and MAX_FIXATIONS_PER_TARGET_KIND.
-pub fn maybe_acquire_item_fixation(tribute: &mut Tribute, item: &Item) {
-    let mut rng = SmallRng::from_rng(&mut rand::rng());
-
+pub fn maybe_acquire_item_fixation(tribute: &mut Tribute, item: &Item, rng: &mut impl Rng) {
     if count_fixations(&tribute.afflictions) >= MAX_FIXATIONS {
         return;
     }
@@ -333,6 +330,7 @@ pub fn process_tribute_fixations(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::areas::arena::Arena;
     use crate::items::OwnsItems;
     use rand::SeedableRng;
     use uuid::Uuid;
@@ -460,11 +458,12 @@ mod tests {
     fn spawn_roll_respects_cap() {
         let mut rng = SmallRng::seed_from_u64(42);
         let mut max_seen = 0;
+        let areas: Vec<Area> = Arena::default().areas().collect();
         for _ in 0..200 {
             let mut t = Tribute::new("Test".to_string(), None, None);
             // Seed manually so the inner roll is deterministic
             let mut inner_rng = SmallRng::seed_from_u64(rng.random());
-            roll_spawn_fixations(&mut t, &mut inner_rng);
+            roll_spawn_fixations(&mut t, &areas, &mut inner_rng);
             let count = count_fixations(&t.afflictions);
             assert!(
                 count <= MAX_FIXATIONS,
@@ -489,7 +488,7 @@ mod tests {
         add_fixation(&mut t, FixationTarget::Tribute("other".to_string()));
 
         let item = Item::new_random_consumable();
-        maybe_acquire_item_fixation(&mut t, &item);
+        maybe_acquire_item_fixation(&mut t, &item, &mut rand::rng());
         // Already at cap, should not add
         assert_eq!(count_fixations(&t.afflictions), 2);
     }
@@ -508,7 +507,7 @@ mod tests {
         // But the can_acquire check happens in anatomy.rs, so we simulate via
         // the game-side function
         let item = Item::new_random_consumable();
-        maybe_acquire_item_fixation(&mut t, &item);
+        maybe_acquire_item_fixation(&mut t, &item, &mut rand::rng());
         // Item fixation is different kind than area, so this should be allowed
         // (per_kind_cap is per variant, but item != area)
         let count = count_fixations(&t.afflictions);
@@ -615,7 +614,7 @@ mod tests {
             &mut SmallRng::seed_from_u64(42),
         );
         // Default area is Cornucopia.
-        t.area = Area::Cornucopia;
+        t.area = Area::CORNUCOPIA;
         add_fixation(&mut t, FixationTarget::Area("Cornucopia".to_string()));
 
         let id_to_uuid = HashMap::new();
@@ -706,7 +705,7 @@ mod tests {
         // add_acquired_fixation sets cycles_since_last_contact = 6,
         // which is above DECAY_THRESHOLD (5).
         // Place tribute in a DIFFERENT area so they're not in contact.
-        t.area = Area::Sector1;
+        t.area = Area::SECTOR_1;
 
         let id_to_uuid = HashMap::new();
         let dead_killers = HashMap::new();
@@ -746,8 +745,8 @@ mod tests {
             None,
             &mut SmallRng::seed_from_u64(99),
         );
-        a.area = Area::Cornucopia;
-        b.area = Area::Cornucopia; // same area
+        a.area = Area::CORNUCOPIA;
+        b.area = Area::CORNUCOPIA; // same area
 
         // Acquired fixation with cycles_since_last_contact = 6 (past decay threshold).
         let key = (
@@ -816,7 +815,7 @@ mod tests {
             None,
             &mut SmallRng::seed_from_u64(42),
         );
-        t.area = Area::Sector1;
+        t.area = Area::SECTOR_1;
         let target = FixationTarget::Area("Cornucopia".to_string());
         add_acquired_fixation(&mut t, target.clone());
 
@@ -877,7 +876,7 @@ mod tests {
             None,
             &mut SmallRng::seed_from_u64(42),
         );
-        t.area = Area::Sector1;
+        t.area = Area::SECTOR_1;
         // Add an Innate fixation with high contact gap.
         let target = FixationTarget::Area("Cornucopia".to_string());
         let key = (AfflictionKind::Fixation(target.clone()), None);
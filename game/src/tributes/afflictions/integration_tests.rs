@@ -233,10 +233,10 @@ mod tests {
     #[test]
     fn severe_affliction_visible_from_different_area() {
         let mut observer = Tribute::new("Observer".to_string(), None, None);
-        observer.area = Area::Sector1;
+        observer.area = Area::SECTOR_1;
         let mut target =
             make_ttribute_with_affliction("Target", AfflictionKind::Blind, Severity::Severe);
-        target.area = Area::Sector6;
+        target.area = Area::SECTOR_6;
 
         let visible = visible_afflictions_to(&observer, &target);
         assert_eq!(visible.len(), 1, "Severe afflictions must be public");
@@ -246,17 +246,17 @@ mod tests {
     #[test]
     fn moderate_affliction_visible_only_in_same_area() {
         let mut observer = Tribute::new("Observer".to_string(), None, None);
-        observer.area = Area::Sector1;
+        observer.area = Area::SECTOR_1;
         let mut target =
             make_ttribute_with_affliction("Target", AfflictionKind::BrokenBone, Severity::Moderate);
-        target.area = Area::Sector1;
+        target.area = Area::SECTOR_1;
 
         // Same area → visible.
         let visible = visible_afflictions_to(&observer, &target);
         assert_eq!(visible.len(), 1);
 
         // Different area → hidden.
-        observer.area = Area::Sector2;
+        observer.area = Area::SECTOR_2;
         let visible = visible_afflictions_to(&observer, &target);
         assert!(
             visible.is_empty(),
@@ -290,13 +290,13 @@ mod tests {
     #[test]
     fn moderate_same_area_vs_different_area() {
         let mut same_area_observer = Tribute::new("Nearby".to_string(), None, None);
-        same_area_observer.area = Area::Sector3;
+        same_area_observer.area = Area::SECTOR_3;
         let mut far_observer = Tribute::new("Faraway".to_string(), None, None);
-        far_observer.area = Area::Sector5;
+        far_observer.area = Area::SECTOR_5;
 
         let mut target =
             make_ttribute_with_affliction("Target", AfflictionKind::MissingArm, Severity::Moderate);
-        target.area = Area::Sector3;
+        target.area = Area::SECTOR_3;
 
         assert!(
             !visible_afflictions_to(&same_area_observer, &target).is_empty(),
@@ -319,7 +319,7 @@ mod tests {
         );
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(crate::terrain::BaseTerrain::Mountains),
         );
         assert_eq!(result, Some(Action::Rest));
@@ -334,7 +334,7 @@ mod tests {
         );
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(crate::terrain::BaseTerrain::Highlands),
         );
         assert_eq!(result, Some(Action::Rest));
@@ -349,7 +349,7 @@ mod tests {
         );
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(crate::terrain::BaseTerrain::Wetlands),
         );
         assert_eq!(result, Some(Action::Rest));
@@ -364,7 +364,7 @@ mod tests {
         );
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(crate::terrain::BaseTerrain::Forest),
         );
         assert!(result.is_none(), "Forest should be allowed");
@@ -376,7 +376,7 @@ mod tests {
             make_ttribute_with_affliction("Tribute", AfflictionKind::MissingLeg, Severity::Mild);
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(crate::terrain::BaseTerrain::Mountains),
         );
         assert!(result.is_none(), "Mild MissingLeg should not block terrain");
@@ -388,7 +388,7 @@ mod tests {
             make_ttribute_with_affliction("Tribute", AfflictionKind::MissingLeg, Severity::Severe);
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(crate::terrain::BaseTerrain::Mountains),
         );
         assert_eq!(result, Some(Action::Rest));
@@ -437,7 +437,7 @@ mod tests {
             AfflictionKind::MissingLeg,
             Severity::Moderate,
         );
-        let result = hard_gates_with_terrain(&tribute, &Action::Move(Some(Area::Sector1)), None);
+        let result = hard_gates_with_terrain(&tribute, &Action::Move(Some(Area::SECTOR_1)), None);
         assert!(
             result.is_none(),
             "MissingLeg gate should be skipped when terrain unknown"
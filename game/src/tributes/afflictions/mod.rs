@@ -9,6 +9,7 @@
 pub mod addiction;
 pub mod anatomy;
 pub mod cascade;
+pub mod contagion;
 pub mod cure;
 pub mod effects;
 pub mod fixation;
@@ -118,16 +119,16 @@ mod visibility_tests {
     #[test]
     fn moderate_affliction_visible_only_in_same_area() {
         let mut observer = Tribute::new("Observer".to_string(), None, None);
-        observer.area = Area::Sector1;
+        observer.area = Area::SECTOR_1;
         let mut target = Tribute::new("Target".to_string(), None, None);
-        target.area = Area::Sector1;
+        target.area = Area::SECTOR_1;
         let aff = make_affliction(AfflictionKind::BrokenBone, Severity::Moderate);
         target.afflictions.insert(aff.key(), aff);
 
         let visible = visible_afflictions_to(&observer, &target);
         assert_eq!(visible.len(), 1);
 
-        observer.area = Area::Sector2;
+        observer.area = Area::SECTOR_2;
         let visible = visible_afflictions_to(&observer, &target);
         assert!(visible.is_empty());
     }
@@ -135,9 +136,9 @@ mod visibility_tests {
     #[test]
     fn severe_affliction_visible_to_all() {
         let mut observer = Tribute::new("Observer".to_string(), None, None);
-        observer.area = Area::Sector1;
+        observer.area = Area::SECTOR_1;
         let mut target = Tribute::new("Target".to_string(), None, None);
-        target.area = Area::Sector6;
+        target.area = Area::SECTOR_6;
         let aff = make_affliction(AfflictionKind::Blind, Severity::Severe);
         target.afflictions.insert(aff.key(), aff);
 
@@ -149,9 +150,9 @@ mod visibility_tests {
     #[test]
     fn mixed_severities_filter_correctly() {
         let mut observer = Tribute::new("Observer".to_string(), None, None);
-        observer.area = Area::Sector1;
+        observer.area = Area::SECTOR_1;
         let mut target = Tribute::new("Target".to_string(), None, None);
-        target.area = Area::Sector1;
+        target.area = Area::SECTOR_1;
 
         let mild = make_affliction(AfflictionKind::Wounded, Severity::Mild);
         let moderate = make_affliction(AfflictionKind::BrokenBone, Severity::Moderate);
@@ -163,7 +164,7 @@ mod visibility_tests {
         let visible = visible_afflictions_to(&observer, &target);
         assert_eq!(visible.len(), 2);
 
-        observer.area = Area::Sector2;
+        observer.area = Area::SECTOR_2;
         let visible = visible_afflictions_to(&observer, &target);
         assert_eq!(visible.len(), 1);
         assert_eq!(visible[0].kind, AfflictionKind::Blind);
@@ -172,9 +173,9 @@ mod visibility_tests {
     #[test]
     fn target_has_visible_affliction_checks_correctly() {
         let mut observer = Tribute::new("Observer".to_string(), None, None);
-        observer.area = Area::Sector1;
+        observer.area = Area::SECTOR_1;
         let mut target = Tribute::new("Target".to_string(), None, None);
-        target.area = Area::Sector1;
+        target.area = Area::SECTOR_1;
         let aff = make_affliction(AfflictionKind::Blind, Severity::Moderate);
         target.afflictions.insert(aff.key(), aff);
 
@@ -191,7 +192,7 @@ mod visibility_tests {
             Severity::Moderate
         ));
 
-        observer.area = Area::Sector2;
+        observer.area = Area::SECTOR_2;
         assert!(!target_has_visible_affliction(
             &observer,
             &target,
@@ -166,7 +166,7 @@ mod tests {
     fn make_context_with_fire() -> PhobiaContext<'static> {
         use std::sync::LazyLock;
         static AREA: LazyLock<crate::areas::AreaDetails> = LazyLock::new(|| {
-            let mut area = crate::areas::AreaDetails::new(None, Area::Cornucopia);
+            let mut area = crate::areas::AreaDetails::new(None, Area::CORNUCOPIA);
             area.terrain = TerrainType::new(BaseTerrain::Forest, vec![]).unwrap();
             area.events = vec![AreaEvent::Wildfire];
             area
@@ -183,7 +183,7 @@ mod tests {
     fn make_context_clear() -> PhobiaContext<'static> {
         use std::sync::LazyLock;
         static AREA: LazyLock<crate::areas::AreaDetails> = LazyLock::new(|| {
-            let mut area = crate::areas::AreaDetails::new(None, Area::Cornucopia);
+            let mut area = crate::areas::AreaDetails::new(None, Area::CORNUCOPIA);
             area.terrain = TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap();
             area
         });
@@ -303,7 +303,7 @@ mod tests {
     fn make_context_with_others(others: &[Tribute]) -> PhobiaContext<'_> {
         use std::sync::LazyLock;
         static AREA: LazyLock<crate::areas::AreaDetails> = LazyLock::new(|| {
-            let mut area = crate::areas::AreaDetails::new(None, Area::Cornucopia);
+            let mut area = crate::areas::AreaDetails::new(None, Area::CORNUCOPIA);
             area.terrain = TerrainType::new(BaseTerrain::Forest, vec![]).unwrap();
             area.events = vec![AreaEvent::Wildfire];
             area
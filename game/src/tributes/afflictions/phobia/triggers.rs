@@ -139,7 +139,7 @@ mod tests {
     use shared::messages::{GameMessage, MessagePayload, MessageSource, Phase, TributeRef};
 
     fn make_area_details(terrain: BaseTerrain, events: Vec<AreaEvent>) -> AreaDetails {
-        let mut area = AreaDetails::new(None, Area::Cornucopia);
+        let mut area = AreaDetails::new(None, Area::CORNUCOPIA);
         area.terrain = TerrainType::new(terrain, vec![]).unwrap();
         area.events = events;
         area
@@ -61,12 +61,12 @@ fn witness_ally_death_acquires_mild_trauma() {
 
     let mut victim = make_tribute("Victim");
     let victim_id = victim.id;
-    victim.area = Area::Sector1;
+    victim.area = Area::SECTOR_1;
     victim.blood = 0;
     game.tributes.push(victim);
 
     let mut witness = make_tribute("Witness");
-    witness.area = Area::Sector1;
+    witness.area = Area::SECTOR_1;
     witness.allies.push(victim_id);
     game.tributes.push(witness);
 
@@ -101,11 +101,11 @@ fn witness_different_area_no_trauma() {
 
     let mut victim = make_tribute("Victim");
     let victim_id = victim.id;
-    victim.area = Area::Sector1;
+    victim.area = Area::SECTOR_1;
     game.tributes.push(victim);
 
     let mut witness = make_tribute("Witness");
-    witness.area = Area::Sector2; // different area
+    witness.area = Area::SECTOR_2; // different area
     witness.allies.push(victim_id);
     game.tributes.push(witness);
 
@@ -272,14 +272,14 @@ fn mass_casualty_three_deaths_moderate() {
     // Three victims in Sector1
     for name in ["V1", "V2", "V3"] {
         let mut v = make_tribute(name);
-        v.area = Area::Sector1;
+        v.area = Area::SECTOR_1;
         v.blood = 0;
         game.tributes.push(v);
     }
 
     // Witness in same area
     let mut w = make_tribute("Witness");
-    w.area = Area::Sector1;
+    w.area = Area::SECTOR_1;
     game.tributes.push(w);
 
     for v in &game.tributes[..3] {
@@ -309,13 +309,13 @@ fn mass_casualty_five_deaths_severe() {
 
     for name in ["V1", "V2", "V3", "V4", "V5"] {
         let mut v = make_tribute(name);
-        v.area = Area::Sector1;
+        v.area = Area::SECTOR_1;
         v.blood = 0;
         game.tributes.push(v);
     }
 
     let mut w = make_tribute("Witness");
-    w.area = Area::Sector1;
+    w.area = Area::SECTOR_1;
     game.tributes.push(w);
 
     for v in &game.tributes[..5] {
@@ -348,11 +348,11 @@ fn different_phase_messages_ignored() {
 
     let mut victim = make_tribute("Victim");
     let victim_id = victim.id;
-    victim.area = Area::Sector1;
+    victim.area = Area::SECTOR_1;
     game.tributes.push(victim);
 
     let mut witness = make_tribute("Witness");
-    witness.area = Area::Sector1;
+    witness.area = Area::SECTOR_1;
     witness.allies.push(victim_id);
     game.tributes.push(witness);
 
@@ -9,7 +9,9 @@ use super::shared::{TraumaEvent, apply_trauma_events};
 
 pub(super) fn produce_witness_mass_casualty(game: &mut Game, phase: Phase) {
     // Phase 1: count deaths per area
-    let mut deaths_by_area: std::collections::HashMap<Area, u32> = std::collections::HashMap::new();
+    // BTreeMap so trauma events come out in a stable area order.
+    let mut deaths_by_area: std::collections::BTreeMap<Area, u32> =
+        std::collections::BTreeMap::new();
 
     for msg in &game.messages {
         if msg.phase != phase {
@@ -4,10 +4,21 @@
 //! Pure functions only. Phase 2 of the tribute-alliances feature. No
 //! `Tribute` mutation lives here; later phases wire these helpers into
 //! the simulation loop.
+//!
+//! The pairwise `allies` graph is the source of truth for who trusts whom.
+//! [`Alliance`] is the group view of it the game keeps on `Game::alliances`:
+//! each connected component of two or more living tributes, with a leader
+//! and a shared stash. See `Game::sync_alliances`.
+
+use std::collections::{BTreeMap, BTreeSet};
 
 use rand::RngExt;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::items::Item;
+use crate::tributes::Attributes;
+use crate::tributes::emotions::Emotions;
 use crate::tributes::traits::{REFUSERS, Trait, geometric_mean_affinity};
 
 /// Per-tribute hard cap on direct alliances.
@@ -165,13 +176,26 @@ pub fn trust_shock_roll(
     rng.random_bool(p)
 }
 
+/// Chance, once per Treacherous cadence, that a tribute turns on a
+/// same-area ally. Treacherous tributes always do (spec §7.4(b)); anyone
+/// else only once their trust has collapsed (see
+/// [`Emotions::betrayal_chance`]).
+pub fn betrayal_chance(traits: &[Trait], emotions: &Emotions) -> f64 {
+    if traits.contains(&Trait::Treacherous) {
+        1.0
+    } else {
+        emotions.betrayal_chance()
+    }
+}
+
 /// Attempt to form an alliance between two tributes. Returns `true` on
 /// success — gate passes, `roll_chance` is positive, and the dice roll
 /// hits. The caller is responsible for mutating both sides' `allies`
 /// lists and for fetching a [`DecidingFactor`] via [`deciding_factor`]
 /// for human-readable messaging. Composes [`passes_gate`] and
 /// [`roll_chance`] so the game cycle has a single integration point per
-/// spec §6.
+/// spec §6. `emotion_shift` comes from [`Emotions::alliance_shift`] and
+/// only moves a chance that is already positive.
 #[allow(clippy::too_many_arguments)]
 pub fn try_form_alliance(
     self_traits: &[Trait],
@@ -182,6 +206,7 @@ pub fn try_form_alliance(
     phobia_penalty: f64,
     trauma_penalty: f64,
     addiction_penalty: f64,
+    emotion_shift: f64,
     rng: &mut impl rand::Rng,
 ) -> bool {
     if !passes_gate(self_traits, target_traits) {
@@ -194,13 +219,138 @@ pub fn try_form_alliance(
         self_allies_len,
         target_allies_len,
     );
-    let chance = (base_chance - phobia_penalty - trauma_penalty - addiction_penalty).max(0.0);
+    if base_chance <= 0.0 {
+        return false;
+    }
+    let chance =
+        (base_chance + emotion_shift - phobia_penalty - trauma_penalty - addiction_penalty)
+            .clamp(0.0, 0.95);
     if chance <= 0.0 {
         return false;
     }
     rng.random_bool(chance)
 }
 
+/// A group of tributes linked, directly or through each other, by the
+/// `allies` graph.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Alliance {
+    pub id: Uuid,
+    /// Living members, sorted.
+    pub members: Vec<Uuid>,
+    /// The member who picks where the group goes. Chosen by
+    /// [`leadership_score`] and kept until they die or leave.
+    pub leader: Uuid,
+    /// Supplies the members pooled. Members with the leader hand over
+    /// their spares and draw what they lack.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stash: Vec<Item>,
+    /// Day the group came together.
+    #[serde(default)]
+    pub formed_day: u32,
+}
+
+impl Alliance {
+    pub fn contains(&self, tribute: &Uuid) -> bool {
+        self.members.contains(tribute)
+    }
+}
+
+/// A tribute's part in its alliance's watch this phase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Watch {
+    /// Stays up while the others in the area rest.
+    Keeping,
+    /// An ally is keeping watch over the area.
+    Guarded,
+}
+
+/// How readily the others follow a tribute. Wits, nerve and a way with
+/// words count for most, strength for some; a few traits make a tribute
+/// easier or harder to follow.
+pub fn leadership_score(traits: &[Trait], attributes: &Attributes) -> u32 {
+    let base = attributes.intelligence
+        + attributes.bravery
+        + attributes.persuasion
+        + attributes.strength / 2;
+    let bonus: i32 = traits
+        .iter()
+        .map(|t| match t {
+            Trait::Loyal | Trait::Cunning => 10,
+            Trait::Friendly | Trait::Resilient | Trait::Defensive => 5,
+            Trait::Reckless => -5,
+            Trait::Paranoid | Trait::Fragile => -10,
+            Trait::Treacherous | Trait::Dim => -15,
+            Trait::LoneWolf => -20,
+            _ => 0,
+        })
+        .sum();
+    base.saturating_add_signed(bonus)
+}
+
+/// The highest-scoring candidate; ties go to the lowest id so the choice
+/// doesn't depend on roster order.
+pub fn choose_leader(candidates: &[(Uuid, u32)]) -> Option<Uuid> {
+    candidates
+        .iter()
+        .max_by(|(a_id, a), (b_id, b)| a.cmp(b).then_with(|| b_id.cmp(a_id)))
+        .map(|(id, _)| *id)
+}
+
+/// The alliance groups in an ally graph: connected components of two or
+/// more tributes. Links to tributes missing from `links` (the dead) are
+/// ignored. Members and groups come out sorted.
+pub fn alliance_groups(links: &[(Uuid, &[Uuid])]) -> Vec<Vec<Uuid>> {
+    let known: BTreeSet<Uuid> = links.iter().map(|(id, _)| *id).collect();
+    let mut edges: BTreeMap<Uuid, BTreeSet<Uuid>> = BTreeMap::new();
+    for (id, allies) in links {
+        for ally in allies.iter().filter(|a| known.contains(a) && *a != id) {
+            edges.entry(*id).or_default().insert(*ally);
+            edges.entry(*ally).or_default().insert(*id);
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut groups = Vec::new();
+    for start in edges.keys() {
+        if !seen.insert(*start) {
+            continue;
+        }
+        let mut group = vec![*start];
+        let mut queue = vec![*start];
+        while let Some(next) = queue.pop() {
+            for linked in &edges[&next] {
+                if seen.insert(*linked) {
+                    group.push(*linked);
+                    queue.push(*linked);
+                }
+            }
+        }
+        group.sort();
+        groups.push(group);
+    }
+    groups
+}
+
+/// Which of the members sharing an area keeps watch this phase: the
+/// awake ones take turns, one per phase. `None` when there is no one to
+/// guard or no one awake to do it.
+pub fn watch_keeper(sharing: &[(Uuid, bool)], rotation: u32) -> Option<Uuid> {
+    if sharing.len() < 2 {
+        return None;
+    }
+    let mut awake: Vec<Uuid> = sharing
+        .iter()
+        .filter(|(_, asleep)| !asleep)
+        .map(|(id, _)| *id)
+        .collect();
+    if awake.is_empty() {
+        return None;
+    }
+    awake.sort();
+    Some(awake[rotation as usize % awake.len()])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,6 +591,7 @@ mod tests {
             0.0,
             0.0,
             0.0,
+            0.0,
             &mut rng,
         );
         assert!(!formed);
@@ -458,6 +609,7 @@ mod tests {
             0.0,
             0.0,
             0.0,
+            0.0,
             &mut rng,
         );
         assert!(!r1, "self at cap blocks");
@@ -470,6 +622,7 @@ mod tests {
             0.0,
             0.0,
             0.0,
+            0.0,
             &mut rng,
         );
         assert!(!r2, "target at cap blocks");
@@ -491,6 +644,7 @@ mod tests {
                 0.0,
                 0.0,
                 0.0,
+                0.0,
                 &mut rng,
             ) {
                 successes += 1;
@@ -516,8 +670,122 @@ mod tests {
                 0.0,
                 0.0,
                 0.0,
+                0.0,
                 &mut rng
             ));
         }
     }
+
+    #[test]
+    fn try_form_alliance_emotion_shift_cannot_revive_a_zero_chance() {
+        let mut rng = StdRng::seed_from_u64(29);
+        for _ in 0..32 {
+            assert!(!try_form_alliance(
+                &[],
+                &[],
+                false,
+                MAX_ALLIES,
+                0,
+                0.0,
+                0.0,
+                0.0,
+                0.5,
+                &mut rng
+            ));
+        }
+    }
+
+    #[test]
+    fn try_form_alliance_distrust_suppresses_formation() {
+        let mut rng = StdRng::seed_from_u64(547);
+        let wary = Emotions {
+            trust: 0,
+            ..Emotions::default()
+        };
+        let shift = wary.alliance_shift(&wary);
+        let mut successes = 0;
+        for _ in 0..200 {
+            if try_form_alliance(&[], &[], false, 0, 0, 0.0, 0.0, 0.0, shift, &mut rng) {
+                successes += 1;
+            }
+        }
+        // 0.20 base - 0.20 shift = 0.0.
+        assert_eq!(successes, 0);
+    }
+
+    #[test]
+    fn betrayal_chance_treacherous_always_betrays() {
+        assert_eq!(
+            betrayal_chance(&[Trait::Treacherous], &Emotions::default()),
+            1.0
+        );
+        assert_eq!(betrayal_chance(&[Trait::Loyal], &Emotions::default()), 0.0);
+        let bitter = Emotions {
+            trust: 0,
+            aggression: 80,
+            ..Emotions::default()
+        };
+        assert!(betrayal_chance(&[Trait::Loyal], &bitter) > 0.0);
+    }
+
+    // ---- Alliance groups -------------------------------------------------
+
+    fn ids(n: u128) -> Vec<Uuid> {
+        (1..=n).map(Uuid::from_u128).collect()
+    }
+
+    #[test]
+    fn alliance_groups_are_components_of_two_or_more() {
+        let id = ids(6);
+        // 1-2-3 chained, 4-5 paired, 6 alone; 3 also lists a dead tribute.
+        let dead = Uuid::from_u128(99);
+        let links = [
+            (id[0], &[id[1]][..]),
+            (id[1], &[id[0], id[2]][..]),
+            (id[2], &[id[1], dead][..]),
+            (id[3], &[id[4]][..]),
+            (id[4], &[id[3]][..]),
+            (id[5], &[][..]),
+        ];
+        assert_eq!(
+            alliance_groups(&links),
+            vec![vec![id[0], id[1], id[2]], vec![id[3], id[4]]]
+        );
+    }
+
+    #[test]
+    fn one_sided_link_still_groups() {
+        let id = ids(2);
+        let links = [(id[0], &[id[1]][..]), (id[1], &[][..])];
+        assert_eq!(alliance_groups(&links), vec![vec![id[0], id[1]]]);
+    }
+
+    #[test]
+    fn leader_is_the_best_scored_with_ties_to_the_lowest_id() {
+        let id = ids(3);
+        assert_eq!(
+            choose_leader(&[(id[2], 50), (id[0], 40), (id[1], 50)]),
+            Some(id[1])
+        );
+        assert_eq!(choose_leader(&[]), None);
+    }
+
+    #[test]
+    fn traits_move_leadership() {
+        let attributes = Attributes::default();
+        let plain = leadership_score(&[], &attributes);
+        assert!(leadership_score(&[Trait::Loyal], &attributes) > plain);
+        assert!(leadership_score(&[Trait::LoneWolf], &attributes) < plain);
+    }
+
+    #[test]
+    fn watch_rotates_among_the_awake() {
+        let id = ids(3);
+        let sharing = [(id[0], false), (id[1], true), (id[2], false)];
+        assert_eq!(watch_keeper(&sharing, 0), Some(id[0]));
+        assert_eq!(watch_keeper(&sharing, 1), Some(id[2]));
+        assert_eq!(watch_keeper(&sharing, 2), Some(id[0]));
+        assert_eq!(watch_keeper(&sharing[..1], 0), None);
+        assert_eq!(watch_keeper(&[(id[0], true), (id[1], true)], 0), None);
+    }
 }
@@ -0,0 +1,389 @@
+//! What a tribute believes about the arena, as opposed to what is true.
+//!
+//! Every phase an awake tribute takes in what it can make out from where it
+//! stands ([`Tribute::perceive`]): who is in its own area and, light and
+//! terrain permitting, in the neighbouring ones; what lies around where it
+//! stands; which traps it notices; which nearby areas are closed. Brains
+//! decide on the arena as the tribute remembers it
+//! ([`Beliefs::arena_view`], [`Beliefs::enemy_density`]) and only attack
+//! tributes they can see, so a hidden tribute is one nobody knows about.
+//!
+//! Beliefs lose confidence every phase and are forgotten once it runs out.
+//! Sightings of other tributes fade faster than what was seen lying in an
+//! area, since tributes move.
+
+use crate::areas::corpses::Corpse;
+use crate::areas::{Area, AreaDetails};
+use crate::items::Item;
+use crate::phases::environment::{LightLevel, derive_light_level};
+use crate::terrain::Visibility;
+use crate::tributes::Tribute;
+use crate::tributes::traps::PlacedTrap;
+use serde::{Deserialize, Serialize};
+use shared::messages::Phase;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// Confidence of something seen this phase.
+pub const FULL_CONFIDENCE: u8 = 100;
+
+/// Confidence a sighting of another tribute loses each phase: it is gone
+/// after a day.
+const SIGHTING_DECAY: u8 = 25;
+
+/// Confidence a memory of a place loses each phase: it is gone after two
+/// and a half days.
+const PLACE_DECAY: u8 = 10;
+
+/// Sightings below this confidence are too stale to count toward how
+/// crowded an area is believed to be.
+const CROWD_CONFIDENCE: u8 = 50;
+
+/// Bonus to noticing a trap in each light level, added to the tribute's
+/// intelligence modifier and checked against the trap's concealment.
+const fn trap_sight_bonus(light: LightLevel) -> u32 {
+    match light {
+        LightLevel::Bright => 10,
+        LightLevel::Dim => 5,
+        LightLevel::Dark => 0,
+    }
+}
+
+/// Something the tribute remembers and how sure of it it still is.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Belief<T> {
+    pub value: T,
+    pub confidence: u8,
+}
+
+impl<T> Belief<T> {
+    fn fresh(value: T) -> Self {
+        Self {
+            value,
+            confidence: FULL_CONFIDENCE,
+        }
+    }
+}
+
+/// What the tribute last saw lying around an area it stood in.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AreaMemory {
+    pub items: Vec<Item>,
+    pub corpses: Vec<Corpse>,
+    /// Traps set by others that the tribute noticed.
+    pub traps: Vec<PlacedTrap>,
+}
+
+/// A tribute's beliefs about the arena. Carried on `Tribute`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Beliefs {
+    /// Where each other tribute was last seen.
+    pub tributes: BTreeMap<Uuid, Belief<Area>>,
+    /// What lies around the areas the tribute has stood in.
+    pub areas: BTreeMap<Area, Belief<AreaMemory>>,
+    /// Areas the tribute has seen closed, with its confidence in each.
+    pub closed_areas: BTreeMap<Area, u8>,
+}
+
+/// What surrounds a tribute at the start of a phase, for
+/// [`Tribute::perceive`].
+pub struct Surroundings<'a> {
+    pub phase: Phase,
+    /// The tribute's own area.
+    pub here: &'a AreaDetails,
+    /// Neighbouring areas on the map.
+    pub neighbours: Vec<&'a AreaDetails>,
+    /// Living tributes by area.
+    pub tributes_by_area: &'a HashMap<Area, Vec<Tribute>>,
+    pub closed_areas: &'a [Area],
+}
+
+/// Whether an observer in `light` makes out a tribute in an area of the
+/// given `visibility`: its own area (`same_area`) or a neighbouring one.
+/// Hidden tributes can only be spotted up close, in broad daylight, with
+/// nothing to hide behind.
+pub fn spots(light: LightLevel, visibility: Visibility, hidden: bool, same_area: bool) -> bool {
+    match (same_area, hidden) {
+        (true, false) => !(light == LightLevel::Dark && visibility == Visibility::Concealed),
+        (true, true) => light == LightLevel::Bright && visibility == Visibility::Exposed,
+        (false, false) => match light {
+            LightLevel::Bright => visibility != Visibility::Concealed,
+            LightLevel::Dim => visibility == Visibility::Exposed,
+            LightLevel::Dark => false,
+        },
+        (false, true) => false,
+    }
+}
+
+impl Beliefs {
+    pub fn is_empty(&self) -> bool {
+        self.tributes.is_empty() && self.areas.is_empty() && self.closed_areas.is_empty()
+    }
+
+    /// Lose a phase's worth of confidence in everything, forgetting what
+    /// runs out.
+    pub fn fade(&mut self) {
+        self.tributes.retain(|_, belief| {
+            belief.confidence = belief.confidence.saturating_sub(SIGHTING_DECAY);
+            belief.confidence > 0
+        });
+        self.areas.retain(|_, belief| {
+            belief.confidence = belief.confidence.saturating_sub(PLACE_DECAY);
+            belief.confidence > 0
+        });
+        self.closed_areas.retain(|_, confidence| {
+            *confidence = confidence.saturating_sub(PLACE_DECAY);
+            *confidence > 0
+        });
+    }
+
+    /// Stop tracking a tribute, e.g. once its cannon has sounded.
+    pub fn forget_tribute(&mut self, tribute: &Uuid) {
+        self.tributes.remove(tribute);
+    }
+
+    /// Whether `tribute` was seen in `area` this phase.
+    pub fn sees(&self, tribute: &Uuid, area: Area) -> bool {
+        self.tributes
+            .get(tribute)
+            .is_some_and(|b| b.value == area && b.confidence == FULL_CONFIDENCE)
+    }
+
+    /// Whether the tribute has noticed the trap with this identifier.
+    pub fn knows_trap(&self, trap: &str) -> bool {
+        self.areas
+            .values()
+            .any(|b| b.value.traps.iter().any(|t| t.id == trap))
+    }
+
+    /// Areas believed closed.
+    pub fn closed(&self) -> Vec<Area> {
+        self.closed_areas.keys().copied().collect()
+    }
+
+    /// Tributes believed to be in each area, from recent sightings, with
+    /// the tribute itself counted in `own_area`.
+    pub fn enemy_density(&self, own_area: Area) -> HashMap<Area, u32> {
+        let mut density = HashMap::from([(own_area, 1)]);
+        for belief in self.tributes.values() {
+            if belief.confidence >= CROWD_CONFIDENCE {
+                *density.entry(belief.value).or_insert(0) += 1;
+            }
+        }
+        density
+    }
+
+    /// The arena as the tribute believes it to be: the map itself is
+    /// common knowledge, but the only items, bodies and traps in it are the
+    /// ones the tribute remembers.
+    pub fn arena_view(&self, areas: &[AreaDetails]) -> Vec<AreaDetails> {
+        areas
+            .iter()
+            .map(|area| {
+                let memory = area
+                    .area
+                    .and_then(|a| self.areas.get(&a))
+                    .map(|b| b.value.clone())
+                    .unwrap_or_default();
+                AreaDetails {
+                    identifier: area.identifier.clone(),
+                    name: area.name.clone(),
+                    area: area.area,
+                    items: memory.items,
+                    events: area.events.clone(),
+                    terrain: area.terrain.clone(),
+                    placed_traps: memory.traps,
+                    corpses: memory.corpses,
+                    tribute_slots: HashMap::new(),
+                    weather: area.weather,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Tribute {
+    /// Take in what the tribute can make out from its area this phase and
+    /// update its beliefs. Only ever adds what is seen: a tribute that
+    /// can't see into an area keeps believing what it last knew of it.
+    pub fn perceive(&mut self, surroundings: &Surroundings<'_>) {
+        let here = surroundings.here;
+        let light = derive_light_level(surroundings.phase, here.terrain.base, here.weather.current);
+
+        let areas = std::iter::once((here, true))
+            .chain(surroundings.neighbours.iter().map(|n| (*n, false)));
+        for (area_details, same_area) in areas {
+            let Some(area) = area_details.area else {
+                continue;
+            };
+            if surroundings.closed_areas.contains(&area) {
+                self.beliefs.closed_areas.insert(area, FULL_CONFIDENCE);
+            } else {
+                self.beliefs.closed_areas.remove(&area);
+            }
+
+            let visibility = area_details.terrain.base.visibility();
+            for other in surroundings
+                .tributes_by_area
+                .get(&area)
+                .into_iter()
+                .flatten()
+            {
+                if other.id != self.id && spots(light, visibility, !other.is_visible(), same_area) {
+                    self.beliefs.tributes.insert(other.id, Belief::fresh(area));
+                }
+            }
+        }
+
+        let sharpness = self.attributes.intelligence / 10 + trap_sight_bonus(light);
+        let traps = here
+            .placed_traps
+            .iter()
+            .filter(|t| !t.triggered && t.set_by != self.identifier)
+            .filter(|t| sharpness >= t.concealment)
+            .cloned()
+            .collect();
+        if let Some(area) = here.area {
+            self.beliefs.areas.insert(
+                area,
+                Belief::fresh(AreaMemory {
+                    items: here.items.clone(),
+                    corpses: here.corpses.clone(),
+                    traps,
+                }),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::{BaseTerrain, TerrainType};
+
+    fn area(area: Area, base: BaseTerrain) -> AreaDetails {
+        AreaDetails::new_with_terrain(None, area, TerrainType::new(base, vec![]).unwrap())
+    }
+
+    fn tribute_in(name: &str, area: Area) -> Tribute {
+        let mut tribute = Tribute::new(name.to_string(), None, None);
+        tribute.area = area;
+        tribute
+    }
+
+    #[test]
+    fn darkness_and_cover_hide_tributes() {
+        use LightLevel::*;
+        use Visibility::*;
+        assert!(spots(Dark, Moderate, false, true));
+        assert!(!spots(Dark, Concealed, false, true));
+        assert!(spots(Bright, Exposed, true, true));
+        assert!(!spots(Dim, Exposed, true, true));
+        assert!(spots(Bright, Moderate, false, false));
+        assert!(!spots(Bright, Concealed, false, false));
+        assert!(spots(Dim, Exposed, false, false));
+        assert!(!spots(Dark, Exposed, false, false));
+        assert!(!spots(Bright, Exposed, true, false));
+    }
+
+    #[test]
+    fn perceiving_records_only_what_can_be_seen() {
+        let here = area(Area::CORNUCOPIA, BaseTerrain::Clearing);
+        let open = area(Area::SECTOR_1, BaseTerrain::Grasslands);
+        let woods = area(Area::SECTOR_2, BaseTerrain::Forest);
+
+        let mut watcher = tribute_in("Watcher", Area::CORNUCOPIA);
+        let neighbour = tribute_in("Neighbour", Area::SECTOR_1);
+        let lurker = tribute_in("Lurker", Area::SECTOR_2);
+        let mut hider = tribute_in("Hider", Area::CORNUCOPIA);
+        hider.attributes.is_hidden = true;
+        let tributes_by_area = HashMap::from([
+            (Area::CORNUCOPIA, vec![watcher.clone(), hider.clone()]),
+            (Area::SECTOR_1, vec![neighbour.clone()]),
+            (Area::SECTOR_2, vec![lurker.clone()]),
+        ]);
+
+        watcher.perceive(&Surroundings {
+            phase: Phase::Day,
+            here: &here,
+            neighbours: vec![&open, &woods],
+            tributes_by_area: &tributes_by_area,
+            closed_areas: &[Area::SECTOR_2],
+        });
+
+        assert!(watcher.beliefs.sees(&neighbour.id, Area::SECTOR_1));
+        assert!(!watcher.beliefs.tributes.contains_key(&lurker.id));
+        assert!(!watcher.beliefs.tributes.contains_key(&hider.id));
+        assert!(!watcher.beliefs.tributes.contains_key(&watcher.id));
+        assert_eq!(watcher.beliefs.closed(), [Area::SECTOR_2]);
+        assert!(watcher.beliefs.areas.contains_key(&Area::CORNUCOPIA));
+        assert!(!watcher.beliefs.areas.contains_key(&Area::SECTOR_1));
+
+        // At night the open neighbour goes dark too; only the clearing's
+        // own occupants are close enough to make out.
+        let mut night_watcher = tribute_in("Night Watcher", Area::CORNUCOPIA);
+        night_watcher.perceive(&Surroundings {
+            phase: Phase::Night,
+            here: &here,
+            neighbours: vec![&open, &woods],
+            tributes_by_area: &tributes_by_area,
+            closed_areas: &[],
+        });
+        assert!(!night_watcher.beliefs.tributes.contains_key(&neighbour.id));
+        assert!(night_watcher.beliefs.sees(&watcher.id, Area::CORNUCOPIA));
+        assert!(!night_watcher.beliefs.tributes.contains_key(&hider.id));
+    }
+
+    #[test]
+    fn beliefs_fade_until_forgotten() {
+        let mut beliefs = Beliefs::default();
+        let other = Uuid::new_v4();
+        beliefs
+            .tributes
+            .insert(other, Belief::fresh(Area::SECTOR_3));
+        beliefs
+            .areas
+            .insert(Area::SECTOR_3, Belief::fresh(AreaMemory::default()));
+        assert!(beliefs.sees(&other, Area::SECTOR_3));
+        assert_eq!(beliefs.enemy_density(Area::SECTOR_1)[&Area::SECTOR_3], 1);
+
+        beliefs.fade();
+        assert!(!beliefs.sees(&other, Area::SECTOR_3));
+        beliefs.fade();
+        beliefs.fade();
+        // Too stale to count toward the crowd, but not forgotten yet.
+        assert!(
+            !beliefs
+                .enemy_density(Area::SECTOR_1)
+                .contains_key(&Area::SECTOR_3)
+        );
+        assert!(beliefs.tributes.contains_key(&other));
+        beliefs.fade();
+        assert!(beliefs.tributes.is_empty());
+        assert!(beliefs.areas.contains_key(&Area::SECTOR_3));
+    }
+
+    #[test]
+    fn arena_view_shows_only_remembered_contents() {
+        let mut stocked = area(Area::SECTOR_1, BaseTerrain::Clearing);
+        stocked.items.push(Item::new_random(None));
+        let mut beliefs = Beliefs::default();
+
+        let view = beliefs.arena_view(std::slice::from_ref(&stocked));
+        assert!(view[0].items.is_empty());
+        assert_eq!(view[0].terrain, stocked.terrain);
+
+        beliefs.areas.insert(
+            Area::SECTOR_1,
+            Belief::fresh(AreaMemory {
+                items: stocked.items.clone(),
+                ..AreaMemory::default()
+            }),
+        );
+        stocked.items.clear();
+        let view = beliefs.arena_view(std::slice::from_ref(&stocked));
+        assert_eq!(view[0].items.len(), 1);
+    }
+}
@@ -206,7 +206,7 @@ mod tests {
         tribute.afflictions.insert(aff.key(), aff);
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(BaseTerrain::Mountains),
         );
         assert_eq!(result, Some(Action::Rest));
@@ -219,7 +219,7 @@ mod tests {
         tribute.afflictions.insert(aff.key(), aff);
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(BaseTerrain::Highlands),
         );
         assert_eq!(result, Some(Action::Rest));
@@ -232,7 +232,7 @@ mod tests {
         tribute.afflictions.insert(aff.key(), aff);
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(BaseTerrain::Wetlands),
         );
         assert_eq!(result, Some(Action::Rest));
@@ -245,7 +245,7 @@ mod tests {
         tribute.afflictions.insert(aff.key(), aff);
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(BaseTerrain::Forest),
         );
         assert!(result.is_none());
@@ -256,7 +256,7 @@ mod tests {
         let mut tribute = Tribute::new("Test".to_string(), None, None);
         let aff = make_affliction(AfflictionKind::MissingLeg, Severity::Moderate);
         tribute.afflictions.insert(aff.key(), aff);
-        let result = hard_gates_with_terrain(&tribute, &Action::Move(Some(Area::Sector1)), None);
+        let result = hard_gates_with_terrain(&tribute, &Action::Move(Some(Area::SECTOR_1)), None);
         assert!(result.is_none());
     }
 
@@ -285,7 +285,7 @@ mod tests {
         tribute.afflictions.insert(aff.key(), aff);
         let result = hard_gates_with_terrain(
             &tribute,
-            &Action::Move(Some(Area::Sector1)),
+            &Action::Move(Some(Area::SECTOR_1)),
             Some(BaseTerrain::Mountains),
         );
         assert_eq!(result, Some(Action::Rest));
@@ -299,7 +299,7 @@ mod tests {
             Severity::Moderate,
         );
         tribute.afflictions.insert(aff.key(), aff);
-        let result = affliction_override(&tribute, &Action::Move(Some(Area::Cornucopia)));
+        let result = affliction_override(&tribute, &Action::Move(Some(Area::CORNUCOPIA)));
         assert_eq!(result, Some(Action::None));
     }
 
@@ -335,7 +335,7 @@ mod tests {
             Severity::Moderate,
         );
         tribute.afflictions.insert(aff.key(), aff);
-        let result = hard_gates_with_terrain(&tribute, &Action::Move(Some(Area::Cornucopia)), None);
+        let result = hard_gates_with_terrain(&tribute, &Action::Move(Some(Area::CORNUCOPIA)), None);
         assert_eq!(result, Some(Action::None));
     }
 }
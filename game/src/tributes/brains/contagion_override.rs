@@ -0,0 +1,114 @@
+//! Contagion override layer for the brain pipeline.
+//!
+//! Runs first, straight after the liveness check, ahead of psychotic
+//! breaks and every other layer: a transformed tribute's compulsion is
+//! stronger than anything else in its head. Incubating tributes act
+//! normally.
+//!
+//! Pipeline order: [liveness, **contagion**, psychotic break, survival, ...]
+
+use crate::tributes::Tribute;
+use crate::tributes::actions::Action;
+use shared::afflictions::Contagion;
+use shared::messages::Phase;
+
+/// Contagion override layer entry point.
+///
+/// - Zombie plague: attack whenever anyone is in reach, otherwise roam in
+///   search of someone. Every phase.
+/// - Lycanthropy: the same hunt, but only at Night.
+/// - Vampirism: hide from the sun at Dawn and Day, holding still once
+///   hidden.
+///
+/// Returns `None` outside those windows, or for a tribute that has not
+/// transformed.
+pub fn contagion_override(
+    tribute: &Tribute,
+    nearby_tributes: u32,
+    phase: Option<Phase>,
+) -> Option<Action> {
+    let hunt = || {
+        if nearby_tributes > 0 {
+            Action::Attack
+        } else {
+            Action::Move(None)
+        }
+    };
+    match tribute.transformed_contagion()? {
+        Contagion::ZombiePlague => Some(hunt()),
+        Contagion::Lycanthropy => matches!(phase, Some(Phase::Night)).then(hunt),
+        Contagion::Vampirism => matches!(phase, Some(Phase::Dawn | Phase::Day)).then(|| {
+            if tribute.attributes.is_hidden {
+                Action::None
+            } else {
+                Action::Hide
+            }
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tributes::afflictions::contagion::contagion_draft;
+    use shared::afflictions::{AfflictionSource, Severity};
+
+    fn transformed(contagion: Contagion) -> Tribute {
+        let mut tribute = Tribute::new("Carrier".to_string(), None, None);
+        let mut draft = contagion_draft(contagion, AfflictionSource::Gamemaker);
+        draft.severity = Severity::Severe;
+        tribute.try_acquire_affliction(draft);
+        tribute
+    }
+
+    #[test]
+    fn zombies_hunt_in_every_phase() {
+        let zombie = transformed(Contagion::ZombiePlague);
+        for phase in Phase::all() {
+            assert_eq!(
+                contagion_override(&zombie, 2, Some(phase)),
+                Some(Action::Attack)
+            );
+            assert_eq!(
+                contagion_override(&zombie, 0, Some(phase)),
+                Some(Action::Move(None))
+            );
+        }
+    }
+
+    #[test]
+    fn werewolves_hunt_only_at_night() {
+        let werewolf = transformed(Contagion::Lycanthropy);
+        assert_eq!(
+            contagion_override(&werewolf, 1, Some(Phase::Night)),
+            Some(Action::Attack)
+        );
+        assert_eq!(contagion_override(&werewolf, 1, Some(Phase::Day)), None);
+        assert_eq!(contagion_override(&werewolf, 1, None), None);
+    }
+
+    #[test]
+    fn vampires_shelter_from_the_sun() {
+        let mut vampire = transformed(Contagion::Vampirism);
+        assert_eq!(
+            contagion_override(&vampire, 1, Some(Phase::Dawn)),
+            Some(Action::Hide)
+        );
+        vampire.attributes.is_hidden = true;
+        assert_eq!(
+            contagion_override(&vampire, 1, Some(Phase::Day)),
+            Some(Action::None)
+        );
+        assert_eq!(contagion_override(&vampire, 1, Some(Phase::Night)), None);
+    }
+
+    #[test]
+    fn incubating_tributes_act_normally() {
+        let mut tribute = Tribute::new("Bitten".to_string(), None, None);
+        tribute.try_acquire_affliction(contagion_draft(
+            Contagion::ZombiePlague,
+            AfflictionSource::Gamemaker,
+        ));
+        assert_eq!(contagion_override(&tribute, 3, Some(Phase::Day)), None);
+    }
+}
@@ -2,7 +2,7 @@ use crate::areas::{Area, AreaDetails};
 use crate::terrain::{BaseTerrain, Harshness, TerrainType, Visibility};
 use crate::tributes::Tribute;
 use crate::tributes::actions::Action;
-use crate::tributes::alliances::MAX_ALLIES;
+use crate::tributes::alliances::{MAX_ALLIES, Watch};
 use crate::tributes::traits::{REFUSERS, ThresholdDelta, Trait, geometric_mean_affinity};
 use rand::Rng;
 use rand::RngExt;
@@ -11,27 +11,18 @@ use std::collections::HashMap;
 
 pub mod addiction_override;
 pub mod affliction_override;
+pub mod contagion_override;
 pub mod fixation_override;
 pub mod phobia_override;
+pub mod strategy;
+pub mod trace;
 pub mod trauma_override;
+pub mod tuning;
 
 mod scoring;
 use scoring::*;
-
-const LOW_ENEMY_LIMIT: u32 = 6;
-
-/// Sleep gating thresholds (PR2c.1, bd-9sjj). See `Brain::should_sleep`.
-const SLEEP_DOMINANT_THRESHOLD: u32 = 12;
-const SLEEP_WANT_THRESHOLD: u32 = 6;
-const SLEEP_EXHAUSTED_PCT: u32 = 25;
-
-/// Score penalty applied per enemy in a destination area, used by
-/// `Brain::choose_destination` to disperse crowded tributes.
-const CROWD_PENALTY_PER_ENEMY: i32 = 8;
-
-/// Cap on the cumulative crowd penalty so a single mob doesn't drown out
-/// affinity / harshness signals entirely.
-const CROWD_PENALTY_MAX: i32 = 32;
+use trace::{DecisionLayer, Reasoning, TRACED_DESTINATIONS, ThresholdCheck};
+use tuning::BrainTuning;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum PsychoticBreakType {
@@ -58,7 +49,8 @@ pub struct PersonalityThresholds {
 impl PersonalityThresholds {
     /// Derive thresholds from a tribute's traits. Sums each trait's
     /// `ThresholdDelta`, applies it to baseline values, then applies ±20%
-    /// individual variance. Each field is clamped to at least 1.
+    /// individual variance. Each field is clamped to at least 1. Baselines
+    /// are the default [`BrainTuning`]'s; see [`retuned`](Self::retuned).
     ///
     /// Field mapping from `ThresholdDelta` to `PersonalityThresholds`:
     /// - `low_health_limit`        → `low_health`
@@ -81,15 +73,18 @@ impl PersonalityThresholds {
         }
 
         // Baseline values match the original `Balanced` personality.
-        let base_low_health: i32 = 20;
-        let base_mid_health: i32 = 40;
-        let base_extreme_low_sanity: i32 = 10;
-        let base_low_sanity: i32 = 20;
-        let base_mid_sanity: i32 = 35;
-        let base_low_movement: i32 = 10;
-        let base_high_intelligence: i32 = 35;
-        let base_low_intelligence: i32 = 80;
-        let base_break_threshold: i32 = 8;
+        let BrainTuning {
+            base_low_health,
+            base_mid_health,
+            base_extreme_low_sanity,
+            base_low_sanity,
+            base_mid_sanity,
+            base_low_movement,
+            base_high_intelligence,
+            base_low_intelligence,
+            base_psychotic_break: base_break_threshold,
+            ..
+        } = BrainTuning::default();
 
         let delta: ThresholdDelta = traits.iter().map(|t| t.threshold_modifiers()).sum();
 
@@ -117,6 +112,64 @@ impl PersonalityThresholds {
             ),
         }
     }
+
+    /// These thresholds with each one moved by however far `tuning`'s
+    /// baseline sits from the default, keeping the tribute's own trait
+    /// deltas and variance. Each field stays at least 1.
+    pub fn retuned(&self, tuning: &BrainTuning) -> Self {
+        fn shift(value: u32, from: i32, to: i32) -> u32 {
+            (value as i32 + to - from).max(1) as u32
+        }
+
+        let base = BrainTuning::default();
+        PersonalityThresholds {
+            low_health: shift(
+                self.low_health,
+                base.base_low_health,
+                tuning.base_low_health,
+            ),
+            mid_health: shift(
+                self.mid_health,
+                base.base_mid_health,
+                tuning.base_mid_health,
+            ),
+            extreme_low_sanity: shift(
+                self.extreme_low_sanity,
+                base.base_extreme_low_sanity,
+                tuning.base_extreme_low_sanity,
+            ),
+            low_sanity: shift(
+                self.low_sanity,
+                base.base_low_sanity,
+                tuning.base_low_sanity,
+            ),
+            mid_sanity: shift(
+                self.mid_sanity,
+                base.base_mid_sanity,
+                tuning.base_mid_sanity,
+            ),
+            low_movement: shift(
+                self.low_movement,
+                base.base_low_movement,
+                tuning.base_low_movement,
+            ),
+            high_intelligence: shift(
+                self.high_intelligence,
+                base.base_high_intelligence,
+                tuning.base_high_intelligence,
+            ),
+            low_intelligence: shift(
+                self.low_intelligence,
+                base.base_low_intelligence,
+                tuning.base_low_intelligence,
+            ),
+            psychotic_break_threshold: shift(
+                self.psychotic_break_threshold,
+                base.base_psychotic_break,
+                tuning.base_psychotic_break,
+            ),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -137,6 +190,10 @@ pub struct Brain {
     pub preferred_action: Option<Action>,
     #[serde(default)]
     pub preferred_action_percentage: f64,
+    /// The game's `GameConfig::brain`, copied in by the cycle before each
+    /// decision. Not persisted; brains outside a game use the defaults.
+    #[serde(skip)]
+    pub tuning: BrainTuning,
 }
 
 /// Deserialize an `Option<E>` for any externally-tagged enum `E`, treating
@@ -166,6 +223,7 @@ impl Default for Brain {
             psychotic_break: None,
             preferred_action: None,
             preferred_action_percentage: 0.0,
+            tuning: BrainTuning::default(),
         }
     }
 }
@@ -178,6 +236,7 @@ impl Brain {
             psychotic_break: None,
             preferred_action: None,
             preferred_action_percentage: 0.0,
+            tuning: BrainTuning::default(),
         }
     }
 
@@ -254,9 +313,37 @@ impl Brain {
         enemy_density: &HashMap<Area, u32>,
         phase: shared::messages::Phase,
         rng: &mut impl Rng,
+    ) -> Action {
+        self.act_traced(
+            tribute,
+            nearby_tributes,
+            available_destinations,
+            all_areas,
+            closed_areas,
+            enemy_density,
+            phase,
+            rng,
+            &mut Reasoning::default(),
+        )
+    }
+
+    /// [`act`](Self::act), noting in `reasoning` each layer that had a
+    /// say, the thresholds it checked and the candidates it scored.
+    #[allow(clippy::too_many_arguments)]
+    pub fn act_traced(
+        &self,
+        tribute: &Tribute,
+        nearby_tributes: u32,
+        available_destinations: &[crate::areas::DestinationInfo],
+        all_areas: &[AreaDetails],
+        closed_areas: &[Area],
+        enemy_density: &HashMap<Area, u32>,
+        phase: shared::messages::Phase,
+        rng: &mut impl Rng,
+        reasoning: &mut Reasoning,
     ) -> Action {
         let area = all_areas.iter().find(|a| a.area == Some(tribute.area));
-        if let Some(early) = self.run_pre_decision_overrides(
+        if let Some((layer, early)) = self.run_pre_decision_overrides(
             tribute,
             nearby_tributes,
             None,
@@ -265,95 +352,240 @@ impl Brain {
             &crate::config::GameConfig::default(),
             rng,
         ) {
+            reasoning.step(layer, &early);
             return early;
         }
 
+        if let Some(step) = self.follow_plan(tribute, nearby_tributes, available_destinations) {
+            reasoning.step(DecisionLayer::Plan, &step);
+            return step;
+        }
+
+        if let Some(step) =
+            self.follow_rally(tribute, available_destinations, all_areas, closed_areas)
+        {
+            reasoning.step(DecisionLayer::Alliance, &step);
+            return step;
+        }
+
         let action = if nearby_tributes == 0 {
             self.decide_action_no_enemies(tribute, rng)
-        } else if nearby_tributes < LOW_ENEMY_LIMIT {
+        } else if nearby_tributes < self.tuning.low_enemy_limit {
             self.decide_action_few_enemies(tribute)
         } else {
             self.decide_action_many_enemies(tribute)
         };
+        reasoning.step_with(
+            DecisionLayer::Base,
+            &action,
+            self.consulted_thresholds(tribute, nearby_tributes),
+        );
+        let steered = self.steer_by_emotion(tribute, nearby_tributes, action.clone());
+        if nearby_tributes > 0 {
+            let tuning = crate::tributes::combat_tuning::CombatTuning::default();
+            reasoning.score(
+                Action::Attack,
+                action_score(tribute, &Action::Attack, &[], &tuning, &self.tuning),
+            );
+        }
+        if steered != action {
+            reasoning.step(DecisionLayer::Emotion, &steered);
+        }
+        let action = self.steer_to_loot(tribute, nearby_tributes, area, steered.clone());
+        if action != steered {
+            reasoning.step(DecisionLayer::Loot, &action);
+        }
 
-        // If the action is Move(None), choose smart destination based on terrain
         match action {
+            // Where the group goes is the leader's call.
+            Action::Move(None) if tribute.rally == Some(tribute.area) => {
+                reasoning.step(DecisionLayer::Alliance, &Action::Rest);
+                Action::Rest
+            }
             Action::Move(None) => {
-                // Multi-hop pathfinding (8pq): score every known area
-                // (not just neighbors), then plan a stamina-aware path
-                // and return the first hop. Falls through to neighbor-
-                // only legacy behavior when `all_areas` is empty.
-                if !all_areas.is_empty()
-                    && let Some(best_goal) =
-                        self.choose_destination(all_areas, tribute, enemy_density)
-                {
-                    // With per-area enemy density baked into the score (see
-                    // `choose_destination`), a non-current empty area now
-                    // naturally outscores the crowded current one, so the
-                    // legacy escape-hatch is gone. If the best area is still
-                    // the current one, the tribute simply rests.
-                    if best_goal == tribute.area {
-                        return Action::Rest;
-                    }
-                    let goal = best_goal;
-                    if let Some((path, _cost)) = crate::areas::path::plan_path(
-                        all_areas,
-                        closed_areas,
-                        tribute,
-                        tribute.area,
-                        goal,
-                    ) && path.len() >= 2
-                    {
-                        let first_hop = path[1];
-                        // Stamina gate against the first hop's cost
-                        // (use available_destinations if present, else
-                        // fall back to permitting the move).
-                        let cost_ok = available_destinations
-                            .iter()
-                            .find(|d| d.area == first_hop)
-                            .map(|d| tribute.stamina >= d.stamina_cost)
-                            .unwrap_or(true);
-                        if cost_ok {
-                            return Action::Move(Some(first_hop));
-                        }
-                        return Action::Rest;
-                    }
-                }
+                let action = self.resolve_move(
+                    tribute,
+                    available_destinations,
+                    all_areas,
+                    closed_areas,
+                    enemy_density,
+                    reasoning,
+                );
+                reasoning.step(DecisionLayer::Destination, &action);
+                action
+            }
+            other => other,
+        }
+    }
 
-                // Legacy neighbor-only path (also used by brains tests
-                // that call `act` with empty slices).
-                if available_destinations.is_empty() {
-                    return Action::Move(None);
-                }
+    /// The next step of the tribute's plan, if it has one and nothing more
+    /// pressing: company pauses a plan unless the company is what the plan
+    /// was after. A tribute too tired for the next hop rests instead.
+    fn follow_plan(
+        &self,
+        tribute: &Tribute,
+        nearby_tributes: u32,
+        available_destinations: &[crate::areas::DestinationInfo],
+    ) -> Option<Action> {
+        let plan = tribute.plan.as_ref()?;
+        if nearby_tributes > 1 && !(plan.route.is_empty() && plan.goal.seeks_company()) {
+            return None;
+        }
+        let step = plan.next_step();
+        if let Action::Move(Some(next)) = step
+            && available_destinations
+                .iter()
+                .any(|d| d.area == next && tribute.stamina < d.stamina_cost)
+        {
+            return Some(Action::Rest);
+        }
+        Some(step)
+    }
+
+    /// The next hop toward where the tribute's alliance is gathering, if
+    /// it has fallen behind. A tribute too tired for the hop rests instead.
+    fn follow_rally(
+        &self,
+        tribute: &Tribute,
+        available_destinations: &[crate::areas::DestinationInfo],
+        all_areas: &[AreaDetails],
+        closed_areas: &[Area],
+    ) -> Option<Action> {
+        let rally = tribute.rally.filter(|rally| *rally != tribute.area)?;
+        let next = if all_areas.is_empty() {
+            rally
+        } else {
+            let (path, _cost) = crate::areas::path::plan_path(
+                all_areas,
+                closed_areas,
+                tribute,
+                tribute.area,
+                rally,
+            )?;
+            *path.get(1)?
+        };
+        let destination = available_destinations.iter().find(|d| d.area == next)?;
+        if tribute.stamina < destination.stamina_cost {
+            return Some(Action::Rest);
+        }
+        Some(Action::Move(Some(next)))
+    }
 
-                // Convert DestinationInfo to AreaDetails for choose_destination
-                let area_details: Vec<AreaDetails> = available_destinations
-                    .iter()
-                    .map(|dest| AreaDetails {
-                        area: Some(dest.area),
-                        terrain: dest.terrain.clone(),
-                        events: dest.active_events.clone(),
-                        ..AreaDetails::default()
-                    })
-                    .collect();
-
-                // Choose best destination using terrain scoring
-                if let Some(best_area) =
-                    self.choose_destination(&area_details, tribute, enemy_density)
+    /// Turn a `Move(None)` into a destination, or a rest when there is
+    /// nowhere worth going or no stamina to get there. Scored destinations
+    /// go into `reasoning`.
+    fn resolve_move(
+        &self,
+        tribute: &Tribute,
+        available_destinations: &[crate::areas::DestinationInfo],
+        all_areas: &[AreaDetails],
+        closed_areas: &[Area],
+        enemy_density: &HashMap<Area, u32>,
+        reasoning: &mut Reasoning,
+    ) -> Action {
+        // Multi-hop pathfinding (8pq): score every known area
+        // (not just neighbors), then plan a stamina-aware path
+        // and return the first hop. Falls through to neighbor-
+        // only legacy behavior when `all_areas` is empty.
+        if !all_areas.is_empty() {
+            let scores = self.destination_scores(all_areas, tribute, enemy_density);
+            trace_destinations(&scores, reasoning);
+            if let Some(best_goal) = best_destination(&scores) {
+                // With per-area enemy density baked into the score (see
+                // `choose_destination`), a non-current empty area now
+                // naturally outscores the crowded current one, so the
+                // legacy escape-hatch is gone. If the best area is still
+                // the current one, the tribute simply rests.
+                if best_goal == tribute.area {
+                    return Action::Rest;
+                }
+                let goal = best_goal;
+                if let Some((path, _cost)) = crate::areas::path::plan_path(
+                    all_areas,
+                    closed_areas,
+                    tribute,
+                    tribute.area,
+                    goal,
+                ) && path.len() >= 2
                 {
-                    // Also check if tribute has enough stamina
-                    if let Some(dest_info) =
-                        available_destinations.iter().find(|d| d.area == best_area)
-                        && tribute.stamina >= dest_info.stamina_cost
-                    {
-                        return Action::Move(Some(best_area));
+                    let first_hop = path[1];
+                    // Stamina gate against the first hop's cost
+                    // (use available_destinations if present, else
+                    // fall back to permitting the move).
+                    let cost_ok = available_destinations
+                        .iter()
+                        .find(|d| d.area == first_hop)
+                        .map(|d| tribute.stamina >= d.stamina_cost)
+                        .unwrap_or(true);
+                    if cost_ok {
+                        return Action::Move(Some(first_hop));
                     }
+                    return Action::Rest;
                 }
-                // Fall back to rest if no good destination or insufficient stamina
-                Action::Rest
             }
-            other => other,
         }
+
+        // Legacy neighbor-only path (also used by brains tests
+        // that call `act` with empty slices).
+        if available_destinations.is_empty() {
+            return Action::Move(None);
+        }
+
+        // Convert DestinationInfo to AreaDetails for choose_destination
+        let area_details: Vec<AreaDetails> = available_destinations
+            .iter()
+            .map(|dest| AreaDetails {
+                area: Some(dest.area),
+                terrain: dest.terrain.clone(),
+                events: dest.active_events.clone(),
+                ..AreaDetails::default()
+            })
+            .collect();
+
+        // Choose best destination using terrain scoring
+        let scores = self.destination_scores(&area_details, tribute, enemy_density);
+        trace_destinations(&scores, reasoning);
+        if let Some(best_area) = best_destination(&scores) {
+            // Also check if tribute has enough stamina
+            if let Some(dest_info) = available_destinations.iter().find(|d| d.area == best_area)
+                && tribute.stamina >= dest_info.stamina_cost
+            {
+                return Action::Move(Some(best_area));
+            }
+        }
+        // Fall back to rest if no good destination or insufficient stamina
+        Action::Rest
+    }
+
+    /// The personality thresholds the health/sanity state machine checks
+    /// for a tribute with `nearby_tributes` around, and the values it
+    /// checks them against.
+    fn consulted_thresholds(&self, tribute: &Tribute, nearby_tributes: u32) -> Vec<ThresholdCheck> {
+        let t = &self.thresholds;
+        let health = tribute.effective_health();
+        let sanity = tribute.effective_sanity();
+        if nearby_tributes >= self.tuning.low_enemy_limit {
+            let recklessness = 100_u32
+                .saturating_sub(tribute.attributes.intelligence)
+                .saturating_sub(sanity);
+            return vec![
+                ThresholdCheck::new("high_intelligence", t.high_intelligence, recklessness),
+                ThresholdCheck::new("low_intelligence", t.low_intelligence, recklessness),
+            ];
+        }
+        let mut checks = vec![
+            ThresholdCheck::new("low_health", t.low_health, health),
+            ThresholdCheck::new("mid_health", t.mid_health, health),
+            ThresholdCheck::new("low_sanity", t.low_sanity, sanity),
+        ];
+        if nearby_tributes > 0 && health < t.low_health {
+            checks.extend([
+                ThresholdCheck::new("low_movement", t.low_movement, tribute.attributes.movement),
+                ThresholdCheck::new("mid_sanity", t.mid_sanity, sanity),
+                ThresholdCheck::new("extreme_low_sanity", t.extreme_low_sanity, sanity),
+            ]);
+        }
+        checks
     }
 
     /// Choose the best destination from available areas based on terrain scoring.
@@ -364,6 +596,7 @@ impl Brain {
     /// - -10 per harshness tier (Mild=0, Moderate=-10, Harsh=-20)
     /// - +5 if terrain visibility is Concealed (good for hiding)
     /// - +3 if area has items
+    /// - +1 per 4 points of loot left on bodies, up to +15
     /// - +60 (3.0x * 20) if tribute health < 30 and area has affinity terrain (desperate behavior)
     pub fn choose_destination(
         &self,
@@ -371,34 +604,40 @@ impl Brain {
         tribute: &Tribute,
         enemy_density: &HashMap<Area, u32>,
     ) -> Option<Area> {
-        if areas.is_empty() {
-            return None;
-        }
+        best_destination(&self.destination_scores(areas, tribute, enemy_density))
+    }
 
+    /// Every area scored as in [`choose_destination`](Self::choose_destination),
+    /// in the order given.
+    pub fn destination_scores(
+        &self,
+        areas: &[AreaDetails],
+        tribute: &Tribute,
+        enemy_density: &HashMap<Area, u32>,
+    ) -> Vec<(Area, i32)> {
         let is_desperate = tribute.effective_health() < 30;
 
-        let mut best_score = i32::MIN;
-        let mut best_area: Option<Area> = None;
-
+        let mut scores = Vec::with_capacity(areas.len());
         for area_details in areas {
+            let Some(area) = area_details.area else {
+                continue;
+            };
             let mut score = 0i32;
 
             // Crowd penalty: subtract a per-enemy amount from this area's
             // score, excluding the tribute itself. This naturally produces
             // dispersion (a non-current empty area outscores the crowded
             // current one) without a call-site special case.
-            if let Some(area) = area_details.area {
-                let raw = enemy_density.get(&area).copied().unwrap_or(0);
-                let others = if area == tribute.area {
-                    raw.saturating_sub(1)
-                } else {
-                    raw
-                };
-                let penalty = (others as i32)
-                    .saturating_mul(CROWD_PENALTY_PER_ENEMY)
-                    .min(CROWD_PENALTY_MAX);
-                score -= penalty;
-            }
+            let raw = enemy_density.get(&area).copied().unwrap_or(0);
+            let others = if area == tribute.area {
+                raw.saturating_sub(1)
+            } else {
+                raw
+            };
+            let penalty = (others as i32)
+                .saturating_mul(self.tuning.crowd_penalty_per_enemy)
+                .min(self.tuning.crowd_penalty_max);
+            score -= penalty;
 
             // Affinity bonus: +20 if terrain matches tribute's affinity
             let has_affinity = tribute
@@ -434,13 +673,14 @@ impl Brain {
                 score += 3;
             }
 
-            if score > best_score {
-                best_score = score;
-                best_area = area_details.area;
-            }
+            // Loot bonus: gear left on bodies, best weapons counting most
+            score += (area_details.corpse_loot_value() as i32 / 4)
+                .min(self.tuning.corpse_loot_bonus_max);
+
+            scores.push((area, score));
         }
 
-        best_area
+        scores
     }
 
     /// Decide action with terrain awareness. Modifies action weights based on terrain.
@@ -456,7 +696,7 @@ impl Brain {
         phase: shared::messages::Phase,
         rng: &mut impl Rng,
     ) -> Action {
-        if let Some(early) = self.run_pre_decision_overrides(
+        if let Some((_, early)) = self.run_pre_decision_overrides(
             tribute,
             nearby_tributes,
             Some(terrain.base),
@@ -480,18 +720,19 @@ impl Brain {
         // Decide base action
         let base_action = if nearby_tributes == 0 {
             self.decide_action_no_enemies(tribute, rng)
-        } else if nearby_tributes < LOW_ENEMY_LIMIT {
+        } else if nearby_tributes < self.tuning.low_enemy_limit {
             self.decide_action_few_enemies_with_terrain(tribute, is_concealed)
         } else {
             self.decide_action_many_enemies_with_terrain(tribute, is_concealed)
         };
+        let base_action = self.steer_by_emotion(tribute, nearby_tributes, base_action);
 
         // Stamina action-gate: an actor that can't pay the per-swing cost
         // cannot take Attack. Fall back to Rest so the tribute recovers
         // instead of cycling back into the same un-payable choice.
         let tuning = crate::tributes::combat_tuning::CombatTuning::default();
         let base_action = if matches!(base_action, Action::Attack)
-            && action_score(tribute, &Action::Attack, &[], &tuning) == i32::MIN
+            && action_score(tribute, &Action::Attack, &[], &tuning, &self.tuning) == i32::MIN
         {
             Action::Rest
         } else {
@@ -512,11 +753,67 @@ impl Brain {
         }
     }
 
+    /// Emotional steer applied after the health/sanity state machine picks
+    /// an action. With enemies in sight, a furious tribute (attack score at
+    /// or above the tuning's `emotional_attack_urge`) stops hiding or
+    /// resting and attacks, and a panicking one (at or below
+    /// `emotional_flight_urge`)
+    /// runs instead of fighting. Alone, a despairing tribute stops roaming.
+    fn steer_by_emotion(&self, tribute: &Tribute, nearby_tributes: u32, action: Action) -> Action {
+        if nearby_tributes == 0 {
+            if tribute.emotions.morale <= self.tuning.despair_morale
+                && matches!(action, Action::Move(None))
+            {
+                return Action::Rest;
+            }
+            return action;
+        }
+        let tuning = crate::tributes::combat_tuning::CombatTuning::default();
+        let urge = action_score(tribute, &Action::Attack, &[], &tuning, &self.tuning);
+        if urge == i32::MIN {
+            return action;
+        }
+        match action {
+            Action::Attack if urge <= self.tuning.emotional_flight_urge => Action::Move(None),
+            Action::Hide | Action::Rest | Action::None | Action::Move(None)
+                if urge >= self.tuning.emotional_attack_urge =>
+            {
+                Action::Attack
+            }
+            other => other,
+        }
+    }
+
+    /// Scavenge instead of idling or wandering off when there is gear on a
+    /// body right here, unless the tribute is badly hurt or the area is
+    /// crowded.
+    fn steer_to_loot(
+        &self,
+        tribute: &Tribute,
+        nearby_tributes: u32,
+        area: Option<&AreaDetails>,
+        action: Action,
+    ) -> Action {
+        let loot = area.map_or(0, AreaDetails::corpse_loot_value);
+        if loot == 0
+            || nearby_tributes >= self.tuning.low_enemy_limit
+            || tribute.effective_health() < self.thresholds.low_health
+        {
+            return action;
+        }
+        match action {
+            Action::Hide | Action::Rest | Action::None | Action::Move(None) => Action::TakeItem,
+            other => other,
+        }
+    }
+
     /// Phase-aware sleep gate (PR2c.1, bd-9sjj). Decides whether the
     /// tribute should begin a multi-phase sleep *now*, returning
     /// `Some(Action::Sleep { duration_phases })` to preempt the standard
     /// brain pipeline, or `None` to defer to `act`.
     ///
+    /// Thresholds below are the [`BrainTuning`] defaults.
+    ///
     /// Conditions (per spec `2026-05-03-four-phase-day-design.md` §6.4):
     /// - Already-sleeping tributes never re-enter the gate (they bypass
     ///   `process_turn_phase` entirely via the engine's sleep tick).
@@ -527,6 +824,10 @@ impl Brain {
     ///   AND phase is Night or Dusk: sleep. Duration: 3 phases.
     /// - Stamina exhausted (≤25% of max) AND no nearby hostiles AND
     ///   non-Day phase: sleep. Duration: 2 phases.
+    ///
+    /// An alliance's watch (see `Tribute::watch`) counts as no nearby
+    /// hostiles for the guarded, and keeps the watcher up short of the
+    /// dominant threshold.
     pub fn should_sleep(
         &self,
         tribute: &Tribute,
@@ -542,48 +843,59 @@ impl Brain {
         if self.psychotic_break.is_some() {
             return None;
         }
+        // A contagion compulsion keeps the tribute on its feet.
+        if contagion_override::contagion_override(tribute, nearby_tributes, Some(phase)).is_some() {
+            return None;
+        }
 
-        let safe = nearby_tributes == 0;
+        // An ally on watch makes anywhere safe enough to sleep.
+        let safe = nearby_tributes == 0 || tribute.watch == Some(Watch::Guarded);
         let is_night_or_dusk = matches!(phase, Phase::Night | Phase::Dusk);
         let is_day = matches!(phase, Phase::Day);
 
-        if tribute.cycles_awake >= SLEEP_DOMINANT_THRESHOLD {
+        if tribute.cycles_awake >= self.tuning.sleep_dominant_threshold {
             return Some(Action::Sleep { duration_phases: 4 });
         }
+        if tribute.watch == Some(Watch::Keeping) {
+            return None;
+        }
 
-        if tribute.cycles_awake >= SLEEP_WANT_THRESHOLD && safe && is_night_or_dusk {
+        if tribute.cycles_awake >= self.tuning.sleep_want_threshold && safe && is_night_or_dusk {
             return Some(Action::Sleep { duration_phases: 3 });
         }
 
         let stamina_pct = (tribute.stamina * 100)
             .checked_div(tribute.max_stamina)
             .unwrap_or(0);
-        if stamina_pct <= SLEEP_EXHAUSTED_PCT && safe && !is_day {
+        if stamina_pct <= self.tuning.sleep_exhausted_pct && safe && !is_day {
             return Some(Action::Sleep { duration_phases: 2 });
         }
 
         None
     }
 
-    /// Shared pre-decision override pipeline. Returns `Some(action)` to
-    /// short-circuit the per-call base scoring, or `None` to fall through
-    /// to the normal nearby-enemies branching.
+    /// Shared pre-decision override pipeline. Returns `Some((layer,
+    /// action))` to short-circuit the per-call base scoring, naming the
+    /// layer that fired, or `None` to fall through to the normal
+    /// nearby-enemies branching.
     ///
     /// Layers run in this order:
     /// 1. Liveness (dead → `Action::None`)
-    /// 2. Psychotic break
-    /// 3. Survival override (terrain-dependent; skipped when `terrain` is `None`)
-    /// 4. Stamina override (terrain-dependent for parity with survival)
-    /// 5. Fixation override (spec §8 — per-tier override semantics)
-    /// 6. Phobia override (spec §5 — fires freeze reactions, stat penalties)
-    /// 7. Trauma override (spec §7 — avoidance hard veto)
-    /// 8. Addiction override (spec §7-8 — craving/compulsion)
-    /// 9. Affliction override (hard gates + brain bias; spec §11)
-    /// 10. Preferred action
-    /// 11. Alliance proposal
-    /// 12. Consumable
+    /// 2. Contagion override (transformed zombies, werewolves at Night,
+    ///    vampires at Dawn/Day)
+    /// 3. Psychotic break
+    /// 4. Survival override (terrain-dependent; skipped when `terrain` is `None`)
+    /// 5. Stamina override (terrain-dependent for parity with survival)
+    /// 6. Fixation override (spec §8 — per-tier override semantics)
+    /// 7. Phobia override (spec §5 — fires freeze reactions, stat penalties)
+    /// 8. Trauma override (spec §7 — avoidance hard veto)
+    /// 9. Addiction override (spec §7-8 — craving/compulsion)
+    /// 10. Affliction override (hard gates + brain bias; spec §11)
+    /// 11. Preferred action
+    /// 12. Alliance proposal
+    /// 13. Consumable
     ///
-    /// Layers 3 and 4 are gated on `terrain.is_some()` because the legacy
+    /// Layers 4 and 5 are gated on `terrain.is_some()` because the legacy
     /// `act` entry point does not yet receive the tribute's current terrain
     /// — those overrides depend on terrain for water/forage richness and
     /// would be unsafe to fire blind.
@@ -597,13 +909,21 @@ impl Brain {
         area: Option<&AreaDetails>,
         config: &crate::config::GameConfig,
         rng: &mut impl Rng,
-    ) -> Option<Action> {
+    ) -> Option<(DecisionLayer, Action)> {
         if !tribute.is_alive() {
-            return Some(Action::None);
+            return Some((DecisionLayer::Dead, Action::None));
+        }
+
+        // Contagion override: a transformed tribute's compulsion beats
+        // every other layer. Only seeded when config.contagions_enabled.
+        if let Some(action) =
+            contagion_override::contagion_override(tribute, nearby_tributes, phase)
+        {
+            return Some((DecisionLayer::Contagion, action));
         }
 
         if let Some(ref break_type) = self.psychotic_break {
-            return Some(match break_type {
+            let action = match break_type {
                 PsychoticBreakType::Berserk => {
                     if nearby_tributes > 0 {
                         Action::Attack
@@ -626,16 +946,17 @@ impl Brain {
                         Action::Move(None)
                     }
                 }
-            });
+            };
+            return Some((DecisionLayer::PsychoticBreak, action));
         }
 
         // Terrain-dependent overrides (spec §6.4). Active combat
         // (nearby_tributes > 0) suppresses survival; stamina has its own
         // visible-band flee path that handles the in-combat case.
         if let Some(base) = terrain {
-            let weather = crate::areas::weather::current_weather();
+            let weather = area.map(|a| a.weather.current).unwrap_or_default();
             if let Some(action) = survival_override(tribute, base, &weather, nearby_tributes > 0) {
-                return Some(action);
+                return Some((DecisionLayer::Survival, action));
             }
 
             // Stamina override (spec §6.4 — runs after survival, before
@@ -648,7 +969,7 @@ impl Brain {
                 false,
                 &crate::tributes::combat_tuning::CombatTuning::default(),
             ) {
-                return Some(action);
+                return Some((DecisionLayer::Stamina, action));
             }
         }
 
@@ -659,7 +980,7 @@ impl Brain {
                 target_reachable: true, // conservative: assume reachable
             };
             if let Some(action) = fixation_override::fixation_override(tribute, &fixation_ctx) {
-                return Some(action);
+                return Some((DecisionLayer::Fixation, action));
             }
         }
 
@@ -674,7 +995,7 @@ impl Brain {
                 nearby_tributes,
             };
             if let Some(action) = phobia_override::phobia_override(tribute, &phobia_ctx, rng) {
-                return Some(action);
+                return Some((DecisionLayer::Phobia, action));
             }
         }
 
@@ -683,7 +1004,7 @@ impl Brain {
         if config.trauma_enabled
             && let Some(action) = trauma_override::trauma_override(tribute)
         {
-            return Some(action);
+            return Some((DecisionLayer::Trauma, action));
         }
 
         // Addiction override (spec §7-8): craving/compulsion.
@@ -691,21 +1012,21 @@ impl Brain {
         if config.addiction_enabled
             && let Some(action) = addiction_override::addiction_override(tribute)
         {
-            return Some(action);
+            return Some((DecisionLayer::Addiction, action));
         }
 
         // Affliction override (spec §11): hard gates + brain bias.
         // Terrain-dependent gates (MissingLeg → cliff/swamp) are deferred
         // to action-execution time via `Tribute::affliction_action_gate`.
         if let Some(action) = affliction_override::affliction_override(tribute, &Action::None) {
-            return Some(action);
+            return Some((DecisionLayer::Affliction, action));
         }
 
         // Preferred action
         if let Some(ref preferred_action) = self.preferred_action
             && rng.random_bool(self.preferred_action_percentage)
         {
-            return Some(preferred_action.clone());
+            return Some((DecisionLayer::PreferredAction, preferred_action.clone()));
         }
 
         // Spec §6.1: alliance proposals are a deliberate first-class action.
@@ -713,12 +1034,12 @@ impl Brain {
         // alliance formation gated by phase"). The `phase` parameter is
         // threaded through for future use.
         if self.wants_to_propose_alliance(tribute, nearby_tributes, rng) {
-            return Some(Action::ProposeAlliance);
+            return Some((DecisionLayer::Alliance, Action::ProposeAlliance));
         }
 
         // Consumables
         if !tribute.consumables().is_empty() {
-            return Some(Action::UseItem(None));
+            return Some((DecisionLayer::Consumable, Action::UseItem(None)));
         }
 
         None
@@ -841,9 +1162,12 @@ impl Brain {
             return false;
         }
         // Base 5% per turn, scaled by trait affinity (Friendly=1.5,
-        // Loyal=1.4 push above; neutral stays at base). Clamp at 15% so
-        // even Friendly+Loyal tributes don't propose every other turn.
-        let chance = (0.05 * affinity).clamp(0.0, 0.15);
+        // Loyal=1.4 push above; neutral stays at base) and by trust
+        // relative to its baseline. Clamp at 15% so even Friendly+Loyal
+        // tributes don't propose every other turn.
+        let trust = tribute.emotions.trust as f64
+            / crate::tributes::emotions::Emotions::BASELINE.trust as f64;
+        let chance = (0.05 * affinity * trust).clamp(0.0, 0.15);
         rng.random_bool(chance)
     }
 
@@ -948,5 +1272,25 @@ impl Brain {
     }
 }
 
+/// The highest-scoring area; the first one listed wins a tie.
+fn best_destination(scores: &[(Area, i32)]) -> Option<Area> {
+    let mut best: Option<(Area, i32)> = None;
+    for &(area, score) in scores {
+        if best.is_none_or(|(_, best_score)| score > best_score) {
+            best = Some((area, score));
+        }
+    }
+    best.map(|(area, _)| area)
+}
+
+/// Note the best-scoring destinations as candidate moves.
+fn trace_destinations(scores: &[(Area, i32)], reasoning: &mut Reasoning) {
+    let mut ranked = scores.to_vec();
+    ranked.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    for (area, score) in ranked.into_iter().take(TRACED_DESTINATIONS) {
+        reasoning.score(Action::Move(Some(area)), score);
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -177,7 +177,7 @@ mod tests {
     };
 
     fn make_area(terrain: BaseTerrain, events: Vec<AreaEvent>) -> AreaDetails {
-        let mut area = AreaDetails::new(None, Area::Cornucopia);
+        let mut area = AreaDetails::new(None, Area::CORNUCOPIA);
         area.terrain = TerrainType::new(terrain, vec![]).unwrap();
         area.events = events;
         area
@@ -144,11 +144,14 @@ pub fn target_attack_score(
 ///
 /// `Action::Attack` is gated on `actor.stamina >= tuning.stamina_cost_attacker`;
 /// Winded actors get `tuning.winded_attack_score_penalty` added (negative).
+/// The actor's emotional `attack_bias` is added on top: aggression raises
+/// the score, lost composure lowers it. `weights` scales both terms.
 pub fn action_score(
     actor: &Tribute,
     action: &Action,
     _nearby: &[Tribute],
     tuning: &crate::tributes::combat_tuning::CombatTuning,
+    weights: &super::tuning::BrainTuning,
 ) -> i32 {
     use crate::tributes::stamina_band::stamina_band;
     use shared::messages::StaminaBand;
@@ -159,11 +162,14 @@ pub fn action_score(
                 return i32::MIN;
             }
             let band = stamina_band(actor.stamina, actor.max_stamina, tuning);
-            match band {
+            let fatigue = match band {
                 StaminaBand::Fresh => 0,
                 StaminaBand::Winded => tuning.winded_attack_score_penalty,
                 StaminaBand::Exhausted => tuning.winded_attack_score_penalty,
-            }
+            };
+            let fatigue = f64::from(fatigue) * weights.fatigue_weight;
+            let emotion = f64::from(actor.emotions.attack_bias()) * weights.emotion_weight;
+            (fatigue + emotion).round() as i32
         }
         _ => 0,
     }
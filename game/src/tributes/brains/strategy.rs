@@ -0,0 +1,371 @@
+//! Pluggable decision-making for tributes.
+//!
+//! [`TributeBrain`] is the seam between what a tribute can see
+//! ([`Perception`], read-only) and what it does ([`Action`]). The built-in
+//! [`Brain`] — scoring plus the override pipeline — is one implementation;
+//! [`RandomBrain`] and [`CowardBrain`] are baselines to measure it against.
+//!
+//! Which implementation a tribute uses is its persisted
+//! [`BrainStrategy`]. Strategies that are not part of the engine (a
+//! tournament entry, say) are registered per tribute on
+//! `Game::custom_brains` and win over the persisted choice.
+
+use super::Brain;
+use super::trace::{DecisionLayer, DecisionTrace, Reasoning};
+use crate::areas::{Area, AreaDetails, DestinationInfo};
+use crate::tributes::Tribute;
+use crate::tributes::actions::Action;
+use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
+use shared::messages::Phase;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Everything a tribute knows when it picks an action. Built fresh for each
+/// decision by `Tribute::process_turn_phase` from the tribute's beliefs.
+#[derive(Clone, Copy, Debug)]
+pub struct Perception<'a> {
+    pub tribute: &'a Tribute,
+    /// Tributes the tribute can see in its area, itself included.
+    pub nearby_tributes: u32,
+    pub phase: Phase,
+    /// Neighbouring areas with their terrain and the stamina it costs to
+    /// move there.
+    pub available_destinations: &'a [DestinationInfo],
+    /// Every area as the tribute remembers it, for decisions that look past
+    /// the neighbours.
+    pub all_areas: &'a [AreaDetails],
+    /// Areas the tribute believes closed.
+    pub closed_areas: &'a [Area],
+    /// Tributes the tribute believes are in each area.
+    pub enemy_density: &'a HashMap<Area, u32>,
+}
+
+impl Perception<'_> {
+    /// Neighbours the tribute can afford to walk into.
+    pub fn reachable_destinations(&self) -> impl Iterator<Item = &DestinationInfo> {
+        self.available_destinations.iter().filter(|d| {
+            !self.closed_areas.contains(&d.area) && self.tribute.stamina >= d.stamina_cost
+        })
+    }
+}
+
+/// A tribute's decision-maker.
+pub trait TributeBrain: Send + Sync {
+    /// Pick this turn's action. Only called for living, awake tributes.
+    fn decide(&self, perception: &Perception<'_>, rng: &mut dyn Rng) -> Action;
+
+    /// [`decide`](Self::decide), noting in `reasoning` how the action was
+    /// reached. Brains that don't explain themselves are recorded as having
+    /// simply decided.
+    fn decide_traced(
+        &self,
+        perception: &Perception<'_>,
+        rng: &mut dyn Rng,
+        reasoning: &mut Reasoning,
+    ) -> Action {
+        let action = self.decide(perception, rng);
+        reasoning.step(DecisionLayer::Strategy, &action);
+        action
+    }
+}
+
+impl fmt::Debug for dyn TributeBrain + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn TributeBrain")
+    }
+}
+
+impl TributeBrain for Brain {
+    fn decide(&self, perception: &Perception<'_>, rng: &mut dyn Rng) -> Action {
+        self.decide_traced(perception, rng, &mut Reasoning::default())
+    }
+
+    fn decide_traced(
+        &self,
+        perception: &Perception<'_>,
+        mut rng: &mut dyn Rng,
+        reasoning: &mut Reasoning,
+    ) -> Action {
+        let tribute = perception.tribute;
+        if let Some(sleep) = self.should_sleep(
+            tribute,
+            perception.nearby_tributes,
+            perception.phase,
+            &mut rng,
+        ) {
+            reasoning.step(DecisionLayer::Sleep, &sleep);
+            return sleep;
+        }
+        self.act_traced(
+            tribute,
+            perception.nearby_tributes,
+            perception.available_destinations,
+            perception.all_areas,
+            perception.closed_areas,
+            perception.enemy_density,
+            perception.phase,
+            &mut rng,
+            reasoning,
+        )
+    }
+}
+
+/// Baseline that picks uniformly among the plausible actions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RandomBrain;
+
+impl TributeBrain for RandomBrain {
+    fn decide(&self, perception: &Perception<'_>, rng: &mut dyn Rng) -> Action {
+        let mut options = vec![Action::Rest, Action::Hide, Action::TakeItem];
+        if perception.nearby_tributes > 1 {
+            options.push(Action::Attack);
+        }
+        options.extend(
+            perception
+                .reachable_destinations()
+                .map(|d| Action::Move(Some(d.area))),
+        );
+        let pick = rng.random_range(0..options.len());
+        options.swap_remove(pick)
+    }
+}
+
+/// Scripted baseline that never fights: it runs from company and from
+/// closing areas toward the emptiest neighbour, and hides otherwise.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CowardBrain;
+
+impl TributeBrain for CowardBrain {
+    fn decide(&self, perception: &Perception<'_>, _rng: &mut dyn Rng) -> Action {
+        let tribute = perception.tribute;
+        let threatened =
+            perception.nearby_tributes > 1 || perception.closed_areas.contains(&tribute.area);
+        if threatened
+            && let Some(escape) = perception
+                .reachable_destinations()
+                .min_by_key(|d| perception.enemy_density.get(&d.area).copied().unwrap_or(0))
+        {
+            return Action::Move(Some(escape.area));
+        }
+        if tribute.attributes.is_hidden {
+            Action::Rest
+        } else {
+            Action::Hide
+        }
+    }
+}
+
+/// Which built-in [`TributeBrain`] drives a tribute. Persisted on the
+/// tribute; defaults to the full [`Brain`].
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrainStrategy {
+    #[default]
+    Builtin,
+    Random,
+    Coward,
+}
+
+impl BrainStrategy {
+    pub const ALL: [BrainStrategy; 3] = [
+        BrainStrategy::Builtin,
+        BrainStrategy::Random,
+        BrainStrategy::Coward,
+    ];
+}
+
+impl Display for BrainStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrainStrategy::Builtin => write!(f, "builtin"),
+            BrainStrategy::Random => write!(f, "random"),
+            BrainStrategy::Coward => write!(f, "coward"),
+        }
+    }
+}
+
+impl FromStr for BrainStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BrainStrategy::ALL
+            .into_iter()
+            .find(|strategy| strategy.to_string() == s)
+            .ok_or_else(|| format!("unknown brain strategy `{s}`"))
+    }
+}
+
+/// Per-tribute brains supplied from outside the engine, keyed by tribute
+/// id. Transient: a game reloaded from storage falls back to each tribute's
+/// `BrainStrategy`.
+#[derive(Clone, Debug, Default)]
+pub struct CustomBrains(HashMap<uuid::Uuid, std::sync::Arc<dyn TributeBrain>>);
+
+impl CustomBrains {
+    pub fn assign(&mut self, tribute: uuid::Uuid, brain: std::sync::Arc<dyn TributeBrain>) {
+        self.0.insert(tribute, brain);
+    }
+
+    pub fn remove(&mut self, tribute: &uuid::Uuid) {
+        self.0.remove(tribute);
+    }
+
+    pub fn get(&self, tribute: &uuid::Uuid) -> Option<std::sync::Arc<dyn TributeBrain>> {
+        self.0.get(tribute).cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Tribute {
+    /// Ask this tribute's brain for an action: `custom` when the game
+    /// supplied one, otherwise the tribute's own `strategy`.
+    pub fn decide_action(
+        &self,
+        perception: &Perception<'_>,
+        custom: Option<&dyn TributeBrain>,
+        rng: &mut dyn Rng,
+    ) -> Action {
+        self.brain_for(custom).decide(perception, rng)
+    }
+
+    /// [`decide_action`](Self::decide_action), traced: the returned
+    /// trace's `action` is the decision.
+    pub fn decide_action_traced(
+        &self,
+        perception: &Perception<'_>,
+        custom: Option<&dyn TributeBrain>,
+        day: u32,
+        rng: &mut dyn Rng,
+    ) -> DecisionTrace {
+        let mut reasoning = Reasoning::default();
+        let action = self
+            .brain_for(custom)
+            .decide_traced(perception, rng, &mut reasoning);
+        let brain = match custom {
+            Some(_) => "custom".to_string(),
+            None => self.strategy.to_string(),
+        };
+        DecisionTrace {
+            tribute: self.identifier.clone(),
+            day,
+            phase: perception.phase,
+            brain,
+            nearby_tributes: perception.nearby_tributes,
+            reasoning,
+            action,
+        }
+    }
+
+    fn brain_for<'a>(&'a self, custom: Option<&'a dyn TributeBrain>) -> &'a dyn TributeBrain {
+        match (custom, self.strategy) {
+            (Some(custom), _) => custom,
+            (None, BrainStrategy::Builtin) => &self.brain,
+            (None, BrainStrategy::Random) => &RandomBrain,
+            (None, BrainStrategy::Coward) => &CowardBrain,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::{BaseTerrain, TerrainType};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn destination(area: Area, stamina_cost: u32) -> DestinationInfo {
+        DestinationInfo {
+            area,
+            terrain: TerrainType::new(BaseTerrain::Forest, vec![]).unwrap(),
+            active_events: vec![],
+            stamina_cost,
+        }
+    }
+
+    fn perception<'a>(
+        tribute: &'a Tribute,
+        nearby_tributes: u32,
+        destinations: &'a [DestinationInfo],
+        density: &'a HashMap<Area, u32>,
+    ) -> Perception<'a> {
+        Perception {
+            tribute,
+            nearby_tributes,
+            phase: Phase::Day,
+            available_destinations: destinations,
+            all_areas: &[],
+            closed_areas: &[],
+            enemy_density: density,
+        }
+    }
+
+    #[test]
+    fn strategy_names_roundtrip() {
+        for strategy in BrainStrategy::ALL {
+            assert_eq!(strategy.to_string().parse(), Ok(strategy));
+        }
+        assert!("genius".parse::<BrainStrategy>().is_err());
+    }
+
+    #[test]
+    fn coward_flees_to_the_emptiest_neighbour() {
+        let tribute = Tribute::new("Coward".to_string(), None, None);
+        let destinations = [
+            destination(Area::SECTOR_1, 10),
+            destination(Area::SECTOR_2, 10),
+        ];
+        let density = HashMap::from([(Area::SECTOR_1, 3), (Area::SECTOR_2, 1)]);
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let action =
+            CowardBrain.decide(&perception(&tribute, 2, &destinations, &density), &mut rng);
+        assert_eq!(action, Action::Move(Some(Area::SECTOR_2)));
+
+        let alone = CowardBrain.decide(&perception(&tribute, 1, &destinations, &density), &mut rng);
+        assert_eq!(alone, Action::Hide);
+    }
+
+    #[test]
+    fn random_brain_only_walks_where_it_can_afford() {
+        let mut tribute = Tribute::new("Dice".to_string(), None, None);
+        tribute.stamina = 5;
+        let destinations = [destination(Area::SECTOR_1, 50)];
+        let density = HashMap::new();
+        let mut rng = SmallRng::seed_from_u64(9);
+        for _ in 0..50 {
+            let action =
+                RandomBrain.decide(&perception(&tribute, 1, &destinations, &density), &mut rng);
+            assert!(matches!(
+                action,
+                Action::Rest | Action::Hide | Action::TakeItem
+            ));
+        }
+    }
+
+    #[test]
+    fn custom_brain_wins_over_the_strategy() {
+        struct AlwaysAttack;
+        impl TributeBrain for AlwaysAttack {
+            fn decide(&self, _: &Perception<'_>, _: &mut dyn Rng) -> Action {
+                Action::Attack
+            }
+        }
+
+        let mut tribute = Tribute::new("Scripted".to_string(), None, None);
+        tribute.strategy = BrainStrategy::Coward;
+        let density = HashMap::new();
+        let seen = perception(&tribute, 1, &[], &density);
+        let mut rng = SmallRng::seed_from_u64(1);
+
+        assert_eq!(tribute.decide_action(&seen, None, &mut rng), Action::Hide);
+        assert_eq!(
+            tribute.decide_action(&seen, Some(&AlwaysAttack), &mut rng),
+            Action::Attack
+        );
+    }
+}
@@ -179,6 +179,60 @@ fn decide_on_action_enemies_medium_health(mut tribute: Tribute, mut small_rng: S
     assert_eq!(action, Action::Move(None));
 }
 
+#[rstest]
+fn panicked_tribute_flees_instead_of_attacking(mut tribute: Tribute, mut small_rng: SmallRng) {
+    // Full health with enemies nearby normally means Attack; a tribute
+    // whose composure has collapsed runs instead.
+    tribute.emotions.composure = 0;
+    tribute.emotions.aggression = 20;
+    let action = tribute.brain.act(
+        &tribute.clone(),
+        2,
+        &[],
+        &[],
+        &[],
+        &HashMap::new(),
+        Phase::Day,
+        &mut small_rng,
+    );
+    assert_eq!(action, Action::Move(None));
+}
+
+#[rstest]
+fn furious_tribute_attacks_instead_of_retreating(mut tribute: Tribute, mut small_rng: SmallRng) {
+    // Same mid-health setup as `decide_on_action_enemies_medium_health`,
+    // which retreats; fury overrides the retreat.
+    tribute.blood = 200;
+    tribute.emotions.aggression = 80;
+    let action = tribute.brain.act(
+        &tribute.clone(),
+        2,
+        &[],
+        &[],
+        &[],
+        &HashMap::new(),
+        Phase::Day,
+        &mut small_rng,
+    );
+    assert_eq!(action, Action::Attack);
+}
+
+#[rstest]
+fn despairing_tribute_stops_roaming(mut tribute: Tribute, mut small_rng: SmallRng) {
+    tribute.emotions.morale = 5;
+    let action = tribute.brain.act(
+        &tribute.clone(),
+        0,
+        &[],
+        &[],
+        &[],
+        &HashMap::new(),
+        Phase::Day,
+        &mut small_rng,
+    );
+    assert_eq!(action, Action::Rest);
+}
+
 #[rstest]
 fn decide_on_action_preferred_action(mut tribute: Tribute, mut small_rng: SmallRng) {
     tribute.brain.set_preferred_action(Action::Rest, 1.0);
@@ -614,7 +668,7 @@ fn brain_act_routes_first_hop_to_non_neighbor_goal(mut tribute: Tribute, mut sma
     use crate::areas::Area;
 
     // Place the tribute in Sector1.
-    tribute.area = Area::Sector1;
+    tribute.area = Area::SECTOR_1;
     // Avoid the items branch & alliance branch — strip both.
     tribute.items.clear();
     tribute.brain.preferred_action = None;
@@ -632,13 +686,13 @@ fn brain_act_routes_first_hop_to_non_neighbor_goal(mut tribute: Tribute, mut sma
     // Give the tribute a Desert affinity, then make Sector4 a Desert.
     tribute.terrain_affinity = vec![BaseTerrain::Desert];
     let all_areas = vec![
-        mk(Area::Cornucopia, BaseTerrain::Clearing),
-        mk(Area::Sector1, BaseTerrain::Clearing),
-        mk(Area::Sector2, BaseTerrain::Clearing),
-        mk(Area::Sector3, BaseTerrain::Clearing),
-        mk(Area::Sector4, BaseTerrain::Desert),
-        mk(Area::Sector5, BaseTerrain::Clearing),
-        mk(Area::Sector6, BaseTerrain::Clearing),
+        mk(Area::CORNUCOPIA, BaseTerrain::Clearing),
+        mk(Area::SECTOR_1, BaseTerrain::Clearing),
+        mk(Area::SECTOR_2, BaseTerrain::Clearing),
+        mk(Area::SECTOR_3, BaseTerrain::Clearing),
+        mk(Area::SECTOR_4, BaseTerrain::Desert),
+        mk(Area::SECTOR_5, BaseTerrain::Clearing),
+        mk(Area::SECTOR_6, BaseTerrain::Clearing),
     ];
 
     let action = tribute.brain.act(
@@ -659,7 +713,7 @@ fn brain_act_routes_first_hop_to_non_neighbor_goal(mut tribute: Tribute, mut sma
             // -> Sector4, so the first hop must be Cornucopia.
             assert_eq!(
                 first_hop,
-                Area::Cornucopia,
+                Area::CORNUCOPIA,
                 "expected first hop toward Sector4 to be Cornucopia, got {first_hop:?}"
             );
         }
@@ -682,11 +736,11 @@ fn choose_destination_avoids_crowded_areas(tribute: Tribute) {
             TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap(),
         )
     };
-    let areas = vec![mk(Area::Sector1), mk(Area::Sector2)];
+    let areas = vec![mk(Area::SECTOR_1), mk(Area::SECTOR_2)];
 
     let mut density = HashMap::new();
-    density.insert(Area::Sector1, 4); // crowded
-    density.insert(Area::Sector2, 0); // empty
+    density.insert(Area::SECTOR_1, 4); // crowded
+    density.insert(Area::SECTOR_2, 0); // empty
 
     let chosen = tribute
         .brain
@@ -694,7 +748,7 @@ fn choose_destination_avoids_crowded_areas(tribute: Tribute) {
         .expect("expected a destination");
     assert_eq!(
         chosen,
-        Area::Sector2,
+        Area::SECTOR_2,
         "should pick empty Sector2 over crowded Sector1"
     );
 }
@@ -708,7 +762,7 @@ fn choose_destination_excludes_self_from_own_area_density(tribute: Tribute) {
     use crate::areas::Area;
 
     let mut t = tribute;
-    t.area = Area::Sector1;
+    t.area = Area::SECTOR_1;
 
     let mk = |a: Area| {
         AreaDetails::new_with_terrain(
@@ -717,18 +771,173 @@ fn choose_destination_excludes_self_from_own_area_density(tribute: Tribute) {
             TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap(),
         )
     };
-    let areas = vec![mk(Area::Sector1), mk(Area::Sector2)];
+    let areas = vec![mk(Area::SECTOR_1), mk(Area::SECTOR_2)];
 
     let mut density = HashMap::new();
-    density.insert(Area::Sector1, 1); // just the tribute itself
-    density.insert(Area::Sector2, 0);
+    density.insert(Area::SECTOR_1, 1); // just the tribute itself
+    density.insert(Area::SECTOR_2, 0);
 
     let chosen = t
         .brain
         .choose_destination(&areas, &t, &density)
         .expect("expected a destination");
     // Tie on "others" (0 vs 0): scoring order picks the first area.
-    assert_eq!(chosen, Area::Sector1);
+    assert_eq!(chosen, Area::SECTOR_1);
+}
+
+#[rstest]
+fn act_traced_records_each_layer_and_the_destinations_weighed(
+    tribute: Tribute,
+    mut small_rng: SmallRng,
+) {
+    use crate::areas::Area;
+    use trace::DecisionLayer;
+
+    let mut t = tribute;
+    t.area = Area::SECTOR_1;
+    let mk = |a: Area| {
+        AreaDetails::new_with_terrain(
+            Some(format!("{a:?}")),
+            a,
+            TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap(),
+        )
+    };
+    let areas = vec![mk(Area::SECTOR_1), mk(Area::SECTOR_2)];
+    let density = HashMap::from([(Area::SECTOR_1, 4)]);
+
+    let mut reasoning = Reasoning::default();
+    let action = t.brain.act_traced(
+        &t,
+        0,
+        &[],
+        &areas,
+        &[],
+        &density,
+        Phase::Day,
+        &mut small_rng,
+        &mut reasoning,
+    );
+
+    assert_eq!(action, Action::Move(Some(Area::SECTOR_2)));
+    let layers: Vec<DecisionLayer> = reasoning.steps.iter().map(|s| s.layer).collect();
+    assert_eq!(layers, [DecisionLayer::Base, DecisionLayer::Destination]);
+    assert_eq!(reasoning.steps[0].action, Action::Move(None));
+    let low_health = &reasoning.steps[0].thresholds[0];
+    assert_eq!(low_health.threshold, "low_health");
+    assert_eq!(low_health.limit, t.brain.thresholds.low_health);
+    assert_eq!(low_health.value, t.effective_health());
+    assert_eq!(
+        reasoning.candidates[0].action,
+        Action::Move(Some(Area::SECTOR_2))
+    );
+    assert!(reasoning.candidates[0].score > reasoning.candidates[1].score);
+}
+
+#[rstest]
+fn a_plan_is_followed_alone_and_paused_in_company(tribute: Tribute, mut small_rng: SmallRng) {
+    use crate::areas::Area;
+    use crate::tributes::goals::{Goal, Plan};
+    use trace::DecisionLayer;
+
+    let mut t = tribute;
+    t.area = Area::SECTOR_1;
+    // Loners never propose alliances, which would cut in ahead of plans.
+    t.traits = vec![Trait::LoneWolf];
+    t.plan = Some(Plan {
+        goal: Goal::SecureWater,
+        destination: Area::SECTOR_3,
+        route: vec![Area::SECTOR_2, Area::SECTOR_3],
+        day: 1,
+        phase: Phase::Day,
+    });
+    let mut act = |t: &Tribute, nearby: u32| {
+        let mut reasoning = Reasoning::default();
+        let action = t.brain.act_traced(
+            t,
+            nearby,
+            &[],
+            &[],
+            &[],
+            &HashMap::new(),
+            Phase::Day,
+            &mut small_rng,
+            &mut reasoning,
+        );
+        (action, reasoning.deciding_layer())
+    };
+
+    assert_eq!(
+        act(&t, 1),
+        (
+            Action::Move(Some(Area::SECTOR_2)),
+            Some(DecisionLayer::Plan)
+        )
+    );
+    let (_, layer) = act(&t, 3);
+    assert_ne!(layer, Some(DecisionLayer::Plan));
+
+    // Company is what a hunter is after once it gets there.
+    t.plan = Some(Plan {
+        goal: Goal::Hunt {
+            target: uuid::Uuid::new_v4(),
+            name: "Cato".into(),
+        },
+        destination: Area::SECTOR_1,
+        route: vec![],
+        day: 1,
+        phase: Phase::Day,
+    });
+    assert_eq!(act(&t, 3), (Action::Attack, Some(DecisionLayer::Plan)));
+}
+
+#[rstest]
+fn followers_keep_up_with_their_alliance(tribute: Tribute, mut small_rng: SmallRng) {
+    use crate::areas::{Area, DestinationInfo};
+    use crate::terrain::{BaseTerrain, TerrainType};
+    use trace::DecisionLayer;
+
+    let mut t = tribute;
+    t.area = Area::SECTOR_1;
+    t.traits = vec![Trait::LoneWolf];
+    t.rally = Some(Area::SECTOR_2);
+    let toward = |stamina_cost| {
+        [DestinationInfo {
+            area: Area::SECTOR_2,
+            terrain: TerrainType::new(BaseTerrain::Forest, vec![]).unwrap(),
+            active_events: vec![],
+            stamina_cost,
+        }]
+    };
+    let mut act = |t: &Tribute, destinations: &[DestinationInfo]| {
+        let mut reasoning = Reasoning::default();
+        let action = t.brain.act_traced(
+            t,
+            3,
+            destinations,
+            &[],
+            &[],
+            &HashMap::new(),
+            Phase::Day,
+            &mut small_rng,
+            &mut reasoning,
+        );
+        (action, reasoning.deciding_layer())
+    };
+
+    assert_eq!(
+        act(&t, &toward(0)),
+        (
+            Action::Move(Some(Area::SECTOR_2)),
+            Some(DecisionLayer::Alliance)
+        )
+    );
+    assert_eq!(
+        act(&t, &toward(u32::MAX)),
+        (Action::Rest, Some(DecisionLayer::Alliance))
+    );
+    t.area = Area::SECTOR_2;
+    let (_, layer) = act(&t, &[]);
+    assert_ne!(layer, Some(DecisionLayer::Alliance), "already there");
 }
 
 // ---- Sleep gating (PR2c.1, bd-9sjj) ----
@@ -737,7 +946,7 @@ fn choose_destination_excludes_self_from_own_area_density(tribute: Tribute) {
 fn should_sleep_dominant_threshold_overrides_safety(tribute: Tribute, mut small_rng: SmallRng) {
     use shared::messages::Phase;
     let mut t = tribute.clone();
-    t.cycles_awake = SLEEP_DOMINANT_THRESHOLD;
+    t.cycles_awake = t.brain.tuning.sleep_dominant_threshold;
     let action = t.brain.should_sleep(&t, 5, Phase::Day, &mut small_rng);
     assert!(matches!(action, Some(Action::Sleep { duration_phases: 4 })));
 }
@@ -749,7 +958,7 @@ fn should_sleep_want_threshold_requires_safety_and_night(
 ) {
     use shared::messages::Phase;
     let mut t = tribute.clone();
-    t.cycles_awake = SLEEP_WANT_THRESHOLD;
+    t.cycles_awake = t.brain.tuning.sleep_want_threshold;
     let action = t.brain.should_sleep(&t, 0, Phase::Night, &mut small_rng);
     assert!(matches!(action, Some(Action::Sleep { duration_phases: 3 })));
     let action = t.brain.should_sleep(&t, 1, Phase::Night, &mut small_rng);
@@ -770,11 +979,38 @@ fn should_sleep_exhausted_naps_when_safe_off_day(tribute: Tribute, mut small_rng
     assert!(action.is_none());
 }
 
+#[rstest]
+fn should_sleep_watch_guards_the_rest_and_keeps_the_watcher_up(
+    tribute: Tribute,
+    mut small_rng: SmallRng,
+) {
+    use crate::tributes::alliances::Watch;
+    let mut t = tribute.clone();
+    t.cycles_awake = t.brain.tuning.sleep_want_threshold;
+    assert!(
+        t.brain
+            .should_sleep(&t, 2, Phase::Night, &mut small_rng)
+            .is_none()
+    );
+    t.watch = Some(Watch::Guarded);
+    let action = t.brain.should_sleep(&t, 2, Phase::Night, &mut small_rng);
+    assert!(matches!(action, Some(Action::Sleep { duration_phases: 3 })));
+    t.watch = Some(Watch::Keeping);
+    assert!(
+        t.brain
+            .should_sleep(&t, 0, Phase::Night, &mut small_rng)
+            .is_none()
+    );
+    t.cycles_awake = t.brain.tuning.sleep_dominant_threshold;
+    let action = t.brain.should_sleep(&t, 0, Phase::Night, &mut small_rng);
+    assert!(matches!(action, Some(Action::Sleep { duration_phases: 4 })));
+}
+
 #[rstest]
 fn should_sleep_psychotic_break_blocks_sleep(tribute: Tribute, mut small_rng: SmallRng) {
     use shared::messages::Phase;
     let mut t = tribute.clone();
-    t.cycles_awake = SLEEP_DOMINANT_THRESHOLD + 4;
+    t.cycles_awake = t.brain.tuning.sleep_dominant_threshold + 4;
     t.brain.psychotic_break = Some(PsychoticBreakType::Berserk);
     let action = t.brain.should_sleep(&t, 0, Phase::Night, &mut small_rng);
     assert!(action.is_none());
@@ -786,11 +1022,42 @@ fn should_sleep_already_sleeping_returns_none(tribute: Tribute, mut small_rng: S
     let mut t = tribute.clone();
     t.sleeping = true;
     t.sleep_remaining = 2;
-    t.cycles_awake = SLEEP_DOMINANT_THRESHOLD + 10;
+    t.cycles_awake = t.brain.tuning.sleep_dominant_threshold + 10;
     let action = t.brain.should_sleep(&t, 0, Phase::Night, &mut small_rng);
     assert!(action.is_none());
 }
 
+#[rstest]
+fn tuning_moves_the_gates_and_the_personality_baselines(tribute: Tribute, mut small_rng: SmallRng) {
+    let mut t = tribute.clone();
+    t.cycles_awake = 4;
+    assert!(
+        t.brain
+            .should_sleep(&t, 5, Phase::Day, &mut small_rng)
+            .is_none()
+    );
+    t.brain.tuning.sleep_dominant_threshold = 4;
+    assert!(matches!(
+        t.brain.should_sleep(&t, 5, Phase::Day, &mut small_rng),
+        Some(Action::Sleep { duration_phases: 4 })
+    ));
+
+    let tuning = BrainTuning {
+        base_low_health: 30,
+        base_psychotic_break: 1,
+        ..BrainTuning::default()
+    };
+    let before = t.brain.thresholds.clone();
+    let after = before.retuned(&tuning);
+    assert_eq!(after.low_health, before.low_health + 10);
+    assert_eq!(
+        after.psychotic_break_threshold,
+        before.psychotic_break_threshold.saturating_sub(7).max(1)
+    );
+    assert_eq!(after.mid_health, before.mid_health);
+    assert_eq!(before.retuned(&BrainTuning::default()), before);
+}
+
 // ---- Survival override tests ----
 
 pub(crate) mod survival_override_tests {
@@ -946,21 +1213,35 @@ pub(crate) mod survival_override_tests {
 
         #[test]
         fn action_gate_blocks_attack_when_stamina_below_cost() {
-            let tuning = CombatTuning::default();
+            let (tuning, weights) = (CombatTuning::default(), BrainTuning::default());
             let mut actor = make("Low", 100);
             actor.stamina = tuning.stamina_cost_attacker - 1;
-            let score = action_score(&actor, &Action::Attack, &[], &tuning);
+            let score = action_score(&actor, &Action::Attack, &[], &tuning, &weights);
             assert_eq!(score, i32::MIN);
         }
 
         #[test]
         fn winded_actor_attack_score_lowered_by_penalty() {
-            let tuning = CombatTuning::default();
+            let (tuning, weights) = (CombatTuning::default(), BrainTuning::default());
             let fresh = make("F", 100);
             let winded = make("W", 30);
-            let s_fresh = action_score(&fresh, &Action::Attack, &[], &tuning);
-            let s_winded = action_score(&winded, &Action::Attack, &[], &tuning);
+            let s_fresh = action_score(&fresh, &Action::Attack, &[], &tuning, &weights);
+            let s_winded = action_score(&winded, &Action::Attack, &[], &tuning, &weights);
             assert_eq!(s_winded - s_fresh, tuning.winded_attack_score_penalty);
         }
+
+        #[test]
+        fn emotions_shift_attack_score() {
+            let (tuning, weights) = (CombatTuning::default(), BrainTuning::default());
+            let calm = make("Calm", 100);
+            let mut furious = make("Furious", 100);
+            furious.emotions.aggression = 90;
+            let mut shaken = make("Shaken", 100);
+            shaken.emotions.composure = 10;
+            let s_calm = action_score(&calm, &Action::Attack, &[], &tuning, &weights);
+            assert_eq!(s_calm, 0);
+            assert!(action_score(&furious, &Action::Attack, &[], &tuning, &weights) > s_calm);
+            assert!(action_score(&shaken, &Action::Attack, &[], &tuning, &weights) < s_calm);
+        }
     }
 }
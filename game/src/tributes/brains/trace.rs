@@ -0,0 +1,185 @@
+//! Decision traces: how a tribute's brain arrived at an action.
+//!
+//! The override layers, the health/sanity state machine and the scoring
+//! helpers each get a say in what a tribute does, and the last one to speak
+//! wins. A [`Reasoning`] records every layer that spoke, the personality
+//! thresholds it checked and the candidates it scored; a [`DecisionTrace`]
+//! wraps it with who decided, when, and what came of it. The game collects
+//! one per tribute per phase in `Game::decision_traces`.
+
+use crate::tributes::actions::Action;
+use serde::{Deserialize, Serialize};
+use shared::messages::Phase;
+
+/// Destinations kept in a trace, best first. Arenas have dozens of areas
+/// and only the front runners explain a move.
+pub const TRACED_DESTINATIONS: usize = 5;
+
+/// A layer of the decision pipeline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionLayer {
+    /// A player's order for the phase.
+    Order,
+    Sleep,
+    Dead,
+    Contagion,
+    PsychoticBreak,
+    Survival,
+    Stamina,
+    Fixation,
+    Phobia,
+    Trauma,
+    Addiction,
+    Affliction,
+    /// A suggested action (day-1 spread, a rescue) the brain went along
+    /// with.
+    PreferredAction,
+    /// Proposing an alliance, or keeping up with the alliance's leader.
+    Alliance,
+    Consumable,
+    /// The next step of the tribute's plan; see `tributes::goals`.
+    Plan,
+    /// The health/sanity state machine, picked by how many tributes are
+    /// around.
+    Base,
+    /// `steer_by_emotion`: fury or panic overruling the state machine.
+    Emotion,
+    /// `steer_to_loot`: gear on a body right here.
+    Loot,
+    /// Where a move leads, from `choose_destination` and the path planner.
+    Destination,
+    /// A brain that doesn't explain itself.
+    Strategy,
+}
+
+/// A personality threshold a layer checked, and the value it checked.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThresholdCheck {
+    /// Field of `PersonalityThresholds`.
+    pub threshold: String,
+    pub limit: u32,
+    pub value: u32,
+}
+
+impl ThresholdCheck {
+    pub fn new(threshold: &str, limit: u32, value: u32) -> Self {
+        Self {
+            threshold: threshold.to_string(),
+            limit,
+            value,
+        }
+    }
+}
+
+/// One layer having its say.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TraceStep {
+    pub layer: DecisionLayer,
+    pub action: Action,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub thresholds: Vec<ThresholdCheck>,
+}
+
+/// An action the brain scored while deciding.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScoredAction {
+    pub action: Action,
+    pub score: i32,
+}
+
+/// What a brain weighed on the way to an action, in the order it weighed
+/// it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Reasoning {
+    pub steps: Vec<TraceStep>,
+    pub candidates: Vec<ScoredAction>,
+}
+
+impl Reasoning {
+    pub fn step(&mut self, layer: DecisionLayer, action: &Action) {
+        self.step_with(layer, action, Vec::new());
+    }
+
+    pub fn step_with(
+        &mut self,
+        layer: DecisionLayer,
+        action: &Action,
+        thresholds: Vec<ThresholdCheck>,
+    ) {
+        self.steps.push(TraceStep {
+            layer,
+            action: action.clone(),
+            thresholds,
+        });
+    }
+
+    pub fn score(&mut self, action: Action, score: i32) {
+        self.candidates.push(ScoredAction { action, score });
+    }
+
+    /// The layer whose action stood.
+    pub fn deciding_layer(&self) -> Option<DecisionLayer> {
+        self.steps.last().map(|step| step.layer)
+    }
+}
+
+/// How one tribute decided what to do in one phase.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecisionTrace {
+    /// The tribute's identifier.
+    pub tribute: String,
+    pub day: u32,
+    pub phase: Phase,
+    /// `builtin`, `random` or `coward` for the tribute's own strategy,
+    /// `custom` for a brain registered on the game, `order` for a player's
+    /// order.
+    pub brain: String,
+    /// Tributes it could see in its area, itself included.
+    pub nearby_tributes: u32,
+    #[serde(flatten)]
+    pub reasoning: Reasoning,
+    /// What it decided on.
+    pub action: Action,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_step_is_the_deciding_layer() {
+        let mut reasoning = Reasoning::default();
+        assert_eq!(reasoning.deciding_layer(), None);
+        reasoning.step_with(
+            DecisionLayer::Base,
+            &Action::Hide,
+            vec![ThresholdCheck::new("low_health", 20, 35)],
+        );
+        reasoning.step(DecisionLayer::Emotion, &Action::Attack);
+        assert_eq!(reasoning.deciding_layer(), Some(DecisionLayer::Emotion));
+    }
+
+    #[test]
+    fn trace_serializes_flat() {
+        let mut reasoning = Reasoning::default();
+        reasoning.step(DecisionLayer::Base, &Action::Rest);
+        reasoning.score(Action::Attack, -4);
+        let trace = DecisionTrace {
+            tribute: "t".into(),
+            day: 2,
+            phase: Phase::Dusk,
+            brain: "builtin".into(),
+            nearby_tributes: 1,
+            reasoning,
+            action: Action::Rest,
+        };
+        let json = serde_json::to_value(&trace).unwrap();
+        assert_eq!(json["steps"][0]["layer"], "base");
+        assert!(json["steps"][0].get("thresholds").is_none());
+        assert_eq!(json["candidates"][0]["score"], -4);
+        let back: DecisionTrace = serde_json::from_value(json).unwrap();
+        assert_eq!(back, trace);
+    }
+}
@@ -0,0 +1,102 @@
+//! Tunable knobs for the built-in brain: the personality baselines, the
+//! company and sleep gates, destination scoring and the `action_score`
+//! weights.
+//!
+//! Defaults are the hand-picked values the brain has always used. A game
+//! carries its own copy on `GameConfig::brain`; `hangrier-tune` in the `sim`
+//! crate evolves one against simulated games and writes it out as a config
+//! patch.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BrainTuning {
+    // --- Personality baselines, before trait deltas and ±20% variance ---
+    pub base_low_health: i32,
+    pub base_mid_health: i32,
+    pub base_extreme_low_sanity: i32,
+    pub base_low_sanity: i32,
+    pub base_mid_sanity: i32,
+    pub base_low_movement: i32,
+    pub base_high_intelligence: i32,
+    pub base_low_intelligence: i32,
+    pub base_psychotic_break: i32,
+
+    // --- Company: at or above this many tributes in sight is "many enemies" ---
+    pub low_enemy_limit: u32,
+
+    // --- Sleep gating (phases awake, % of max stamina) ---
+    pub sleep_dominant_threshold: u32,
+    pub sleep_want_threshold: u32,
+    pub sleep_exhausted_pct: u32,
+
+    // --- Destination scoring ---
+    /// Score penalty per enemy in a destination area, to disperse crowds.
+    pub crowd_penalty_per_enemy: i32,
+    /// Cap on the crowd penalty so a single mob doesn't drown out affinity
+    /// and harshness.
+    pub crowd_penalty_max: i32,
+    /// Cap on the bonus for gear left on bodies.
+    pub corpse_loot_bonus_max: i32,
+
+    // --- `action_score` weights and the emotional steer ---
+    /// Multiplier on the winded (and exhausted) attack penalty.
+    pub fatigue_weight: f64,
+    /// Multiplier on the emotional `attack_bias`.
+    pub emotion_weight: f64,
+    /// Attack score at or above which a tribute with enemies in sight
+    /// drops a passive choice and picks a fight.
+    pub emotional_attack_urge: i32,
+    /// Attack score at or below which a tribute flees a fight it chose.
+    pub emotional_flight_urge: i32,
+    /// Morale at or below which a tribute alone stops roaming and rests.
+    pub despair_morale: u8,
+}
+
+impl Default for BrainTuning {
+    fn default() -> Self {
+        Self {
+            base_low_health: 20,
+            base_mid_health: 40,
+            base_extreme_low_sanity: 10,
+            base_low_sanity: 20,
+            base_mid_sanity: 35,
+            base_low_movement: 10,
+            base_high_intelligence: 35,
+            base_low_intelligence: 80,
+            base_psychotic_break: 8,
+
+            low_enemy_limit: 6,
+
+            sleep_dominant_threshold: 12,
+            sleep_want_threshold: 6,
+            sleep_exhausted_pct: 25,
+
+            crowd_penalty_per_enemy: 8,
+            crowd_penalty_max: 32,
+            corpse_loot_bonus_max: 15,
+
+            fatigue_weight: 1.0,
+            emotion_weight: 1.0,
+            emotional_attack_urge: 10,
+            emotional_flight_urge: -10,
+            despair_morale: 15,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_tuning_fills_defaults() {
+        let tuning: BrainTuning =
+            serde_json::from_str(r#"{"low_enemy_limit": 4, "emotion_weight": 1.5}"#).unwrap();
+        assert_eq!(tuning.low_enemy_limit, 4);
+        assert_eq!(tuning.emotion_weight, 1.5);
+        assert_eq!(tuning.crowd_penalty_per_enemy, 8);
+        assert_eq!(tuning.base_low_health, 20);
+    }
+}
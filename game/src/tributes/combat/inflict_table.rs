@@ -6,7 +6,8 @@
 use crate::tributes::AfflictionDraft;
 use rand::RngExt;
 use rand::prelude::*;
-use shared::afflictions::{AfflictionKind, AfflictionSource, BodyPart, Severity};
+use serde::Serialize;
+use shared::afflictions::{AfflictionKind, AfflictionSource, BodyPart, Contagion, Severity};
 
 /// A single entry in an inflict table row.
 #[derive(Debug, Clone)]
@@ -28,7 +29,8 @@ pub enum HitSeverity {
 }
 
 /// Which weapon category was used.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum WeaponKind {
     Unarmed,
     Bladed,
@@ -36,6 +38,35 @@ pub enum WeaponKind {
     Ranged,
 }
 
+impl WeaponKind {
+    /// Classify a weapon by its generated name. Names that match no known
+    /// keyword count as bladed.
+    pub fn from_weapon_name(name: &str) -> Self {
+        let name_lower = name.to_lowercase();
+        if name_lower.contains("bow")
+            || name_lower.contains("arrow")
+            || name_lower.contains("spear")
+        {
+            WeaponKind::Ranged
+        } else if name_lower.contains("knife")
+            || name_lower.contains("sword")
+            || name_lower.contains("blade")
+            || name_lower.contains("dagger")
+            || name_lower.contains("axe")
+        {
+            WeaponKind::Bladed
+        } else if name_lower.contains("club")
+            || name_lower.contains("hammer")
+            || name_lower.contains("mace")
+            || name_lower.contains("bat")
+        {
+            WeaponKind::Blunt
+        } else {
+            WeaponKind::Bladed // Default for unknown weapons
+        }
+    }
+}
+
 /// Look up affliction inflicts for a given weapon + severity combination.
 ///
 /// Returns a Vec of 0–2 AfflictionDrafts selected via weighted random.
@@ -105,6 +136,43 @@ pub fn lookup_break_mid_swing_inflict(
     })
 }
 
+/// Contagion inflict: a blow landed by a transformed carrier may pass its
+/// contagion on as an incubating (Mild) infection. Draws nothing from the
+/// RNG when `carrier` is `None`, so contagion-free fights roll as before.
+pub fn lookup_contagion_inflict(
+    carrier: Option<Contagion>,
+    severity: HitSeverity,
+    carrier_id: &str,
+    rng: &mut impl Rng,
+) -> Option<AfflictionDraft> {
+    let contagion = carrier?;
+    if !rng.random_bool(transmission_chance(contagion, severity)) {
+        return None;
+    }
+    Some(crate::tributes::afflictions::contagion::contagion_draft(
+        contagion,
+        AfflictionSource::Combat {
+            attacker_id: carrier_id.into(),
+        },
+    ))
+}
+
+/// Odds a landed blow transmits `contagion`. Zombie bites spread most
+/// readily; harder hits spread every contagion more. Placeholder values.
+fn transmission_chance(contagion: Contagion, severity: HitSeverity) -> f64 {
+    let base: f64 = match contagion {
+        Contagion::Lycanthropy => 0.25,
+        Contagion::Vampirism => 0.3,
+        Contagion::ZombiePlague => 0.45,
+    };
+    let scale = match severity {
+        HitSeverity::Normal => 1.0,
+        HitSeverity::Heavy => 1.4,
+        HitSeverity::Critical => 1.8,
+    };
+    (base * scale).min(0.9)
+}
+
 /// Select a body part appropriate for the affliction kind.
 fn select_body_part(kind: AfflictionKind, rng: &mut impl Rng) -> Option<BodyPart> {
     match kind {
@@ -136,6 +204,7 @@ fn select_body_part(kind: AfflictionKind, rng: &mut impl Rng) -> Option<BodyPart
         | AfflictionKind::Phobia(_)
         | AfflictionKind::Fixation(_)
         | AfflictionKind::Addiction(_)
+        | AfflictionKind::Contagion(_)
         | AfflictionKind::Trapped(_) => None,
     }
 }
@@ -533,4 +602,44 @@ mod tests {
         // Wounded should get a body part
         assert!(select_body_part(AfflictionKind::Wounded, &mut rng).is_some());
     }
+
+    #[test]
+    fn contagion_inflict_needs_a_carrier() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        for _ in 0..50 {
+            assert!(lookup_contagion_inflict(None, HitSeverity::Critical, "a", &mut rng).is_none());
+        }
+        let caught = (0..200)
+            .filter_map(|_| {
+                lookup_contagion_inflict(
+                    Some(Contagion::ZombiePlague),
+                    HitSeverity::Normal,
+                    "a",
+                    &mut rng,
+                )
+            })
+            .inspect(|draft| {
+                assert_eq!(
+                    draft.kind,
+                    AfflictionKind::Contagion(Contagion::ZombiePlague)
+                );
+                assert_eq!(draft.severity, Severity::Mild);
+            })
+            .count();
+        assert!(caught > 50 && caught < 150, "caught {caught}");
+    }
+
+    #[test]
+    fn harder_hits_spread_contagions_more() {
+        for contagion in [
+            Contagion::Lycanthropy,
+            Contagion::Vampirism,
+            Contagion::ZombiePlague,
+        ] {
+            assert!(
+                transmission_chance(contagion, HitSeverity::Critical)
+                    > transmission_chance(contagion, HitSeverity::Normal)
+            );
+        }
+    }
 }
@@ -24,6 +24,7 @@ use crate::tributes::Tribute;
 use crate::tributes::actions::{AttackOutcome, AttackResult};
 use rand::prelude::*;
 use resolve::tref;
+use shared::afflictions::AfflictionKind;
 
 // ---------------------------------------------------------------------------
 // Tribute::attacks — the combat orchestrator
@@ -271,14 +272,29 @@ impl Tribute {
                     | crate::tributes::afflictions::AcquireResolution::Upgrade(_)
                     | crate::tributes::afflictions::AcquireResolution::Supersede(_)
             ) {
-                events.push(TaggedEvent::new(
-                    String::new(),
-                    MessagePayload::AfflictionAcquired {
-                        tribute_id: target.identifier.to_string(),
-                        affliction: draft.kind.to_string(),
-                        severity: draft.severity.to_string(),
-                    },
-                ));
+                events.push(match draft.kind {
+                    AfflictionKind::Contagion(contagion) => TaggedEvent::new(
+                        GameOutput::ContagionInfected(
+                            target.name.as_str(),
+                            contagion,
+                            Some(self.name.as_str()),
+                        )
+                        .to_string(),
+                        MessagePayload::ContagionInfected {
+                            tribute: tref(target),
+                            source: Some(tref(self)),
+                            contagion,
+                        },
+                    ),
+                    _ => TaggedEvent::new(
+                        String::new(),
+                        MessagePayload::AfflictionAcquired {
+                            tribute_id: target.identifier.to_string(),
+                            affliction: draft.kind.to_string(),
+                            severity: draft.severity.to_string(),
+                        },
+                    ),
+                });
             }
         }
         for draft in &attacker_inflicts {
@@ -289,14 +305,29 @@ impl Tribute {
                     | crate::tributes::afflictions::AcquireResolution::Upgrade(_)
                     | crate::tributes::afflictions::AcquireResolution::Supersede(_)
             ) {
-                events.push(TaggedEvent::new(
-                    String::new(),
-                    MessagePayload::AfflictionAcquired {
-                        tribute_id: self.identifier.to_string(),
-                        affliction: draft.kind.to_string(),
-                        severity: draft.severity.to_string(),
-                    },
-                ));
+                events.push(match draft.kind {
+                    AfflictionKind::Contagion(contagion) => TaggedEvent::new(
+                        GameOutput::ContagionInfected(
+                            self.name.as_str(),
+                            contagion,
+                            Some(target.name.as_str()),
+                        )
+                        .to_string(),
+                        MessagePayload::ContagionInfected {
+                            tribute: tref(self),
+                            source: Some(tref(target)),
+                            contagion,
+                        },
+                    ),
+                    _ => TaggedEvent::new(
+                        String::new(),
+                        MessagePayload::AfflictionAcquired {
+                            tribute_id: self.identifier.to_string(),
+                            affliction: draft.kind.to_string(),
+                            severity: draft.severity.to_string(),
+                        },
+                    ),
+                });
             }
         }
 
@@ -12,7 +12,8 @@ use crate::output::GameOutput;
 use crate::tributes::Tribute;
 use crate::tributes::actions::{AttackOutcome, AttackResult};
 use crate::tributes::combat::inflict_table::{
-    HitSeverity, WeaponKind, lookup_break_mid_swing_inflict, lookup_inflicts,
+    HitSeverity, WeaponKind, lookup_break_mid_swing_inflict, lookup_contagion_inflict,
+    lookup_inflicts,
 };
 use crate::tributes::stamina_band::stamina_band;
 use rand::RngExt;
@@ -376,7 +377,7 @@ pub fn attack_contest(
     // Phase 3: affliction inflict table lookup.
     let hit_severity = result_to_hit_severity(&result);
     let attacker_id = attacker.identifier.as_str();
-    let (inflicts, attacker_inflicts) =
+    let (mut inflicts, mut attacker_inflicts) =
         if matches!(result, AttackResult::Miss | AttackResult::CriticalFumble) {
             (Vec::new(), Vec::new())
         } else {
@@ -393,6 +394,28 @@ pub fn attack_contest(
             (target_inflicts, attacker_inflicts)
         };
 
+    // Contagion transmission: whoever lands the blow may pass on a
+    // contagion they have transformed under.
+    match result {
+        AttackResult::AttackerWins
+        | AttackResult::AttackerWinsDecisively
+        | AttackResult::CriticalHit => inflicts.extend(lookup_contagion_inflict(
+            attacker.transformed_contagion(),
+            hit_severity,
+            attacker_id,
+            rng,
+        )),
+        AttackResult::DefenderWins
+        | AttackResult::DefenderWinsDecisively
+        | AttackResult::PerfectBlock => attacker_inflicts.extend(lookup_contagion_inflict(
+            target.transformed_contagion(),
+            hit_severity,
+            target.identifier.as_str(),
+            rng,
+        )),
+        AttackResult::CriticalFumble | AttackResult::Miss => {}
+    }
+
     // TODO(dvd): emit SponsorEvent::AttackOnTrapped when attacker wins against
     //            a trapped target, so the sponsorship system can apply affinity
     //            penalties and generate audience-disapproval narration.
@@ -559,25 +582,7 @@ fn result_to_hit_severity(result: &AttackResult) -> HitSeverity {
 
 /// Classify an Item into a WeaponKind for the inflict table.
 fn classify_weapon(item: &Item) -> WeaponKind {
-    let name_lower = item.name.to_lowercase();
-    if name_lower.contains("bow") || name_lower.contains("arrow") || name_lower.contains("spear") {
-        WeaponKind::Ranged
-    } else if name_lower.contains("knife")
-        || name_lower.contains("sword")
-        || name_lower.contains("blade")
-        || name_lower.contains("dagger")
-        || name_lower.contains("axe")
-    {
-        WeaponKind::Bladed
-    } else if name_lower.contains("club")
-        || name_lower.contains("hammer")
-        || name_lower.contains("mace")
-        || name_lower.contains("bat")
-    {
-        WeaponKind::Blunt
-    } else {
-        WeaponKind::Bladed // Default for unknown weapons
-    }
+    WeaponKind::from_weapon_name(&item.name)
 }
 
 // ---------------------------------------------------------------------------
@@ -6,8 +6,10 @@
 //! See `docs/superpowers/specs/2026-05-03-stamina-combat-resource-design.md`.
 
 use serde::{Deserialize, Serialize};
+use shared::Difficulty;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CombatTuning {
     // --- Existing constants (verbatim from combat.rs:21-26) ---
     pub decisive_win_multiplier: f64,
@@ -70,6 +72,30 @@ impl Default for CombatTuning {
     }
 }
 
+impl CombatTuning {
+    /// The tuning a difficulty preset starts from.
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        match difficulty {
+            // Fights drag on: wins are less decisive and swings tire faster.
+            Difficulty::Gentle => Self {
+                decisive_win_multiplier: 1.25,
+                stamina_cost_attacker: 30,
+                recovery_resting: 40,
+                recovery_sheltered_resting: 75,
+                ..Self::default()
+            },
+            Difficulty::Standard => Self::default(),
+            // Fights end quickly and tributes can keep swinging.
+            Difficulty::Bloodbath => Self {
+                decisive_win_multiplier: 2.0,
+                stamina_cost_attacker: 15,
+                winded_attack_score_penalty: -5,
+                ..Self::default()
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,324 @@
+//! Short-lived emotional state layered on top of long-term sanity and
+//! static traits. Four 0–100 axes move in response to events a tribute
+//! takes part in or witnesses and drift back to baseline between phases.
+//!
+//! The axes feed the brain (`Brain::act`, `action_score`) and the alliance
+//! and betrayal odds in `tributes::alliances`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+use crate::tributes::Tribute;
+use crate::tributes::traits::Trait;
+
+const AXIS_MAX: i16 = 100;
+
+/// Deviation from baseline below which an axis does not colour the
+/// tribute's dominant emotion.
+const DOMINANT_THRESHOLD: i16 = 15;
+
+/// Share of the distance back to baseline recovered each phase.
+const SETTLE_DIVISOR: i16 = 10;
+
+/// Trust below which a non-Treacherous tribute starts eyeing betrayal.
+pub const BETRAYAL_TRUST_CEILING: u8 = 20;
+
+/// Emotional state carried on `Tribute`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Emotions {
+    /// Hope and will to keep going. Low morale means despair.
+    pub morale: u8,
+    /// Appetite for violence.
+    pub aggression: u8,
+    /// Willingness to rely on other tributes.
+    pub trust: u8,
+    /// Emotional control. Low composure means panic.
+    pub composure: u8,
+}
+
+impl Default for Emotions {
+    fn default() -> Self {
+        Self::BASELINE
+    }
+}
+
+/// Something that happened to, or in front of, a tribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmotionalStimulus {
+    /// A direct ally died.
+    AllyDied,
+    /// Someone died in the tribute's area.
+    WitnessedDeath,
+    /// The tribute killed someone.
+    KilledTribute,
+    /// An ally turned on the tribute.
+    Betrayed,
+    /// The tribute turned on an ally.
+    BetrayedAlly,
+    /// The tribute joined an alliance.
+    AllianceFormed,
+    /// A sponsor sent the tribute a gift.
+    SponsorGift,
+}
+
+/// Label for whichever axis has strayed furthest from baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DominantEmotion {
+    Calm,
+    Confident,
+    Despairing,
+    Furious,
+    Meek,
+    Trusting,
+    Distrustful,
+    Steady,
+    Panicked,
+}
+
+impl DominantEmotion {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DominantEmotion::Calm => "calm",
+            DominantEmotion::Confident => "confident",
+            DominantEmotion::Despairing => "despairing",
+            DominantEmotion::Furious => "furious",
+            DominantEmotion::Meek => "meek",
+            DominantEmotion::Trusting => "trusting",
+            DominantEmotion::Distrustful => "distrustful",
+            DominantEmotion::Steady => "steady",
+            DominantEmotion::Panicked => "panicked",
+        }
+    }
+}
+
+impl Display for DominantEmotion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+fn adjust(axis: &mut u8, delta: i16) {
+    *axis = (*axis as i16 + delta).clamp(0, AXIS_MAX) as u8;
+}
+
+/// Scale a negative delta by how well the tribute's traits absorb shocks.
+/// Positive deltas pass through unchanged.
+fn scale_setback(delta: i16, traits: &[Trait]) -> i16 {
+    if delta >= 0 {
+        return delta;
+    }
+    if traits.contains(&Trait::Resilient) {
+        delta / 2
+    } else if traits.contains(&Trait::Fragile) {
+        delta * 3 / 2
+    } else {
+        delta
+    }
+}
+
+impl Emotions {
+    pub const BASELINE: Self = Self {
+        morale: 60,
+        aggression: 30,
+        trust: 50,
+        composure: 60,
+    };
+
+    /// Apply one stimulus. Morale and composure setbacks are softened for
+    /// Resilient tributes and sharpened for Fragile ones.
+    pub fn feel(&mut self, stimulus: EmotionalStimulus, traits: &[Trait]) {
+        // (morale, aggression, trust, composure)
+        let (morale, aggression, trust, composure): (i16, i16, i16, i16) = match stimulus {
+            EmotionalStimulus::AllyDied => (-20, 10, -5, -15),
+            EmotionalStimulus::WitnessedDeath => (-5, 0, 0, -10),
+            EmotionalStimulus::KilledTribute => (10, 10, 0, -5),
+            EmotionalStimulus::Betrayed => (-10, 15, -35, -10),
+            EmotionalStimulus::BetrayedAlly => (0, 5, -10, 0),
+            EmotionalStimulus::AllianceFormed => (5, 0, 10, 5),
+            EmotionalStimulus::SponsorGift => (15, 0, 0, 5),
+        };
+        adjust(&mut self.morale, scale_setback(morale, traits));
+        adjust(&mut self.aggression, aggression);
+        adjust(&mut self.trust, trust);
+        adjust(&mut self.composure, scale_setback(composure, traits));
+    }
+
+    /// Drift every axis a tenth of the way (at least one point) back
+    /// toward baseline.
+    pub fn settle(&mut self) {
+        fn toward(axis: &mut u8, baseline: u8) {
+            let gap = baseline as i16 - *axis as i16;
+            if gap == 0 {
+                return;
+            }
+            let step = gap / SETTLE_DIVISOR;
+            let step = if step == 0 { gap.signum() } else { step };
+            adjust(axis, step);
+        }
+        toward(&mut self.morale, Self::BASELINE.morale);
+        toward(&mut self.aggression, Self::BASELINE.aggression);
+        toward(&mut self.trust, Self::BASELINE.trust);
+        toward(&mut self.composure, Self::BASELINE.composure);
+    }
+
+    /// The axis furthest from baseline, or `Calm` when none has moved by
+    /// more than `DOMINANT_THRESHOLD`. Ties favour the earlier axis in
+    /// morale, aggression, trust, composure order.
+    pub fn dominant(&self) -> DominantEmotion {
+        let deviations = [
+            (
+                self.morale as i16 - Self::BASELINE.morale as i16,
+                DominantEmotion::Confident,
+                DominantEmotion::Despairing,
+            ),
+            (
+                self.aggression as i16 - Self::BASELINE.aggression as i16,
+                DominantEmotion::Furious,
+                DominantEmotion::Meek,
+            ),
+            (
+                self.trust as i16 - Self::BASELINE.trust as i16,
+                DominantEmotion::Trusting,
+                DominantEmotion::Distrustful,
+            ),
+            (
+                self.composure as i16 - Self::BASELINE.composure as i16,
+                DominantEmotion::Steady,
+                DominantEmotion::Panicked,
+            ),
+        ];
+        let mut best = (DOMINANT_THRESHOLD, DominantEmotion::Calm);
+        for (deviation, high, low) in deviations {
+            if deviation.abs() > best.0 {
+                best = (deviation.abs(), if deviation > 0 { high } else { low });
+            }
+        }
+        best.1
+    }
+
+    /// Emotional contribution to an `Action::Attack` score. Aggression
+    /// pushes toward a fight, lost composure pulls away from one. Zero at
+    /// baseline.
+    pub fn attack_bias(&self) -> i32 {
+        let aggression = self.aggression as i32 - Self::BASELINE.aggression as i32;
+        let composure = self.composure as i32 - Self::BASELINE.composure as i32;
+        aggression / 3 + composure / 5
+    }
+
+    /// Additive shift to an alliance formation chance from the proposer's
+    /// and target's trust. Zero at baseline; ±0.2 at the extremes.
+    pub fn alliance_shift(&self, target: &Emotions) -> f64 {
+        let mean_trust = (self.trust as f64 + target.trust as f64) / 2.0;
+        (mean_trust - Self::BASELINE.trust as f64) / 250.0
+    }
+
+    /// Chance that a tribute without the Treacherous trait turns on a
+    /// same-area ally this turn. Zero until trust falls below
+    /// `BETRAYAL_TRUST_CEILING`, then scales with the trust deficit and
+    /// aggression up to 0.5.
+    pub fn betrayal_chance(&self) -> f64 {
+        if self.trust >= BETRAYAL_TRUST_CEILING {
+            return 0.0;
+        }
+        let deficit = (BETRAYAL_TRUST_CEILING - self.trust) as f64 / BETRAYAL_TRUST_CEILING as f64;
+        (0.5 * deficit * (self.aggression as f64 / AXIS_MAX as f64)).clamp(0.0, 0.5)
+    }
+}
+
+impl Tribute {
+    /// Apply an emotional stimulus, filtered through this tribute's traits.
+    pub fn feel(&mut self, stimulus: EmotionalStimulus) {
+        self.emotions.feel(stimulus, &self.traits);
+    }
+
+    /// The label the UI and broadcast digests show for this tribute's mood.
+    pub fn dominant_emotion(&self) -> DominantEmotion {
+        self.emotions.dominant()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_is_calm() {
+        assert_eq!(Emotions::default().dominant(), DominantEmotion::Calm);
+        assert!(
+            Emotions::default()
+                .alliance_shift(&Emotions::default())
+                .abs()
+                < f64::EPSILON
+        );
+        assert_eq!(Emotions::default().betrayal_chance(), 0.0);
+    }
+
+    #[test]
+    fn ally_death_leaves_a_tribute_despairing() {
+        let mut emotions = Emotions::default();
+        emotions.feel(EmotionalStimulus::AllyDied, &[]);
+        assert_eq!(emotions.morale, 40);
+        assert_eq!(emotions.composure, 45);
+        assert_eq!(emotions.dominant(), DominantEmotion::Despairing);
+    }
+
+    #[test]
+    fn resilience_softens_and_fragility_sharpens_setbacks() {
+        let mut resilient = Emotions::default();
+        let mut fragile = Emotions::default();
+        resilient.feel(EmotionalStimulus::AllyDied, &[Trait::Resilient]);
+        fragile.feel(EmotionalStimulus::AllyDied, &[Trait::Fragile]);
+        assert_eq!(resilient.morale, 50);
+        assert_eq!(fragile.morale, 30);
+    }
+
+    #[test]
+    fn betrayal_breeds_distrust_and_betrayal() {
+        let mut emotions = Emotions::default();
+        emotions.feel(EmotionalStimulus::Betrayed, &[]);
+        assert_eq!(emotions.dominant(), DominantEmotion::Distrustful);
+        assert!(emotions.trust < BETRAYAL_TRUST_CEILING);
+        let once = emotions.betrayal_chance();
+        assert!(once > 0.0);
+        emotions.feel(EmotionalStimulus::Betrayed, &[]);
+        assert!(emotions.betrayal_chance() > once);
+        assert!(emotions.alliance_shift(&Emotions::default()) < 0.0);
+    }
+
+    #[test]
+    fn kills_raise_the_attack_bias() {
+        let mut emotions = Emotions::default();
+        let before = emotions.attack_bias();
+        emotions.feel(EmotionalStimulus::KilledTribute, &[]);
+        emotions.feel(EmotionalStimulus::KilledTribute, &[]);
+        assert!(emotions.attack_bias() > before);
+    }
+
+    #[test]
+    fn axes_are_clamped() {
+        let mut emotions = Emotions::default();
+        for _ in 0..10 {
+            emotions.feel(EmotionalStimulus::Betrayed, &[]);
+            emotions.feel(EmotionalStimulus::SponsorGift, &[]);
+        }
+        assert_eq!(emotions.trust, 0);
+        assert_eq!(emotions.aggression, 100);
+        assert_eq!(emotions.morale, 100);
+    }
+
+    #[test]
+    fn settling_returns_to_baseline() {
+        let mut emotions = Emotions {
+            morale: 5,
+            aggression: 95,
+            trust: 49,
+            composure: 60,
+        };
+        for _ in 0..60 {
+            emotions.settle();
+        }
+        assert_eq!(emotions, Emotions::BASELINE);
+    }
+}
@@ -1,6 +1,5 @@
 use crate::threats::animals::Animal;
 use rand::RngExt;
-use rand::prelude::SmallRng;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -75,9 +74,8 @@ impl Display for TributeEvent {
 }
 
 impl TributeEvent {
-    pub fn random() -> TributeEvent {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
-        let animal = Animal::random();
+    pub fn random(rng: &mut impl Rng) -> TributeEvent {
+        let animal = Animal::random(rng);
         let events = [
             TributeEvent::AnimalAttack(animal),
             TributeEvent::Dysentery,
@@ -138,7 +136,7 @@ mod tests {
 
     #[test]
     fn random_tribute_event() {
-        let te = TributeEvent::random();
+        let te = TributeEvent::random(&mut rand::rng());
         assert_eq!(TributeEvent::from_str(&te.to_string()).unwrap(), te);
     }
 
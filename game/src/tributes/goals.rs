@@ -0,0 +1,492 @@
+//! Goals, and the plans that carry them out over several phases.
+//!
+//! Left to itself the brain picks whatever action scores best each phase,
+//! so a thirsty tribute drifts toward water, turns away when the scores
+//! shift, and drifts back. A tribute with a [`Plan`] has committed to a
+//! [`Goal`] instead: a destination, the route there from
+//! [`plan_path`](crate::areas::path::plan_path), and what to do on arrival.
+//! The brain follows the plan while the tribute has no company to deal
+//! with, and [`Tribute::review_plan`] only reconsiders it when something
+//! interrupts it: the goal is met or moot, the quarry moves or is lost,
+//! the way is closed, or the tribute is knocked off its route.
+//!
+//! Plans are made on beliefs like every other decision. Only tributes on
+//! the built-in brain make them.
+
+use crate::areas::forage::forage_richness;
+use crate::areas::path::plan_path;
+use crate::areas::shelter::shelter_quality;
+use crate::areas::water::water_source;
+use crate::areas::{Area, AreaDetails};
+use crate::tributes::Tribute;
+use crate::tributes::actions::Action;
+use crate::tributes::survival::{HungerBand, ThirstBand, hunger_band, thirst_band};
+use crate::tributes::traits::{REFUSERS, geometric_mean_affinity};
+use serde::{Deserialize, Serialize};
+use shared::messages::Phase;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use uuid::Uuid;
+
+/// Aggression at or above which a healthy tribute goes looking for a
+/// fight instead of waiting for one.
+const HUNT_AGGRESSION: u8 = 60;
+
+/// Shelter quality (see `shelter_quality`) worth walking to for the night.
+const GOOD_SHELTER: u8 = 2;
+
+/// Something a tribute has set out to do.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Goal {
+    SecureWater,
+    FindFood,
+    /// Get somewhere sheltered before Night falls.
+    ReachShelter,
+    Hunt {
+        target: Uuid,
+        name: String,
+    },
+    FindAlly {
+        tribute: Uuid,
+        name: String,
+    },
+}
+
+impl Goal {
+    /// The tribute the goal is about, if any.
+    fn quarry(&self) -> Option<Uuid> {
+        match self {
+            Goal::Hunt { target, .. } => Some(*target),
+            Goal::FindAlly { tribute, .. } => Some(*tribute),
+            Goal::SecureWater | Goal::FindFood | Goal::ReachShelter => None,
+        }
+    }
+
+    /// Whether company at the destination is the point of the goal rather
+    /// than a distraction from it.
+    pub fn seeks_company(&self) -> bool {
+        self.quarry().is_some()
+    }
+
+    /// What to do once at the destination.
+    fn final_step(&self) -> Action {
+        match self {
+            Goal::SecureWater => Action::DrinkFromTerrain,
+            Goal::FindFood => Action::Forage,
+            Goal::ReachShelter => Action::Hide,
+            Goal::Hunt { .. } => Action::Attack,
+            Goal::FindAlly { .. } => Action::ProposeAlliance,
+        }
+    }
+}
+
+impl Display for Goal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Goal::SecureWater => write!(f, "secure water"),
+            Goal::FindFood => write!(f, "find food"),
+            Goal::ReachShelter => write!(f, "reach shelter before nightfall"),
+            Goal::Hunt { name, .. } => write!(f, "hunt down {name}"),
+            Goal::FindAlly { name, .. } => write!(f, "make an ally of {name}"),
+        }
+    }
+}
+
+/// A goal and how the tribute means to get there. Carried on `Tribute`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Plan {
+    pub goal: Goal,
+    /// Where the goal is to be met.
+    pub destination: Area,
+    /// Areas still to cross to get there, next first. Empty on arrival.
+    #[serde(default)]
+    pub route: Vec<Area>,
+    /// When the plan was made.
+    pub day: u32,
+    pub phase: Phase,
+}
+
+impl Plan {
+    /// The next step toward the goal.
+    pub fn next_step(&self) -> Action {
+        match self.route.first() {
+            Some(next) => Action::Move(Some(*next)),
+            None => self.goal.final_step(),
+        }
+    }
+
+    /// Whether `action`, taken at the destination, sees the plan through.
+    pub fn fulfilled_by(&self, action: &Action) -> bool {
+        self.route.is_empty() && *action == self.goal.final_step()
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.route.is_empty() {
+            write!(f, "{} in {}", self.goal, self.destination)
+        } else {
+            write!(f, "{}, heading for {}", self.goal, self.destination)
+        }
+    }
+}
+
+/// What a tribute plans with, for [`Tribute::review_plan`].
+pub struct Planning<'a> {
+    pub day: u32,
+    pub phase: Phase,
+    /// The arena's areas. The map is common knowledge; what lies in it is
+    /// taken from the tribute's beliefs.
+    pub areas: &'a [AreaDetails],
+    /// Living tributes by area, to put names to the ones the tribute
+    /// believes it has seen.
+    pub tributes_by_area: &'a HashMap<Area, Vec<Tribute>>,
+}
+
+impl Planning<'_> {
+    fn name_of(&self, tribute: &Uuid) -> Option<&str> {
+        self.tributes_by_area
+            .values()
+            .flatten()
+            .find(|t| t.id == *tribute)
+            .map(|t| t.name.as_str())
+    }
+}
+
+/// What a review makes of a plan.
+#[derive(Debug, PartialEq, Eq)]
+enum Review {
+    Keep,
+    /// The goal stands but the way to it has changed.
+    Reroute,
+    /// The goal is met, moot or out of reach.
+    Abandon,
+}
+
+impl Tribute {
+    /// Keep the tribute's plan unless something has interrupted it, and
+    /// make a new one if it has none. Called once a phase, after the
+    /// tribute has perceived its surroundings.
+    pub fn review_plan(&mut self, planning: &Planning<'_>) {
+        let areas = self.beliefs.arena_view(planning.areas);
+        let closed = self.beliefs.closed();
+
+        if let Some(plan) = self.plan.take() {
+            match self.review(&plan, planning, &closed) {
+                Review::Keep => {
+                    self.plan = Some(plan);
+                    return;
+                }
+                Review::Reroute => {
+                    self.plan = self.plan_for(plan.goal, planning, &areas, &closed);
+                    if self.plan.is_some() {
+                        return;
+                    }
+                }
+                Review::Abandon => {}
+            }
+        }
+
+        self.plan = self
+            .wanted_goals(planning)
+            .into_iter()
+            .find_map(|goal| self.plan_for(goal, planning, &areas, &closed));
+    }
+
+    /// Tick off the plan's next step if `action` took it, and drop the
+    /// plan once it has been seen through.
+    pub fn advance_plan(&mut self, action: &Action) {
+        let Some(plan) = self.plan.as_mut() else {
+            return;
+        };
+        if plan.fulfilled_by(action) {
+            self.plan = None;
+        } else if plan.route.first() == Some(&self.area) {
+            plan.route.remove(0);
+        }
+    }
+
+    /// The plan's goal as the tribute would put it, while it is alive to
+    /// pursue one.
+    pub fn current_goal(&self) -> Option<String> {
+        self.plan
+            .as_ref()
+            .filter(|_| self.is_alive())
+            .map(|plan| plan.to_string())
+    }
+
+    fn review(&self, plan: &Plan, planning: &Planning<'_>, closed: &[Area]) -> Review {
+        let met_or_moot = match &plan.goal {
+            Goal::SecureWater => !is_thirsty(self),
+            Goal::FindFood => !is_hungry(self),
+            Goal::ReachShelter => planning.phase == Phase::Dawn,
+            Goal::Hunt { target, .. } => {
+                self.allies.contains(target)
+                    || self.effective_health() < self.brain.thresholds.low_health
+            }
+            Goal::FindAlly { .. } => !self.allies.is_empty(),
+        };
+        if met_or_moot {
+            return Review::Abandon;
+        }
+
+        if let Some(quarry) = plan.goal.quarry() {
+            match self.beliefs.tributes.get(&quarry) {
+                None => return Review::Abandon,
+                Some(sighting) if sighting.value != plan.destination => return Review::Reroute,
+                Some(_) => {}
+            }
+        }
+
+        let blocked = plan.route.iter().any(|area| closed.contains(area))
+            || (plan.goal.quarry().is_none() && closed.contains(&plan.destination));
+        let on_route = match plan.route.first() {
+            Some(next) => self.area.grid_neighbors().contains(next),
+            None => self.area == plan.destination,
+        };
+        if blocked || !on_route {
+            Review::Reroute
+        } else {
+            Review::Keep
+        }
+    }
+
+    /// Goals the tribute would take on now, most pressing first.
+    fn wanted_goals(&self, planning: &Planning<'_>) -> Vec<Goal> {
+        let mut goals = Vec::new();
+        if is_thirsty(self) && !self.items.iter().any(|i| i.item_type.is_water()) {
+            goals.push(Goal::SecureWater);
+        }
+        if is_hungry(self) && !self.items.iter().any(|i| i.item_type.is_food()) {
+            goals.push(Goal::FindFood);
+        }
+        if planning.phase == Phase::Dusk {
+            goals.push(Goal::ReachShelter);
+        }
+
+        // The freshest sighting of someone who isn't already an ally.
+        let quarry = self
+            .beliefs
+            .tributes
+            .iter()
+            .filter(|(id, _)| !self.allies.contains(id))
+            .max_by_key(|(_, sighting)| sighting.confidence)
+            .and_then(|(id, _)| planning.name_of(id).map(|name| (*id, name.to_string())));
+        if let Some((id, name)) = quarry {
+            if self.emotions.aggression >= HUNT_AGGRESSION
+                && self.effective_health() >= self.brain.thresholds.mid_health
+            {
+                goals.push(Goal::Hunt { target: id, name });
+            } else if self.allies.is_empty()
+                && !self.traits.iter().any(|t| REFUSERS.contains(t))
+                && geometric_mean_affinity(&self.traits) > 1.0
+            {
+                goals.push(Goal::FindAlly { tribute: id, name });
+            }
+        }
+        goals
+    }
+
+    /// A plan for `goal`, if there is anywhere to meet it.
+    fn plan_for(
+        &self,
+        goal: Goal,
+        planning: &Planning<'_>,
+        areas: &[AreaDetails],
+        closed: &[Area],
+    ) -> Option<Plan> {
+        let (destination, route) = match goal.quarry() {
+            Some(quarry) => {
+                let area = self.beliefs.tributes.get(&quarry)?.value;
+                (area, self.route_to(areas, closed, area)?.0)
+            }
+            None => {
+                let suitable = |details: &AreaDetails| {
+                    let weather = &details.weather.current;
+                    match goal {
+                        Goal::SecureWater => water_source(details.terrain.base, weather) > 0,
+                        Goal::FindFood => forage_richness(details.terrain.base) > 0,
+                        _ => shelter_quality(details.terrain.base, weather) >= GOOD_SHELTER,
+                    }
+                };
+                areas
+                    .iter()
+                    .filter(|details| suitable(details))
+                    .filter_map(|details| details.area)
+                    .filter(|area| !closed.contains(area))
+                    .filter_map(|area| {
+                        let (route, cost) = self.route_to(areas, closed, area)?;
+                        Some((cost, area, route))
+                    })
+                    .min_by_key(|(cost, area, _)| (*cost, *area))
+                    .map(|(_, area, route)| (area, route))?
+            }
+        };
+        Some(Plan {
+            goal,
+            destination,
+            route,
+            day: planning.day,
+            phase: planning.phase,
+        })
+    }
+
+    /// The areas to cross to reach `destination`, and what it costs.
+    fn route_to(
+        &self,
+        areas: &[AreaDetails],
+        closed: &[Area],
+        destination: Area,
+    ) -> Option<(Vec<Area>, u32)> {
+        let (path, cost) = plan_path(areas, closed, self, self.area, destination)?;
+        Some((path.into_iter().skip(1).collect(), cost))
+    }
+}
+
+fn is_thirsty(tribute: &Tribute) -> bool {
+    matches!(
+        thirst_band(tribute.thirst),
+        ThirstBand::Parched | ThirstBand::Dehydrated
+    )
+}
+
+fn is_hungry(tribute: &Tribute) -> bool {
+    matches!(
+        hunger_band(tribute.hunger),
+        HungerBand::Hungry | HungerBand::Starving
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::{BaseTerrain, TerrainType};
+
+    fn area(area: Area, base: BaseTerrain) -> AreaDetails {
+        AreaDetails::new_with_terrain(None, area, TerrainType::new(base, vec![]).unwrap())
+    }
+
+    /// Desert, but for wetlands two areas out from the Cornucopia.
+    fn dry_arena() -> Vec<AreaDetails> {
+        let layout = crate::areas::hex::arena_layout(2);
+        let wet = layout.iter().map(|(a, _)| *a).find(|a| a.ring() == 2);
+        layout
+            .into_iter()
+            .map(|(a, _)| {
+                let base = if Some(a) == wet {
+                    BaseTerrain::Wetlands
+                } else {
+                    BaseTerrain::Desert
+                };
+                area(a, base)
+            })
+            .collect()
+    }
+
+    fn planning<'a>(
+        areas: &'a [AreaDetails],
+        tributes_by_area: &'a HashMap<Area, Vec<Tribute>>,
+    ) -> Planning<'a> {
+        Planning {
+            day: 2,
+            phase: Phase::Day,
+            areas,
+            tributes_by_area,
+        }
+    }
+
+    #[test]
+    fn a_parched_tribute_commits_to_the_nearest_water() {
+        let areas = dry_arena();
+        let nobody = HashMap::new();
+        let mut tribute = Tribute::new("Thirsty".to_string(), None, None);
+        tribute.area = Area::CORNUCOPIA;
+        tribute.thirst = 2;
+
+        tribute.review_plan(&planning(&areas, &nobody));
+        let plan = tribute.plan.clone().expect("a plan for water");
+        assert_eq!(plan.goal, Goal::SecureWater);
+        assert_eq!(plan.route.len(), 2);
+        assert_eq!(plan.route.last(), Some(&plan.destination));
+
+        // Following it a step leaves the plan standing.
+        let Action::Move(Some(next)) = plan.next_step() else {
+            panic!("expected a move, got {:?}", plan.next_step());
+        };
+        tribute.area = next;
+        tribute.advance_plan(&Action::Move(Some(next)));
+        tribute.review_plan(&planning(&areas, &nobody));
+        let plan = tribute.plan.clone().unwrap();
+        assert_eq!(plan.route.len(), 1);
+        assert_eq!(plan.day, 2);
+
+        tribute.area = plan.destination;
+        tribute.advance_plan(&plan.next_step());
+        assert_eq!(
+            tribute.plan.as_ref().unwrap().next_step(),
+            Action::DrinkFromTerrain
+        );
+        tribute.advance_plan(&Action::DrinkFromTerrain);
+        assert!(tribute.plan.is_none());
+    }
+
+    #[test]
+    fn interruptions_reroute_or_abandon_the_plan() {
+        let areas = dry_arena();
+        let nobody = HashMap::new();
+        let mut tribute = Tribute::new("Thirsty".to_string(), None, None);
+        tribute.area = Area::CORNUCOPIA;
+        tribute.thirst = 3;
+        tribute.review_plan(&planning(&areas, &nobody));
+        let first_hop = tribute.plan.as_ref().unwrap().route[0];
+
+        // Seeing the way closed finds another.
+        tribute.beliefs.closed_areas.insert(first_hop, 100);
+        tribute.review_plan(&planning(&areas, &nobody));
+        let plan = tribute.plan.clone().unwrap();
+        assert_eq!(plan.goal, Goal::SecureWater);
+        assert_ne!(plan.route[0], first_hop);
+
+        // Water from elsewhere makes the trip moot.
+        tribute.thirst = 0;
+        tribute.review_plan(&planning(&areas, &nobody));
+        assert!(tribute.plan.is_none());
+    }
+
+    #[test]
+    fn a_hunter_follows_its_quarry_and_gives_up_when_it_is_lost() {
+        let areas = dry_arena();
+        let mut hunter = Tribute::new("Hunter".to_string(), None, None);
+        hunter.area = Area::CORNUCOPIA;
+        hunter.emotions.aggression = 80;
+        let mut quarry = Tribute::new("Quarry".to_string(), None, None);
+        quarry.area = Area::CORNUCOPIA.grid_neighbors()[0];
+        let tributes_by_area = HashMap::from([(quarry.area, vec![quarry.clone()])]);
+        hunter.beliefs.tributes.insert(
+            quarry.id,
+            crate::tributes::beliefs::Belief {
+                value: quarry.area,
+                confidence: 100,
+            },
+        );
+
+        hunter.review_plan(&planning(&areas, &tributes_by_area));
+        let plan = hunter.plan.clone().unwrap();
+        assert_eq!(
+            plan.to_string(),
+            format!("hunt down Quarry, heading for {}", quarry.area)
+        );
+        assert_eq!(plan.route, [quarry.area]);
+
+        // The quarry is seen moving on; the hunter changes course.
+        let moved = Area::CORNUCOPIA.grid_neighbors()[1];
+        hunter.beliefs.tributes.get_mut(&quarry.id).unwrap().value = moved;
+        hunter.review_plan(&planning(&areas, &tributes_by_area));
+        assert_eq!(hunter.plan.as_ref().unwrap().destination, moved);
+
+        hunter.beliefs.forget_tribute(&quarry.id);
+        hunter.review_plan(&planning(&areas, &tributes_by_area));
+        assert!(hunter.plan.is_none());
+    }
+}
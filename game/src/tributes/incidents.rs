@@ -1,6 +1,6 @@
 use crate::areas::Area;
 use crate::areas::shelter;
-use crate::areas::weather;
+use crate::areas::weather::Weather;
 use crate::tributes::Tribute;
 use rand::Rng;
 use rand::RngExt;
@@ -65,10 +65,13 @@ pub fn base_incident_chance(phase: crate::messages::Phase) -> u32 {
     }
 }
 
-/// Terrain-based multiplier derived from the biome's inherent shelter quality.
-/// Better shelter biomes have lower incident chances.
-pub fn biome_incident_multiplier(biome: crate::terrain::types::BaseTerrain) -> f64 {
-    let quality = shelter::shelter_quality(biome, &weather::current_weather());
+/// Terrain-based multiplier derived from the biome's shelter quality under
+/// the area's current weather. Better shelter means fewer incidents.
+pub fn biome_incident_multiplier(
+    biome: crate::terrain::types::BaseTerrain,
+    weather: Weather,
+) -> f64 {
+    let quality = shelter::shelter_quality(biome, &weather);
     match quality {
         3 => SHELTER_QUALITY_SCORE_3,
         2 => SHELTER_QUALITY_SCORE_2,
@@ -89,16 +92,18 @@ pub fn day_scaling_multiplier(_current_day: u32) -> f64 {
 }
 
 /// Combined effective incident chance (0.0-1.0) factoring in phase, terrain,
-/// shelter status, constructed shelter quality, and game-day progression.
+/// weather, shelter status, constructed shelter quality, and game-day
+/// progression.
 pub fn effective_incident_chance(
     phase: crate::messages::Phase,
     biome: crate::terrain::types::BaseTerrain,
+    weather: Weather,
     is_sheltered: bool,
     sleep_shelter: &SleepShelter,
     current_day: u32,
 ) -> f64 {
     base_incident_chance(phase) as f64
-        * biome_incident_multiplier(biome)
+        * biome_incident_multiplier(biome, weather)
         * if is_sheltered {
             SLEEP_INCIDENT_SHELTER_MULTIPLIER
         } else {
@@ -167,6 +172,7 @@ pub fn biome_flavor_pool(biome: crate::terrain::types::BaseTerrain) -> &'static
 ///
 /// * `intelligence` / `strength` — tribute attributes.
 /// * `terrain` — determines the base shelter quality (0-3).
+/// * `weather` — the area's current weather, which can degrade that quality.
 /// * `rng` — source of randomness for variance.
 ///
 /// Returns the best [`SleepShelter`] level the tribute can achieve.
@@ -174,9 +180,10 @@ pub fn find_shelter(
     intelligence: u32,
     strength: u32,
     terrain: crate::terrain::types::BaseTerrain,
+    weather: Weather,
     rng: &mut impl Rng,
 ) -> SleepShelter {
-    let quality = shelter::shelter_quality(terrain, &weather::current_weather());
+    let quality = shelter::shelter_quality(terrain, &weather);
     let dc = 25 - (quality as u32 * 5);
     let effective_roll =
         (std::cmp::max(intelligence, strength) as f64 * rng.random_range(0.8..=1.2)) as u32;
@@ -289,17 +296,24 @@ impl SleepIncident {
     }
 
     /// Roll whether a sleep incident occurs this phase, factoring in terrain,
-    /// shelter, and game-day progression.
+    /// weather, shelter, and game-day progression.
     pub fn roll(
         rng: &mut impl Rng,
         phase: crate::messages::Phase,
         biome: crate::terrain::types::BaseTerrain,
+        weather: Weather,
         is_sheltered: bool,
         sleep_shelter: &SleepShelter,
         current_day: u32,
     ) -> Option<Self> {
-        let chance =
-            effective_incident_chance(phase, biome, is_sheltered, sleep_shelter, current_day);
+        let chance = effective_incident_chance(
+            phase,
+            biome,
+            weather,
+            is_sheltered,
+            sleep_shelter,
+            current_day,
+        );
         if !rng.random_bool(chance / 100.0) {
             return None;
         }
@@ -352,11 +366,11 @@ impl SleepIncident {
 
     fn random_area(rng: &mut impl Rng) -> Area {
         let areas = [
-            Area::Cornucopia,
-            Area::Sector1,
-            Area::Sector2,
-            Area::Sector3,
-            Area::Sector4,
+            Area::CORNUCOPIA,
+            Area::SECTOR_1,
+            Area::SECTOR_2,
+            Area::SECTOR_3,
+            Area::SECTOR_4,
         ];
         let idx = rng.random_range(0..areas.len());
         areas[idx]
@@ -469,6 +483,7 @@ mod tests {
                 &mut rng,
                 Phase::Night,
                 BaseTerrain::Forest,
+                Weather::Clear,
                 false,
                 &shelter,
                 1,
@@ -524,12 +539,12 @@ mod tests {
         let mut tribute = Tribute::new("Test".to_string(), None, None);
         let orig_area = tribute.area;
         let incident = SleepIncident::Relocation {
-            new_area: Area::Sector1,
+            new_area: Area::SECTOR_1,
         };
         let mut rng = SmallRng::seed_from_u64(42);
         apply_sleep_incident(&mut tribute, &incident, &mut rng);
         assert_ne!(tribute.area, orig_area, "area should change");
-        assert_eq!(tribute.area, Area::Sector1);
+        assert_eq!(tribute.area, Area::SECTOR_1);
     }
 
     #[test]
@@ -624,7 +639,7 @@ mod tests {
     fn find_shelter_based_on_attributes() {
         let mut rng = SmallRng::seed_from_u64(42);
         // High int + str with good terrain should yield at least Crude
-        let shelter = find_shelter(80, 80, BaseTerrain::Forest, &mut rng);
+        let shelter = find_shelter(80, 80, BaseTerrain::Forest, Weather::Clear, &mut rng);
         assert_ne!(
             shelter,
             SleepShelter::None,
@@ -66,18 +66,23 @@ impl OwnsItems for Tribute {
 
 impl Tribute {
     /// Take an item from the current area
-    pub(crate) fn take_nearby_item(&mut self, area_details: &mut AreaDetails) -> Option<Item> {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+    pub(crate) fn take_nearby_item(
+        &mut self,
+        area_details: &mut AreaDetails,
+        rng: &mut impl Rng,
+    ) -> Option<Item> {
         let items = area_details.items.clone();
         if items.is_empty() {
             None
         } else {
-            let item = items.choose(&mut rng).unwrap().clone();
+            let item = items.choose(rng).unwrap().clone();
             if let Ok(()) = area_details.use_item(&item) {
                 self.add_item(item.clone());
 
                 // ~10% chance to acquire a fixation on the picked-up item.
-                crate::tributes::afflictions::fixation::maybe_acquire_item_fixation(self, &item);
+                crate::tributes::afflictions::fixation::maybe_acquire_item_fixation(
+                    self, &item, rng,
+                );
 
                 return Some(item.clone());
             }
@@ -2,15 +2,14 @@ use crate::tributes::Tribute;
 use crate::tributes::statuses::TributeStatus;
 use rand::RngExt;
 use rand::prelude::*;
-use rand::rngs::SmallRng;
 
 impl Tribute {
-    /// Marks the tribute as dead and reveals them.
+    /// Marks the tribute as dead and reveals them. Their items stay on the
+    /// body for `Game::clean_up_recent_deaths` to leave in the area.
     pub fn dies(&mut self) {
         self.blood = 0;
         self.set_status(TributeStatus::Dead);
         self.attributes.is_hidden = false;
-        self.items.clear();
     }
 
     /// Does the tribute have health and an OK status?
@@ -21,8 +20,7 @@ impl Tribute {
     }
 
     /// Hides the tribute from view.
-    pub(crate) fn hides(&mut self) -> bool {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+    pub(crate) fn hides(&mut self, rng: &mut impl Rng) -> bool {
         let hidden = rng.random_bool(self.attributes.intelligence as f64 / 100.0);
         self.attributes.is_hidden = hidden;
         hidden
@@ -62,11 +60,12 @@ mod tests {
 
     #[rstest]
     fn dies(mut tribute: Tribute) {
+        let items = tribute.items.len();
         tribute.dies();
         assert_eq!(tribute.blood, 0);
         assert_eq!(tribute.status, TributeStatus::Dead);
         assert!(!tribute.attributes.is_hidden);
-        assert_eq!(tribute.items.len(), 0);
+        assert_eq!(tribute.items.len(), items);
     }
 
     #[rstest]
@@ -79,7 +78,7 @@ mod tests {
     #[rstest]
     fn hides_success(mut tribute: Tribute) {
         tribute.attributes.intelligence = 100;
-        let hidden = tribute.hides();
+        let hidden = tribute.hides(&mut rand::rng());
         assert!(hidden);
         assert!(tribute.attributes.is_hidden);
     }
@@ -87,7 +86,7 @@ mod tests {
     #[rstest]
     fn hides_fail(mut tribute: Tribute) {
         tribute.attributes.intelligence = 0;
-        let hidden = tribute.hides();
+        let hidden = tribute.hides(&mut rand::rng());
         assert!(!hidden);
         assert!(!tribute.attributes.is_hidden);
     }
@@ -354,7 +354,8 @@ impl Tribute {
                 | AfflictionKind::Trauma
                 | AfflictionKind::Phobia(_)
                 | AfflictionKind::Fixation(_)
-                | AfflictionKind::Addiction(_) => {}
+                | AfflictionKind::Addiction(_)
+                | AfflictionKind::Contagion(_) => {}
             }
         }
 
@@ -426,7 +427,7 @@ mod tests {
         let blood = tribute.blood;
         tribute.status = TributeStatus::Mauled(Animal::Bear);
         let area_details =
-            AreaDetails::new(Some("Forest".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Forest".to_string()), crate::areas::Area::CORNUCOPIA);
         tribute.process_status(&area_details, &mut small_rng, &mut Vec::new());
         assert!(tribute.blood < blood);
     }
@@ -440,7 +441,7 @@ mod tests {
     ) {
         tribute.status = status.clone();
         let area_details =
-            AreaDetails::new(Some("Forest".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Forest".to_string()), crate::areas::Area::CORNUCOPIA);
         tribute.process_status(&area_details, &mut small_rng, &mut Vec::new());
         assert!(tribute.is_alive());
     }
@@ -456,7 +457,7 @@ mod tests {
             trapped_metadata: None,
         });
         let area_details =
-            AreaDetails::new(Some("Forest".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Forest".to_string()), crate::areas::Area::CORNUCOPIA);
         tribute.process_status(&area_details, &mut small_rng, &mut Vec::new());
         assert_eq!(tribute.blood, 0);
         assert_eq!(tribute.status, TributeStatus::RecentlyDead);
@@ -464,7 +465,7 @@ mod tests {
 
     #[rstest]
     fn process_status_from_area_event(mut tribute: Tribute, mut small_rng: SmallRng) {
-        let mut area_details = AreaDetails::new(Some("Forest".to_string()), Area::Cornucopia);
+        let mut area_details = AreaDetails::new(Some("Forest".to_string()), Area::CORNUCOPIA);
         area_details.events.push(AreaEvent::Wildfire);
 
         tribute.process_status(&area_details, &mut small_rng, &mut Vec::new());
@@ -478,7 +479,7 @@ mod tests {
     #[rstest]
     fn wildfire_sets_affliction(mut tribute: Tribute) {
         let mut area_details =
-            AreaDetails::new(Some("Forest".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Forest".to_string()), crate::areas::Area::CORNUCOPIA);
         area_details.events.push(AreaEvent::Wildfire);
 
         tribute.apply_area_effects(&area_details);
@@ -493,7 +494,7 @@ mod tests {
     #[rstest]
     fn blizzard_sets_affliction(mut tribute: Tribute) {
         let mut area_details =
-            AreaDetails::new(Some("Tundra".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Tundra".to_string()), crate::areas::Area::CORNUCOPIA);
         area_details.events.push(AreaEvent::Blizzard);
 
         tribute.apply_area_effects(&area_details);
@@ -508,7 +509,7 @@ mod tests {
     #[rstest]
     fn heatwave_sets_affliction(mut tribute: Tribute) {
         let mut area_details =
-            AreaDetails::new(Some("Desert".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Desert".to_string()), crate::areas::Area::CORNUCOPIA);
         area_details.events.push(AreaEvent::Heatwave);
 
         tribute.apply_area_effects(&area_details);
@@ -523,7 +524,7 @@ mod tests {
     #[rstest]
     fn sandstorm_sets_affliction(mut tribute: Tribute) {
         let mut area_details =
-            AreaDetails::new(Some("Desert".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Desert".to_string()), crate::areas::Area::CORNUCOPIA);
         area_details.events.push(AreaEvent::Sandstorm);
 
         tribute.apply_area_effects(&area_details);
@@ -538,7 +539,7 @@ mod tests {
     #[rstest]
     fn drought_sets_affliction(mut tribute: Tribute) {
         let mut area_details =
-            AreaDetails::new(Some("Desert".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Desert".to_string()), crate::areas::Area::CORNUCOPIA);
         area_details.events.push(AreaEvent::Drought);
 
         tribute.apply_area_effects(&area_details);
@@ -553,7 +554,7 @@ mod tests {
     #[rstest]
     fn flood_sets_trapped_affliction(mut tribute: Tribute) {
         let mut area_details =
-            AreaDetails::new(Some("River".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("River".to_string()), crate::areas::Area::CORNUCOPIA);
         area_details.events.push(AreaEvent::Flood);
 
         tribute.apply_area_effects(&area_details);
@@ -568,7 +569,7 @@ mod tests {
     #[rstest]
     fn earthquake_sets_trapped_affliction(mut tribute: Tribute) {
         let mut area_details =
-            AreaDetails::new(Some("Cave".to_string()), crate::areas::Area::Cornucopia);
+            AreaDetails::new(Some("Cave".to_string()), crate::areas::Area::CORNUCOPIA);
         area_details.events.push(AreaEvent::Earthquake);
 
         tribute.apply_area_effects(&area_details);
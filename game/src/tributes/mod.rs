@@ -1,11 +1,14 @@
 pub mod actions;
 pub mod afflictions;
 pub mod alliances;
+pub mod beliefs;
 pub mod brains;
 pub mod combat;
 pub mod combat_beat;
 pub mod combat_tuning;
+pub mod emotions;
 pub mod events;
+pub mod goals;
 mod helpers;
 pub mod incidents;
 pub mod inventory;
@@ -29,13 +32,15 @@ pub use movement::TravelResult;
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::areas::{Area, AreaDetails};
-use crate::items::Item;
+use crate::items::{Item, OwnsItems};
 use crate::messages::{AreaRef, ItemRef, MessagePayload, TaggedEvent, TributeRef};
 use crate::output::GameOutput;
 use crate::tributes::afflictions::{AcquireResolution, can_acquire};
 use crate::tributes::events::TributeEvent;
 use actions::{Action, AttackOutcome};
 use brains::Brain;
+use brains::strategy::{BrainStrategy, Perception, TributeBrain};
+use brains::trace::{DecisionLayer, DecisionTrace, Reasoning};
 use fake::Fake;
 use fake::faker::name::raw::*;
 use fake::locales::*;
@@ -144,10 +149,6 @@ pub struct EnvironmentContext<'a> {
     /// All known areas (read-only snapshot). Used by multi-hop
     /// pathfinding so the planner can reason about non-neighbor goals.
     pub all_areas: &'a [AreaDetails],
-    /// Per-area count of living tributes. Fed into `Brain::choose_destination`
-    /// as a crowd penalty so movement scoring naturally disperses crowded
-    /// tributes without a call-site escape hatch.
-    pub enemy_density: &'a std::collections::HashMap<Area, u32>,
     /// Current game day (1-indexed). Used to gate day-1-only behavior such
     /// as suppressing sponsor gifts in the opening cycle.
     pub current_day: u32,
@@ -159,6 +160,9 @@ pub struct EnvironmentContext<'a> {
     /// Tuple of (tribute UUID, tribute name). Populated by the game cycle
     /// in `execute_cycle`; consumed by `act_take_item` for target selection.
     pub sleeping_nearby: Vec<(Uuid, String)>,
+    /// Brain registered for this tribute on `Game::custom_brains`, if any.
+    /// Takes precedence over the tribute's own `strategy`.
+    pub custom_brain: Option<&'a dyn TributeBrain>,
 }
 
 #[derive(Clone, Debug)]
@@ -196,6 +200,18 @@ pub struct Tribute {
     /// `brain` column hydrate via `Brain::default()`.
     #[serde(default)]
     pub brain: Brain,
+    /// Which decision-maker drives this tribute; see `brains::strategy`.
+    #[serde(default)]
+    pub strategy: BrainStrategy,
+    /// Record id (`user:...`) of the player controlling this tribute, if
+    /// any. See `games::orders`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub controller: Option<String>,
+    /// The controlling player's orders for the coming day, by phase. Loaded
+    /// by the API before each run and consumed as the phases play;
+    /// transient — orders are stored in their own table.
+    #[serde(default, skip)]
+    pub orders: BTreeMap<shared::messages::Phase, Action>,
     /// How they present themselves to the real world
     pub avatar: Option<String>,
     /// Who created them in the real world
@@ -345,10 +361,34 @@ pub struct Tribute {
     /// `process_turn_phase`. Transient — never persisted.
     #[serde(default, skip)]
     pub pending_theft_target: Option<Uuid>,
+    /// How the tribute decided on its action this phase. Set by
+    /// `process_turn_phase` and collected into `Game::decision_traces` by
+    /// the game cycle. Transient — never persisted.
+    #[serde(default, skip)]
+    pub decision: Option<DecisionTrace>,
     /// Active mental conditions (pain, horror, panic, etc.).
     /// Recalculated each period based on wounds and stress.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub mental_conditions: Vec<shared::conditions::MentalCondition>,
+    /// Morale, aggression, trust and composure. Moved by the events the
+    /// tribute takes part in or witnesses; see `emotions`.
+    #[serde(default)]
+    pub emotions: emotions::Emotions,
+    /// Where the tribute believes the others are and what it remembers of
+    /// the arena. What its brain decides on; see `beliefs`.
+    #[serde(default, skip_serializing_if = "beliefs::Beliefs::is_empty")]
+    pub beliefs: beliefs::Beliefs,
+    /// What the tribute has set out to do and how; see `goals`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<goals::Plan>,
+    /// Where the tribute's alliance is gathering this phase, when it follows
+    /// a leader. Set by the game cycle. Transient — never persisted.
+    #[serde(default, skip)]
+    pub rally: Option<Area>,
+    /// The tribute's part in its alliance's watch this phase. Set by the
+    /// game cycle. Transient — never persisted.
+    #[serde(default, skip)]
+    pub watch: Option<alliances::Watch>,
 }
 
 impl Default for Tribute {
@@ -380,10 +420,13 @@ impl Tribute {
         Self {
             identifier: id,
             id: id_uuid,
-            area: Area::Cornucopia,
+            area: Area::CORNUCOPIA,
             name: name.clone(),
             district,
             brain,
+            strategy: BrainStrategy::default(),
+            controller: None,
+            orders: BTreeMap::new(),
             status: TributeStatus::default(),
             avatar,
             human_player_name: None,
@@ -420,16 +463,21 @@ impl Tribute {
             hangover_cycles_remaining: 0,
             was_ambushed: false,
             pending_theft_target: None,
+            decision: None,
             mental_conditions: Vec::new(),
+            emotions: emotions::Emotions::default(),
+            beliefs: beliefs::Beliefs::default(),
+            plan: None,
+            rally: None,
+            watch: None,
         }
     }
 
-    #[cfg(test)]
     pub(crate) fn new_with_rng(
         name: String,
         district: Option<u32>,
         avatar: Option<String>,
-        rng: &mut SmallRng,
+        rng: &mut impl Rng,
     ) -> Self {
         let district = district.unwrap_or(0);
         let attributes = Attributes::new();
@@ -450,10 +498,13 @@ impl Tribute {
         Self {
             identifier: id,
             id: id_uuid,
-            area: Area::Cornucopia,
+            area: Area::CORNUCOPIA,
             name,
             district,
             brain,
+            strategy: BrainStrategy::default(),
+            controller: None,
+            orders: BTreeMap::new(),
             status: TributeStatus::default(),
             avatar,
             human_player_name: None,
@@ -490,7 +541,13 @@ impl Tribute {
             hangover_cycles_remaining: 0,
             was_ambushed: false,
             pending_theft_target: None,
+            decision: None,
             mental_conditions: Vec::new(),
+            emotions: emotions::Emotions::default(),
+            beliefs: beliefs::Beliefs::default(),
+            plan: None,
+            rally: None,
+            watch: None,
         }
     }
 
@@ -501,6 +558,33 @@ impl Tribute {
         Tribute::new(name, Some(district), None)
     }
 
+    /// [`Tribute::random_with_rng`], starting with health rolled from
+    /// `config`'s starting health range and personality thresholds moved to
+    /// its brain tuning's baselines.
+    pub fn random_for_config(
+        config: &crate::config::GameConfig,
+        district: u32,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let mut tribute = Tribute::random_with_rng(district, rng);
+        tribute.blood = config.roll_starting_blood(rng);
+        tribute.brain.thresholds = tribute.brain.thresholds.retuned(&config.brain);
+        tribute
+    }
+
+    /// Seeded counterpart of [`Tribute::random`] for a fixed district.
+    ///
+    /// Name, traits, attributes and identifier are all drawn from `rng`, so
+    /// the same RNG state always builds the same tribute.
+    pub fn random_with_rng(district: u32, rng: &mut impl Rng) -> Self {
+        let name: String = Name(EN).fake_with_rng(rng);
+        let mut tribute = Tribute::new_with_rng(name, Some(district), None, rng);
+        tribute.attributes = Attributes::new_with_rng(rng);
+        tribute.id = crate::seeding::uuid_from_rng(rng);
+        tribute.identifier = tribute.id.to_string();
+        tribute
+    }
+
     /// Builder: add a pre-existing affliction (addiction, missing limb, trauma, etc.).
     /// Also populates `ever_addicted_to` for Addiction kinds so relapse semantics work.
     pub fn with_affliction(mut self, affliction: Affliction) -> Self {
@@ -605,21 +689,24 @@ impl Tribute {
             return;
         }
 
-        // Treacherous active betrayal (spec §7.4(b)). When the timer has
-        // elapsed and the tribute carries the Treacherous trait, attempt to
-        // betray a same-area ally. On success, drop the symmetric pair
-        // locally, enqueue BetrayalRecorded so the victim's `allies` is
-        // cleaned and `pending_trust_shock` flips on the next drain. The
-        // timer resets unconditionally so a missed opportunity does not
-        // stack (one chance per cadence).
-        if self.traits.contains(&traits::Trait::Treacherous)
+        // Active betrayal (spec §7.4(b)). When the timer has elapsed and
+        // the tribute carries the Treacherous trait, or their trust has
+        // collapsed and the betrayal roll hits, attempt to betray a
+        // same-area ally. On success, drop the symmetric pair locally,
+        // enqueue BetrayalRecorded so the victim's `allies` is cleaned and
+        // `pending_trust_shock` flips on the next drain. The timer resets
+        // unconditionally so a missed opportunity does not stack (one
+        // chance per cadence).
+        let betrayal_chance = alliances::betrayal_chance(&self.traits, &self.emotions);
+        if betrayal_chance > 0.0
             && self.turns_since_last_betrayal >= alliances::TREACHEROUS_BETRAYAL_INTERVAL
         {
             let same_area_ally = encounter_context
                 .potential_targets
                 .iter()
                 .find(|t| self.allies.contains(&t.id) && t.is_alive())
-                .cloned();
+                .cloned()
+                .filter(|_| betrayal_chance >= 1.0 || rng.random_bool(betrayal_chance));
             if let Some(victim) = same_area_ally {
                 self.allies.retain(|id| id != &victim.id);
                 self.alliance_events
@@ -648,36 +735,67 @@ impl Tribute {
                 .set_preferred_action(suggestion.action, suggestion.probability.unwrap_or(1.0));
         }
 
-        // Get tribute action
-        let number_of_nearby_tributes = encounter_context.nearby_tributes_count;
-        let action = if let Some(sleep_action) = self.brain.should_sleep(
-            self,
-            number_of_nearby_tributes,
-            environment_details.phase,
-            rng,
-        ) {
-            sleep_action
-        } else {
-            self.brain.act(
-                self,
-                number_of_nearby_tributes,
-                &environment_details.available_destinations,
-                environment_details.all_areas,
-                environment_details.closed_areas,
-                environment_details.enemy_density,
-                environment_details.phase,
-                rng,
-            )
+        // A player's order for this phase replaces the brain entirely,
+        // unless the arena has moved on since it was given.
+        let order = self
+            .orders
+            .remove(&environment_details.phase)
+            .filter(|order| {
+                self.can_carry_out(
+                    order,
+                    environment_details.closed_areas,
+                    environment_details.all_areas,
+                )
+            });
+        let trace = match order {
+            Some(order) => {
+                let mut reasoning = Reasoning::default();
+                reasoning.step(DecisionLayer::Order, &order);
+                DecisionTrace {
+                    tribute: self.identifier.clone(),
+                    day: environment_details.current_day,
+                    phase: environment_details.phase,
+                    brain: "order".to_string(),
+                    nearby_tributes: encounter_context.nearby_tributes_count,
+                    reasoning,
+                    action: order,
+                }
+            }
+            None => {
+                // The brain goes on what the tribute believes, not on the
+                // arena as it is.
+                let all_areas = self.beliefs.arena_view(environment_details.all_areas);
+                let closed_areas = self.beliefs.closed();
+                let enemy_density = self.beliefs.enemy_density(self.area);
+                let perception = Perception {
+                    tribute: self,
+                    nearby_tributes: encounter_context.nearby_tributes_count,
+                    phase: environment_details.phase,
+                    available_destinations: &environment_details.available_destinations,
+                    all_areas: &all_areas,
+                    closed_areas: &closed_areas,
+                    enemy_density: &enemy_density,
+                };
+                self.decide_action_traced(
+                    &perception,
+                    environment_details.custom_brain,
+                    environment_details.current_day,
+                    rng,
+                )
+            }
         };
+        let action = trace.action.clone();
+        self.decision = Some(trace);
 
         let closed_areas = environment_details.closed_areas;
 
-        match action {
+        match action.clone() {
             Action::Move(area) => {
                 self.act_move(
                     &area,
                     closed_areas,
                     &environment_details.available_destinations,
+                    rng,
                     events,
                 );
             }
@@ -685,7 +803,7 @@ impl Tribute {
                 self.act_rest(events);
             }
             Action::Hide => {
-                self.act_hide(events);
+                self.act_hide(rng, events);
             }
             Action::Attack => {
                 self.act_attack(
@@ -706,7 +824,7 @@ impl Tribute {
                 );
             }
             Action::UseItem(maybe_item) => {
-                self.act_use_item(&maybe_item, events);
+                self.act_use_item(&maybe_item, rng, events);
             }
             Action::None => {}
             Action::ProposeAlliance => {
@@ -724,6 +842,7 @@ impl Tribute {
                     self.attributes.intelligence,
                     self.attributes.strength,
                     terrain,
+                    area_details.weather.current,
                     rng,
                 ));
                 self.act_sleep(duration_phases, environment_details.phase, events);
@@ -739,7 +858,8 @@ impl Tribute {
 
                 if let Some(item) = matching_item {
                     // Found — auto-use it.
-                    self.try_use_consumable(&item, events, None).ok();
+                    self.try_use_consumable(&item, events, Some(rng.random()))
+                        .ok();
                 } else {
                     // Not found — emit visible craving.
                     let craving_severity = self
@@ -772,9 +892,12 @@ impl Tribute {
             }
         }
 
+        self.advance_plan(&action);
+
         // ── Trap trigger check ──
         // After resolving the chosen action, check if any traps in the area trigger.
-        // Setter auto-passes (no friendly fire).
+        // Setter auto-passes (no friendly fire), as does anyone who has
+        // noticed the trap.
         for idx in (0..area_details.placed_traps.len()).rev() {
             let triggered = area_details.placed_traps[idx].triggered;
             if triggered {
@@ -784,6 +907,9 @@ impl Tribute {
             if self.identifier == set_by {
                 continue; // Setter auto-passes
             }
+            if self.beliefs.knows_trap(&area_details.placed_traps[idx].id) {
+                continue;
+            }
 
             // Passive Perception check
             let perception_mod = (self.attributes.intelligence as f32 / 10.0).floor() as i32;
@@ -835,6 +961,7 @@ impl Tribute {
         mut targets: Vec<Tribute>,
         living_tributes_count: u32,
         events: &mut Vec<TaggedEvent>,
+        rng: &mut impl Rng,
     ) -> Option<Tribute> {
         // If there are no targets, check if the tribute is feeling suicidal.
         if targets.is_empty() {
@@ -883,8 +1010,27 @@ impl Tribute {
         } else {
             sleeping_enemies
         };
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
-        Some(pool.choose(&mut rng).unwrap().clone())
+        Some(pool.choose(rng).unwrap().clone())
+    }
+
+    /// Whether a player's order still holds where the tribute stands now.
+    /// Orders are checked when given, but a tribute can be pushed around
+    /// before its phase comes, so a move must still lead to an open area
+    /// next door.
+    fn can_carry_out(
+        &self,
+        order: &Action,
+        closed_areas: &[Area],
+        all_areas: &[AreaDetails],
+    ) -> bool {
+        match order {
+            Action::Move(Some(area)) => {
+                self.area.grid_neighbors().contains(area)
+                    && !closed_areas.contains(area)
+                    && (all_areas.is_empty() || all_areas.iter().any(|ad| ad.area == Some(*area)))
+            }
+            _ => true,
+        }
     }
 
     // --- Per-Action executor helpers (extracted from process_turn_phase) ---
@@ -894,6 +1040,7 @@ impl Tribute {
         area: &Option<Area>,
         closed_areas: &[Area],
         available_destinations: &[crate::areas::DestinationInfo],
+        rng: &mut impl Rng,
         events: &mut Vec<TaggedEvent>,
     ) {
         let tribute_ref = TributeRef {
@@ -901,10 +1048,10 @@ impl Tribute {
             name: self.name.clone(),
         };
 
-        let travel_result = match area {
-            Some(specific_area) => self.travels(closed_areas, Some(*specific_area), events),
-            None => self.travels(closed_areas, None, events),
-        };
+        // Destinations are built from the game's areas, so they never run
+        // off the map.
+        let neighbors: Vec<Area> = available_destinations.iter().map(|d| d.area).collect();
+        let travel_result = self.travels(&neighbors, closed_areas, *area, events, rng);
 
         match travel_result {
             TravelResult::Success(destination) => {
@@ -961,7 +1108,7 @@ impl Tribute {
         self.long_rests();
     }
 
-    fn act_hide(&mut self, events: &mut Vec<TaggedEvent>) {
+    fn act_hide(&mut self, rng: &mut impl Rng, events: &mut Vec<TaggedEvent>) {
         let tribute_ref = TributeRef {
             identifier: self.identifier.clone().into(),
             name: self.name.clone(),
@@ -974,7 +1121,7 @@ impl Tribute {
             }
         };
 
-        let _hidden = self.hides();
+        let _hidden = self.hides(rng);
         let current_area = self.area;
         let line = GameOutput::TributeHide(self.name.as_str()).to_string();
         events.push(TaggedEvent::new(
@@ -1017,7 +1164,7 @@ impl Tribute {
         let concealment = 10 + int_mod + roll / 2; // Base 10 + int bonus + luck
 
         let trap = crate::tributes::traps::PlacedTrap {
-            id: uuid::Uuid::new_v4().to_string(),
+            id: crate::seeding::uuid_from_rng(_rng).to_string(),
             kind,
             severity,
             set_by: self.identifier.to_string(),
@@ -1040,13 +1187,16 @@ impl Tribute {
         ));
     }
 
-    /// Execute a Search action — reveals hidden traps in current area.
+    /// Execute a Search action — goes through any body in the area, then
+    /// reveals hidden traps.
     fn act_search(
         &mut self,
         area_details: &mut AreaDetails,
         rng: &mut impl Rng,
         events: &mut Vec<TaggedEvent>,
     ) {
+        self.loot_corpse(area_details, rng, events);
+
         let untriggered: Vec<usize> = area_details
             .placed_traps
             .iter()
@@ -1098,7 +1248,7 @@ impl Tribute {
         phase: shared::messages::Phase,
         combat_tuning: &crate::tributes::combat_tuning::CombatTuning,
     ) {
-        let target = self.pick_target(potential_targets, total_living_tributes, events);
+        let target = self.pick_target(potential_targets, total_living_tributes, events, rng);
         if let Some(mut target) = target {
             let outcome = self.attacks(&mut target, rng, events, phase, combat_tuning);
             match outcome {
@@ -1133,8 +1283,13 @@ impl Tribute {
             return;
         }
 
+        // Gear left on a body beats whatever is lying around.
+        if self.loot_corpse(area_details, rng, events) {
+            return;
+        }
+
         // Original area item logic
-        if let Some(item) = self.take_nearby_item(area_details) {
+        if let Some(item) = self.take_nearby_item(area_details, rng) {
             let tribute_ref = TributeRef {
                 identifier: self.identifier.clone().into(),
                 name: self.name.clone(),
@@ -1163,13 +1318,59 @@ impl Tribute {
         }
     }
 
-    fn act_use_item(&mut self, maybe_item: &Option<Item>, events: &mut Vec<TaggedEvent>) {
+    /// Take the best item off the richest body in the area. Returns
+    /// whether there was anything to take.
+    fn loot_corpse(
+        &mut self,
+        area_details: &mut AreaDetails,
+        rng: &mut impl Rng,
+        events: &mut Vec<TaggedEvent>,
+    ) -> bool {
+        let Some((item, corpse_id, corpse_name)) = area_details.loot_corpse() else {
+            return false;
+        };
+        self.add_item(item.clone());
+        crate::tributes::afflictions::fixation::maybe_acquire_item_fixation(self, &item, rng);
+
+        let line = GameOutput::TributeLootsCorpse(self.name.as_str(), &item.name, &corpse_name)
+            .to_string();
+        let area = self.area.to_string();
+        events.push(TaggedEvent::new(
+            line,
+            MessagePayload::CorpseLooted {
+                looter: TributeRef {
+                    identifier: self.identifier.clone().into(),
+                    name: self.name.clone(),
+                },
+                corpse: TributeRef {
+                    identifier: corpse_id.into(),
+                    name: corpse_name,
+                },
+                item: ItemRef {
+                    identifier: item.identifier.clone().into(),
+                    name: item.name.clone(),
+                },
+                area: AreaRef {
+                    identifier: area.clone().into(),
+                    name: area,
+                },
+            },
+        ));
+        true
+    }
+
+    fn act_use_item(
+        &mut self,
+        maybe_item: &Option<Item>,
+        rng: &mut impl Rng,
+        events: &mut Vec<TaggedEvent>,
+    ) {
         if let Some(item) = maybe_item {
             let tribute_ref = TributeRef {
                 identifier: self.identifier.clone().into(),
                 name: self.name.clone(),
             };
-            if let Err(error) = self.try_use_consumable(item, events, None) {
+            if let Err(error) = self.try_use_consumable(item, events, Some(rng.random())) {
                 let line = GameOutput::TributeCannotUseItem(self.name.as_str(), &error.to_string())
                     .to_string();
                 let item_ref = ItemRef {
@@ -1345,6 +1546,7 @@ impl Tribute {
             phobia_penalty,
             trauma_penalty - trauma_observer_bonus,
             addiction_penalty,
+            self.emotions.alliance_shift(&target.emotions),
             rng,
         );
         if formed {
@@ -1723,7 +1925,11 @@ impl Default for Attributes {
 impl Attributes {
     /// Provides a randomized set of Attributes using default config values
     pub fn new() -> Self {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
+        Self::new_with_rng(&mut SmallRng::from_rng(&mut rand::rng()))
+    }
+
+    /// Like [`Attributes::new`], but rolled from the caller's RNG.
+    pub fn new_with_rng(rng: &mut impl Rng) -> Self {
         let config = crate::config::GameConfig::default();
 
         Self {
@@ -1771,6 +1977,17 @@ mod tests {
         assert_eq!(tribute.name, "Default Tribute");
     }
 
+    #[rstest]
+    fn random_with_rng_is_reproducible() {
+        let a = Tribute::random_with_rng(4, &mut SmallRng::seed_from_u64(9));
+        let b = Tribute::random_with_rng(4, &mut SmallRng::seed_from_u64(9));
+        assert_eq!(a.identifier, b.identifier);
+        assert_eq!(a.name, b.name);
+        assert_eq!(a.attributes, b.attributes);
+        assert_eq!(a.traits, b.traits);
+        assert_eq!(a.district, 4);
+    }
+
     #[rstest]
     fn serde_roundtrip_alliance_fields() {
         use crate::tributes::traits::Trait;
@@ -2071,7 +2288,7 @@ mod tests {
         me.allies.push(ally.id);
 
         let mut events: Vec<TaggedEvent> = vec![];
-        let target = me.pick_target(vec![ally.clone()], 5, &mut events);
+        let target = me.pick_target(vec![ally.clone()], 5, &mut events, &mut rand::rng());
         // Only candidate was an ally and we're not in final confrontation.
         assert!(target.is_none());
     }
@@ -2083,7 +2300,12 @@ mod tests {
         let same_district = Tribute::new("Peeta".to_string(), Some(12), None);
 
         let mut events: Vec<TaggedEvent> = vec![];
-        let target = me.pick_target(vec![same_district.clone()], 5, &mut events);
+        let target = me.pick_target(
+            vec![same_district.clone()],
+            5,
+            &mut events,
+            &mut rand::rng(),
+        );
         assert!(target.is_some());
         assert_eq!(target.unwrap().id, same_district.id);
     }
@@ -2097,7 +2319,7 @@ mod tests {
         me.allies.push(ally.id);
 
         let mut events: Vec<TaggedEvent> = vec![];
-        let target = me.pick_target(vec![ally.clone()], 2, &mut events);
+        let target = me.pick_target(vec![ally.clone()], 2, &mut events, &mut rand::rng());
         assert!(target.is_some());
         assert_eq!(target.unwrap().id, ally.id);
     }
@@ -2150,7 +2372,7 @@ mod tests {
         victim.allies.retain(|id| *id != ex_ally.id);
 
         let mut events: Vec<TaggedEvent> = vec![];
-        let target = victim.pick_target(vec![ex_ally.clone()], 5, &mut events);
+        let target = victim.pick_target(vec![ex_ally.clone()], 5, &mut events, &mut rand::rng());
         assert!(
             target.is_some(),
             "ex-ally must be targetable after the bond breaks"
@@ -10,7 +10,6 @@ use crate::messages::{AreaRef, MessagePayload, TaggedEvent, TributeRef};
 use crate::output::GameOutput;
 use crate::tributes::Tribute;
 use rand::prelude::*;
-use rand::rngs::SmallRng;
 
 #[derive(Debug, PartialEq)]
 pub enum TravelResult {
@@ -24,13 +23,16 @@ impl Tribute {
     /// If the tribute is already in the suggested area, they stay put.
     /// If the tribute has low movement, they can only move to the suggested area or stay put.
     /// If the tribute has high movement, they can move to any open neighbor or the suggested area.
+    /// `neighbors` are the areas next door that are on the map; grid cells
+    /// past the arena's edge are never among them.
     pub(crate) fn travels(
         &self,
+        neighbors: &[Area],
         closed_areas: &[Area],
         suggested_area: Option<Area>,
         events: &mut Vec<TaggedEvent>,
+        rng: &mut impl Rng,
     ) -> TravelResult {
-        let mut rng = SmallRng::from_rng(&mut rand::rng());
         // Where is the tribute?
         let current_area = self.area;
 
@@ -147,9 +149,9 @@ impl Tribute {
                     return TravelResult::Success(new_area);
                 }
 
-                let neighbors = current_area.neighbors();
                 let available_neighbors: Vec<Area> = neighbors
-                    .into_iter()
+                    .iter()
+                    .copied()
                     .filter(|area| area != &current_area && !closed_areas.contains(area))
                     .collect();
 
@@ -172,7 +174,7 @@ impl Tribute {
 
                 // TODO: Loyalty bit goes here
 
-                let chosen_neighbor = available_neighbors.choose(&mut rng).unwrap();
+                let chosen_neighbor = available_neighbors.choose(rng).unwrap();
                 let current_area_name = current_area.to_string();
                 let chosen_area_name = chosen_neighbor.to_string();
                 let line = GameOutput::TributeTravel(
@@ -198,8 +200,9 @@ impl Tribute {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::areas::Area::*;
+    use crate::areas::Area;
     use crate::areas::AreaDetails;
+    use crate::areas::arena::Arena;
     use crate::tributes::Tribute;
     use rstest::*;
 
@@ -211,11 +214,20 @@ mod tests {
     #[rstest]
     #[tokio::test]
     async fn travels_success(tribute: Tribute) {
-        let open_area = AreaDetails::new(Some("Forest".to_string()), Cornucopia);
+        let open_area = AreaDetails::new(Some("Forest".to_string()), Area::CORNUCOPIA);
         let result = tribute.travels(
-            &[Sector1, Sector2, Sector3, Sector4, Sector5, Sector6],
+            &Arena::default().neighbors(tribute.area),
+            &[
+                Area::SECTOR_1,
+                Area::SECTOR_2,
+                Area::SECTOR_3,
+                Area::SECTOR_4,
+                Area::SECTOR_5,
+                Area::SECTOR_6,
+            ],
             None,
             &mut Vec::new(),
+            &mut rand::rng(),
         );
         assert_eq!(result, TravelResult::Success(open_area.area.unwrap()));
     }
@@ -224,18 +236,31 @@ mod tests {
     #[tokio::test]
     async fn travels_fail_no_movement(mut tribute: Tribute) {
         tribute.attributes.movement = 0;
-        let result = tribute.travels(&[], None, &mut Vec::new());
+        let result = tribute.travels(
+            &Arena::default().neighbors(tribute.area),
+            &[],
+            None,
+            &mut Vec::new(),
+            &mut rand::rng(),
+        );
         assert_eq!(result, TravelResult::Failure);
     }
 
     #[rstest]
     #[tokio::test]
     async fn travels_fail_already_there(mut tribute: Tribute) {
-        tribute.area = Sector1;
+        tribute.area = Area::SECTOR_1;
         let result = tribute.travels(
-            &[Cornucopia, Sector2, Sector5, Sector4],
-            Some(Sector1),
+            &Arena::default().neighbors(tribute.area),
+            &[
+                Area::CORNUCOPIA,
+                Area::SECTOR_2,
+                Area::SECTOR_5,
+                Area::SECTOR_4,
+            ],
+            Some(Area::SECTOR_1),
             &mut Vec::new(),
+            &mut rand::rng(),
         );
         assert_eq!(result, TravelResult::Failure);
     }
@@ -245,9 +270,16 @@ mod tests {
     async fn travels_fail_low_movement_no_suggestion(mut tribute: Tribute) {
         tribute.attributes.movement = 5;
         let result = tribute.travels(
-            &[Cornucopia, Sector2, Sector5, Sector1],
+            &Arena::default().neighbors(tribute.area),
+            &[
+                Area::CORNUCOPIA,
+                Area::SECTOR_2,
+                Area::SECTOR_5,
+                Area::SECTOR_1,
+            ],
             None,
             &mut Vec::new(),
+            &mut rand::rng(),
         );
         assert_eq!(result, TravelResult::Failure);
     }
@@ -257,9 +289,16 @@ mod tests {
     async fn travels_fail_low_movement_suggestion(mut tribute: Tribute) {
         tribute.attributes.movement = 5;
         let result = tribute.travels(
-            &[Cornucopia, Sector2, Sector5, Sector1],
-            Some(Sector1),
+            &Arena::default().neighbors(tribute.area),
+            &[
+                Area::CORNUCOPIA,
+                Area::SECTOR_2,
+                Area::SECTOR_5,
+                Area::SECTOR_1,
+            ],
+            Some(Area::SECTOR_1),
             &mut Vec::new(),
+            &mut rand::rng(),
         );
         assert_eq!(result, TravelResult::Failure);
     }
@@ -267,10 +306,31 @@ mod tests {
     #[rstest]
     #[tokio::test]
     async fn travels_success_low_movement_suggestion(mut tribute: Tribute) {
-        tribute.area = Sector1;
+        tribute.area = Area::SECTOR_1;
         tribute.attributes.movement = 5;
-        let open_area = AreaDetails::new(Some("Forest".to_string()), Cornucopia);
-        let result = tribute.travels(&[Sector2, Sector4], Some(Cornucopia), &mut Vec::new());
+        let open_area = AreaDetails::new(Some("Forest".to_string()), Area::CORNUCOPIA);
+        let result = tribute.travels(
+            &Arena::default().neighbors(tribute.area),
+            &[Area::SECTOR_2, Area::SECTOR_4],
+            Some(Area::CORNUCOPIA),
+            &mut Vec::new(),
+            &mut rand::rng(),
+        );
         assert_eq!(result, TravelResult::Success(open_area.area.unwrap()));
     }
+
+    #[rstest]
+    fn travels_stays_on_the_map(mut tribute: Tribute) {
+        // Half of an outer-ring sector's grid cells lie past the arena's edge.
+        tribute.area = Area::SECTOR_1;
+        let neighbors = Arena::default().neighbors(tribute.area);
+        assert!(neighbors.len() < tribute.area.grid_neighbors().len());
+        let mut rng = rand::rng();
+        for _ in 0..50 {
+            match tribute.travels(&neighbors, &[], None, &mut Vec::new(), &mut rng) {
+                TravelResult::Success(area) => assert!(neighbors.contains(&area)),
+                TravelResult::Failure => panic!("a high-movement tribute should wander"),
+            }
+        }
+    }
 }
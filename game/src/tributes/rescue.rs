@@ -211,13 +211,13 @@ mod tests {
     fn free_tribute(name: &str, strength: u32) -> Tribute {
         let mut t = Tribute::new(name.into(), None, None);
         t.attributes.strength = strength;
-        t.area = Area::Cornucopia;
+        t.area = Area::CORNUCOPIA;
         t
     }
 
     fn same_area_details() -> AreaDetails {
         AreaDetails {
-            area: Some(Area::Cornucopia),
+            area: Some(Area::CORNUCOPIA),
             ..Default::default()
         }
     }
@@ -282,7 +282,7 @@ mod tests {
         let mut target = trapped_tribute("target", Severity::Moderate);
         let rescuer = free_tribute("rescuer", 30);
         let mut area = same_area_details();
-        area.area = Some(Area::Sector1); // different area from Cornucopia
+        area.area = Some(Area::SECTOR_1); // different area from Cornucopia
         let mut events = Vec::new();
         let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
 
@@ -402,10 +402,10 @@ mod tests {
     #[test]
     fn evaluate_rescue_finds_trapped_tribute() {
         let mut rescuer = Tribute::new("Rescuer".into(), None, None);
-        rescuer.area = Area::Cornucopia;
+        rescuer.area = Area::CORNUCOPIA;
 
         let mut trapped = Tribute::new("Trapped".into(), None, None);
-        trapped.area = Area::Cornucopia;
+        trapped.area = Area::CORNUCOPIA;
         trapped.try_acquire_affliction(crate::tributes::AfflictionDraft {
             kind: AfflictionKind::Trapped(TrapKind::Buried),
             body_part: None,
@@ -26,7 +26,7 @@ fn area_event_survival_narration_reaches_game_messages() {
 
     let area_details = AreaDetails::new_with_terrain(
         Some("Cornucopia".to_string()),
-        Area::Cornucopia,
+        Area::CORNUCOPIA,
         TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap(),
     );
     game.areas.push(area_details);
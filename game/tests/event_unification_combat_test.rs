@@ -21,7 +21,7 @@ fn combat_events_reach_game_messages_with_tribute_source() {
     // Single-area arena keeps both tributes guaranteed-adjacent.
     let area_details = AreaDetails::new_with_terrain(
         Some("Cornucopia".to_string()),
-        Area::Cornucopia,
+        Area::CORNUCOPIA,
         TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap(),
     );
     game.areas.push(area_details);
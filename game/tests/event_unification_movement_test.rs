@@ -21,8 +21,8 @@ fn movement_and_turn_phase_events_reach_game_messages() {
 
     // Two areas so movement is actually possible.
     for (name, area) in [
-        ("Cornucopia", Area::Cornucopia),
-        ("North Field", Area::Sector1),
+        ("Cornucopia", Area::CORNUCOPIA),
+        ("North Field", Area::SECTOR_1),
     ] {
         let details = AreaDetails::new_with_terrain(
             Some(name.to_string()),
@@ -37,7 +37,7 @@ fn movement_and_turn_phase_events_reach_game_messages() {
     // take-item / use-item / sponsor-gift / exhausted-travel paths.
     let mut lone = Tribute::random();
     lone.name = "Lone".to_string();
-    lone.area = Area::Cornucopia;
+    lone.area = Area::CORNUCOPIA;
     lone.blood = 1000;
     lone.attributes.movement = 80; // high movement → travel branch
     lone.statistics.game = game.identifier.clone();
@@ -16,14 +16,14 @@ fn build_test_game() -> Game {
 
     let area = AreaDetails::new_with_terrain(
         Some("Arena".to_string()),
-        Area::Sector1,
+        Area::SECTOR_1,
         TerrainType::new(BaseTerrain::Clearing, vec![]).unwrap(),
     );
     game.areas.push(area);
 
     for i in 0..6 {
         let mut tribute = Tribute::new(format!("Tribute{}", i), Some((i % 12) + 1), None);
-        tribute.area = Area::Sector1;
+        tribute.area = Area::SECTOR_1;
         tribute.blood = 1000;
         tribute.statistics.game = game.identifier.clone();
         game.tributes.push(tribute);
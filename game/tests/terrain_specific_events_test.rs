@@ -14,10 +14,10 @@ fn test_game_loop_generates_terrain_appropriate_events() {
 
     // Create areas with different terrains
     let terrains = vec![
-        (Area::Sector1, BaseTerrain::Forest, "Forest"),
-        (Area::Sector4, BaseTerrain::Desert, "Desert"),
-        (Area::Sector2, BaseTerrain::Mountains, "Mountains"),
-        (Area::Sector5, BaseTerrain::Wetlands, "Wetlands"),
+        (Area::SECTOR_1, BaseTerrain::Forest, "Forest"),
+        (Area::SECTOR_4, BaseTerrain::Desert, "Desert"),
+        (Area::SECTOR_2, BaseTerrain::Mountains, "Mountains"),
+        (Area::SECTOR_5, BaseTerrain::Wetlands, "Wetlands"),
     ];
 
     for (area, terrain, name) in terrains {
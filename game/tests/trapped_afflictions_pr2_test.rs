@@ -12,7 +12,7 @@ use shared::afflictions::{AfflictionKind, AfflictionSource, Severity, TrapKind,
 
 fn make_tribute(name: &str) -> Tribute {
     let mut t = Tribute::new(name.into(), None, None);
-    t.area = game::areas::Area::Cornucopia;
+    t.area = game::areas::Area::CORNUCOPIA;
     t.attributes.strength = 30;
     t.attributes.defense = 15;
     t
@@ -69,14 +69,14 @@ fn rescue_bonus_applied_to_trapped_target() {
 
     let mut rescuer = make_tribute("Rescuer");
     rescuer.attributes.strength = 40;
-    rescuer.area = game::areas::Area::Cornucopia;
+    rescuer.area = game::areas::Area::CORNUCOPIA;
 
     let mut target = make_tribute("Target");
-    target.area = game::areas::Area::Cornucopia;
+    target.area = game::areas::Area::CORNUCOPIA;
     add_buried(&mut target, Severity::Mild);
 
     let area = AreaDetails {
-        area: Some(game::areas::Area::Cornucopia),
+        area: Some(game::areas::Area::CORNUCOPIA),
         ..Default::default()
     };
 
@@ -119,7 +119,7 @@ fn trapped_blocked_from_moving() {
     let mut tribute = make_tribute("Trapped");
     add_buried(&mut tribute, Severity::Moderate);
 
-    let result = hard_gates_with_terrain(&tribute, &Action::Move(Some(Area::Sector1)), None);
+    let result = hard_gates_with_terrain(&tribute, &Action::Move(Some(Area::SECTOR_1)), None);
     assert_eq!(result, Some(Action::None));
 }
 
@@ -146,7 +146,7 @@ fn trapped_override_blocks_move() {
     let mut tribute = make_tribute("Trapped");
     add_buried(&mut tribute, Severity::Moderate);
 
-    let result = affliction_override(&tribute, &Action::Move(Some(game::areas::Area::Cornucopia)));
+    let result = affliction_override(&tribute, &Action::Move(Some(game::areas::Area::CORNUCOPIA)));
     assert_eq!(result, Some(Action::None));
 }
 
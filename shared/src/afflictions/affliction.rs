@@ -47,6 +47,7 @@ impl Affliction {
     }
 
     /// Returns true if this affliction kind is permanent and cannot be cured in v1.
+    /// Contagions are permanent too: once caught there is no cure.
     pub fn is_permanent(&self) -> bool {
         matches!(
             self.kind,
@@ -54,6 +55,7 @@ impl Affliction {
                 | AfflictionKind::MissingLeg
                 | AfflictionKind::Blind
                 | AfflictionKind::Deaf
+                | AfflictionKind::Contagion(_)
         )
     }
 
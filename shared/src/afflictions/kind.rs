@@ -144,6 +144,43 @@ impl FromStr for Substance {
     }
 }
 
+/// Supernatural afflictions that spread from a transformed carrier to
+/// anyone it lands a blow on. Carried as `Mild` while incubating and
+/// `Severe` once the tribute has transformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Contagion {
+    /// Frenzied hunting every Night.
+    Lycanthropy,
+    /// Shuns the sun at Dawn and Day; burns when caught in the open.
+    Vampirism,
+    /// Mindless, relentless aggression.
+    ZombiePlague,
+}
+
+impl fmt::Display for Contagion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Contagion::Lycanthropy => write!(f, "lycanthropy"),
+            Contagion::Vampirism => write!(f, "vampirism"),
+            Contagion::ZombiePlague => write!(f, "zombie_plague"),
+        }
+    }
+}
+
+impl FromStr for Contagion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lycanthropy" => Ok(Contagion::Lycanthropy),
+            "vampirism" => Ok(Contagion::Vampirism),
+            "zombie_plague" => Ok(Contagion::ZombiePlague),
+            other => Err(format!("unknown Contagion: {other}")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AfflictionKind {
@@ -167,6 +204,7 @@ pub enum AfflictionKind {
     Phobia(PhobiaTrigger),
     Fixation(FixationTarget),
     Addiction(Substance),
+    Contagion(Contagion),
 }
 
 impl fmt::Display for AfflictionKind {
@@ -192,6 +230,7 @@ impl fmt::Display for AfflictionKind {
             AfflictionKind::Phobia(trigger) => write!(f, "phobia:{trigger}"),
             AfflictionKind::Fixation(target) => write!(f, "fixation:{target}"),
             AfflictionKind::Addiction(sub) => write!(f, "addiction:{sub}"),
+            AfflictionKind::Contagion(contagion) => write!(f, "contagion:{contagion}"),
         }
     }
 }
@@ -234,6 +273,11 @@ impl FromStr for AfflictionKind {
                 let sub = Substance::from_str(sub_str)?;
                 Ok(AfflictionKind::Addiction(sub))
             }
+            rest if rest.starts_with("contagion:") => {
+                let contagion_str = rest.strip_prefix("contagion:").unwrap();
+                let contagion = Contagion::from_str(contagion_str)?;
+                Ok(AfflictionKind::Contagion(contagion))
+            }
             rest if rest.starts_with("trapped:") => {
                 let kind_str = rest.strip_prefix("trapped:").unwrap();
                 match kind_str {
@@ -417,6 +461,24 @@ mod tests {
         assert!(AfflictionKind::from_str("fixation:unknown:foo").is_err());
     }
 
+    #[test]
+    fn affliction_kind_contagion_display_roundtrip() {
+        for contagion in [
+            Contagion::Lycanthropy,
+            Contagion::Vampirism,
+            Contagion::ZombiePlague,
+        ] {
+            let kind = AfflictionKind::Contagion(contagion);
+            let parsed: AfflictionKind = kind.to_string().parse().unwrap();
+            assert_eq!(kind, parsed);
+        }
+        assert_eq!(
+            AfflictionKind::Contagion(Contagion::ZombiePlague).to_string(),
+            "contagion:zombie_plague"
+        );
+        assert!(AfflictionKind::from_str("contagion:mummy").is_err());
+    }
+
     #[test]
     fn affliction_kind_trapped_serializes_with_inner_kind() {
         let kind = AfflictionKind::Trapped(TrapKind::Drowning);
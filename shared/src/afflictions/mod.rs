@@ -12,7 +12,7 @@ mod trauma;
 pub use addiction::{AddictionMetadata, AddictionResistReason};
 pub use affliction::Affliction;
 pub use fixation::{FixationAction, FixationMetadata, FixationOrigin, ThwartReason};
-pub use kind::{AfflictionKind, BodyPart, FixationTarget, PhobiaTrigger, Substance};
+pub use kind::{AfflictionKind, BodyPart, Contagion, FixationTarget, PhobiaTrigger, Substance};
 pub use mechanics::{
     DecayOutcome, ReinforcementOutcome, apply_traumatic_reinforcement, tick_decay,
 };
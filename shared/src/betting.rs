@@ -0,0 +1,72 @@
+//! Play-money betting markets spectators can wager on.
+
+use serde::{Deserialize, Serialize};
+
+/// Something a bet can be placed on. Tributes are named by identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Market {
+    /// The tribute wins the game.
+    Winner { tribute: String },
+    /// The tribute is the first to die.
+    FirstBlood { tribute: String },
+    /// The tribute is still alive when day `day` begins, or wins before it.
+    SurvivesToDay { tribute: String, day: u32 },
+}
+
+impl Market {
+    pub fn tribute(&self) -> &str {
+        match self {
+            Market::Winner { tribute }
+            | Market::FirstBlood { tribute }
+            | Market::SurvivesToDay { tribute, .. } => tribute,
+        }
+    }
+}
+
+/// Current price of a market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketOdds {
+    pub market: Market,
+    pub tribute_name: String,
+    /// Modelled chance the bet wins, 0.0..=1.0.
+    pub probability: f64,
+    /// Decimal odds: a winning stake is paid back `stake * payout`.
+    pub payout: f64,
+}
+
+/// Every open market of a game, priced from its state after the latest
+/// phase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OddsBoard {
+    pub day: u32,
+    pub markets: Vec<MarketOdds>,
+}
+
+/// Request body for placing a bet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaceBet {
+    pub market: Market,
+    pub stake: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BetStatus {
+    Open,
+    Won,
+    Lost,
+}
+
+/// A bet as its owner sees it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bet {
+    pub identifier: String,
+    pub market: Market,
+    pub tribute_name: String,
+    pub stake: u32,
+    /// Decimal odds locked in when the bet was placed.
+    pub payout: f64,
+    pub status: BetStatus,
+    pub placed_day: u32,
+}
@@ -5,6 +5,7 @@ use validator::{Validate, ValidationError};
 
 pub mod afflictions;
 pub mod audience;
+pub mod betting;
 pub mod combat_beat;
 pub mod conditions;
 pub mod ids;
@@ -84,6 +85,30 @@ impl EventFrequency {
     }
 }
 
+/// Difficulty preset for game customization.
+/// Sets many engine tuning knobs at once; the individual `CreateGame`
+/// options are applied on top of it.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum Difficulty {
+    Gentle, // Fewer and softer events, no instant deaths, generous feasts
+    #[default]
+    Standard, // The original tuning (default)
+    Bloodbath, // Frequent deadly events, decisive fights, early constriction
+}
+
+/// Tributes in a classic game: a boy and a girl from each district.
+pub const DEFAULT_TRIBUTE_COUNT: u32 = 24;
+/// Districts in a classic game.
+pub const DEFAULT_DISTRICT_COUNT: u32 = 12;
+/// Smallest game that can still produce a winner.
+pub const MIN_TRIBUTE_COUNT: u32 = 2;
+/// Largest supported game, a Quarter Quell with a double reaping.
+pub const MAX_TRIBUTE_COUNT: u32 = 48;
+/// Panem has twelve districts; there are no district profiles beyond them.
+pub const MAX_DISTRICT_COUNT: u32 = 12;
+/// Most hex rings of sectors an arena can have around the Cornucopia.
+pub const MAX_ARENA_RINGS: u32 = 4;
+
 /// Custom validator to ensure a string is a valid UUID
 fn validate_uuid(value: &str) -> Result<(), ValidationError> {
     uuid::Uuid::parse_str(value)
@@ -92,6 +117,7 @@ fn validate_uuid(value: &str) -> Result<(), ValidationError> {
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate)]
+#[validate(schema(function = "validate_create_game_roster"))]
 pub struct CreateGame {
     #[validate(length(
         min = 1,
@@ -104,12 +130,116 @@ pub struct CreateGame {
     #[serde(default)]
     pub item_quantity: ItemQuantity,
 
-    /// Random event frequency preset (Rare, Normal, Frequent)
+    /// Difficulty preset (Gentle, Standard, Bloodbath)
+    #[serde(default)]
+    pub difficulty: Difficulty,
+
+    /// Random event frequency preset (Rare, Normal, Frequent). Overrides
+    /// the difficulty's event rates when set.
     #[serde(default)]
-    pub event_frequency: EventFrequency,
+    pub event_frequency: Option<EventFrequency>,
 
-    /// Starting health range for tributes (optional, defaults to 80-100)
+    /// Inclusive starting health range for tributes, 1-100 (optional,
+    /// defaults to the difficulty's range)
+    #[validate(custom(function = "validate_health_range"))]
     pub starting_health_range: Option<(u32, u32)>,
+
+    /// Affliction systems; each defaults to the difficulty's setting.
+    #[serde(default)]
+    pub trauma_enabled: Option<bool>,
+    #[serde(default)]
+    pub phobias_enabled: Option<bool>,
+    #[serde(default)]
+    pub fixations_enabled: Option<bool>,
+    #[serde(default)]
+    pub addiction_enabled: Option<bool>,
+
+    /// Gamemaker interventions (fireballs, mutts, force-field shifts,
+    /// feasts); off unless enabled.
+    #[serde(default)]
+    pub gamemaker_enabled: Option<bool>,
+
+    /// Supernatural contagions (lycanthropy, vampirism, zombie plague) for
+    /// themed horror games; off unless enabled.
+    #[serde(default)]
+    pub contagions_enabled: Option<bool>,
+
+    /// Tributes reaped for the game, 2-48 (optional, defaults to two per
+    /// district)
+    #[serde(default)]
+    pub tribute_count: Option<u32>,
+
+    /// Districts the tributes are reaped from, 1-12 (optional, defaults to
+    /// half the tribute count)
+    #[serde(default)]
+    pub district_count: Option<u32>,
+
+    /// Hex rings of sectors around the Cornucopia, 1-4 (optional, defaults
+    /// to the classic single ring of six)
+    #[serde(default)]
+    #[validate(range(min = 1, max = 4, message = "Arena must have 1-4 rings"))]
+    pub arena_rings: Option<u32>,
+}
+
+impl CreateGame {
+    /// The requested `(tribute_count, district_count)`; see [`resolve_roster`].
+    pub fn roster(&self) -> (u32, u32) {
+        resolve_roster(self.tribute_count, self.district_count)
+    }
+}
+
+/// Fill in an optional tribute and district count. Either one left out is
+/// derived from the other at two tributes per district; with neither set
+/// this is the classic 24 tributes from 12 districts.
+pub fn resolve_roster(tribute_count: Option<u32>, district_count: Option<u32>) -> (u32, u32) {
+    match (tribute_count, district_count) {
+        (Some(tributes), Some(districts)) => (tributes, districts),
+        (Some(tributes), None) => (tributes, (tributes / 2).clamp(1, MAX_DISTRICT_COUNT)),
+        (None, Some(districts)) => (districts.saturating_mul(2), districts),
+        (None, None) => (DEFAULT_TRIBUTE_COUNT, DEFAULT_DISTRICT_COUNT),
+    }
+}
+
+/// Check a `(tribute_count, district_count)` pair: the tribute count must
+/// be within 2-48, and every one of 1-12 districts must reap at least one
+/// tribute.
+pub fn validate_roster(tribute_count: u32, district_count: u32) -> Result<(), ValidationError> {
+    if !(MIN_TRIBUTE_COUNT..=MAX_TRIBUTE_COUNT).contains(&tribute_count) {
+        let mut error = ValidationError::new("invalid_tribute_count");
+        error.message = Some(
+            format!("Tribute count must be between {MIN_TRIBUTE_COUNT} and {MAX_TRIBUTE_COUNT}")
+                .into(),
+        );
+        return Err(error);
+    }
+    if !(1..=MAX_DISTRICT_COUNT.min(tribute_count)).contains(&district_count) {
+        let mut error = ValidationError::new("invalid_district_count");
+        error.message = Some(
+            format!(
+                "District count must be between 1 and {MAX_DISTRICT_COUNT}, and no more than the tribute count"
+            )
+            .into(),
+        );
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// Custom schema validator for the roster of `CreateGame`
+fn validate_create_game_roster(request: &CreateGame) -> Result<(), ValidationError> {
+    let (tribute_count, district_count) = request.roster();
+    validate_roster(tribute_count, district_count)
+}
+
+/// Custom validator for `CreateGame::starting_health_range`
+fn validate_health_range(range: &(u32, u32)) -> Result<(), ValidationError> {
+    let (min, max) = *range;
+    if min == 0 || max > 100 || min > max {
+        let mut error = ValidationError::new("invalid_health_range");
+        error.message = Some("Starting health must be a range within 1-100".into());
+        return Err(error);
+    }
+    Ok(())
 }
 
 pub type DeleteTribute = String;
@@ -162,6 +292,24 @@ pub struct EditGame {
     pub private: bool,
 }
 
+/// Request body for branching a new game off an existing one at the end of
+/// a past phase.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Validate)]
+pub struct ForkGame {
+    pub day: u32,
+    pub phase: messages::Phase,
+    /// Name for the fork; defaults to the original's with "(fork)" added.
+    #[validate(length(min = 1, max = 100, message = "Name must be 1-100 characters"))]
+    pub name: Option<String>,
+}
+
+/// Request body for rolling a game back to the end of a past phase.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RollbackGame {
+    pub day: u32,
+    pub phase: messages::Phase,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GameArea {
     pub identifier: String,
@@ -342,4 +490,37 @@ mod tests {
         let result = user.validate();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_starting_health_range_validation() {
+        let request = |range| -> CreateGame {
+            serde_json::from_value(serde_json::json!({ "starting_health_range": range })).unwrap()
+        };
+        assert!(request([50, 80]).validate().is_ok());
+        assert!(request([100, 100]).validate().is_ok());
+        assert!(request([80, 50]).validate().is_err());
+        assert!(request([0, 50]).validate().is_err());
+        assert!(request([50, 101]).validate().is_err());
+    }
+
+    #[test]
+    fn test_roster_defaults_and_validation() {
+        use serde_json::json;
+        let request = |value| -> CreateGame { serde_json::from_value(value).unwrap() };
+        let valid = |value| request(value).validate().is_ok();
+
+        assert_eq!(request(json!({})).roster(), (24, 12));
+        assert_eq!(request(json!({ "tribute_count": 8 })).roster(), (8, 4));
+        assert_eq!(request(json!({ "tribute_count": 48 })).roster(), (48, 12));
+        assert_eq!(request(json!({ "district_count": 6 })).roster(), (12, 6));
+
+        assert!(valid(json!({ "tribute_count": 48 })));
+        assert!(!valid(json!({ "tribute_count": 1 })));
+        assert!(!valid(json!({ "tribute_count": 49 })));
+        assert!(!valid(json!({ "tribute_count": 4, "district_count": 6 })));
+        assert!(!valid(json!({ "district_count": 13 })));
+        assert!(valid(json!({ "arena_rings": 4 })));
+        assert!(!valid(json!({ "arena_rings": 0 })));
+        assert!(!valid(json!({ "arena_rings": 5 })));
+    }
 }
@@ -46,11 +46,16 @@ impl MessagePayload {
                 refs.push(tribute);
                 refs.push(partner);
             }
+            CorpseLooted { looter, corpse, .. } => {
+                refs.push(looter);
+                refs.push(corpse);
+            }
             TributeMoved { tribute, .. }
             | TributeHidden { tribute, .. }
             | ItemFound { tribute, .. }
             | ItemUsed { tribute, .. }
             | ItemDropped { tribute, .. }
+            | CorpseLeft { tribute, .. }
             | TributeRested { tribute, .. }
             | TributeStarved { tribute, .. }
             | TributeDehydrated { tribute, .. }
@@ -71,6 +76,23 @@ impl MessagePayload {
             | TrapSet { tribute, .. } => refs.push(tribute),
             TrapTriggered { victim, .. } => refs.push(victim),
             SponsorGift { recipient, .. } => refs.push(recipient),
+            FireballStrike {
+                victims, survivors, ..
+            } => {
+                refs.extend(victims);
+                refs.extend(survivors);
+            }
+            MuttSwarmAttack { victim, .. } => refs.push(victim),
+            ContagionInfected {
+                tribute, source, ..
+            } => {
+                refs.push(tribute);
+                if let Some(source) = source {
+                    refs.push(source);
+                }
+            }
+            ContagionTransformed { tribute, .. } => refs.push(tribute),
+            AreaSealEntryDamage { tribute, .. } => refs.push(tribute),
             GameEnded { winner } => {
                 if let Some(w) = winner {
                     refs.push(w);
@@ -126,6 +148,12 @@ impl MessagePayload {
             | Generic
             | AreaClosed { .. }
             | AreaEvent { .. }
+            | WeatherChanged { .. }
+            | MuttSwarmSpawned { .. }
+            | MuttSwarmDespawned { .. }
+            | ForceFieldShifted { .. }
+            | ConvergencePointAnnounced { .. }
+            | ConvergencePointExpired { .. }
             | CycleStart { .. }
             | CycleEnd { .. }
             | PhaseStarted { .. }
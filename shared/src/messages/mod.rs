@@ -286,6 +286,24 @@ pub enum SleepIncidentKind {
     LimbInjury,
 }
 
+/// Why a gamemaker mutt swarm left the arena (`MuttSwarmDespawned`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DespawnReason {
+    /// Swarms are recalled at dawn.
+    Morning,
+    /// Every member of the swarm was killed.
+    NoMembersLeft,
+}
+
+/// Flavor of a gamemaker convergence point. Feast is the only lure today;
+/// water caches and airdrops would slot in as further variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lure {
+    Feast,
+}
+
 /// Effect category for a `PhobiaTriggered` event. Mirrors the game-layer
 /// `PhobiaEffect` enum so the wire format is self-contained.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -363,6 +381,62 @@ pub enum MessagePayload {
         kind: AreaEventKind,
         description: String,
     },
+    /// An area's weather changed at a phase boundary. `from` / `to` are
+    /// the engine's display names ("clear skies", "heavy rain", ...).
+    WeatherChanged {
+        area: AreaRef,
+        from: String,
+        to: String,
+    },
+
+    // ---- Gamemaker interventions ----
+    /// A gamemaker fireball hit every tribute in `area`. `victims` died,
+    /// `survivors` were burned.
+    FireballStrike {
+        area: AreaRef,
+        victims: Vec<TributeRef>,
+        survivors: Vec<TributeRef>,
+    },
+    /// The gamemakers released a mutt swarm. `kind` is the animal's
+    /// display name.
+    MuttSwarmSpawned {
+        area: AreaRef,
+        kind: String,
+        members: u8,
+    },
+    MuttSwarmAttack {
+        area: AreaRef,
+        kind: String,
+        victim: TributeRef,
+        damage: u32,
+        killed: bool,
+    },
+    MuttSwarmDespawned {
+        area: AreaRef,
+        kind: String,
+        reason: DespawnReason,
+    },
+    /// The force field moved: `closed` areas are sealed, `opened` areas
+    /// were released from an earlier seal.
+    ForceFieldShifted {
+        closed: Vec<AreaRef>,
+        opened: Vec<AreaRef>,
+    },
+    /// A tribute lingering in a sealed area was burned by the force field.
+    AreaSealEntryDamage {
+        area: AreaRef,
+        tribute: TributeRef,
+        damage: u32,
+    },
+    ConvergencePointAnnounced {
+        area: AreaRef,
+        lure: Lure,
+        items: Vec<ItemRef>,
+    },
+    ConvergencePointExpired {
+        area: AreaRef,
+        lure: Lure,
+    },
 
     ItemFound {
         tribute: TributeRef,
@@ -383,6 +457,19 @@ pub enum MessagePayload {
         item: ItemRef,
         donor: String,
     },
+    /// A dead tribute's items were left on their body.
+    CorpseLeft {
+        tribute: TributeRef,
+        area: AreaRef,
+        items: Vec<ItemRef>,
+    },
+    /// A tribute took an item off a body.
+    CorpseLooted {
+        looter: TributeRef,
+        corpse: TributeRef,
+        item: ItemRef,
+        area: AreaRef,
+    },
 
     TributeRested {
         tribute: TributeRef,
@@ -767,6 +854,21 @@ pub enum MessagePayload {
         from_severity: String,
         to_severity: Option<String>,
     },
+    // Contagion events (supernatural afflictions).
+    /// A tribute caught a contagion. `source` is the transformed carrier
+    /// whose blow passed it on, or `None` for the gamemakers' patient zero.
+    /// The contagion incubates before it takes hold.
+    ContagionInfected {
+        tribute: TributeRef,
+        source: Option<TributeRef>,
+        contagion: crate::afflictions::Contagion,
+    },
+    /// An incubating contagion took hold; the tribute now acts under its
+    /// compulsion and passes it on with every blow.
+    ContagionTransformed {
+        tribute: TributeRef,
+        contagion: crate::afflictions::Contagion,
+    },
     /// Tribute became trapped by a hazard (drowning, buried, etc.).
     TributeTrapped {
         tribute: String,
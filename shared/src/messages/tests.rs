@@ -173,6 +173,72 @@ fn kind_movement_variants_map_correctly() {
         .kind(),
         MessageKind::AreaEvent
     );
+    assert_eq!(
+        MessagePayload::WeatherChanged {
+            area: area.clone(),
+            from: "fog".into(),
+            to: "storm".into(),
+        }
+        .kind(),
+        MessageKind::WeatherChanged
+    );
+}
+
+#[test]
+fn kind_contagion_variants_map_correctly() {
+    use crate::afflictions::Contagion;
+    let infected = MessagePayload::ContagionInfected {
+        tribute: t("a"),
+        source: Some(t("b")),
+        contagion: Contagion::Vampirism,
+    };
+    assert_eq!(infected.kind(), MessageKind::ContagionInfected);
+    assert_eq!(infected.tribute_refs().len(), 2);
+    let patient_zero = MessagePayload::ContagionInfected {
+        tribute: t("a"),
+        source: None,
+        contagion: Contagion::ZombiePlague,
+    };
+    assert_eq!(patient_zero.tribute_refs().len(), 1);
+    let transformed = MessagePayload::ContagionTransformed {
+        tribute: t("a"),
+        contagion: Contagion::Lycanthropy,
+    };
+    assert_eq!(transformed.kind(), MessageKind::ContagionTransformed);
+    assert!(transformed.involves("id-a"));
+}
+
+#[test]
+fn kind_gamemaker_variants_map_correctly() {
+    let area = AreaRef {
+        identifier: "a1".into(),
+        name: "A".into(),
+    };
+    let strike = MessagePayload::FireballStrike {
+        area: area.clone(),
+        victims: vec![t("a")],
+        survivors: vec![t("b")],
+    };
+    assert_eq!(strike.kind(), MessageKind::FireballStrike);
+    assert_eq!(strike.tribute_refs().len(), 2);
+    assert_eq!(
+        MessagePayload::MuttSwarmDespawned {
+            area: area.clone(),
+            kind: "wolf".into(),
+            reason: DespawnReason::Morning,
+        }
+        .kind(),
+        MessageKind::MuttSwarmDespawned
+    );
+    assert_eq!(
+        MessagePayload::ConvergencePointAnnounced {
+            area: area.clone(),
+            lure: Lure::Feast,
+            items: vec![],
+        }
+        .kind(),
+        MessageKind::ConvergencePointAnnounced
+    );
 }
 
 #[test]
@@ -217,6 +283,14 @@ fn kind_item_variants_map_correctly() {
         donor: "Capitol".into(),
     };
     assert_eq!(sponsor.kind(), MessageKind::SponsorGift);
+    let looted = MessagePayload::CorpseLooted {
+        looter: t("a"),
+        corpse: t("b"),
+        item: item.clone(),
+        area: area.clone(),
+    };
+    assert_eq!(looted.kind(), MessageKind::CorpseLooted);
+    assert_eq!(looted.tribute_refs().len(), 2);
 }
 
 #[test]
@@ -50,6 +50,21 @@ pub const ITEM_COSTS: &[(ItemKindTag, u32)] = &[
     (ItemKindTag::Shield, 30),
 ];
 
+/// A player's sponsor wallet for one game.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SponsorWallet {
+    pub balance: u32,
+    pub spent: u32,
+    /// What the wallet can buy, with prices, from `ITEM_COSTS`.
+    pub catalog: Vec<(ItemKindTag, u32)>,
+}
+
+/// Request body for buying a tribute a gift.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BuyGift {
+    pub item: ItemKindTag,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Sponsor {
     pub id: u32,
@@ -0,0 +1,682 @@
+//! Monte Carlo balance harness.
+//!
+//! Plays many seeded games in parallel, boils each one down to a
+//! [`GameTally`], then folds the tallies into a [`BalanceReport`] that
+//! serializes to JSON and renders as a plain-text table.
+//!
+//! Game `i` of a batch is played with `base_seed + i`, so any outlier can be
+//! replayed on its own with `hangrier-sim --seed <base_seed + i>`.
+
+use crate::{SimError, SimOptions};
+use game::games::Game;
+use game::messages::MessagePayload;
+use game::tributes::combat::inflict_table::WeaponKind;
+use serde::Serialize;
+use shared::afflictions::{AfflictionKind, DeathCause, HazardKind};
+use shared::combat_beat::SwingOutcome;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Coarse bucket for how a tribute died.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, strum::EnumCount)]
+#[serde(rename_all = "snake_case")]
+pub enum DeathCategory {
+    /// Killed in a fight, including counter-kills and fumbles.
+    Combat,
+    /// Fire, flood, rockslide and the other catastrophic area events.
+    AreaEvent,
+    Starvation,
+    Dehydration,
+    /// Died while held by a `Trapped` affliction, or to a trap hazard.
+    Trapped,
+    Beast,
+    /// Blood loss from untreated wounds.
+    BledOut,
+    /// Any other affliction-driven death (cascade death rolls).
+    Affliction,
+    Suicide,
+    Gamemaker,
+    /// The engine reported no cause, or the tribute died without a message.
+    Unknown,
+}
+
+impl DeathCategory {
+    pub fn from_cause(cause: &DeathCause) -> Self {
+        match cause {
+            DeathCause::Tribute(_) | DeathCause::Combat | DeathCause::CriticalFumble => {
+                DeathCategory::Combat
+            }
+            DeathCause::Hazard(HazardKind::Trap)
+            | DeathCause::Affliction(AfflictionKind::Trapped(_)) => DeathCategory::Trapped,
+            DeathCause::Fire | DeathCause::Drowning | DeathCause::Hazard(_) => {
+                DeathCategory::AreaEvent
+            }
+            DeathCause::Starvation => DeathCategory::Starvation,
+            DeathCause::Dehydration => DeathCategory::Dehydration,
+            DeathCause::Beast(_) => DeathCategory::Beast,
+            DeathCause::Affliction(_) => DeathCategory::Affliction,
+            DeathCause::Suicide => DeathCategory::Suicide,
+            DeathCause::Gamemaker => DeathCategory::Gamemaker,
+            DeathCause::Unknown => DeathCategory::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for DeathCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            DeathCategory::Combat => "combat",
+            DeathCategory::AreaEvent => "area event",
+            DeathCategory::Starvation => "starvation",
+            DeathCategory::Dehydration => "dehydration",
+            DeathCategory::Trapped => "trapped",
+            DeathCategory::Beast => "beast",
+            DeathCategory::BledOut => "bled out",
+            DeathCategory::Affliction => "affliction",
+            DeathCategory::Suicide => "suicide",
+            DeathCategory::Gamemaker => "gamemaker",
+            DeathCategory::Unknown => "unknown",
+        };
+        f.write_str(label)
+    }
+}
+
+/// A tribute as the report sees it: where it came from and what it was like.
+#[derive(Clone, Debug)]
+pub struct Entrant {
+    pub district: u32,
+    pub brain: String,
+    pub traits: Vec<String>,
+}
+
+/// Everything the report needs from one finished game.
+#[derive(Clone, Debug)]
+pub struct GameTally {
+    pub seed: u64,
+    pub days: u32,
+    pub entrants: Vec<Entrant>,
+    /// Index into `entrants`.
+    pub winner: Option<usize>,
+    pub no_survivors: bool,
+    pub deaths: BTreeMap<DeathCategory, u32>,
+    /// Killing blows landed by an attacker, by the attacker's weapon.
+    /// Tributes only pick up gear when looting bodies or fixated on an
+    /// item, so nearly every kill is unarmed.
+    pub kills_by_weapon: BTreeMap<WeaponKind, u32>,
+    /// Number of tributes that carried each affliction at some point.
+    pub afflicted: BTreeMap<String, u32>,
+}
+
+/// Play one game to the end and tally it.
+pub fn play_and_tally(options: &SimOptions) -> Result<GameTally, SimError> {
+    let mut game = crate::build_game(options)?;
+
+    let mut crowned = None;
+    let mut dead: BTreeMap<String, DeathCategory> = BTreeMap::new();
+    let mut kills_by_weapon = BTreeMap::new();
+    // (tribute, affliction) pairs, so a kind counts once per tribute.
+    let mut afflicted: BTreeSet<(String, String)> = BTreeSet::new();
+
+    crate::run_to_completion(&mut game, options.max_days, |game| {
+        // Deaths to a period's events come without a cause; a tribute still
+        // held by a trap counts as trapped.
+        let trapped: BTreeSet<String> = game
+            .tributes
+            .iter()
+            .filter(|t| {
+                t.afflictions
+                    .keys()
+                    .any(|(kind, _)| matches!(kind, AfflictionKind::Trapped(_)))
+            })
+            .map(|t| t.identifier.clone())
+            .collect();
+        for message in &game.messages {
+            // The first `GameEnded` names the winner, whatever befalls them
+            // after.
+            if let MessagePayload::GameEnded {
+                winner: Some(winner),
+            } = &message.payload
+            {
+                crowned.get_or_insert_with(|| winner.identifier.to_string());
+            }
+            record_message(&message.payload, &trapped, &mut dead, &mut kills_by_weapon);
+        }
+        // Afflictions are mostly acquired without a message; read them off
+        // the tributes instead. Anything that comes and goes within a
+        // single day is missed.
+        for tribute in &game.tributes {
+            for (kind, _) in tribute.afflictions.keys() {
+                afflicted.insert((tribute.identifier.clone(), kind.to_string()));
+            }
+        }
+        Ok(())
+    })?;
+
+    Ok(tally(
+        options.seed,
+        &game,
+        crowned,
+        dead,
+        kills_by_weapon,
+        afflicted,
+    ))
+}
+
+/// Attribute deaths and killing blows from one message. The first death
+/// report for each tribute counts; later ones are follow-up bookkeeping.
+/// `trapped` holds the tributes caught in a trap, whose deaths of unknown
+/// cause are put down to it.
+fn record_message(
+    payload: &MessagePayload,
+    trapped: &BTreeSet<String>,
+    dead: &mut BTreeMap<String, DeathCategory>,
+    kills_by_weapon: &mut BTreeMap<WeaponKind, u32>,
+) {
+    match payload {
+        MessagePayload::TributeKilled { victim, cause, .. } => {
+            let victim = victim.identifier.to_string();
+            let category = match DeathCategory::from_cause(cause) {
+                DeathCategory::Unknown if trapped.contains(&victim) => DeathCategory::Trapped,
+                category => category,
+            };
+            let recorded = dead.entry(victim).or_insert(category);
+            // Bleed-out fires whenever blood hits zero with open wounds, even
+            // when a trap did the draining; a named cause that follows wins.
+            if *recorded == DeathCategory::BledOut && category != DeathCategory::Unknown {
+                *recorded = category;
+            }
+        }
+        // Provisional: see the `TributeKilled` arm.
+        MessagePayload::TributeBledOut { tribute } => {
+            dead.entry(tribute.identifier.to_string())
+                .or_insert(DeathCategory::BledOut);
+        }
+        MessagePayload::CombatSwing(beat) => match beat.outcome {
+            SwingOutcome::Kill { .. } => {
+                let victim = beat.target.identifier.to_string();
+                if let Entry::Vacant(slot) = dead.entry(victim) {
+                    slot.insert(DeathCategory::Combat);
+                    let weapon = beat.weapon.as_ref().map_or(WeaponKind::Unarmed, |w| {
+                        WeaponKind::from_weapon_name(&w.name)
+                    });
+                    *kills_by_weapon.entry(weapon).or_default() += 1;
+                }
+            }
+            SwingOutcome::AttackerDied { .. } => {
+                dead.entry(beat.attacker.identifier.to_string())
+                    .or_insert(DeathCategory::Combat);
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+fn tally(
+    seed: u64,
+    game: &Game,
+    winner_id: Option<String>,
+    dead: BTreeMap<String, DeathCategory>,
+    kills_by_weapon: BTreeMap<WeaponKind, u32>,
+    afflicted: BTreeSet<(String, String)>,
+) -> GameTally {
+    let mut winner = None;
+    let mut entrants = Vec::with_capacity(game.tributes.len());
+    let mut deaths = BTreeMap::new();
+
+    for (idx, tribute) in game.tributes.iter().enumerate() {
+        if winner_id.as_ref() == Some(&tribute.identifier) {
+            winner = Some(idx);
+        }
+        if !tribute.is_alive() {
+            let category = dead
+                .get(&tribute.identifier)
+                .copied()
+                .unwrap_or(DeathCategory::Unknown);
+            *deaths.entry(category).or_default() += 1;
+        }
+        entrants.push(Entrant {
+            district: tribute.district,
+            brain: tribute.strategy.to_string(),
+            traits: tribute.traits.iter().map(|t| format!("{t:?}")).collect(),
+        });
+    }
+
+    let mut afflicted_counts = BTreeMap::new();
+    for (_, kind) in afflicted {
+        *afflicted_counts.entry(kind).or_default() += 1;
+    }
+
+    GameTally {
+        seed,
+        days: game.day.unwrap_or(0),
+        entrants,
+        winner,
+        no_survivors: game.living_tributes_count() == 0,
+        deaths,
+        kills_by_weapon,
+        afflicted: afflicted_counts,
+    }
+}
+
+/// Play `games` games across `jobs` threads. Game `i` uses
+/// `template.seed + i`; results come back in seed order.
+pub fn run_batch(
+    template: &SimOptions,
+    games: u32,
+    jobs: usize,
+) -> Result<Vec<GameTally>, SimError> {
+    let next = AtomicU32::new(0);
+    let results = Mutex::new(Vec::with_capacity(games as usize));
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    if idx >= games {
+                        break;
+                    }
+                    let options = SimOptions {
+                        seed: template.seed.wrapping_add(u64::from(idx)),
+                        ..template.clone()
+                    };
+                    let tally = play_and_tally(&options);
+                    results.lock().unwrap().push((idx, tally));
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, tally)| tally).collect()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WinRate {
+    pub entrants: u32,
+    pub wins: u32,
+    /// `wins / entrants`: the chance a single tribute from this group wins.
+    pub rate: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Share {
+    pub count: u32,
+    pub share: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Outcomes {
+    pub winner: u32,
+    pub no_survivors: u32,
+    /// Still running when `max_days` was reached.
+    pub timed_out: u32,
+}
+
+/// Aggregate statistics over a batch of games.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct BalanceReport {
+    pub games: u32,
+    pub base_seed: u64,
+    pub mean_days: f64,
+    pub outcomes: Outcomes,
+    pub win_rate_by_district: BTreeMap<u32, WinRate>,
+    pub win_rate_by_trait: BTreeMap<String, WinRate>,
+    pub win_rate_by_brain: BTreeMap<String, WinRate>,
+    /// Share is of all deaths.
+    pub death_causes: BTreeMap<DeathCategory, Share>,
+    pub kills_by_weapon: BTreeMap<WeaponKind, Share>,
+    /// Share is of all tributes that entered.
+    pub afflictions: BTreeMap<String, Share>,
+}
+
+impl BalanceReport {
+    pub fn from_tallies(base_seed: u64, tallies: &[GameTally]) -> Self {
+        let mut report = BalanceReport {
+            games: tallies.len() as u32,
+            base_seed,
+            ..Default::default()
+        };
+        let mut total_days = 0u64;
+        let mut total_entrants = 0u32;
+        let mut deaths = BTreeMap::new();
+        let mut kills = BTreeMap::new();
+        let mut afflicted = BTreeMap::new();
+
+        for tally in tallies {
+            total_days += u64::from(tally.days);
+            total_entrants += tally.entrants.len() as u32;
+            match (tally.winner, tally.no_survivors) {
+                (Some(_), _) => report.outcomes.winner += 1,
+                (None, true) => report.outcomes.no_survivors += 1,
+                (None, false) => report.outcomes.timed_out += 1,
+            }
+
+            for (idx, entrant) in tally.entrants.iter().enumerate() {
+                let won = u32::from(tally.winner == Some(idx));
+                let by_district = report
+                    .win_rate_by_district
+                    .entry(entrant.district)
+                    .or_default();
+                by_district.entrants += 1;
+                by_district.wins += won;
+                let by_brain = report
+                    .win_rate_by_brain
+                    .entry(entrant.brain.clone())
+                    .or_default();
+                by_brain.entrants += 1;
+                by_brain.wins += won;
+                for name in &entrant.traits {
+                    let by_trait = report.win_rate_by_trait.entry(name.clone()).or_default();
+                    by_trait.entrants += 1;
+                    by_trait.wins += won;
+                }
+            }
+
+            merge_counts(&mut deaths, &tally.deaths);
+            merge_counts(&mut kills, &tally.kills_by_weapon);
+            merge_counts(&mut afflicted, &tally.afflicted);
+        }
+
+        if report.games > 0 {
+            report.mean_days = total_days as f64 / f64::from(report.games);
+        }
+        for rate in report
+            .win_rate_by_district
+            .values_mut()
+            .chain(report.win_rate_by_trait.values_mut())
+            .chain(report.win_rate_by_brain.values_mut())
+        {
+            rate.rate = ratio(rate.wins, rate.entrants);
+        }
+        report.death_causes = shares(deaths, None);
+        report.kills_by_weapon = shares(kills, None);
+        report.afflictions = shares(afflicted, Some(total_entrants));
+        report
+    }
+}
+
+fn merge_counts<K: Ord + Clone>(into: &mut BTreeMap<K, u32>, from: &BTreeMap<K, u32>) {
+    for (key, count) in from {
+        *into.entry(key.clone()).or_default() += count;
+    }
+}
+
+/// Turn counts into shares of `total`, or of their own sum when `None`.
+fn shares<K: Ord>(counts: BTreeMap<K, u32>, total: Option<u32>) -> BTreeMap<K, Share> {
+    let total = total.unwrap_or_else(|| counts.values().sum());
+    counts
+        .into_iter()
+        .map(|(key, count)| {
+            let share = ratio(count, total);
+            (key, Share { count, share })
+        })
+        .collect()
+}
+
+fn ratio(part: u32, whole: u32) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        f64::from(part) / f64::from(whole)
+    }
+}
+
+impl fmt::Display for BalanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} games, seeds {}..={}",
+            self.games,
+            self.base_seed,
+            self.base_seed
+                .wrapping_add(u64::from(self.games.saturating_sub(1)))
+        )?;
+        writeln!(
+            f,
+            "mean length {:.2} days | winner {} | no survivors {} | timed out {}",
+            self.mean_days,
+            self.outcomes.winner,
+            self.outcomes.no_survivors,
+            self.outcomes.timed_out
+        )?;
+
+        writeln!(f, "\nWin rate by district")?;
+        writeln!(
+            f,
+            "  {:<20} {:>8} {:>6} {:>7}",
+            "district", "entrants", "wins", "rate"
+        )?;
+        for (district, rate) in &self.win_rate_by_district {
+            write_rate(f, &district.to_string(), rate)?;
+        }
+
+        writeln!(f, "\nWin rate by trait")?;
+        writeln!(
+            f,
+            "  {:<20} {:>8} {:>6} {:>7}",
+            "trait", "entrants", "wins", "rate"
+        )?;
+        for (name, rate) in &self.win_rate_by_trait {
+            write_rate(f, name, rate)?;
+        }
+
+        writeln!(f, "\nWin rate by brain")?;
+        writeln!(
+            f,
+            "  {:<20} {:>8} {:>6} {:>7}",
+            "brain", "entrants", "wins", "rate"
+        )?;
+        for (name, rate) in &self.win_rate_by_brain {
+            write_rate(f, name, rate)?;
+        }
+
+        write_shares(
+            f,
+            "Death causes",
+            "cause",
+            self.death_causes.iter().map(|(k, v)| (k.to_string(), v)),
+        )?;
+        write_shares(
+            f,
+            "Kills by weapon",
+            "weapon",
+            self.kills_by_weapon
+                .iter()
+                .map(|(k, v)| (format!("{k:?}"), v)),
+        )?;
+        write_shares(
+            f,
+            "Afflictions (share of tributes)",
+            "affliction",
+            self.afflictions.iter().map(|(k, v)| (k.clone(), v)),
+        )
+    }
+}
+
+fn write_rate(f: &mut fmt::Formatter<'_>, label: &str, rate: &WinRate) -> fmt::Result {
+    writeln!(
+        f,
+        "  {:<20} {:>8} {:>6} {:>6.1}%",
+        label,
+        rate.entrants,
+        rate.wins,
+        rate.rate * 100.0
+    )
+}
+
+fn write_shares<'a>(
+    f: &mut fmt::Formatter<'_>,
+    title: &str,
+    column: &str,
+    rows: impl Iterator<Item = (String, &'a Share)>,
+) -> fmt::Result {
+    writeln!(f, "\n{title}")?;
+    writeln!(f, "  {:<28} {:>8} {:>7}", column, "count", "share")?;
+    for (label, share) in rows {
+        writeln!(
+            f,
+            "  {:<28} {:>8} {:>6.1}%",
+            label,
+            share.count,
+            share.share * 100.0
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::config::GameConfig;
+    use game::messages::{ItemRef, TributeRef};
+    use shared::combat_beat::{CombatBeat, StressReport};
+
+    fn tref(id: &str) -> TributeRef {
+        TributeRef {
+            identifier: id.into(),
+            name: id.to_string(),
+        }
+    }
+
+    fn beat(outcome: SwingOutcome, weapon: Option<&str>) -> MessagePayload {
+        MessagePayload::CombatSwing(CombatBeat {
+            attacker: tref("a"),
+            target: tref("t"),
+            weapon: weapon.map(|name| ItemRef {
+                identifier: "w".into(),
+                name: name.to_string(),
+            }),
+            shield: None,
+            wear: vec![],
+            outcome,
+            stress: StressReport::default(),
+            attacker_stamina_cost: 0,
+            target_stamina_cost: 0,
+        })
+    }
+
+    #[test]
+    fn death_causes_map_to_requested_buckets() {
+        use shared::afflictions::TrapKind;
+        assert_eq!(
+            DeathCategory::from_cause(&DeathCause::Tribute("x".into())),
+            DeathCategory::Combat
+        );
+        assert_eq!(
+            DeathCategory::from_cause(&DeathCause::Fire),
+            DeathCategory::AreaEvent
+        );
+        assert_eq!(
+            DeathCategory::from_cause(&DeathCause::Starvation),
+            DeathCategory::Starvation
+        );
+        assert_eq!(
+            DeathCategory::from_cause(&DeathCause::Affliction(AfflictionKind::Trapped(
+                TrapKind::Buried
+            ))),
+            DeathCategory::Trapped
+        );
+    }
+
+    #[test]
+    fn first_death_report_wins_and_kills_are_tallied_by_weapon() {
+        let none = BTreeSet::new();
+        let mut dead = BTreeMap::new();
+        let mut kills = BTreeMap::new();
+        record_message(
+            &beat(SwingOutcome::Kill { damage: 9 }, Some("sharp spear")),
+            &none,
+            &mut dead,
+            &mut kills,
+        );
+        record_message(
+            &MessagePayload::TributeKilled {
+                victim: tref("t"),
+                killer: None,
+                cause: DeathCause::Unknown,
+            },
+            &none,
+            &mut dead,
+            &mut kills,
+        );
+        assert_eq!(dead.get("t"), Some(&DeathCategory::Combat));
+        assert_eq!(kills.get(&WeaponKind::Ranged), Some(&1));
+
+        record_message(
+            &beat(SwingOutcome::AttackerDied { damage: 3 }, None),
+            &none,
+            &mut dead,
+            &mut kills,
+        );
+        assert_eq!(dead.get("a"), Some(&DeathCategory::Combat));
+        assert_eq!(kills.values().sum::<u32>(), 1);
+    }
+
+    #[test]
+    fn unknown_death_in_a_trap_replaces_provisional_bleed_out() {
+        let killed = MessagePayload::TributeKilled {
+            victim: tref("t"),
+            killer: None,
+            cause: DeathCause::Unknown,
+        };
+        let bled_out = MessagePayload::TributeBledOut { tribute: tref("t") };
+
+        let mut dead = BTreeMap::new();
+        let mut kills = BTreeMap::new();
+        let free = BTreeSet::new();
+        record_message(&bled_out, &free, &mut dead, &mut kills);
+        record_message(&killed, &free, &mut dead, &mut kills);
+        assert_eq!(dead.get("t"), Some(&DeathCategory::BledOut));
+
+        let mut dead = BTreeMap::new();
+        let trapped = BTreeSet::from(["t".to_string()]);
+        record_message(&bled_out, &trapped, &mut dead, &mut kills);
+        record_message(&killed, &trapped, &mut dead, &mut kills);
+        assert_eq!(dead.get("t"), Some(&DeathCategory::Trapped));
+    }
+
+    #[test]
+    fn batch_is_reproducible_and_accounts_for_every_death() {
+        let template = SimOptions {
+            seed: 500,
+            max_days: 20,
+            config: GameConfig {
+                tribute_count: 12,
+                ..GameConfig::default()
+            },
+            ..SimOptions::default()
+        };
+        let tallies = run_batch(&template, 6, 3).unwrap();
+        assert_eq!(
+            tallies.iter().map(|t| t.seed).collect::<Vec<_>>(),
+            (500..506).collect::<Vec<_>>()
+        );
+
+        let report = BalanceReport::from_tallies(template.seed, &tallies);
+        assert_eq!(report.games, 6);
+        assert_eq!(
+            report.outcomes.winner + report.outcomes.no_survivors + report.outcomes.timed_out,
+            6
+        );
+        assert!(
+            report.outcomes.winner > 0,
+            "some game in the batch crowns a winner"
+        );
+        let deaths: u32 = report.death_causes.values().map(|s| s.count).sum();
+        let survivors: u32 = tallies
+            .iter()
+            .map(|t| t.entrants.len() as u32 - t.deaths.values().sum::<u32>())
+            .sum();
+        assert_eq!(deaths + survivors, 6 * 12);
+
+        let again =
+            BalanceReport::from_tallies(template.seed, &run_batch(&template, 6, 2).unwrap());
+        assert_eq!(report, again);
+    }
+}
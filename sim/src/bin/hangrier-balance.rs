@@ -0,0 +1,80 @@
+//! `hangrier-balance` — play a batch of seeded games and report aggregate
+//! balance statistics.
+//!
+//! ```text
+//! hangrier-balance --games 500 --seed 1
+//! hangrier-balance --games 200 --set instant_death_enabled=false --json before.json
+//! ```
+
+use clap::{Parser, ValueEnum};
+use sim::SimError;
+use sim::balance::{self, BalanceReport};
+use sim::cli::SetupArgs;
+use std::fs::File;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    /// Aligned plain-text tables.
+    Table,
+    /// The full report as pretty-printed JSON.
+    Json,
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "hangrier-balance",
+    about = "Play many Hangrier Games matches and report balance statistics"
+)]
+struct Cli {
+    #[command(flatten)]
+    setup: SetupArgs,
+
+    /// Number of games to play. Game `i` uses seed `--seed + i`.
+    #[arg(long, default_value_t = 100)]
+    games: u32,
+
+    /// Worker threads. Defaults to the available parallelism.
+    #[arg(long)]
+    jobs: Option<usize>,
+
+    /// What to print on stdout.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Also write the JSON report to this file.
+    #[arg(long)]
+    json: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("hangrier-balance: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), SimError> {
+    let options = cli.setup.to_options()?;
+    let jobs = cli.jobs.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+
+    let tallies = balance::run_batch(&options, cli.games, jobs)?;
+    let report = BalanceReport::from_tallies(options.seed, &tallies);
+
+    if let Some(path) = &cli.json {
+        serde_json::to_writer_pretty(File::create(path)?, &report)?;
+    }
+    match cli.format {
+        Format::Table => print!("{report}"),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+    }
+    Ok(())
+}
@@ -0,0 +1,111 @@
+//! `hangrier-sim` — play a whole game offline and dump its message log.
+//!
+//! ```text
+//! hangrier-sim --seed 42 --format prose
+//! hangrier-sim --tributes 12 --set day_event_frequency=0.5 -o run.jsonl
+//! ```
+
+use clap::{Parser, ValueEnum};
+use game::messages::{GameMessage, Phase};
+use sim::SimError;
+use sim::cli::SetupArgs;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    /// One serialized `GameMessage` per line.
+    Jsonl,
+    /// The narrated message text, grouped under day/phase headings.
+    Prose,
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "hangrier-sim",
+    about = "Run a Hangrier Games match without the API or database"
+)]
+struct Cli {
+    #[command(flatten)]
+    setup: SetupArgs,
+
+    #[arg(long, value_enum, default_value_t = Format::Jsonl)]
+    format: Format,
+
+    /// Write here instead of stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        // Piped into `head` and the reader hung up: nothing left to report.
+        Err(SimError::Io(e)) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("hangrier-sim: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), SimError> {
+    let options = cli.setup.to_options()?;
+
+    let out: Box<dyn Write> = match &cli.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut out = BufWriter::new(out);
+    let mut heading = None;
+
+    let mut game = sim::build_game(&options)?;
+    sim::run_to_completion(&mut game, options.max_days, |game| {
+        for message in &game.messages {
+            match cli.format {
+                Format::Jsonl => {
+                    serde_json::to_writer(&mut out, message)?;
+                    writeln!(out)?;
+                }
+                Format::Prose => write_prose(&mut out, message, &mut heading)?,
+            }
+        }
+        Ok(())
+    })?;
+    out.flush()?;
+
+    let day = game.day.unwrap_or(0);
+    match game.winner() {
+        Some(winner) => eprintln!(
+            "seed {}: {} of District {} won on day {day}",
+            options.seed, winner.name, winner.district
+        ),
+        None if game.living_tributes_count() == 0 => {
+            eprintln!("seed {}: no survivors by day {day}", options.seed)
+        }
+        None => eprintln!("seed {}: no winner after {day} days", options.seed),
+    }
+    Ok(())
+}
+
+fn write_prose(
+    out: &mut impl Write,
+    message: &GameMessage,
+    heading: &mut Option<(u32, Phase)>,
+) -> io::Result<()> {
+    // Structural markers (phase start/end, band changes) carry only a payload.
+    if message.content.is_empty() {
+        return Ok(());
+    }
+    let current = (message.game_day, message.phase);
+    if *heading != Some(current) {
+        if heading.is_some() {
+            writeln!(out)?;
+        }
+        writeln!(out, "== Day {} · {} ==", message.game_day, message.phase)?;
+        *heading = Some(current);
+    }
+    writeln!(out, "{}", message.content)
+}
@@ -0,0 +1,106 @@
+//! `hangrier-tune` — evolve the built-in brain's tuning against simulated
+//! games and write the winner out as a `GameConfig` patch.
+//!
+//! ```text
+//! hangrier-tune --games 30 --generations 15 --seed 1 -o tuned.json
+//! hangrier-tune --objective long-games.json --tributes 12 -o tuned.json
+//! hangrier-balance --games 500 --seed 1000 --config tuned.json
+//! ```
+
+use clap::Parser;
+use sim::SimError;
+use sim::cli::SetupArgs;
+use sim::evolve::{self, EvolveOptions, Objective};
+use std::fs::File;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "hangrier-tune",
+    about = "Evolve Hangrier Games brain weights against simulated matches"
+)]
+struct Cli {
+    #[command(flatten)]
+    setup: SetupArgs,
+
+    /// JSON file of `Objective` fields (`target_days`, `length_weight`,
+    /// `diversity_weight`, `stalemate_weight`). Fields left out keep their
+    /// default values.
+    #[arg(long)]
+    objective: Option<PathBuf>,
+
+    /// Tunings alive in each generation.
+    #[arg(long, default_value_t = EvolveOptions::default().population)]
+    population: usize,
+
+    /// Generations bred after the first.
+    #[arg(long, default_value_t = EvolveOptions::default().generations)]
+    generations: u32,
+
+    /// Fittest tunings carried into the next generation unchanged.
+    #[arg(long, default_value_t = EvolveOptions::default().elite)]
+    elite: usize,
+
+    /// Chance each gene of a child mutates.
+    #[arg(long, default_value_t = EvolveOptions::default().mutation)]
+    mutation: f64,
+
+    /// Games each tuning plays, with seeds `--seed` onwards.
+    #[arg(long, default_value_t = EvolveOptions::default().games)]
+    games: u32,
+
+    /// Worker threads. Defaults to the available parallelism.
+    #[arg(long)]
+    jobs: Option<usize>,
+
+    /// Write the winning tuning here, as a config file that `--config`
+    /// accepts.
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("hangrier-tune: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), SimError> {
+    let template = cli.setup.to_options()?;
+    let objective = match &cli.objective {
+        Some(path) => {
+            serde_json::from_reader(File::open(path)?).map_err(SimError::InvalidObjective)?
+        }
+        None => Objective::default(),
+    };
+    let options = EvolveOptions {
+        population: cli.population,
+        generations: cli.generations,
+        elite: cli.elite,
+        mutation: cli.mutation,
+        games: cli.games,
+        jobs: cli.jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        }),
+    };
+    let evolution = evolve::evolve(&template, &objective, &options, |generation| {
+        eprintln!(
+            "generation {:>3}: best {:.4}, mean {:.4}",
+            generation.generation, generation.best, generation.mean
+        );
+    })?;
+
+    let patch = serde_json::json!({ "brain": evolution.best });
+    serde_json::to_writer_pretty(File::create(&cli.output)?, &patch)?;
+
+    println!("fitness {:.4}\n", evolution.fitness);
+    print!("{}", evolution.report);
+    Ok(())
+}
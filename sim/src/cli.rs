@@ -0,0 +1,81 @@
+//! Flags shared by the `hangrier-sim` and `hangrier-balance` binaries.
+
+use crate::{SimError, SimOptions};
+use game::config::GameConfig;
+use std::fs::File;
+use std::path::PathBuf;
+
+/// How each game is set up and how long it may run.
+#[derive(clap::Args, Debug)]
+pub struct SetupArgs {
+    /// RNG seed. A random one is chosen (and reported on stderr) if omitted.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Number of tributes, assigned to the districts in turn. Defaults to
+    /// two per district.
+    #[arg(long)]
+    pub tributes: Option<u32>,
+
+    /// Number of districts, 1-12. Defaults to half the tributes.
+    #[arg(long)]
+    pub districts: Option<u32>,
+
+    /// Hex rings of sectors around the Cornucopia, 1-4.
+    #[arg(long)]
+    pub rings: Option<u32>,
+
+    /// Random items placed in each area before the game starts.
+    #[arg(long, default_value_t = shared::ItemQuantity::default().base_item_count())]
+    pub items_per_area: u32,
+
+    /// Stop after this many days even if nobody has won.
+    #[arg(long, default_value_t = crate::DEFAULT_MAX_DAYS)]
+    pub max_days: u32,
+
+    /// JSON file of `GameConfig` fields to apply over the defaults. Fields
+    /// left out keep their default values.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// Override one `GameConfig` field, e.g. `--set trauma_enabled=false`.
+    /// Repeatable; applied after `--config`.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    pub overrides: Vec<String>,
+
+    /// Drive one district's tributes with a different brain, e.g.
+    /// `--brain 3=coward`. Strategies: builtin, random, coward. Repeatable.
+    #[arg(long = "brain", value_name = "DISTRICT=STRATEGY")]
+    pub brains: Vec<String>,
+}
+
+impl SetupArgs {
+    pub fn to_options(&self) -> Result<SimOptions, SimError> {
+        let mut config = GameConfig::default();
+        if let Some(path) = &self.config {
+            let patch = serde_json::from_reader(File::open(path)?)?;
+            config = crate::merge_config(&config, patch)?;
+        }
+        let mut config = crate::apply_overrides(&config, &self.overrides)?;
+        if self.tributes.is_some() || self.districts.is_some() {
+            (config.tribute_count, config.district_count) =
+                shared::resolve_roster(self.tributes, self.districts);
+        }
+        shared::validate_roster(config.tribute_count, config.district_count)
+            .map_err(|e| SimError::InvalidRoster(e.to_string()))?;
+        if let Some(rings) = self.rings {
+            config.arena_rings = rings;
+        }
+        if !(1..=shared::MAX_ARENA_RINGS).contains(&config.arena_rings) {
+            return Err(SimError::InvalidArena(config.arena_rings));
+        }
+
+        Ok(SimOptions {
+            seed: self.seed.unwrap_or_else(game::seeding::random_seed),
+            items_per_area: self.items_per_area,
+            max_days: self.max_days,
+            config,
+            brains: crate::parse_brains(&self.brains)?,
+        })
+    }
+}
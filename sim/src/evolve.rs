@@ -0,0 +1,408 @@
+//! Evolutionary tuning of the built-in brain.
+//!
+//! A [`BrainTuning`] is flattened into a genome, one gene per [`GENES`]
+//! entry. Every genome plays the same batch of seeded games (see
+//! [`balance::run_batch`]) and is scored by an [`Objective`] on the
+//! resulting [`BalanceReport`]. Each generation the fittest few carry over
+//! unchanged and breed the rest by tournament selection, uniform crossover
+//! and mutation.
+//!
+//! Breeding draws from the template's seed, so the same options always
+//! evolve the same tuning. Fitness is measured on one batch of seeds for
+//! the whole run; check the winner against a fresh range with
+//! `hangrier-balance` before trusting it.
+
+use crate::balance::{self, BalanceReport, DeathCategory};
+use crate::{SimError, SimOptions};
+use game::tributes::brains::tuning::BrainTuning;
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+use serde::{Deserialize, Serialize};
+use strum::EnumCount;
+
+/// Largest single mutation, as a fraction of the gene's range.
+const MUTATION_STEP: f64 = 0.25;
+
+/// One tunable [`BrainTuning`] field and the range it may evolve in.
+#[derive(Clone, Copy, Debug)]
+pub struct Gene {
+    pub field: &'static str,
+    pub min: f64,
+    pub max: f64,
+    /// Rounded to a whole number before it reaches the brain.
+    pub integer: bool,
+}
+
+impl Gene {
+    const fn int(field: &'static str, min: f64, max: f64) -> Self {
+        Self {
+            field,
+            min,
+            max,
+            integer: true,
+        }
+    }
+
+    const fn real(field: &'static str, min: f64, max: f64) -> Self {
+        Self {
+            field,
+            min,
+            max,
+            integer: false,
+        }
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let value = value.clamp(self.min, self.max);
+        if self.integer { value.round() } else { value }
+    }
+}
+
+/// The parameter vector, in genome order.
+pub const GENES: &[Gene] = &[
+    Gene::int("base_low_health", 5.0, 40.0),
+    Gene::int("base_mid_health", 20.0, 70.0),
+    Gene::int("base_extreme_low_sanity", 2.0, 20.0),
+    Gene::int("base_low_sanity", 5.0, 35.0),
+    Gene::int("base_mid_sanity", 15.0, 60.0),
+    Gene::int("base_low_movement", 2.0, 30.0),
+    Gene::int("base_high_intelligence", 15.0, 70.0),
+    Gene::int("base_low_intelligence", 50.0, 100.0),
+    Gene::int("base_psychotic_break", 1.0, 20.0),
+    Gene::int("low_enemy_limit", 2.0, 12.0),
+    Gene::int("sleep_dominant_threshold", 6.0, 20.0),
+    Gene::int("sleep_want_threshold", 2.0, 12.0),
+    Gene::int("sleep_exhausted_pct", 5.0, 50.0),
+    Gene::int("crowd_penalty_per_enemy", 0.0, 20.0),
+    Gene::int("crowd_penalty_max", 0.0, 80.0),
+    Gene::int("corpse_loot_bonus_max", 0.0, 40.0),
+    Gene::real("fatigue_weight", 0.0, 3.0),
+    Gene::real("emotion_weight", 0.0, 3.0),
+    Gene::int("emotional_attack_urge", 0.0, 30.0),
+    Gene::int("emotional_flight_urge", -30.0, 0.0),
+    Gene::int("despair_morale", 0.0, 50.0),
+];
+
+/// `tuning`'s genes, in [`GENES`] order.
+pub fn genome_of(tuning: &BrainTuning) -> Vec<f64> {
+    let value = serde_json::to_value(tuning).expect("BrainTuning serializes");
+    GENES
+        .iter()
+        .map(|gene| {
+            value[gene.field]
+                .as_f64()
+                .expect("every gene names a numeric BrainTuning field")
+        })
+        .collect()
+}
+
+/// The default tuning with `genome` written over the fields it covers.
+pub fn tuning_from(genome: &[f64]) -> BrainTuning {
+    let mut value = serde_json::to_value(BrainTuning::default()).expect("BrainTuning serializes");
+    for (gene, &raw) in GENES.iter().zip(genome) {
+        let raw = gene.clamp(raw);
+        value[gene.field] = if gene.integer {
+            serde_json::json!(raw as i64)
+        } else {
+            serde_json::json!(raw)
+        };
+    }
+    serde_json::from_value(value).expect("every gene fits its BrainTuning field")
+}
+
+/// What the designer wants from a batch of games. Higher scores are
+/// better; each term is weighted so they can be traded off.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Objective {
+    /// Mean game length to aim for, in days.
+    pub target_days: f64,
+    /// Penalty per `target_days` of distance from it.
+    pub length_weight: f64,
+    /// Reward for a spread of death causes: the normalized entropy of
+    /// the causes, 0 when everyone dies the same way and 1 when every
+    /// cause is equally common.
+    pub diversity_weight: f64,
+    /// Penalty per share of games still running at `max_days`.
+    pub stalemate_weight: f64,
+}
+
+impl Default for Objective {
+    fn default() -> Self {
+        Self {
+            target_days: 7.0,
+            length_weight: 1.0,
+            diversity_weight: 1.0,
+            stalemate_weight: 2.0,
+        }
+    }
+}
+
+impl Objective {
+    pub fn score(&self, report: &BalanceReport) -> f64 {
+        let length_miss = (report.mean_days - self.target_days).abs() / self.target_days.max(1.0);
+        let stalemates = if report.games == 0 {
+            0.0
+        } else {
+            f64::from(report.outcomes.timed_out) / f64::from(report.games)
+        };
+        self.diversity_weight * death_cause_diversity(report)
+            - self.length_weight * length_miss
+            - self.stalemate_weight * stalemates
+    }
+}
+
+/// Normalized entropy of the report's death causes, in 0..=1.
+fn death_cause_diversity(report: &BalanceReport) -> f64 {
+    let entropy: f64 = report
+        .death_causes
+        .values()
+        .filter(|cause| cause.share > 0.0)
+        .map(|cause| -cause.share * cause.share.ln())
+        .sum();
+    entropy / (DeathCategory::COUNT as f64).ln()
+}
+
+/// Shape of the search.
+#[derive(Clone, Debug)]
+pub struct EvolveOptions {
+    pub population: usize,
+    pub generations: u32,
+    /// Fittest genomes carried into the next generation unchanged.
+    pub elite: usize,
+    /// Chance each gene of a child mutates.
+    pub mutation: f64,
+    /// Games each genome plays per evaluation.
+    pub games: u32,
+    pub jobs: usize,
+}
+
+impl Default for EvolveOptions {
+    fn default() -> Self {
+        Self {
+            population: 16,
+            generations: 10,
+            elite: 2,
+            mutation: 0.2,
+            games: 20,
+            jobs: 1,
+        }
+    }
+}
+
+/// Fitness of one generation, after it has been scored.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Generation {
+    pub generation: u32,
+    pub best: f64,
+    pub mean: f64,
+}
+
+/// The fittest tuning found and how the search got there.
+#[derive(Clone, Debug)]
+pub struct Evolution {
+    pub best: BrainTuning,
+    pub fitness: f64,
+    /// The best tuning's batch.
+    pub report: BalanceReport,
+    pub history: Vec<Generation>,
+}
+
+struct Scored {
+    genome: Vec<f64>,
+    fitness: f64,
+    report: BalanceReport,
+}
+
+/// Evolve a brain tuning against games set up like `template`, starting
+/// from `template.config.brain`. `on_generation` sees each generation's
+/// fitness as soon as it is scored.
+pub fn evolve<F>(
+    template: &SimOptions,
+    objective: &Objective,
+    options: &EvolveOptions,
+    mut on_generation: F,
+) -> Result<Evolution, SimError>
+where
+    F: FnMut(&Generation),
+{
+    let mut rng = SmallRng::seed_from_u64(template.seed);
+    let population = options.population.max(2);
+    let elite = options.elite.clamp(1, population);
+
+    // The starting tuning competes alongside random ones, so the result
+    // is never worse than what we began with on this batch.
+    let mut genomes = vec![genome_of(&template.config.brain)];
+    while genomes.len() < population {
+        genomes.push(
+            GENES
+                .iter()
+                .map(|gene| gene.clamp(rng.random_range(gene.min..=gene.max)))
+                .collect(),
+        );
+    }
+    let mut ranked = Vec::with_capacity(population);
+    for genome in genomes {
+        ranked.push(evaluate(template, objective, options, genome)?);
+    }
+
+    let mut history = Vec::new();
+    for generation in 0..=options.generations {
+        if generation > 0 {
+            let children: Vec<Vec<f64>> = (elite..population)
+                .map(|_| breed(&ranked, options.mutation, &mut rng))
+                .collect();
+            ranked.truncate(elite);
+            for child in children {
+                ranked.push(evaluate(template, objective, options, child)?);
+            }
+        }
+        ranked.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
+
+        let summary = Generation {
+            generation,
+            best: ranked[0].fitness,
+            mean: ranked.iter().map(|s| s.fitness).sum::<f64>() / ranked.len() as f64,
+        };
+        on_generation(&summary);
+        history.push(summary);
+    }
+
+    let best = ranked.swap_remove(0);
+    Ok(Evolution {
+        best: tuning_from(&best.genome),
+        fitness: best.fitness,
+        report: best.report,
+        history,
+    })
+}
+
+fn evaluate(
+    template: &SimOptions,
+    objective: &Objective,
+    options: &EvolveOptions,
+    genome: Vec<f64>,
+) -> Result<Scored, SimError> {
+    let mut setup = template.clone();
+    setup.config.brain = tuning_from(&genome);
+    let tallies = balance::run_batch(&setup, options.games, options.jobs)?;
+    let report = BalanceReport::from_tallies(template.seed, &tallies);
+    Ok(Scored {
+        genome,
+        fitness: objective.score(&report),
+        report,
+    })
+}
+
+fn breed(parents: &[Scored], mutation: f64, rng: &mut SmallRng) -> Vec<f64> {
+    let mother = tournament(parents, rng);
+    let father = tournament(parents, rng);
+    GENES
+        .iter()
+        .zip(mother.genome.iter().zip(&father.genome))
+        .map(|(gene, (&m, &f))| {
+            let mut value = if rng.random_bool(0.5) { m } else { f };
+            if rng.random_bool(mutation.clamp(0.0, 1.0)) {
+                let step = (gene.max - gene.min) * MUTATION_STEP;
+                value += rng.random_range(-step..=step);
+            }
+            gene.clamp(value)
+        })
+        .collect()
+}
+
+/// The fitter of two parents picked at random.
+fn tournament<'a>(parents: &'a [Scored], rng: &mut SmallRng) -> &'a Scored {
+    let a = &parents[rng.random_range(0..parents.len())];
+    let b = &parents[rng.random_range(0..parents.len())];
+    if a.fitness >= b.fitness { a } else { b }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance::{Outcomes, Share};
+    use game::config::GameConfig;
+
+    fn report(mean_days: f64, timed_out: u32, causes: &[(DeathCategory, f64)]) -> BalanceReport {
+        BalanceReport {
+            games: 4,
+            mean_days,
+            outcomes: Outcomes {
+                winner: 4 - timed_out,
+                timed_out,
+                ..Outcomes::default()
+            },
+            death_causes: causes
+                .iter()
+                .map(|&(cause, share)| (cause, Share { count: 1, share }))
+                .collect(),
+            ..BalanceReport::default()
+        }
+    }
+
+    #[test]
+    fn default_tuning_survives_the_genome_round_trip() {
+        let tuning = BrainTuning::default();
+        let genome = genome_of(&tuning);
+        assert_eq!(genome.len(), GENES.len());
+        assert_eq!(tuning_from(&genome), tuning);
+
+        let wild = tuning_from(&[1000.0; GENES.len()]);
+        assert_eq!(wild.low_enemy_limit, 12);
+        assert_eq!(wild.emotion_weight, 3.0);
+    }
+
+    #[test]
+    fn objective_rewards_length_spread_and_finished_games() {
+        let objective = Objective::default();
+        let combat = [(DeathCategory::Combat, 1.0)];
+        let mixed = [
+            (DeathCategory::Combat, 0.5),
+            (DeathCategory::Starvation, 0.25),
+            (DeathCategory::AreaEvent, 0.25),
+        ];
+
+        let on_target = objective.score(&report(7.0, 0, &combat));
+        assert_eq!(on_target, 0.0);
+        assert!(objective.score(&report(14.0, 0, &combat)) < on_target);
+        assert!(objective.score(&report(7.0, 1, &combat)) < on_target);
+        assert!(objective.score(&report(7.0, 0, &mixed)) > on_target);
+    }
+
+    #[test]
+    fn evolution_is_reproducible_and_never_loses_ground() {
+        let template = SimOptions {
+            seed: 9,
+            max_days: 10,
+            config: GameConfig {
+                tribute_count: 6,
+                district_count: 6,
+                ..GameConfig::default()
+            },
+            ..SimOptions::default()
+        };
+        let options = EvolveOptions {
+            population: 4,
+            generations: 2,
+            elite: 1,
+            games: 2,
+            ..EvolveOptions::default()
+        };
+        let run = || evolve(&template, &Objective::default(), &options, |_| {}).unwrap();
+
+        let first = run();
+        assert_eq!(first.history.len(), 3);
+        assert!(
+            first
+                .history
+                .windows(2)
+                .all(|pair| pair[1].best >= pair[0].best)
+        );
+        assert_eq!(first.fitness, first.history[2].best);
+
+        let second = run();
+        assert_eq!(first.best, second.best);
+        assert_eq!(first.history, second.history);
+    }
+}
@@ -0,0 +1,339 @@
+//! # Sim — headless game runner.
+//!
+//! Builds a [`Game`] entirely in memory and plays it to the end with
+//! `run_next_phase`, no API or SurrealDB involved. Setup and play are both
+//! driven by [`SimOptions::seed`], so two runs with the same options emit
+//! the same message log (wall-clock timestamps aside).
+
+use game::config::GameConfig;
+use game::games::{Game, GameError};
+use game::items::Item;
+use game::tributes::Tribute;
+use game::tributes::brains::strategy::BrainStrategy;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use shared::GameStatus;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+pub mod balance;
+pub mod cli;
+pub mod evolve;
+
+/// Hard stop for games that never produce a winner.
+pub const DEFAULT_MAX_DAYS: u32 = 100;
+
+#[derive(Error, Debug)]
+pub enum SimError {
+    #[error("game error: {0}")]
+    Game(#[from] GameError),
+
+    #[error("expected KEY=VALUE, got `{0}`")]
+    MalformedOverride(String),
+
+    #[error("expected DISTRICT=STRATEGY, got `{0}`")]
+    MalformedBrain(String),
+
+    #[error("{0}")]
+    UnknownBrain(String),
+
+    #[error("unknown GameConfig field `{0}`")]
+    UnknownConfigKey(String),
+
+    #[error("invalid GameConfig: {0}")]
+    InvalidConfig(#[from] serde_json::Error),
+
+    #[error("invalid objective: {0}")]
+    InvalidObjective(serde_json::Error),
+
+    #[error("invalid roster: {0}")]
+    InvalidRoster(String),
+
+    #[error("arena must have 1-{max} rings, got {0}", max = shared::MAX_ARENA_RINGS)]
+    InvalidArena(u32),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Everything needed to set up and play one game.
+#[derive(Clone, Debug)]
+pub struct SimOptions {
+    pub seed: u64,
+    /// Items spawned in each area at setup, matching the API's
+    /// `ItemQuantity` presets.
+    pub items_per_area: u32,
+    pub max_days: u32,
+    pub config: GameConfig,
+    /// Brain strategy per district; districts left out use the built-in
+    /// brain.
+    pub brains: BTreeMap<u32, BrainStrategy>,
+}
+
+impl Default for SimOptions {
+    fn default() -> Self {
+        Self {
+            seed: game::seeding::random_seed(),
+            items_per_area: shared::ItemQuantity::default().base_item_count(),
+            max_days: DEFAULT_MAX_DAYS,
+            config: GameConfig::default(),
+            brains: BTreeMap::new(),
+        }
+    }
+}
+
+/// Build a started game the way quickstart does: the config's roster reaped
+/// from its districts in turn, and an arena of the config's size with
+/// generated terrain, each area stocked with `items_per_area` random items.
+pub fn build_game(options: &SimOptions) -> Result<Game, SimError> {
+    // Setup draws from its own stream; phases derive theirs from
+    // `(seed, day, phase)` and never see this one.
+    let mut rng = SmallRng::seed_from_u64(options.seed);
+
+    let mut game = Game::new("Simulation");
+    game.identifier = game::seeding::uuid_from_rng(&mut rng).to_string();
+    game.seed = options.seed;
+    game.config = options.config.clone();
+
+    for mut details in game.arena().generate(&mut rng) {
+        details.identifier = game::seeding::uuid_from_rng(&mut rng).to_string();
+        for _ in 0..options.items_per_area {
+            details
+                .items
+                .push(Item::new_random_with_rng(None, &mut rng));
+        }
+        game.areas.push(details);
+    }
+
+    for idx in 0..options.config.tribute_count {
+        let mut tribute =
+            Tribute::random_for_config(&options.config, options.config.district_for(idx), &mut rng);
+        tribute.statistics.game = game.identifier.clone();
+        if let Some(strategy) = options.brains.get(&tribute.district) {
+            tribute.strategy = *strategy;
+        }
+        game.tributes.push(tribute);
+    }
+
+    game.start()?;
+    Ok(game)
+}
+
+/// Play `game` a phase at a time until it finishes or reaches `max_days`.
+///
+/// Play stops with the phase that ends the game, so the tribute crowned by
+/// its `GameEnded` message is still the one standing afterwards.
+///
+/// `after_day` sees the game at the end of each day, and once more if it
+/// finishes mid-day, with the messages since the last call still in
+/// `game.messages`; they are cleared after each call.
+pub fn run_to_completion<F>(
+    game: &mut Game,
+    max_days: u32,
+    mut after_day: F,
+) -> Result<(), SimError>
+where
+    F: FnMut(&Game) -> Result<(), SimError>,
+{
+    while game.status != GameStatus::Finished
+        && (game.resting_phase.is_some() || game.day.unwrap_or(0) < max_days)
+    {
+        game.run_next_phase()?;
+        if game.status == GameStatus::Finished || game.resting_phase.is_none() {
+            after_day(game)?;
+            game.messages.clear();
+        }
+    }
+    Ok(())
+}
+
+/// Parse `DISTRICT=STRATEGY` assignments, e.g. `3=coward`.
+pub fn parse_brains(raw: &[String]) -> Result<BTreeMap<u32, BrainStrategy>, SimError> {
+    raw.iter()
+        .map(|entry| {
+            let (district, strategy) = entry
+                .split_once('=')
+                .ok_or_else(|| SimError::MalformedBrain(entry.clone()))?;
+            let district = district
+                .trim()
+                .parse()
+                .map_err(|_| SimError::MalformedBrain(entry.clone()))?;
+            let strategy = strategy.trim().parse().map_err(SimError::UnknownBrain)?;
+            Ok((district, strategy))
+        })
+        .collect()
+}
+
+/// Apply `KEY=VALUE` overrides to `config` by field name.
+///
+/// Values are parsed as JSON first (`0.5`, `true`, `12`) and fall back to a
+/// bare string; see [`merge_config`] for how they are applied.
+pub fn apply_overrides(config: &GameConfig, overrides: &[String]) -> Result<GameConfig, SimError> {
+    let mut patch = serde_json::Map::new();
+    for raw in overrides {
+        let (key, val) = raw
+            .split_once('=')
+            .ok_or_else(|| SimError::MalformedOverride(raw.clone()))?;
+        let val = val.trim();
+        let parsed = serde_json::from_str(val)
+            .unwrap_or_else(|_| serde_json::Value::String(val.to_string()));
+        patch.insert(key.trim().to_string(), parsed);
+    }
+    merge_config(config, patch)
+}
+
+/// Overlay `patch` onto `config` field by field.
+///
+/// The merged object goes back through `GameConfig`'s own deserializer, so
+/// a type mismatch is an error rather than a silently ignored field.
+pub fn merge_config(
+    config: &GameConfig,
+    patch: serde_json::Map<String, serde_json::Value>,
+) -> Result<GameConfig, SimError> {
+    let mut value = serde_json::to_value(config)?;
+    let fields = value
+        .as_object_mut()
+        .expect("GameConfig serializes to an object");
+
+    for (key, val) in patch {
+        let slot = fields
+            .get_mut(&key)
+            .ok_or(SimError::UnknownConfigKey(key))?;
+        *slot = val;
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_options(seed: u64) -> SimOptions {
+        SimOptions {
+            seed,
+            max_days: 15,
+            config: GameConfig {
+                tribute_count: 8,
+                district_count: 8,
+                ..GameConfig::default()
+            },
+            ..SimOptions::default()
+        }
+    }
+
+    fn log_without_timestamps(options: &SimOptions) -> Vec<serde_json::Value> {
+        let mut game = build_game(options).unwrap();
+        let mut log = Vec::new();
+        run_to_completion(&mut game, options.max_days, |game| {
+            for message in &game.messages {
+                let mut value = serde_json::to_value(message)?;
+                value.as_object_mut().unwrap().remove("timestamp");
+                log.push(value);
+            }
+            Ok(())
+        })
+        .unwrap();
+        log
+    }
+
+    #[test]
+    fn brains_are_assigned_by_district() {
+        let brains = parse_brains(&["1=coward".to_string(), " 2 = random ".to_string()]).unwrap();
+        let options = SimOptions {
+            brains,
+            ..small_options(3)
+        };
+        let game = build_game(&options).unwrap();
+        for tribute in &game.tributes {
+            let expected = match tribute.district {
+                1 => BrainStrategy::Coward,
+                2 => BrainStrategy::Random,
+                _ => BrainStrategy::Builtin,
+            };
+            assert_eq!(tribute.strategy, expected);
+        }
+
+        assert!(matches!(
+            parse_brains(&["coward".to_string()]),
+            Err(SimError::MalformedBrain(_))
+        ));
+        assert!(matches!(
+            parse_brains(&["1=genius".to_string()]),
+            Err(SimError::UnknownBrain(_))
+        ));
+    }
+
+    #[test]
+    fn build_game_populates_areas_and_tributes() {
+        let game = build_game(&small_options(1)).unwrap();
+        assert_eq!(game.areas.len(), game.arena().size());
+        assert!(game.areas.iter().all(|a| a.items.len() == 3));
+        assert_eq!(game.tributes.len(), 8);
+        assert_eq!(game.status, GameStatus::InProgress);
+    }
+
+    #[test]
+    fn play_stops_with_the_crowned_winner_standing() {
+        let options = small_options(2);
+        let mut game = build_game(&options).unwrap();
+        let mut crowned = Vec::new();
+        run_to_completion(&mut game, options.max_days, |game| {
+            crowned.extend(game.messages.iter().filter_map(|m| match &m.payload {
+                game::messages::MessagePayload::GameEnded { winner } => Some(winner.clone()),
+                _ => None,
+            }));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(game.status, GameStatus::Finished);
+        assert_eq!(crowned.len(), 1, "the game ends exactly once");
+        let crowned = crowned[0].as_ref().expect("seed 2 crowns a winner");
+        let winner = game
+            .winner()
+            .expect("the crowned tribute is still standing");
+        assert_eq!(winner.identifier, crowned.identifier.to_string());
+    }
+
+    #[test]
+    fn same_seed_same_log_from_scratch() {
+        let options = small_options(77);
+        let first = log_without_timestamps(&options);
+        assert!(!first.is_empty());
+        assert_eq!(first, log_without_timestamps(&options));
+    }
+
+    #[test]
+    fn overrides_update_named_fields() {
+        let config = apply_overrides(
+            &GameConfig::default(),
+            &[
+                "day_event_frequency=0.9".to_string(),
+                "trauma_enabled=false".to_string(),
+                "max_strength = 20".to_string(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(config.day_event_frequency, 0.9);
+        assert!(!config.trauma_enabled);
+        assert_eq!(config.max_strength, 20);
+    }
+
+    #[test]
+    fn overrides_reject_bad_input() {
+        let config = GameConfig::default();
+        assert!(matches!(
+            apply_overrides(&config, &["nope".to_string()]),
+            Err(SimError::MalformedOverride(_))
+        ));
+        assert!(matches!(
+            apply_overrides(&config, &["not_a_field=1".to_string()]),
+            Err(SimError::UnknownConfigKey(_))
+        ));
+        assert!(matches!(
+            apply_overrides(&config, &["max_strength=lots".to_string()]),
+            Err(SimError::InvalidConfig(_))
+        ));
+    }
+}